@@ -1,16 +1,226 @@
-use clap::{arg, value_parser, ArgMatches, Command};
-use radlr_build::BuildConfig;
-use radlr_core::{ParserConfig, RadlrError, RadlrResult};
+use clap::{arg, value_parser, Arg, ArgAction, ArgMatches, Command};
+use radlr_build::{BuildConfig, ParserType, TargetLanguage};
+use radlr_core::{worker_pool::StandardPool, OptimizeConfig, ParserConfig, RadlrError, RadlrGrammar, RadlrResult};
 use radlr_lab::run_lab_server;
-use std::path::PathBuf;
+use radlr_rust_runtime::kernel::disassemble_bytecode_db;
+use std::{
+  fs::OpenOptions,
+  io::Write,
+  path::{Path, PathBuf},
+};
 
-#[derive(Clone, Debug)]
-enum ParserType {
-  LLVM,
-  Bytecode,
+/// Presets that map onto the [ParserConfig] builder methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ParserMode {
+  /// `ParserConfig::default()`
+  Default,
+  /// `ParserConfig::hybrid()` - RAD(k) parser.
+  Hybrid,
+  /// `ParserConfig::g_hybrid()` - RAD(k) parser with forking.
+  GHybrid,
+  /// `ParserConfig::lrk()`
+  Lrk,
+  /// `ParserConfig::llk()`
+  Llk,
+  /// `ParserConfig::ll1()`
+  Ll1,
+  /// `ParserConfig::glr()`
+  Glr,
+  /// `ParserConfig::gll()`
+  Gll,
+  /// `ParserConfig::recursive_descent_k()`
+  RecursiveDescent,
+  /// `ParserConfig::g_recursive_descent_k()`
+  GRecursiveDescent,
+  /// `ParserConfig::cst_editor()`
+  CstEditor,
+}
+
+impl ParserMode {
+  const NAMES: [&'static str; 11] =
+    ["default", "hybrid", "ghybrid", "lrk", "llk", "ll1", "glr", "gll", "rd", "grd", "cst-editor"];
+
+  fn parse(value: &str) -> Result<Self, String> {
+    match value {
+      "default" => Ok(Self::Default),
+      "hybrid" | "rad" => Ok(Self::Hybrid),
+      "ghybrid" | "grad" => Ok(Self::GHybrid),
+      "lrk" | "lr" => Ok(Self::Lrk),
+      "llk" | "ll" => Ok(Self::Llk),
+      "ll1" => Ok(Self::Ll1),
+      "glr" => Ok(Self::Glr),
+      "gll" => Ok(Self::Gll),
+      "rd" | "recursive-descent" => Ok(Self::RecursiveDescent),
+      "grd" => Ok(Self::GRecursiveDescent),
+      "cst-editor" => Ok(Self::CstEditor),
+      _ => Err(format!("expected one of [ {} ]", Self::NAMES.join(" | "))),
+    }
+  }
+
+  fn name(&self) -> &'static str {
+    Self::NAMES[*self as usize]
+  }
+
+  /// Modes whose lookahead is set by the `k` argument of the preset.
+  fn takes_k(&self) -> bool {
+    matches!(self, Self::Lrk | Self::Llk | Self::RecursiveDescent)
+  }
+
+  /// Modes that require LR style states.
+  fn requires_lr(&self) -> bool {
+    matches!(self, Self::Lrk | Self::Glr)
+  }
+
+  /// Modes that require recursive descent style call states.
+  fn requires_calls(&self) -> bool {
+    matches!(self, Self::RecursiveDescent | Self::GRecursiveDescent)
+  }
+}
+
+/// Arguments that configure the [ParserConfig] used to compile a grammar.
+fn parser_config_args() -> [Arg; 10] {
+  [
+    arg!( -m --mode <MODE> "The type of parser to construct. One of \n  default | hybrid | ghybrid | lrk | llk | ll1 | glr | gll | rd | grd | cst-editor\n  Defaults to `default`" )
+      .required(false)
+      .value_parser(ParserMode::parse),
+    arg!( -k --k <K> "The maximum number of lookahead tokens allowed when resolving conflicts. Must be greater than 0" )
+      .required(false)
+      .value_parser(value_parser!(u32).range(1..)),
+    arg!( --"no-lr" "Disable LR style states. Grammars that require them, such as left recursive grammars, will be rejected" )
+      .required(false),
+    arg!( --"no-calls" "Disable recursive descent style call states" )
+      .required(false),
+    arg!( --"no-peek" "Disable lookahead (k > 1) states" )
+      .required(false),
+    arg!( --forks "Allow the parser to fork its context to handle ambiguities" )
+      .required(false),
+    arg!( --"context-free" "Build a single context free scanner instead of contextual scanners" )
+      .required(false),
+    arg!( --"lookahead-scanners" "Build FOLLOW aware scanners. May significantly increase the number of scanner states" )
+      .required(false),
+    arg!( --"export-all" "Export all non-terminals as parser entry points" )
+      .required(false),
+    arg!( --"byte-sequences" "Recognize sequences of characters using wide data types" )
+      .required(false),
+  ]
+}
+
+/// Builds a [ParserConfig] from a `--mode` preset and the individual parser
+/// configuration flags, rejecting combinations that contradict each other.
+fn configure_parser(matches: &ArgMatches) -> RadlrResult<ParserConfig> {
+  let mode = matches.get_one::<ParserMode>("mode").cloned().unwrap_or(ParserMode::Default);
+  let k = matches.get_one::<u32>("k").cloned();
+  let flag = |name: &str| matches.get_flag(name);
+
+  let no_lr = flag("no-lr");
+  let no_calls = flag("no-calls");
+  let no_peek = flag("no-peek");
+  let forks = flag("forks");
+
+  let mut errors = vec![];
+
+  if no_lr && mode.requires_lr() {
+    errors.push(format!("--no-lr cannot be used with the `{}` mode, which requires LR states", mode.name()));
+  }
+
+  if no_calls && mode.requires_calls() {
+    errors.push(format!("--no-calls cannot be used with the `{}` mode, which requires call states", mode.name()));
+  }
+
+  if let Some(k) = k {
+    if no_peek && k > 1 {
+      errors.push(format!("--k {k} requires lookahead states, which are disabled by --no-peek"));
+    }
+
+    if mode == ParserMode::Ll1 && k > 1 {
+      errors.push(format!("--k {k} cannot be used with the `ll1` mode, which is limited to one token of lookahead"));
+    }
+  }
+
+  if forks && matches!(mode, ParserMode::Lrk | ParserMode::Llk | ParserMode::Ll1) {
+    errors.push(format!(
+      "--forks cannot be used with the deterministic `{}` mode; use glr, gll, ghybrid, or grd instead",
+      mode.name()
+    ));
+  }
+
+  if !errors.is_empty() {
+    return Err(RadlrError::from(format!("Invalid parser configuration:\n  {}", errors.join("\n  "))));
+  }
+
+  let preset_k = if mode.takes_k() { k.unwrap_or(8) } else { 8 };
+
+  let mut config = match mode {
+    ParserMode::Default => ParserConfig::default(),
+    ParserMode::Hybrid => ParserConfig::default().hybrid(),
+    ParserMode::GHybrid => ParserConfig::default().g_hybrid(),
+    ParserMode::Lrk => ParserConfig::default().lrk(preset_k),
+    ParserMode::Llk => ParserConfig::default().llk(preset_k),
+    ParserMode::Ll1 => ParserConfig::default().ll1(),
+    ParserMode::Glr => ParserConfig::default().glr(),
+    ParserMode::Gll => ParserConfig::default().gll(),
+    ParserMode::RecursiveDescent => ParserConfig::default().recursive_descent_k(preset_k),
+    ParserMode::GRecursiveDescent => ParserConfig::default().g_recursive_descent_k(),
+    ParserMode::CstEditor => ParserConfig::default().cst_editor(),
+  };
+
+  if let Some(k) = k {
+    config = config.set_k(k);
+  }
+
+  if no_lr {
+    config.ALLOW_LR = false;
+  }
+
+  if no_calls {
+    config = config.use_call_states(false);
+  }
+
+  if no_peek {
+    config = config.set_k(1);
+  }
+
+  if forks {
+    config = config.use_fork_states(true);
+  }
+
+  if flag("context-free") {
+    config = config.force_context_free(true);
+  }
+
+  if flag("lookahead-scanners") {
+    config = config.use_lookahead_scanners(true);
+  }
+
+  if flag("export-all") {
+    config.EXPORT_ALL_NONTERMS = true;
+  }
+
+  if flag("byte-sequences") {
+    config.ALLOW_BYTE_SEQUENCES = true;
+  }
+
+  Ok(config)
+}
+
+/// The `-I` argument, shared by all commands that load grammars.
+fn include_path_arg() -> Arg {
+  arg!( -I --include <INCLUDE_PATH> "A directory to search for imported grammars. May be given more than once.\n  Searched after the directory of the importing grammar, then the directories of RADLR_INCLUDE_PATH, then the current directory" )
+    .required(false)
+    .action(ArgAction::Append)
+    .value_parser(value_parser!(PathBuf))
+}
+
+/// Returns the paths of all `-I` arguments, in the order they were given.
+fn include_paths(matches: &ArgMatches) -> Vec<PathBuf> {
+  matches.get_many::<PathBuf>("include").unwrap_or_default().cloned().collect()
 }
 
 pub fn command() -> ArgMatches {
+  cli().get_matches()
+}
+
+fn cli() -> Command {
   Command::new("Radlr")
     .version(env!("CARGO_PKG_VERSION"))
     .author("Anthony Weathersby <acweathersby.codes@gmail.com>")
@@ -18,6 +228,8 @@ pub fn command() -> ArgMatches {
     .subcommand(
         Command::new("disassemble")
         .about("Produce a disassembly file representing the bytecode of a parser for a specific grammar.")
+        .args(parser_config_args())
+        .arg(include_path_arg())
         .arg(
           arg!( -o --out <OUTPUT_PATH> "The path to the directory which the disassembly file(s) will be written to.\n    Defaults to the CWD" )
           .required(false)
@@ -27,6 +239,44 @@ pub fn command() -> ArgMatches {
             arg!(<INPUTS>)
                 .help("Path(s) to source grammar files")
                 .required(true)
+                .num_args(1..)
+                .value_parser(value_parser!(PathBuf))
+        )
+        .arg_required_else_help(true)
+    )
+    .subcommand(
+        Command::new("dump")
+        .about("Write the FIRST and FOLLOW sets of a grammar, and the item sets of its parse graph nodes, in text and JSON forms, and Graphviz renderings of its parse graph and IR states.")
+        .args(parser_config_args())
+        .arg(include_path_arg())
+        .arg(
+          arg!( -e --entry <ENTRY_NAME> "Only render the states reachable from the named entry point in the Graphviz files" )
+          .required(false)
+          .value_parser(value_parser!(String))
+        )
+        .arg(
+          arg!( -o --out <OUTPUT_PATH> "The path to the directory in which a `<grammar_name>.debug` directory of dump files will be written.\n    Defaults to the CWD" )
+          .required(false)
+          .value_parser(value_parser!(PathBuf))
+        )
+        .arg(
+            arg!(<INPUTS>)
+                .help("Path(s) to source grammar files")
+                .required(true)
+                .num_args(1..)
+                .value_parser(value_parser!(PathBuf))
+        )
+        .arg_required_else_help(true)
+    )
+    .subcommand(
+        Command::new("check")
+        .about("Analyzes grammars, reporting unreachable, unproductive, nullable, and recursive rules, and unused symbols, as warnings.")
+        .arg(include_path_arg())
+        .arg(
+            arg!(<INPUTS>)
+                .help("Path(s) to source grammar files")
+                .required(true)
+                .num_args(1..)
                 .value_parser(value_parser!(PathBuf))
         )
         .arg_required_else_help(true)
@@ -39,12 +289,15 @@ pub fn command() -> ArgMatches {
     .subcommand(
       Command::new("build")
         .about("Constructs a parser from a Radlr grammar.")
+        .args(parser_config_args())
+        .arg(include_path_arg())
         .arg(
-          arg!( -t --type <TYPE> "The type of parser Radlr will construct\n" )
+          arg!( -t --type <TYPE> "The type of parser Radlr will construct. One of `bytecode | llvm`\n" )
           .required(false)
           .value_parser(|value: &str| -> Result<ParserType, &'static str> {match value {
-            "llvm" => Ok(ParserType::LLVM),
-            _ => Ok(ParserType::Bytecode)
+            "bytecode" => Ok(ParserType::Bytecode),
+            "llvm" | "binary" => Ok(ParserType::Binary),
+            _ => Err("expected one of [ bytecode | llvm ]")
           }})
           .default_value("bytecode")
         )
@@ -56,10 +309,32 @@ pub fn command() -> ArgMatches {
           arg!( -a --ast "Create AST code, in the target language, from AScripT definitions" )
           .required(false)
         )
+        .arg(
+          arg!( --"state-cache" <CACHE_PATH> "Directory in which the parse states of non-terminals are cached between builds.\n  Only the states of non-terminals affected by grammar changes are rebuilt" )
+          .required(false)
+          .value_parser(value_parser!(PathBuf))
+        )
+        .arg(
+          arg!( --"opt-passes" <PASSES> "Comma separated list of the optimization passes to run, in order. `default` expands to the\n  default passes, `none` runs no passes, and `-<pass>` removes a pass listed before it. Passes:\n  garbage-collect | canonicalize | canonicalize-full | merge-branches | combine-branches | inline-states\n  inline-scanners | byte-sequences | remove-redundant-defaults" )
+          .required(false)
+          .value_parser(|value: &str| OptimizeConfig::parse(value).map_err(|err| err.to_string()))
+        )
+        .arg(
+          arg!( --"opt-report" "Prints the number of states and the complexity of the parser after each optimization pass" )
+          .required(false)
+        )
         .arg(
           arg!( -l --lang <LANGUAGE>)
           .help("The target programming language the parser will be written in.\n")
-          .value_parser(["rust", "r", "ts", "typescript"])
+          .value_parser(|value: &str| -> Result<TargetLanguage, &'static str> {match value {
+            "rust" | "r" => Ok(TargetLanguage::Rust),
+            "typescript" | "ts" => Ok(TargetLanguage::TypeScript),
+            "javascript" | "js" => Ok(TargetLanguage::JavaScript),
+            "c" => Ok(TargetLanguage::C),
+            "cpp" | "c++" => Ok(TargetLanguage::Cpp),
+            "llvm" => Ok(TargetLanguage::Llvm),
+            _ => Err("expected one of [ rust | typescript | javascript | c | cpp | llvm ]")
+          }})
           .required(false)
           .default_value("rust")
         )
@@ -89,7 +364,6 @@ pub fn command() -> ArgMatches {
         .arg_required_else_help(true)
     )
     .arg_required_else_help(true)
-    .get_matches()
 }
 
 fn configure_matches(matches: &ArgMatches, pwd: &PathBuf) -> (ParserType, PathBuf, PathBuf) {
@@ -110,8 +384,12 @@ fn main() -> RadlrResult<()> {
 
   if let Some(matches) = matches.subcommand_matches("build") {
     process_build_command(matches, pwd)
-  } else if matches.subcommand_matches("disassemble").is_some() {
-    RadlrResult::Ok(())
+  } else if let Some(matches) = matches.subcommand_matches("disassemble") {
+    process_disassemble_command(matches, pwd)
+  } else if let Some(matches) = matches.subcommand_matches("dump") {
+    process_dump_command(matches, pwd)
+  } else if let Some(matches) = matches.subcommand_matches("check") {
+    process_check_command(matches)
   } else if let Some(matches) = matches.subcommand_matches("lab-mode") {
     run_lab_server(matches.get_one::<u16>("port").cloned())
   } else {
@@ -120,35 +398,201 @@ fn main() -> RadlrResult<()> {
 }
 
 fn process_build_command(matches: &ArgMatches, pwd: PathBuf) -> Result<(), RadlrError> {
-  let (_, out_dir, _lib_out_dir) = configure_matches(matches, &pwd);
+  let (parser_type, out_dir, _lib_out_dir) = configure_matches(matches, &pwd);
   let grammar_sources = matches.get_many::<PathBuf>("INPUTS").unwrap_or_default().cloned().collect::<Vec<_>>();
   let name = matches.get_one::<String>("name").cloned();
 
   let debug = matches.get_one::<bool>("debug").cloned().unwrap_or_default();
-  let target_language = match true {
-    _ => radlr_build::TargetLanguage::Rust,
+  let target_language = matches.get_one::<TargetLanguage>("lang").cloned().unwrap_or(TargetLanguage::Rust);
+
+  // `--lang llvm` and `--type llvm` each imply the other, unless both are given.
+  let is_default = |id: &str| matches.value_source(id) == Some(clap::parser::ValueSource::DefaultValue);
+  let (target_language, parser_type) = match (target_language, parser_type) {
+    (TargetLanguage::Llvm, ParserType::Bytecode) if is_default("type") => (TargetLanguage::Llvm, ParserType::Binary),
+    (_, ParserType::Binary) if is_default("lang") => (TargetLanguage::Llvm, ParserType::Binary),
+    types => types,
   };
 
+  let include_paths = include_paths(matches);
+  let include_paths = include_paths.iter().map(|p| p.as_path()).collect::<Vec<_>>();
+
   let mut build_config = BuildConfig::new(&grammar_sources.as_slice()[0]);
 
   build_config.include_debug_symbols = debug;
   build_config.build_ast = matches.get_one::<bool>("ast").cloned().unwrap_or_default();
   build_config.lib_out = &_lib_out_dir;
   build_config.source_out = &out_dir;
+  build_config.include_paths = &include_paths;
+  build_config.state_cache_dir = matches.get_one::<PathBuf>("state-cache").map(|p| p.as_path());
+
+  let opt_config = configure_optimizations(matches);
+  build_config.optimize_config = opt_config.as_ref();
 
   if let Some(name) = &name {
     build_config.name_prefix = Some(name);
   }
 
-  build_config.parser_type = match true {
-    _ => radlr_build::ParserType::Bytecode,
-  };
+  build_config.parser_type = parser_type;
 
-  let parser_config = ParserConfig::default();
+  let parser_config = configure_parser(matches)?;
 
   radlr_build::fs_build(build_config, parser_config, target_language)
 }
 
+/// Builds an [OptimizeConfig] from the `--opt-passes` and `--opt-report`
+/// arguments. Returns `None` if neither is present.
+fn configure_optimizations(matches: &ArgMatches) -> Option<OptimizeConfig> {
+  let passes = matches.get_one::<OptimizeConfig>("opt-passes").cloned();
+  let report = matches.get_flag("opt-report");
+
+  match (passes, report) {
+    (None, false) => None,
+    (passes, report_passes) => Some(OptimizeConfig { report_passes, ..passes.unwrap_or_default() }),
+  }
+}
+
+fn process_disassemble_command(matches: &ArgMatches, pwd: PathBuf) -> Result<(), RadlrError> {
+  let out_dir = matches.get_one::<PathBuf>("out").unwrap_or(&pwd);
+  let grammar_sources = matches.get_many::<PathBuf>("INPUTS").unwrap_or_default().cloned().collect::<Vec<_>>();
+  let parser_config = configure_parser(matches)?;
+  let include_paths = include_paths(matches);
+
+  for grammar_source in grammar_sources {
+    let file_path = write_disassembly(&grammar_source, out_dir, &include_paths, parser_config)?;
+    println!("Wrote disassembly to {}", file_path.to_string_lossy());
+  }
+
+  Ok(())
+}
+
+fn process_dump_command(matches: &ArgMatches, pwd: PathBuf) -> Result<(), RadlrError> {
+  let out_dir = matches.get_one::<PathBuf>("out").unwrap_or(&pwd);
+  let grammar_sources = matches.get_many::<PathBuf>("INPUTS").unwrap_or_default().cloned().collect::<Vec<_>>();
+  let parser_config = configure_parser(matches)?;
+  let include_paths = include_paths(matches);
+  let entry = matches.get_one::<String>("entry").map(|s| s.as_str());
+
+  for grammar_source in grammar_sources {
+    let dir_path = write_debug_dump(&grammar_source, out_dir, &include_paths, parser_config, entry)?;
+    println!("Wrote debug dump to {}", dir_path.to_string_lossy());
+  }
+
+  Ok(())
+}
+
+/// Writes the FIRST and FOLLOW sets of the grammar at `grammar_path`, the
+/// item sets of its parse graph nodes, and DOT renderings of its parse graph
+/// (`graph.dot`) and optimized IR states (`ir.dot`), to
+/// `<out_dir>/<grammar_name>.debug`, returning the path of that directory.
+///
+/// The FIRST and FOLLOW sets are written before the parse graph is built, so
+/// they are available even if the grammar has conflicts. If `entry` is given,
+/// the DOT renderings only contain the states reachable from that entry point.
+fn write_debug_dump(
+  grammar_path: &Path,
+  out_dir: &Path,
+  include_paths: &[PathBuf],
+  parser_config: ParserConfig,
+  entry: Option<&str>,
+) -> RadlrResult<PathBuf> {
+  let pool = StandardPool::new_with_max_workers()?;
+  let root_path = RadlrGrammar::resolve_to_grammar_file(grammar_path)?;
+
+  let file_name = root_path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or("parser".to_string());
+  let dir_path = out_dir.join(file_name + ".debug");
+
+  std::fs::create_dir_all(&dir_path)?;
+
+  let mut grammar = RadlrGrammar::new();
+
+  for path in include_paths {
+    grammar.add_include_path(path);
+  }
+
+  let db = grammar.add_source(&root_path)?.build_db(&root_path, parser_config)?;
+
+  for (file_name, data) in [
+    ("first_follow.debug.txt", db.get_internal().first_follow_string()),
+    ("first_follow.debug.json", db.get_internal().first_follow_json()),
+  ] {
+    std::fs::write(dir_path.join(file_name), data)?;
+  }
+
+  let graph = db.build_states(parser_config, &pool)?;
+
+  graph.write_debug_file(&dir_path)?;
+
+  std::fs::write(dir_path.join("graph.dot"), graph.to_dot(entry)?)?;
+  std::fs::write(dir_path.join("ir.dot"), graph.build_ir_parser(true, false, &pool)?.to_dot(entry)?)?;
+
+  Ok(dir_path)
+}
+
+fn process_check_command(matches: &ArgMatches) -> Result<(), RadlrError> {
+  let grammar_sources = matches.get_many::<PathBuf>("INPUTS").unwrap_or_default().cloned().collect::<Vec<_>>();
+  let include_paths = include_paths(matches);
+
+  for grammar_source in grammar_sources {
+    let warnings = check_grammar(&grammar_source, &include_paths)?;
+
+    for warning in &warnings {
+      eprintln!("{warning}");
+    }
+
+    println!("{}: {} warning(s)", grammar_source.to_string_lossy(), warnings.len());
+  }
+
+  Ok(())
+}
+
+/// Loads the grammar at `grammar_path` and returns the warnings of its
+/// analysis.
+fn check_grammar(grammar_path: &Path, include_paths: &[PathBuf]) -> RadlrResult<Vec<RadlrError>> {
+  let root_path = RadlrGrammar::resolve_to_grammar_file(grammar_path)?;
+
+  let mut grammar = RadlrGrammar::new();
+
+  for path in include_paths {
+    grammar.add_include_path(path);
+  }
+
+  grammar.add_source(&root_path)?.build_db(&root_path, ParserConfig::default())?.analyze()
+}
+
+/// Compiles the grammar at `grammar_path` into bytecode and writes its
+/// annotated disassembly to `<out_dir>/<grammar_name>.disassembly.txt`,
+/// returning the path of the written file.
+fn write_disassembly(
+  grammar_path: &Path,
+  out_dir: &Path,
+  include_paths: &[PathBuf],
+  parser_config: ParserConfig,
+) -> RadlrResult<PathBuf> {
+  std::fs::create_dir_all(out_dir)?;
+
+  let pool = StandardPool::new_with_max_workers()?;
+  let root_path = RadlrGrammar::resolve_to_grammar_file(grammar_path)?;
+
+  let mut grammar = RadlrGrammar::new();
+
+  for path in include_paths {
+    grammar.add_include_path(path);
+  }
+
+  let db = grammar.add_source(&root_path)?.build_db(&root_path, parser_config)?;
+  let parser = db.build_states(parser_config, &pool)?.build_ir_parser(true, false, &pool)?;
+  let pkg = radlr_bytecode::compile_bytecode(&parser, true)?;
+
+  let file_name = root_path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or("parser".to_string());
+  let file_path = out_dir.join(file_name + ".disassembly.txt");
+
+  let mut file = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&file_path)?;
+  file.write_all(disassemble_bytecode_db(&pkg).as_bytes())?;
+  file.flush()?;
+
+  Ok(file_path)
+}
+
 #[test]
 fn test_radlr_bytecode_bootstrap() -> RadlrResult<()> {
   let radlr_grammar =
@@ -169,3 +613,97 @@ fn test_radlr_bytecode_bootstrap() -> RadlrResult<()> {
 
   radlr_build::fs_build(build_config, parser_config, radlr_build::TargetLanguage::Rust)
 }
+
+#[test]
+fn test_disassemble_json_grammar() -> RadlrResult<()> {
+  let json_grammar =
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../grammars/json/json.radlr").canonicalize().unwrap();
+  let temp_dir = std::env::temp_dir().join("radlr_disassembly");
+
+  let file_path = write_disassembly(&json_grammar, &temp_dir, &[], ParserConfig::default())?;
+  let disassembly = std::fs::read_to_string(file_path)?;
+
+  assert!(disassembly.contains("ENTRY POINTS:"));
+  assert!(disassembly.contains("[STATE "));
+
+  Ok(())
+}
+
+#[test]
+fn test_dump_json_grammar() -> RadlrResult<()> {
+  let json_grammar =
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../grammars/json/json.radlr").canonicalize().unwrap();
+  let temp_dir = std::env::temp_dir().join("radlr_dump");
+
+  let dir_path = write_debug_dump(&json_grammar, &temp_dir, &[], ParserConfig::default(), None)?;
+
+  let first_follow = std::fs::read_to_string(dir_path.join("first_follow.debug.txt"))?;
+  assert!(first_follow.contains("FIRST  : {"));
+  assert!(std::fs::read_to_string(dir_path.join("first_follow.debug.json"))?.starts_with("[{\"name\":"));
+
+  let item_sets = std::fs::read_to_string(dir_path.join("item_sets.debug.txt"))?;
+  assert!(item_sets.contains("kernel     :"));
+  assert!(std::fs::read_to_string(dir_path.join("item_sets.debug.json"))?.starts_with("[{\"hash\":"));
+
+  assert!(std::fs::read_to_string(dir_path.join("graph.dot"))?.starts_with("digraph graphs {"));
+  assert!(std::fs::read_to_string(dir_path.join("ir.dot"))?.starts_with("digraph ir {"));
+
+  Ok(())
+}
+
+#[test]
+fn test_check_json_grammar() -> RadlrResult<()> {
+  let json_grammar =
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../grammars/json/json.radlr").canonicalize().unwrap();
+
+  let warnings = check_grammar(&json_grammar, &[])?;
+
+  assert!(warnings.iter().all(|warning| warning.is_warning()));
+
+  Ok(())
+}
+
+#[test]
+fn test_parser_config_flags() -> RadlrResult<()> {
+  let build_matches = |args: &[&str]| {
+    let matches = cli().try_get_matches_from(["radlr", "build"].iter().chain(args).chain(&["grammar.radlr"])).unwrap();
+    configure_parser(matches.subcommand_matches("build").unwrap())
+  };
+
+  let config = build_matches(&["--mode", "lrk", "--k", "3"])?;
+  assert!(config.ALLOW_LR && !config.ALLOW_CALLS && config.max_k == 3);
+
+  let config = build_matches(&["--mode", "glr", "--context-free", "--export-all"])?;
+  assert!(config.ALLOW_CONTEXT_SPLITTING && config.CONTEXT_FREE && config.EXPORT_ALL_NONTERMS);
+
+  let config = build_matches(&["--no-lr", "--lookahead-scanners"])?;
+  assert!(!config.ALLOW_LR && config.ALLOW_CALLS && config.ALLOW_LOOKAHEAD_SCANNERS);
+
+  assert!(build_matches(&["--mode", "lrk", "--no-lr"]).is_err());
+  assert!(build_matches(&["--mode", "ll1", "--k", "2"]).is_err());
+  assert!(build_matches(&["--no-peek", "--k", "4"]).is_err());
+  assert!(build_matches(&["--mode", "llk", "--forks"]).is_err());
+
+  Ok(())
+}
+
+#[test]
+fn test_optimization_flags() -> RadlrResult<()> {
+  use radlr_core::OptimizationPass;
+
+  let build_matches = |args: &[&str]| {
+    let matches = cli().try_get_matches_from(["radlr", "build"].iter().chain(args).chain(&["grammar.radlr"])).unwrap();
+    configure_optimizations(matches.subcommand_matches("build").unwrap())
+  };
+
+  assert_eq!(build_matches(&[]), None);
+  assert_eq!(build_matches(&["--opt-report"]), Some(OptimizeConfig { report_passes: true, ..Default::default() }));
+
+  let config = build_matches(&["--opt-passes", "canonicalize,garbage-collect"]).unwrap();
+  assert_eq!(config.passes, [OptimizationPass::Canonicalize, OptimizationPass::GarbageCollect]);
+  assert!(!config.report_passes);
+
+  assert!(cli().try_get_matches_from(["radlr", "build", "--opt-passes", "inline", "grammar.radlr"]).is_err());
+
+  Ok(())
+}
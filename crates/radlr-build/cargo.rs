@@ -0,0 +1,130 @@
+//! Helpers for building Radlr parsers from a Cargo build script.
+//!
+//! The parser and AST sources are written to the `OUT_DIR` of the package
+//! being built, and Cargo is told to rerun the build script whenever the root
+//! grammar, or any grammar it imports, changes.
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() -> radlr_build::RadlrResult<()> {
+//!   radlr_build::cargo::build("grammar.radlr")
+//! }
+//! ```
+//!
+//! The generated code is then brought into the package with the
+//! `include_parser!` macro of the `radlr_rust_runtime` crate, which declares
+//! the `parser` and `ast` modules:
+//!
+//! ```ignore
+//! radlr_rust_runtime::include_parser!();
+//!
+//! let db = parser::ParserDB::new();
+//! ```
+use crate::{fs_build_grammar, BuildConfig, TargetLanguage};
+use radlr_core::{ParserConfig, RadlrError, RadlrGrammar, RadlrResult};
+use std::{
+  fs::OpenOptions,
+  io::Write,
+  path::{Path, PathBuf},
+};
+
+/// Name of the file, written to `OUT_DIR`, that declares the modules of the
+/// generated parser. When a name prefix is set, the file is named
+/// `{prefix}_radlr.rs` instead.
+pub const MOD_FILE_NAME: &'static str = "radlr.rs";
+
+/// Builds a Rust parser, with AST, from the grammar at `root_grammar`. A
+/// relative path is resolved against the manifest directory of the package.
+pub fn build<T: AsRef<Path>>(root_grammar: T) -> RadlrResult<()> {
+  let root_grammar = manifest_dir()?.join(root_grammar);
+  build_with_config(BuildConfig::new(&root_grammar), Default::default())
+}
+
+/// Builds a Rust parser into `OUT_DIR` using `build_config` and
+/// `parser_config`.
+///
+/// The `source_out` and `lib_out` paths of `build_config` are replaced with
+/// `OUT_DIR`, and a relative root grammar path is resolved against the
+/// manifest directory of the package.
+pub fn build_with_config(build_config: BuildConfig, parser_config: ParserConfig) -> RadlrResult<()> {
+  let out_dir = PathBuf::from(env_var("OUT_DIR")?);
+  let root_grammar = manifest_dir()?.join(build_config.root_grammar_path);
+
+  let mut build_config = build_config;
+  build_config.root_grammar_path = &root_grammar;
+
+  build_in(&out_dir, build_config, parser_config, &mut std::io::stdout())
+}
+
+/// Builds the parser into `out_dir` and writes the `cargo:` directives of the
+/// build to `directives`.
+pub(crate) fn build_in<W: Write>(
+  out_dir: &Path,
+  build_config: BuildConfig,
+  parser_config: ParserConfig,
+  directives: &mut W,
+) -> RadlrResult<()> {
+  let mut build_config = build_config;
+  build_config.source_out = out_dir;
+  build_config.lib_out = out_dir;
+  build_config.rust.add_mod = false;
+
+  writeln!(directives, "cargo:rerun-if-env-changed={}", RadlrGrammar::INCLUDE_PATH_VAR)?;
+
+  let grammar = fs_build_grammar(build_config, parser_config, TargetLanguage::Rust)?;
+
+  for path in grammar.source_paths()? {
+    writeln!(directives, "cargo:rerun-if-changed={}", path.display())?;
+  }
+
+  write_mod_file(out_dir, build_config)
+}
+
+/// Writes the file included by `include_parser!`, declaring a module for each
+/// generated source file.
+fn write_mod_file(out_dir: &Path, build_config: BuildConfig) -> RadlrResult<()> {
+  let out_dir = out_dir.canonicalize()?;
+
+  let (mod_path, prefix) = match build_config.name_prefix {
+    Some(name_prefix) => (out_dir.join(format!("{name_prefix}_{MOD_FILE_NAME}")), format!("{name_prefix}_")),
+    None => (out_dir.join(MOD_FILE_NAME), String::new()),
+  };
+
+  let mut modules = Vec::new();
+
+  if build_config.build_parser {
+    modules.push(("parser", out_dir.join(format!("{prefix}parser.rs"))));
+  }
+
+  if build_config.build_ast {
+    modules.push(("ast", out_dir.join(format!("{prefix}ast.rs"))));
+  }
+
+  let mut mod_file = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&mod_path)?;
+
+  for (name, path) in modules {
+    // Debug formatting produces an escaped Rust string literal of the path.
+    let path = format!("{:?}", path.to_string_lossy());
+
+    if build_config.rust.flat_namespace {
+      writeln!(mod_file, "mod {name} {{ include!({path}); }}")?;
+      writeln!(mod_file, "pub use {name}::*;")?;
+    } else {
+      writeln!(mod_file, "pub mod {name} {{ include!({path}); }}")?;
+    }
+  }
+
+  mod_file.flush()?;
+
+  Ok(())
+}
+
+fn manifest_dir() -> RadlrResult<PathBuf> {
+  env_var("CARGO_MANIFEST_DIR").map(PathBuf::from)
+}
+
+fn env_var(name: &str) -> RadlrResult<String> {
+  std::env::var(name).map_err(|_| {
+    RadlrError::from(format!("The {name} environment variable is not set. Is this running in a Cargo build script?"))
+  })
+}
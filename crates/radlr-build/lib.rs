@@ -1,11 +1,12 @@
-use radlr_core::RadlrGrammar;
 pub use radlr_core::RadlrResult;
+use radlr_core::{OptimizeConfig, RadlrDatabase, RadlrError, RadlrGrammar};
 use std::path::{Path, PathBuf};
 use targets::rust::RustConfig;
 
+pub mod cargo;
 mod targets;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TargetLanguage {
   Rust,
   TypeScript,
@@ -15,7 +16,31 @@ pub enum TargetLanguage {
   Llvm,
 }
 
-#[derive(Clone, Copy)]
+impl TargetLanguage {
+  /// Human readable name of the target.
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::Rust => "Rust",
+      Self::TypeScript => "TypeScript",
+      Self::JavaScript => "JavaScript",
+      Self::C => "C",
+      Self::Cpp => "C++",
+      Self::Llvm => "LLVM",
+    }
+  }
+
+  /// Returns `true` if radlr-build has a backend that can produce parsers of
+  /// `parser_type` for this target.
+  pub fn supports(&self, parser_type: ParserType) -> bool {
+    match (self, parser_type) {
+      (Self::Rust | Self::TypeScript | Self::JavaScript | Self::C | Self::Cpp, ParserType::Bytecode) => true,
+      (Self::Llvm, ParserType::Binary) => true,
+      _ => false,
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ParserType {
   /// Builds a bytecode parser to be run by the Radlr bytecode interpreter
   /// of target langauge.
@@ -69,6 +94,11 @@ pub struct BuildConfig<'a> {
   pub parser_type: ParserType,
 
   /// Paths to search for `radlr` input files.
+  ///
+  /// Grammars named by `IMPORT` statements are searched for in the directory
+  /// of the importing grammar, then in each of these paths in order, then
+  /// in the paths of the `RADLR_INCLUDE_PATH` environment variable, and
+  /// finally in the current working directory.
   pub include_paths: &'a [&'a Path],
 
   /// Configurations specific to the Rust language target
@@ -76,6 +106,20 @@ pub struct BuildConfig<'a> {
 
   /// Name prefix to add to output files
   pub name_prefix: Option<&'a str>,
+
+  /// Directory in which the parse states of each non-terminal are cached
+  /// between builds. When set, only the states of non-terminals affected by
+  /// changes to the grammar are rebuilt.
+  ///
+  /// Defaults to `None`
+  pub state_cache_dir: Option<&'a Path>,
+
+  /// The optimization passes run on the parse states. If
+  /// [OptimizeConfig::report_passes] is set, the optimization report is
+  /// printed once the parser is built.
+  ///
+  /// Defaults to `None`, which runs the default passes.
+  pub optimize_config: Option<&'a OptimizeConfig>,
 }
 
 impl<'a> BuildConfig<'a> {
@@ -92,6 +136,8 @@ impl<'a> BuildConfig<'a> {
       include_paths:         &[],
       rust:                  Default::default(),
       name_prefix:           Default::default(),
+      state_cache_dir:       Default::default(),
+      optimize_config:       Default::default(),
     }
   }
 }
@@ -103,6 +149,8 @@ pub fn source_string_build<'b>(
   source: &str,
   target: TargetLanguage,
 ) -> RadlrResult<()> {
+  check_target_support(target, build_config.parser_type)?;
+
   let mut local_build_config = build_config;
 
   let BuildConfig { lib_out, source_out, root_grammar_path, .. } = &mut local_build_config;
@@ -116,32 +164,11 @@ pub fn source_string_build<'b>(
   (*lib_out) = &canonical_libout;
   (*source_out) = &canonical_source_out;
 
-  let db = RadlrGrammar::new()
+  let db = new_grammar(build_config.include_paths)
     .add_source_from_string(&source, &root_grammar_path, false)?
     .build_db(&root_grammar_path, parser_config)?;
 
-  match target {
-    TargetLanguage::Rust => {
-      targets::rust::build(&db, local_build_config, parser_config)?;
-    }
-    TargetLanguage::TypeScript => {
-      targets::typescript::build(&db, local_build_config, parser_config)?;
-    }
-    TargetLanguage::JavaScript => {
-      targets::javascript::build(&db, local_build_config, parser_config)?;
-    }
-    TargetLanguage::Cpp => {
-      todo!("Build Cpp: Not yet supported")
-    }
-    TargetLanguage::C => {
-      todo!("Build C: Not yet supported")
-    }
-    TargetLanguage::Llvm => {
-      todo!("Build Llvm: Not yet supported")
-    }
-  }
-
-  Ok(())
+  build_target(&db, local_build_config, parser_config, target)
 }
 
 /// Build a Radlr parser from a grammar file
@@ -150,6 +177,18 @@ pub fn fs_build<'b>(
   parser_config: radlr_core::ParserConfig,
   target: TargetLanguage,
 ) -> RadlrResult<()> {
+  fs_build_grammar(build_config, parser_config, target).map(|_| ())
+}
+
+/// Builds a parser from a grammar file, returning the grammar context so that
+/// callers can inspect the grammars that were loaded.
+fn fs_build_grammar<'b>(
+  build_config: BuildConfig<'b>,
+  parser_config: radlr_core::ParserConfig,
+  target: TargetLanguage,
+) -> RadlrResult<RadlrGrammar> {
+  check_target_support(target, build_config.parser_type)?;
+
   let mut local_build_config = build_config;
 
   let BuildConfig { lib_out, source_out, root_grammar_path, .. } = &mut local_build_config;
@@ -165,30 +204,55 @@ pub fn fs_build<'b>(
 
   let resolved_root_path = RadlrGrammar::resolve_to_grammar_file(root_grammar_path)?;
 
-  let db = RadlrGrammar::new().add_source(&resolved_root_path)?.build_db(resolved_root_path, parser_config)?;
+  let mut grammar = new_grammar(build_config.include_paths);
 
-  match target {
-    TargetLanguage::Rust => {
-      targets::rust::build(&db, local_build_config, parser_config)?;
-    }
-    TargetLanguage::TypeScript => {
-      targets::typescript::build(&db, local_build_config, parser_config)?;
-    }
-    TargetLanguage::JavaScript => {
-      targets::javascript::build(&db, local_build_config, parser_config)?;
-    }
-    TargetLanguage::Cpp => {
-      todo!("Build Cpp: Not yet supported")
-    }
-    TargetLanguage::C => {
-      todo!("Build C: Not yet supported")
-    }
-    TargetLanguage::Llvm => {
-      todo!("Build Llvm: Not yet supported")
-    }
+  let db = grammar.add_source(&resolved_root_path)?.build_db(resolved_root_path, parser_config)?;
+
+  build_target(&db, local_build_config, parser_config, target)?;
+
+  Ok(grammar)
+}
+
+/// Creates a grammar context that searches `include_paths` for imported
+/// grammars.
+fn new_grammar(include_paths: &[&Path]) -> RadlrGrammar {
+  let mut grammar = RadlrGrammar::new();
+
+  for path in include_paths {
+    grammar.add_include_path(*path);
   }
 
-  Ok(())
+  grammar
+}
+
+/// Rejects target and parser type combinations that do not have a backend,
+/// before any time is spent compiling the grammar.
+fn check_target_support(target: TargetLanguage, parser_type: ParserType) -> RadlrResult<()> {
+  if target.supports(parser_type) {
+    Ok(())
+  } else {
+    let parser_type = match parser_type {
+      ParserType::Bytecode => "bytecode",
+      ParserType::Binary => "binary",
+    };
+    Err(RadlrError::from(format!("The {} target does not support {parser_type} parsers yet", target.name())))
+  }
+}
+
+fn build_target(
+  db: &RadlrDatabase,
+  build_config: BuildConfig,
+  parser_config: radlr_core::ParserConfig,
+  target: TargetLanguage,
+) -> RadlrResult<()> {
+  match target {
+    TargetLanguage::Rust => targets::rust::build(db, build_config, parser_config),
+    TargetLanguage::TypeScript => targets::typescript::build(db, build_config, parser_config),
+    TargetLanguage::JavaScript => targets::javascript::build(db, build_config, parser_config),
+    TargetLanguage::C => targets::c::build(db, build_config, parser_config),
+    TargetLanguage::Cpp => targets::cpp::build(db, build_config, parser_config),
+    TargetLanguage::Llvm => targets::llvm::build(db, build_config, parser_config),
+  }
 }
 
 #[test]
@@ -206,6 +270,97 @@ fn builds_basic_grammar() -> RadlrResult<()> {
   Ok(())
 }
 
+#[test]
+fn builds_basic_grammar_typescript() -> RadlrResult<()> {
+  build_basic_grammar(TargetLanguage::TypeScript, ParserType::Bytecode)?;
+
+  Ok(())
+}
+
+#[test]
+fn typescript_token_strings_are_valid_literals() -> RadlrResult<()> {
+  if !tool_available("node") {
+    return Ok(());
+  }
+
+  let output = build_strings_grammar(TargetLanguage::TypeScript, ParserType::Bytecode)?;
+  let source = std::fs::read_to_string(output.join("parser.ts"))?;
+
+  // Node can not run TypeScript, so only the token table, which holds the
+  // grammar provided strings, is evaluated.
+  let (_, table) = source.split_once("const TOKEN_ID_TO_STRING: [number, string][] = ").unwrap();
+  let (table, _) = table.split_once(";\n").unwrap();
+
+  let stdout = run_tool(
+    std::process::Command::new("node").args(["-e", &format!("for (const [, s] of {table}) console.log(s)")]),
+  );
+
+  for token in STRINGS_GRAMMAR_TOKENS {
+    assert!(stdout.lines().any(|line| line == token), "Token {token} is missing from:\n{stdout}");
+  }
+
+  Ok(())
+}
+
+#[test]
+#[ignore = "type checks the generated TypeScript with `tsc`, which must be on the PATH along with `node`"]
+fn runs_grammar_typescript() -> RadlrResult<()> {
+  assert!(tool_available("tsc") && tool_available("node"), "`tsc` and `node` are required to run the TypeScript parser");
+
+  let output = build_strings_grammar(TargetLanguage::TypeScript, ParserType::Bytecode)?;
+
+  std::fs::write(
+    output.join("main.ts"),
+    r#"import { reduce_rules } from "./ast";
+import { ENTRY_POINTS, db, parse, parser } from "./parser";
+import { ParseAction, StringInput } from "./radlr_runtime";
+
+function accepts(text: string): boolean {
+  const input = new StringInput(text);
+  const bc_parser = parser();
+  const ctx = bc_parser.init(db.default_entry);
+  let action: ParseAction | null;
+  let last: ParseAction | null = null;
+
+  while ((action = bc_parser.next(input, ctx))) last = action;
+
+  return last != null && last.type == "Accept" && last.final_offset == text.length;
+}
+
+for (const name of db.token_id_to_str.values()) console.log(name);
+
+if (!("default" in ENTRY_POINTS) || !accepts("??= ` ${ \\ \" 12") || accepts("?? 12")) throw new Error("Parser results are incorrect");
+
+const ops: unknown[] = parse("??= ` ${ \\ \" 12", reduce_rules);
+
+if (ops.map(String).join(" ") != "??= ` ${ \\ \" 12") throw new Error(`AST is incorrect: ${ops}`);
+"#,
+  )?;
+
+  // The runtime only relies on globals that are shared by browsers and node,
+  // so the DOM declarations stand in for the node type definitions.
+  run_tool(std::process::Command::new("tsc").current_dir(&output).args([
+    "--strict",
+    "--target",
+    "es2020",
+    "--module",
+    "commonjs",
+    "--lib",
+    "es2020,dom",
+    "--outDir",
+    "out",
+    "main.ts",
+  ]));
+
+  let stdout = run_tool(std::process::Command::new("node").current_dir(&output).arg("out/main.js"));
+
+  for token in STRINGS_GRAMMAR_TOKENS {
+    assert!(stdout.lines().any(|line| line == token), "Token {token} is missing from:\n{stdout}");
+  }
+
+  Ok(())
+}
+
 #[test]
 fn builds_basic_grammar_javascript() -> RadlrResult<()> {
   let root = std::path::PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).canonicalize()?;
@@ -222,6 +377,44 @@ fn builds_basic_grammar_javascript() -> RadlrResult<()> {
   Ok(())
 }
 
+#[test]
+fn runs_grammar_javascript() -> RadlrResult<()> {
+  if !tool_available("node") {
+    return Ok(());
+  }
+
+  let output = build_strings_grammar(TargetLanguage::JavaScript, ParserType::Bytecode)?;
+
+  std::fs::write(
+    output.join("main.mjs"),
+    r#"import { ENTRY_POINTS, StringInput, db, parser } from "./parser.js";
+
+function accepts(text) {
+  const input = new StringInput(text);
+  const bc_parser = parser();
+  const ctx = bc_parser.init(db.default_entry);
+  let action, last;
+
+  while ((action = bc_parser.next(input, ctx))) last = action;
+
+  return last.type == "Accept" && last.final_offset == text.length;
+}
+
+for (const name of db.token_id_to_str.values()) console.log(name);
+
+if (!("default" in ENTRY_POINTS) || !accepts("??= ` ${ \\ \" 12") || accepts("?? 12")) process.exit(1);
+"#,
+  )?;
+
+  let stdout = run_tool(std::process::Command::new("node").current_dir(&output).arg("main.mjs"));
+
+  for token in STRINGS_GRAMMAR_TOKENS {
+    assert!(stdout.lines().any(|line| line == token), "Token {token} is missing from:\n{stdout}");
+  }
+
+  Ok(())
+}
+
 #[test]
 fn builds_basic_grammar_javascript_a() -> RadlrResult<()> {
   let root = std::path::PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).canonicalize()?;
@@ -240,6 +433,323 @@ fn builds_basic_grammar_javascript_a() -> RadlrResult<()> {
   Ok(())
 }
 
+#[test]
+fn builds_basic_grammar_c() -> RadlrResult<()> {
+  build_basic_grammar(TargetLanguage::C, ParserType::Bytecode)?;
+
+  Ok(())
+}
+
+/// Generates a parser for `test_grammar_strings.radlr`, whose tokens contain
+/// characters that must be escaped in the string literals of every target,
+/// into an empty directory.
+#[cfg(test)]
+fn build_strings_grammar(target: TargetLanguage, parser_type: ParserType) -> RadlrResult<PathBuf> {
+  build_strings_grammar_into(target, parser_type, &format!("strings_{}", target.name()))
+}
+
+/// Generates a parser for `test_grammar_strings.radlr` into the empty directory
+/// `build/<dir_name>`.
+#[cfg(test)]
+fn build_strings_grammar_into(target: TargetLanguage, parser_type: ParserType, dir_name: &str) -> RadlrResult<PathBuf> {
+  build_test_grammar_into("test_grammar_strings.radlr", target, parser_type, dir_name)
+}
+
+/// Generates a parser for `test_grammar.radlr` into the empty directory
+/// `build/basic_<target>`, so the tests of different targets do not write to
+/// the same files.
+#[cfg(test)]
+fn build_basic_grammar(target: TargetLanguage, parser_type: ParserType) -> RadlrResult<PathBuf> {
+  build_test_grammar_into("test_grammar.radlr", target, parser_type, &format!("basic_{}", target.name()))
+}
+
+/// Generates a parser for the grammar `grammar_file` of this crate into the
+/// empty directory `build/<dir_name>`.
+#[cfg(test)]
+fn build_test_grammar_into(
+  grammar_file: &str,
+  target: TargetLanguage,
+  parser_type: ParserType,
+  dir_name: &str,
+) -> RadlrResult<PathBuf> {
+  let root = std::path::PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).canonicalize()?;
+  let path = root.join(grammar_file);
+  let output = root.join("build").join(dir_name);
+
+  let _ = std::fs::remove_dir_all(&output);
+  std::fs::create_dir_all(&output)?;
+
+  let mut build_config = BuildConfig::new(&path);
+  build_config.source_out = &output;
+  build_config.lib_out = &output;
+  build_config.parser_type = parser_type;
+
+  fs_build(build_config, Default::default(), target)?;
+
+  Ok(output)
+}
+
+/// Returns `true` if `tool` can be run. Tests that compile generated sources
+/// are skipped when their compiler is not installed.
+#[cfg(test)]
+fn tool_available(tool: &str) -> bool {
+  let available = std::process::Command::new(tool).arg("--version").output().is_ok_and(|output| output.status.success());
+
+  if !available {
+    eprintln!("Skipping test: `{tool}` is not available");
+  }
+
+  available
+}
+
+/// Runs `command`, asserting that it succeeds, and returns its stdout.
+#[cfg(test)]
+fn run_tool(command: &mut std::process::Command) -> String {
+  let output = command.output().unwrap();
+
+  assert!(
+    output.status.success(),
+    "{command:?} failed:\n{}{}",
+    String::from_utf8_lossy(&output.stdout),
+    String::from_utf8_lossy(&output.stderr)
+  );
+
+  String::from_utf8(output.stdout).unwrap()
+}
+
+/// The token strings of `test_grammar_strings.radlr` that are printed by the
+/// compiled test programs.
+#[cfg(test)]
+const STRINGS_GRAMMAR_TOKENS: [&str; 5] = ["??=", "`", "${", "\\", "\""];
+
+/// A C program that prints the token strings of the parser of
+/// `test_grammar_strings.radlr`, and fails if the parser does not accept valid
+/// input or accepts invalid input.
+#[cfg(test)]
+const C_TEST_MAIN: &str = r#"#include "parser.h"
+#include <stdio.h>
+#include <string.h>
+
+static radlr_status parse(const char* text) {
+  radlr_buffer buffer = { (const uint8_t*)text, (uint32_t)strlen(text) };
+  radlr_input input = radlr_buffer_input(&buffer);
+  return radlr_parse(RADLR_DEFAULT_ENTRY, &input, NULL, NULL);
+}
+
+int main(void) {
+  for (uint32_t id = 0; id < 64; id++) {
+    if (radlr_token_name(id)) printf("%s\n", radlr_token_name(id));
+  }
+
+  return parse("?\?= ` ${ \\ \" 12") != RADLR_OK || parse("?\? 12") == RADLR_OK;
+}
+"#;
+
+#[test]
+fn compiles_and_runs_grammar_c() -> RadlrResult<()> {
+  if !tool_available("gcc") {
+    return Ok(());
+  }
+
+  let output = build_strings_grammar(TargetLanguage::C, ParserType::Bytecode)?;
+
+  std::fs::write(output.join("main.c"), C_TEST_MAIN)?;
+
+  run_tool(
+    std::process::Command::new("gcc")
+      .current_dir(&output)
+      .args(["-std=c99", "-Wall", "-Werror=trigraphs", "main.c", "parser.c", "-o", "parser_test"]),
+  );
+
+  let stdout = run_tool(&mut std::process::Command::new(output.join("parser_test")));
+
+  for token in STRINGS_GRAMMAR_TOKENS {
+    assert!(stdout.lines().any(|line| line == token), "Token {token} is missing from:\n{stdout}");
+  }
+
+  Ok(())
+}
+
+#[test]
+fn builds_basic_grammar_cpp() -> RadlrResult<()> {
+  build_basic_grammar(TargetLanguage::Cpp, ParserType::Bytecode)?;
+
+  Ok(())
+}
+
+#[test]
+fn compiles_and_runs_grammar_cpp() -> RadlrResult<()> {
+  if !tool_available("g++") {
+    return Ok(());
+  }
+
+  let output = build_strings_grammar(TargetLanguage::Cpp, ParserType::Bytecode)?;
+
+  std::fs::write(
+    output.join("main.cpp"),
+    r#"#include "parser.hpp"
+#include <cstdio>
+
+static bool accepts(std::string_view text) {
+  radlr::StringInput input(text);
+  auto bc_parser = parser::parser();
+  auto ctx = bc_parser.init(parser::DEFAULT_ENTRY);
+  bool accepted = false;
+
+  while (auto action = bc_parser.next(input, ctx)) {
+    accepted = action->type == radlr::ActionType::Accept && action->byte_offset == text.size();
+  }
+
+  return accepted;
+}
+
+int main() {
+  for (size_t id = 0; id < parser::db.token_names.len; id++) {
+    if (parser::db.token_names[id]) std::printf("%s\n", parser::db.token_names[id]);
+  }
+
+  return !accepts("?\?= ` ${ \\ \" 12") || accepts("?\? 12");
+}
+"#,
+  )?;
+
+  run_tool(
+    std::process::Command::new("g++")
+      .current_dir(&output)
+      .args(["-std=c++17", "-Wall", "-Werror=trigraphs", "main.cpp", "-o", "parser_test"]),
+  );
+
+  let stdout = run_tool(&mut std::process::Command::new(output.join("parser_test")));
+
+  for token in STRINGS_GRAMMAR_TOKENS {
+    assert!(stdout.lines().any(|line| line == token), "Token {token} is missing from:\n{stdout}");
+  }
+
+  Ok(())
+}
+
+#[test]
+fn builds_basic_grammar_llvm() -> RadlrResult<()> {
+  build_basic_grammar(TargetLanguage::Llvm, ParserType::Binary)?;
+
+  Ok(())
+}
+
+#[test]
+fn compiles_and_runs_grammar_llvm() -> RadlrResult<()> {
+  if !tool_available("llc") || !tool_available("gcc") {
+    return Ok(());
+  }
+
+  let output = build_strings_grammar(TargetLanguage::Llvm, ParserType::Binary)?;
+
+  std::fs::write(output.join("main.c"), C_TEST_MAIN)?;
+
+  // The module uses opaque pointers, which are the default from LLVM 15.
+  let version = run_tool(std::process::Command::new("llc").arg("--version"));
+  let major = version.split("LLVM version ").nth(1).and_then(|v| v.split('.').next()?.parse::<u32>().ok()).unwrap_or(15);
+
+  let mut llc = std::process::Command::new("llc");
+  llc.current_dir(&output).args(["-filetype=obj", "-relocation-model=pic", "parser.ll", "-o", "parser.o"]);
+
+  if major < 15 {
+    llc.arg("-opaque-pointers");
+  }
+
+  run_tool(&mut llc);
+
+  run_tool(std::process::Command::new("gcc").current_dir(&output).args(["-std=c99", "main.c", "parser.o", "-o", "parser_test"]));
+
+  let stdout = run_tool(&mut std::process::Command::new(output.join("parser_test")));
+
+  for token in STRINGS_GRAMMAR_TOKENS {
+    assert!(stdout.lines().any(|line| line == token), "Token {token} is missing from:\n{stdout}");
+  }
+
+  Ok(())
+}
+
+/// A C program that times the parser of `test_grammar_strings.radlr` on a long
+/// input, and prints the average time of a parse in nanoseconds.
+#[cfg(test)]
+const C_BENCHMARK_MAIN: &str = r#"#define _POSIX_C_SOURCE 199309L
+#include "parser.h"
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <time.h>
+
+#define STATEMENT "?\?= ` ${ \\ \" 1234 "
+#define REPEATS 10000
+#define ITERATIONS 50
+
+int main(void) {
+  size_t length = strlen(STATEMENT);
+  char* text = malloc(length * REPEATS + 1);
+
+  for (size_t i = 0; i < REPEATS; i++) memcpy(text + i * length, STATEMENT, length);
+  text[length * REPEATS] = 0;
+
+  radlr_buffer buffer = { (const uint8_t*)text, (uint32_t)(length * REPEATS) };
+  struct timespec start, end;
+
+  clock_gettime(CLOCK_MONOTONIC, &start);
+
+  for (int i = 0; i < ITERATIONS; i++) {
+    radlr_input input = radlr_buffer_input(&buffer);
+    if (radlr_parse(RADLR_DEFAULT_ENTRY, &input, NULL, NULL) != RADLR_OK) return 1;
+  }
+
+  clock_gettime(CLOCK_MONOTONIC, &end);
+
+  printf("%lld\n", ((end.tv_sec - start.tv_sec) * 1000000000LL + (end.tv_nsec - start.tv_nsec)) / ITERATIONS);
+
+  free(text);
+  return 0;
+}
+"#;
+
+/// Compares the native parser of the LLVM target with the bytecode interpreter
+/// of the C target, both compiled with `-O2`.
+#[test]
+#[ignore = "benchmarks the LLVM target against the bytecode interpreter, and requires `llc` and `gcc`"]
+fn benchmarks_llvm_against_bytecode() -> RadlrResult<()> {
+  assert!(tool_available("llc") && tool_available("gcc"), "`llc` and `gcc` must be on the PATH");
+
+  let output = build_strings_grammar_into(TargetLanguage::C, ParserType::Bytecode, "bench_C")?;
+
+  std::fs::write(output.join("main.c"), C_BENCHMARK_MAIN)?;
+
+  run_tool(std::process::Command::new("gcc").current_dir(&output).args(["-std=c99", "-O2", "main.c", "parser.c", "-o", "parser_bench"]));
+
+  let bytecode_ns = run_tool(&mut std::process::Command::new(output.join("parser_bench"))).trim().parse::<u64>().unwrap();
+
+  let output = build_strings_grammar_into(TargetLanguage::Llvm, ParserType::Binary, "bench_LLVM")?;
+
+  std::fs::write(output.join("main.c"), C_BENCHMARK_MAIN)?;
+
+  let version = run_tool(std::process::Command::new("llc").arg("--version"));
+  let major = version.split("LLVM version ").nth(1).and_then(|v| v.split('.').next()?.parse::<u32>().ok()).unwrap_or(15);
+
+  let mut llc = std::process::Command::new("llc");
+  llc.current_dir(&output).args(["-O2", "-filetype=obj", "-relocation-model=pic", "parser.ll", "-o", "parser.o"]);
+
+  if major < 15 {
+    llc.arg("-opaque-pointers");
+  }
+
+  run_tool(&mut llc);
+
+  run_tool(std::process::Command::new("gcc").current_dir(&output).args(["-std=c99", "-O2", "main.c", "parser.o", "-o", "parser_bench"]));
+
+  let llvm_ns = run_tool(&mut std::process::Command::new(output.join("parser_bench"))).trim().parse::<u64>().unwrap();
+
+  println!("bytecode interpreter: {bytecode_ns} ns/parse");
+  println!("LLVM: {llvm_ns} ns/parse ({:.2}x)", bytecode_ns as f64 / llvm_ns.max(1) as f64);
+
+  Ok(())
+}
+
 #[test]
 fn builds_radlr_lang() -> RadlrResult<()> {
   let root = std::path::PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).canonicalize()?;
@@ -331,3 +841,116 @@ fn builds_values() -> RadlrResult<()> {
 
   Ok(())
 }
+
+#[test]
+fn resolves_imports_from_include_paths() -> RadlrResult<()> {
+  let root = std::env::temp_dir().join("radlr_include_paths");
+  let (grammar_dir, include_dir) = (root.join("grammar"), root.join("include"));
+
+  std::fs::create_dir_all(&grammar_dir)?;
+  std::fs::create_dir_all(&include_dir)?;
+  std::fs::write(include_dir.join("numbers.radlr"), "<> num > c:num(+)")?;
+  std::fs::write(grammar_dir.join("grammar.radlr"), "IMPORT numbers as n\n\n<> list > n::num(+\",\")")?;
+
+  let path = grammar_dir.join("grammar.radlr");
+  let output = root.join("build");
+
+  let mut build_config = BuildConfig::new(&path);
+  build_config.source_out = &output;
+  build_config.lib_out = &output;
+  build_config.build_ast = false;
+
+  let error = fs_build(build_config, Default::default(), TargetLanguage::Rust).unwrap_err().to_string();
+  assert!(error.contains(&grammar_dir.join("numbers.radlr").to_string_lossy().to_string()));
+
+  let include_paths = [include_dir.as_path()];
+  build_config.include_paths = &include_paths;
+
+  fs_build(build_config, Default::default(), TargetLanguage::Rust)?;
+
+  Ok(())
+}
+
+#[test]
+fn cargo_build_tracks_imported_grammars() -> RadlrResult<()> {
+  let root = std::env::temp_dir().join("radlr_cargo_build");
+  let out_dir = root.join("out");
+
+  std::fs::create_dir_all(&out_dir)?;
+  std::fs::write(root.join("numbers.radlr"), "<> num > c:num(+)")?;
+  std::fs::write(root.join("grammar.radlr"), "IMPORT numbers as n\n\n<> list > n::num(+\",\")")?;
+
+  let path = root.join("grammar.radlr");
+  let build_config = BuildConfig::new(&path);
+
+  let mut directives = Vec::new();
+  cargo::build_in(&out_dir, build_config, Default::default(), &mut directives)?;
+
+  let directives = String::from_utf8(directives).unwrap();
+  let numbers = root.join("numbers.radlr").canonicalize()?;
+  assert!(directives.contains(&format!("cargo:rerun-if-changed={}", path.canonicalize()?.display())));
+  assert!(directives.contains(&format!("cargo:rerun-if-changed={}", numbers.display())));
+
+  let mod_file = std::fs::read_to_string(out_dir.join(cargo::MOD_FILE_NAME))?;
+  assert!(mod_file.contains("pub mod parser"));
+  assert!(mod_file.contains("pub mod ast"));
+  assert!(out_dir.join("parser.bin").exists());
+
+  Ok(())
+}
+
+#[test]
+fn reports_unresolved_imports_of_string_sources() -> RadlrResult<()> {
+  let root = std::env::temp_dir().join("radlr_string_source_imports");
+  std::fs::create_dir_all(&root)?;
+
+  let path = root.join("grammar.radlr");
+  let source = "IMPORT missing_numbers as n\n\n<> list > n::num(+\",\")";
+
+  let missing = root.join("missing_numbers.radlr").to_string_lossy().to_string();
+
+  let mut grammar = RadlrGrammar::new();
+  grammar.add_source_from_string(source, &path, false)?;
+
+  let error = grammar.build_db(&path, Default::default()).map(|_| ()).unwrap_err();
+  assert!(error.to_string().contains(&missing));
+
+  let mut grammar = RadlrGrammar::new();
+  grammar.add_source_from_string("<> list > c:num", &path, false)?;
+  grammar.add_source_from_string(source, &path, true)?;
+
+  let error = grammar.build_db(&path, Default::default()).map(|_| ()).unwrap_err();
+  assert!(error.to_string().contains(&missing));
+
+  // The import is satisfied by a source that is added after the importing
+  // source.
+  grammar.add_source_from_string("<> num > c:num", "missing_numbers", false)?;
+  grammar.build_db(&path, Default::default())?;
+
+  Ok(())
+}
+
+#[test]
+fn rejects_unsupported_targets() -> RadlrResult<()> {
+  let root = std::path::PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).canonicalize()?;
+  let path = root.join("test_grammar.radlr");
+  let output = root.join("build");
+
+  for target in [TargetLanguage::Llvm] {
+    let mut build_config = BuildConfig::new(&path);
+    build_config.source_out = &output;
+    build_config.lib_out = &output;
+
+    assert!(fs_build(build_config, Default::default(), target).is_err());
+  }
+
+  let mut build_config = BuildConfig::new(&path);
+  build_config.source_out = &output;
+  build_config.lib_out = &output;
+  build_config.parser_type = ParserType::Binary;
+
+  assert!(fs_build(build_config, Default::default(), TargetLanguage::Rust).is_err());
+
+  Ok(())
+}
+
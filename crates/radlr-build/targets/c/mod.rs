@@ -0,0 +1,80 @@
+use super::common::{build_parser_states, write_parser_source, LookupTables};
+use crate::BuildConfig;
+use radlr_core::*;
+use radlr_rust_runtime::types::BytecodeParserDB;
+use std::{fs::*, io::Write, path::PathBuf};
+
+const HEADER_SCRIPT: &'static str = include_str!("c_header_script.atat");
+const PARSER_SCRIPT: &'static str = include_str!("c_parser_script.atat");
+const RUNTIME_HEADER: &'static str = include_str!("radlr_runtime.h");
+const RUNTIME: &'static str = include_str!("radlr_runtime.c");
+
+/// Writes a C99 header declaring the parser API, and a source file holding the
+/// parser tables and the bytecode interpreter.
+///
+/// The C target does not generate AST sources; the shift, reduce, and skip
+/// callbacks of `radlr_parse` are used to build parse results instead.
+pub fn build(db: &RadlrDatabase, build_config: BuildConfig, parser_config: ParserConfig) -> RadlrResult<()> {
+  if !build_config.build_parser {
+    return Ok(());
+  }
+
+  let out_dir = build_config.source_out;
+
+  let (header_name, source_name) = if let Some(name_prefix) = build_config.name_prefix {
+    (format!("{name_prefix}_parser.h"), format!("{name_prefix}_parser.c"))
+  } else {
+    ("parser.h".to_string(), "parser.c".to_string())
+  };
+
+  let (header_path, source_path) = (out_dir.join(&header_name), out_dir.join(&source_name));
+
+  let parser = build_parser_states(db, &build_config, parser_config)?;
+
+  let bytecode = radlr_bytecode::compile_bytecode(&parser, false)?;
+
+  let header_guard = header_name.to_uppercase().replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+  let tables = LookupTables::new(&bytecode);
+
+  let properties = [
+    ("header_name", header_name.as_str()),
+    ("header_guard", header_guard.as_str()),
+    ("entry_addresses", tables.entry_addresses.as_str()),
+    ("entry_names", tables.entry_names.as_str()),
+    ("entry_ids", tables.entry_ids.as_str()),
+    ("token_names", tables.token_names.as_str()),
+    ("state_tokens", tables.state_tokens.as_str()),
+    ("token_lists", tables.token_lists.as_str()),
+  ];
+
+  // The generated parts of each file are followed by the static declarations
+  // and definitions of the runtime.
+  write_header(db, bytecode.clone(), header_path, &properties)?;
+
+  let mut source = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&source_path)?;
+
+  write_parser_source(db, PARSER_SCRIPT, bytecode, source_path.clone(), source_path, &mut source, &properties)?;
+
+  source.write_all(RUNTIME.as_bytes())?;
+  source.flush()?;
+
+  Ok(())
+}
+
+/// Writes the header declaring the parser API. The LLVM target implements the
+/// same API, and shares this header with the C target.
+pub(super) fn write_header(
+  db: &RadlrDatabase,
+  bytecode: BytecodeParserDB,
+  header_path: PathBuf,
+  properties: &[(&str, &str)],
+) -> RadlrResult<()> {
+  let mut header = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&header_path)?;
+
+  write_parser_source(db, HEADER_SCRIPT, bytecode, header_path.clone(), header_path, &mut header, properties)?;
+
+  header.write_all(RUNTIME_HEADER.as_bytes())?;
+  header.flush()?;
+
+  Ok(())
+}
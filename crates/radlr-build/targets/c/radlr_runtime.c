@@ -0,0 +1,691 @@
+
+/* Opcodes ------------------------------------------------------------------ */
+
+#define OP_NO_OP 0
+#define OP_PASS 1
+#define OP_FAIL 2
+#define OP_SHIFT_CHAR 3
+#define OP_SHIFT_TOKEN 4
+#define OP_SHIFT_TOKEN_SCANLESS 5
+#define OP_PEEK_TOKEN 6
+#define OP_PEEK_TOKEN_SCANLESS 7
+#define OP_SKIP_TOKEN 8
+#define OP_SKIP_TOKEN_SCANLESS 9
+#define OP_PEEK_SKIP_TOKEN 10
+#define OP_PEEK_SKIP_TOKEN_SCANLESS 11
+#define OP_PEEK_RESET 12
+#define OP_ACCEPT 13
+#define OP_POP_GOTO 14
+#define OP_PUSH_GOTO 15
+#define OP_PUSH_EXCEPTION_HANDLER 16
+#define OP_GOTO 17
+#define OP_ASSIGN_TOKEN 18
+#define OP_REDUCE 19
+#define OP_VECTOR_BRANCH 20
+#define OP_HASH_BRANCH 21
+#define OP_BYTE_SEQUENCE 22
+#define OP_FORK 23
+#define OP_READ_CODEPOINT 24
+
+/* Match input types -------------------------------------------------------- */
+
+#define INPUT_NONTERMINAL 0
+#define INPUT_TOKEN 1
+#define INPUT_CLASS 2
+#define INPUT_CODEPOINT 3
+#define INPUT_BYTE 4
+#define INPUT_END_OF_FILE 5
+#define INPUT_BYTE_SCANLESS 7
+#define INPUT_CODEPOINT_SCANLESS 8
+#define INPUT_CLASS_SCANLESS 9
+#define INPUT_CST_NODE 11
+
+/* Results of a dispatch that did not produce a parse action. */
+#define RESULT_NONE 0
+#define RESULT_ACTION 1
+#define RESULT_COMPLETE_STATE 2
+#define RESULT_FAIL_STATE 3
+
+#define NORMAL_STATE_FLAG (1u << 0)
+#define STATE_HEADER (1u << 2)
+
+#define RADLR_LEN(table) (uint32_t)(sizeof(table) / sizeof(table[0]))
+
+/* Input -------------------------------------------------------------------- */
+
+static uint32_t radlr_buffer_len(void* data) {
+  return ((const radlr_buffer*)data)->len;
+}
+
+static uint8_t radlr_buffer_byte(void* data, uint32_t offset) {
+  const radlr_buffer* buffer = (const radlr_buffer*)data;
+  return offset < buffer->len ? buffer->bytes[offset] : 0;
+}
+
+radlr_input radlr_buffer_input(const radlr_buffer* buffer) {
+  radlr_input input;
+  input.data = (void*)buffer;
+  input.len = radlr_buffer_len;
+  input.byte = radlr_buffer_byte;
+  return input;
+}
+
+static uint32_t leading_ones(uint8_t byte) {
+  uint32_t count = 0;
+  while (count < 8 && (byte & (0x80 >> count)) != 0) count++;
+  return count;
+}
+
+static uint32_t codepoint(const radlr_input* input, uint32_t cursor) {
+  uint8_t header_byte = input->byte(input->data, cursor);
+  uint32_t ones = leading_ones(header_byte);
+  uint32_t num, i;
+
+  if (ones == 0) return header_byte;
+
+  num = header_byte & (0x7F >> ones);
+  for (i = 0; i + 1 < ones; i++) {
+    num = (num << 6) | (input->byte(input->data, cursor + 1 + i) & 0x3F);
+  }
+  return num;
+}
+
+static uint32_t utf8_byte_length(uint32_t cp) {
+  if (cp == 0) return 0;
+  if ((cp & 0x7F) == cp) return 1;
+  if ((cp & 0x7FF) == cp) return 2;
+  if ((cp & 0xFFFF) == cp) return 3;
+  return 4;
+}
+
+static uint32_t codepoint_class(uint32_t cp) {
+  uint32_t low = 0;
+  uint32_t high = RADLR_LEN(RADLR_CLASS_RANGES) / 2 - 1;
+  while (low < high) {
+    uint32_t mid = (low + high + 1) >> 1;
+    if (RADLR_CLASS_RANGES[mid << 1] <= cp) low = mid;
+    else high = mid - 1;
+  }
+  return RADLR_CLASS_RANGES[(low << 1) + 1];
+}
+
+/* Symbols ------------------------------------------------------------------ */
+
+static uint32_t radlr_nonterm_address(uint32_t nonterm_id) {
+  uint32_t i;
+  for (i = 0; i < RADLR_LEN(RADLR_ENTRY_ADDRESSES); i += 2) {
+    if (RADLR_ENTRY_ADDRESSES[i] == nonterm_id) return RADLR_ENTRY_ADDRESSES[i + 1];
+  }
+  return 0;
+}
+
+int64_t radlr_entry_id(const char* name) {
+  uint32_t i;
+  for (i = 0; i < RADLR_LEN(RADLR_ENTRY_NAMES); i++) {
+    if (strcmp(RADLR_ENTRY_NAMES[i], name) == 0) return RADLR_ENTRY_IDS[i];
+  }
+  return -1;
+}
+
+const char* radlr_token_name(uint32_t token_id) {
+  return token_id < RADLR_LEN(RADLR_TOKEN_NAMES) ? RADLR_TOKEN_NAMES[token_id] : NULL;
+}
+
+const uint32_t* radlr_expected_tokens(uint32_t state, size_t* count) {
+  uint32_t low = 0;
+  uint32_t high = RADLR_LEN(RADLR_STATE_TOKENS) / 3;
+
+  while (low < high) {
+    uint32_t mid = (low + high) >> 1;
+    uint32_t address = RADLR_STATE_TOKENS[mid * 3];
+    if (address == state) {
+      *count = RADLR_STATE_TOKENS[mid * 3 + 2];
+      return *count > 0 ? RADLR_TOKEN_LISTS + RADLR_STATE_TOKENS[mid * 3 + 1] : NULL;
+    } else if (address < state) {
+      low = mid + 1;
+    } else {
+      high = mid;
+    }
+  }
+
+  *count = 0;
+  return NULL;
+}
+
+/* Kernel ------------------------------------------------------------------- */
+
+static uint32_t u16(uint32_t address) {
+  return (uint32_t)RADLR_BYTECODE[address] | ((uint32_t)RADLR_BYTECODE[address + 1] << 8);
+}
+
+static uint32_t u32(uint32_t address) {
+  return (uint32_t)RADLR_BYTECODE[address] | ((uint32_t)RADLR_BYTECODE[address + 1] << 8)
+       | ((uint32_t)RADLR_BYTECODE[address + 2] << 16) | ((uint32_t)RADLR_BYTECODE[address + 3] << 24);
+}
+
+static uint32_t next_address(uint32_t address) {
+  switch (RADLR_BYTECODE[address]) {
+    case OP_FORK: return address + 3 + (u16(address + 1) << 2);
+    case OP_BYTE_SEQUENCE: return address + 7 + u16(address + 1);
+    case OP_VECTOR_BRANCH: case OP_HASH_BRANCH: return address + u32(address + 2);
+    case OP_REDUCE: return address + 11;
+    case OP_GOTO: case OP_PUSH_GOTO: case OP_PUSH_EXCEPTION_HANDLER: return address + 6;
+    case OP_ASSIGN_TOKEN: return address + 5;
+    default: return address + 1;
+  }
+}
+
+static void push_state(radlr_parser* ctx, uint32_t address, uint32_t is_state_entry) {
+  if (ctx->stack_len + 2 > ctx->stack_cap) {
+    uint32_t cap = ctx->stack_cap > 0 ? ctx->stack_cap * 2 : 64;
+    uint32_t* stack = (uint32_t*)realloc(ctx->stack, cap * sizeof(uint32_t));
+    if (stack == NULL) {
+      ctx->out_of_memory = 1;
+      return;
+    }
+    ctx->stack = stack;
+    ctx->stack_cap = cap;
+  }
+  ctx->stack[ctx->stack_len++] = address;
+  ctx->stack[ctx->stack_len++] = is_state_entry;
+}
+
+/* Pops the top `[address, is_state_entry]` pair from the goto stack and
+ * returns its address. */
+static uint32_t pop_state(radlr_parser* ctx) {
+  if (ctx->stack_len < 2) return 0;
+  ctx->stack_len -= 2;
+  return ctx->stack[ctx->stack_len];
+}
+
+static void set_error(radlr_parser* ctx, uint32_t state, radlr_action* action) {
+  action->type = RADLR_ACTION_ERROR;
+  action->nonterminal_id = ctx->nonterm;
+  action->state = state;
+  action->token.token_id = ctx->tok_id;
+  action->token.byte_offset = ctx->sym_ptr;
+  action->token.byte_length = ctx->tok_byte_len;
+  action->token.line_num = ctx->chkp_line_num;
+  action->token.line_offset = ctx->chkp_line_off;
+}
+
+static void skip_token(radlr_parser* ctx, radlr_action* action) {
+  uint32_t original_offset = ctx->sym_ptr;
+  uint32_t tok_len = ctx->tok_byte_len;
+  action->type = RADLR_ACTION_SKIP;
+  action->token.token_id = ctx->tok_id;
+  action->token.byte_offset = original_offset;
+  action->token.byte_length = tok_len;
+  action->token.line_num = ctx->start_line_num;
+  action->token.line_offset = ctx->start_line_off;
+  ctx->input_ptr = ctx->sym_ptr = original_offset + tok_len;
+  ctx->tok_id = ctx->recovery_tok_id = 0;
+}
+
+static int dispatch(uint32_t base_address, radlr_parser* ctx, const radlr_input* input, radlr_action* action,
+                    uint32_t* next_out, uint32_t* block_out);
+
+static void token_scan(uint32_t scan_address, radlr_parser* ctx, const radlr_input* input) {
+  uint32_t stack[4];
+  uint32_t stack_len = 0;
+  uint32_t address, state;
+  radlr_action action;
+
+  ctx->tok_id = 0;
+  ctx->input_ptr = ctx->sym_ptr;
+
+  stack[stack_len++] = 0;
+  stack[stack_len++] = 0;
+  stack[stack_len++] = NORMAL_STATE_FLAG | STATE_HEADER;
+  stack[stack_len++] = scan_address;
+
+  address = stack[--stack_len];
+  state = stack[--stack_len];
+
+  while (state >= 1) {
+    uint32_t next = 0, block = 0;
+    int result = dispatch(address, ctx, input, &action, &next, &block);
+
+    if (result == RESULT_FAIL_STATE) break;
+
+    if (result != RESULT_COMPLETE_STATE && next > 0) {
+      stack[stack_len++] = NORMAL_STATE_FLAG;
+      stack[stack_len++] = next;
+    }
+
+    address = stack[--stack_len];
+    state = stack[--stack_len];
+  }
+
+  ctx->input_ptr = ctx->sym_ptr;
+}
+
+static uint32_t get_input_value(uint32_t input_type, uint32_t scan_address, radlr_parser* ctx, const radlr_input* input,
+                                int* is_nl) {
+  *is_nl = 0;
+  switch (input_type) {
+    case INPUT_NONTERMINAL:
+      return ctx->nonterm;
+    case INPUT_END_OF_FILE:
+      return ctx->input_ptr >= input->len(input->data) ? 1 : 0;
+    case INPUT_TOKEN:
+      if (ctx->recovery_tok_id > 0) {
+        ctx->tok_id = ctx->recovery_tok_id;
+        ctx->tok_byte_len = 0;
+        ctx->byte_len = 0;
+      } else {
+        token_scan(scan_address, ctx, input);
+      }
+      return ctx->tok_id;
+    case INPUT_CST_NODE:
+      return 0xFFFFFFFF;
+    case INPUT_BYTE: {
+      uint8_t byte = input->byte(input->data, ctx->input_ptr);
+      ctx->byte_len = byte > 0 ? 1 : 0;
+      *is_nl = byte == 10;
+      return byte;
+    }
+    case INPUT_BYTE_SCANLESS: {
+      uint8_t byte = input->byte(input->data, ctx->input_ptr);
+      ctx->tok_byte_len = byte > 0 ? 1 : 0;
+      *is_nl = byte == 10;
+      return byte;
+    }
+    default: {
+      uint32_t cp = codepoint(input, ctx->input_ptr);
+      uint32_t len = utf8_byte_length(cp);
+      *is_nl = cp == 10;
+      switch (input_type) {
+        case INPUT_CLASS_SCANLESS:
+          ctx->tok_byte_len = len;
+          return cp > 0 ? codepoint_class(cp) : 0;
+        case INPUT_CLASS:
+          ctx->byte_len = len;
+          return cp > 0 ? codepoint_class(cp) : 0;
+        case INPUT_CODEPOINT_SCANLESS:
+          ctx->tok_byte_len = len;
+          return cp;
+        default:
+          ctx->byte_len = len;
+          return cp;
+      }
+    }
+  }
+}
+
+static uint32_t hash_branch(uint32_t i, radlr_parser* ctx, const radlr_input* input) {
+  uint32_t input_type = RADLR_BYTECODE[i + 1];
+  uint32_t default_block = i + u32(i + 2);
+  uint32_t scan_address = u32(i + 6);
+  uint32_t hash_mask = (1u << u32(i + 14)) - 1;
+  uint32_t table_start = i + 18;
+  int is_nl;
+  uint32_t input_value = get_input_value(input_type, scan_address, ctx, input, &is_nl);
+  int32_t hash_index = (int32_t)(input_value & hash_mask);
+
+  while (1) {
+    uint32_t cell = u32(table_start + (uint32_t)hash_index * 4);
+    uint32_t value = cell & 0x7FF;
+    uint32_t off = (cell >> 11) & 0x7FF;
+    int32_t next = (int32_t)((cell >> 22) & 0x3FF) - 512;
+
+    if (value == input_value) {
+      if (is_nl) {
+        ctx->end_line_num += 1;
+        ctx->end_line_off = ctx->input_ptr;
+      }
+      return i + off;
+    } else if (next != 0) {
+      hash_index += next;
+    } else {
+      return default_block;
+    }
+  }
+}
+
+static uint32_t vector_branch(uint32_t i, radlr_parser* ctx, const radlr_input* input) {
+  uint32_t input_type = RADLR_BYTECODE[i + 1];
+  uint32_t default_block = i + u32(i + 2);
+  uint32_t scan_address = u32(i + 6);
+  uint32_t table_length = u32(i + 10);
+  uint32_t value_offset = u32(i + 14);
+  uint32_t table_start = i + 18;
+  int is_nl;
+  uint32_t input_value = get_input_value(input_type, scan_address, ctx, input, &is_nl);
+  uint32_t value_index = input_value - value_offset;
+
+  if (input_value >= value_offset && value_index < table_length) {
+    if (is_nl) {
+      ctx->end_line_num += 1;
+      ctx->end_line_off = ctx->input_ptr;
+    }
+    return i + u32(table_start + value_index * 4);
+  } else {
+    return default_block;
+  }
+}
+
+static int dispatch(uint32_t base_address, radlr_parser* ctx, const radlr_input* input, radlr_action* action,
+                    uint32_t* next_out, uint32_t* block_out) {
+  uint32_t block_base = base_address;
+  uint32_t i = base_address;
+
+  while (1) {
+    int result = RESULT_NONE;
+    uint32_t next = 0;
+    int is_goto = 0;
+
+    switch (RADLR_BYTECODE[i]) {
+      case OP_BYTE_SEQUENCE: {
+        uint32_t length = u16(i + 1);
+        uint32_t default_offset = u32(i + 3);
+        uint32_t offset = ctx->input_ptr;
+        uint32_t line_incr = 0;
+        uint32_t line_offset = ctx->end_line_off;
+        int matched = 1;
+        uint32_t b;
+        for (b = 0; b < length; b++) {
+          uint8_t byte = RADLR_BYTECODE[i + 7 + b];
+          if (byte != input->byte(input->data, offset + b)) {
+            matched = 0;
+            break;
+          }
+          if (byte == 10) {
+            line_incr++;
+            line_offset = offset + b;
+          }
+        }
+        if (matched) {
+          ctx->end_line_off = line_offset;
+          ctx->end_line_num += line_incr;
+          ctx->byte_len = length;
+          next = next_address(i);
+        } else if (default_offset > 0) {
+          next = i + default_offset;
+        } else {
+          result = RESULT_FAIL_STATE;
+        }
+      } break;
+      case OP_SHIFT_TOKEN_SCANLESS:
+        ctx->tok_byte_len = ctx->byte_len;
+        /* falls through */
+      case OP_SHIFT_TOKEN: {
+        uint32_t new_offset = ctx->sym_ptr + ctx->tok_byte_len;
+        action->type = RADLR_ACTION_SHIFT;
+        action->token.token_id = ctx->tok_id;
+        action->token.byte_offset = ctx->sym_ptr;
+        action->token.byte_length = ctx->tok_byte_len;
+        action->token.line_num = ctx->start_line_num;
+        action->token.line_offset = ctx->start_line_off;
+        action->state = base_address;
+        result = RESULT_ACTION;
+        ctx->start_line_num = ctx->end_line_num = ctx->chkp_line_num;
+        ctx->start_line_off = ctx->end_line_off = ctx->chkp_line_off;
+        ctx->anchor_ptr = ctx->sym_ptr = ctx->input_ptr = new_offset;
+        ctx->tok_id = ctx->recovery_tok_id = ctx->tok_byte_len = 0;
+        next = next_address(i);
+      } break;
+      case OP_SHIFT_CHAR:
+        ctx->input_ptr += ctx->byte_len;
+        ctx->byte_len = 0;
+        next = next_address(i);
+        break;
+      case OP_SKIP_TOKEN_SCANLESS:
+        ctx->tok_byte_len = ctx->byte_len;
+        /* falls through */
+      case OP_SKIP_TOKEN:
+        skip_token(ctx, action);
+        result = RESULT_ACTION;
+        ctx->end_line_num = ctx->start_line_num = ctx->chkp_line_num;
+        ctx->end_line_off = ctx->start_line_off = ctx->chkp_line_off;
+        next = block_base;
+        break;
+      case OP_PEEK_SKIP_TOKEN_SCANLESS:
+        ctx->tok_byte_len = ctx->byte_len;
+        /* falls through */
+      case OP_PEEK_SKIP_TOKEN: {
+        radlr_action skipped;
+        skip_token(ctx, &skipped);
+        next = block_base;
+      } break;
+      case OP_PEEK_TOKEN_SCANLESS:
+        ctx->tok_byte_len = ctx->byte_len;
+        /* falls through */
+      case OP_PEEK_TOKEN: {
+        uint32_t offset = ctx->sym_ptr + ctx->tok_byte_len;
+        ctx->sym_ptr = ctx->input_ptr = offset;
+        ctx->tok_id = ctx->recovery_tok_id = ctx->tok_byte_len = 0;
+        next = next_address(i);
+      } break;
+      case OP_PEEK_RESET:
+        ctx->sym_ptr = ctx->input_ptr = ctx->anchor_ptr;
+        ctx->tok_id = ctx->recovery_tok_id = ctx->tok_byte_len = ctx->byte_len = 0;
+        ctx->end_line_off = ctx->chkp_line_off = ctx->start_line_off;
+        ctx->end_line_num = ctx->chkp_line_num = ctx->start_line_num;
+        next = next_address(i);
+        break;
+      case OP_REDUCE:
+        ctx->nonterm = u32(i + 1);
+        action->type = RADLR_ACTION_REDUCE;
+        action->nonterminal_id = ctx->nonterm;
+        action->rule_id = u32(i + 5);
+        action->symbol_count = u16(i + 9);
+        result = RESULT_ACTION;
+        next = next_address(i);
+        break;
+      case OP_GOTO:
+        next = u32(i + 2);
+        is_goto = 1;
+        break;
+      case OP_PUSH_GOTO:
+      case OP_PUSH_EXCEPTION_HANDLER:
+        push_state(ctx, u32(i + 2), 1);
+        next = next_address(i);
+        break;
+      case OP_POP_GOTO:
+        pop_state(ctx);
+        next = next_address(i);
+        break;
+      case OP_ASSIGN_TOKEN:
+        ctx->tok_id = u32(i + 1);
+        ctx->tok_byte_len = ctx->input_ptr - ctx->sym_ptr;
+        ctx->chkp_line_num = ctx->end_line_num;
+        ctx->chkp_line_off = ctx->end_line_off;
+        next = next_address(i);
+        break;
+      case OP_VECTOR_BRANCH:
+        next = vector_branch(i, ctx, input);
+        break;
+      case OP_HASH_BRANCH:
+        next = hash_branch(i, ctx, input);
+        break;
+      case OP_READ_CODEPOINT: {
+        int is_nl;
+        uint32_t cp = get_input_value(INPUT_CODEPOINT, 0, ctx, input, &is_nl);
+        if (cp == 0) {
+          result = RESULT_FAIL_STATE;
+        } else {
+          if (is_nl) {
+            ctx->chkp_line_num += 1;
+            ctx->chkp_line_off = ctx->input_ptr;
+          }
+          next = next_address(i);
+        }
+      } break;
+      case OP_PASS:
+        result = RESULT_COMPLETE_STATE;
+        break;
+      case OP_FORK:
+        action->type = RADLR_ACTION_FORK;
+        action->state = i;
+        result = RESULT_ACTION;
+        break;
+      case OP_ACCEPT:
+        ctx->is_finished = 1;
+        action->type = RADLR_ACTION_ACCEPT;
+        action->nonterminal_id = ctx->nonterm;
+        action->token.token_id = 0;
+        action->token.byte_offset = ctx->sym_ptr;
+        action->token.byte_length = 0;
+        action->token.line_num = ctx->end_line_num;
+        action->token.line_offset = ctx->end_line_off;
+        result = RESULT_ACTION;
+        break;
+      case OP_NO_OP:
+        next = next_address(i);
+        break;
+      default:
+        /* OP_FAIL, or an invalid opcode. */
+        result = RESULT_FAIL_STATE;
+        break;
+    }
+
+    if (result == RESULT_NONE) {
+      if (is_goto) block_base = next;
+      i = next;
+    } else {
+      *next_out = next;
+      *block_out = block_base;
+      return result;
+    }
+  }
+}
+
+int radlr_parser_init(radlr_parser* parser, uint32_t nonterm_id) {
+  uint32_t address = radlr_nonterm_address(nonterm_id);
+
+  memset(parser, 0, sizeof(radlr_parser));
+
+  if (address == 0) return -1;
+
+  push_state(parser, 0, 0);
+  push_state(parser, address, 1);
+
+  if (parser->out_of_memory) {
+    radlr_parser_free(parser);
+    return -1;
+  }
+
+  return 0;
+}
+
+int radlr_parser_next(radlr_parser* ctx, const radlr_input* input, radlr_action* action) {
+  uint32_t address;
+
+  if (ctx->is_finished) return 0;
+
+  address = pop_state(ctx);
+
+  while (1) {
+    uint32_t next = 0, block = 0;
+    int result;
+
+    if (address < 1) {
+      ctx->is_finished = 1;
+      set_error(ctx, address, action);
+      return 1;
+    }
+
+    result = dispatch(address, ctx, input, action, &next, &block);
+
+    if (ctx->out_of_memory) {
+      ctx->is_finished = 1;
+      return -1;
+    }
+
+    switch (result) {
+      case RESULT_COMPLETE_STATE:
+        address = pop_state(ctx);
+        break;
+      case RESULT_FAIL_STATE:
+        ctx->is_finished = 1;
+        set_error(ctx, block, action);
+        return 1;
+      default:
+        if (next > 0) push_state(ctx, next, 0);
+        return ctx->out_of_memory ? -1 : 1;
+    }
+  }
+}
+
+void radlr_parser_free(radlr_parser* parser) {
+  free(parser->stack);
+  parser->stack = NULL;
+  parser->stack_len = 0;
+  parser->stack_cap = 0;
+}
+
+/* Events ------------------------------------------------------------------- */
+
+radlr_status radlr_parse(uint32_t nonterm_id, const radlr_input* input, const radlr_events* events, radlr_action* last_action) {
+  radlr_parser parser;
+  radlr_action action;
+  radlr_status status = RADLR_UNEXPECTED_INPUT;
+  int result;
+
+  memset(&action, 0, sizeof(radlr_action));
+
+  if (radlr_parser_init(&parser, nonterm_id) != 0) {
+    return radlr_nonterm_address(nonterm_id) == 0 ? RADLR_INVALID_ENTRY : RADLR_OUT_OF_MEMORY;
+  }
+
+  while ((result = radlr_parser_next(&parser, input, &action)) > 0) {
+    int stop = 0;
+
+    switch (action.type) {
+      case RADLR_ACTION_SHIFT:
+        stop = events != NULL && events->shift != NULL && events->shift(events->data, &action.token);
+        break;
+      case RADLR_ACTION_SKIP:
+        stop = events != NULL && events->skip != NULL && events->skip(events->data, &action.token);
+        break;
+      case RADLR_ACTION_REDUCE:
+        stop = events != NULL && events->reduce != NULL
+            && events->reduce(events->data, action.nonterminal_id, action.rule_id, action.symbol_count);
+        break;
+      case RADLR_ACTION_ACCEPT:
+        if (action.token.byte_offset != input->len(input->data)) {
+          status = RADLR_INCOMPLETE_INPUT;
+        } else if (action.nonterminal_id != nonterm_id) {
+          status = RADLR_UNEXPECTED_NONTERMINAL;
+        } else {
+          status = RADLR_OK;
+        }
+        break;
+      case RADLR_ACTION_ERROR:
+        status = RADLR_UNEXPECTED_INPUT;
+        break;
+      case RADLR_ACTION_FORK:
+        status = RADLR_FORK_UNSUPPORTED;
+        parser.is_finished = 1;
+        break;
+    }
+
+    if (stop) {
+      status = RADLR_ABORTED;
+      break;
+    }
+  }
+
+  if (result < 0) status = RADLR_OUT_OF_MEMORY;
+
+  if (last_action != NULL) *last_action = action;
+
+  radlr_parser_free(&parser);
+
+  return status;
+}
+
+const char* radlr_status_message(radlr_status status) {
+  switch (status) {
+    case RADLR_OK: return "The input was parsed successfully";
+    case RADLR_UNEXPECTED_INPUT: return "Encountered an unexpected character";
+    case RADLR_INCOMPLETE_INPUT: return "Failed to read entire input";
+    case RADLR_UNEXPECTED_NONTERMINAL: return "Top symbol did not match the target nonterminal";
+    case RADLR_FORK_UNSUPPORTED: return "No implementation of fork resolution is available";
+    case RADLR_ABORTED: return "The parse was stopped by an event callback";
+    case RADLR_INVALID_ENTRY: return "Invalid entry point";
+    case RADLR_OUT_OF_MEMORY: return "Failed to allocate the parse stack";
+  }
+  return "Unknown status";
+}
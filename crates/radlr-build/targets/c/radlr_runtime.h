@@ -0,0 +1,169 @@
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Input -------------------------------------------------------------------- */
+
+/* Provides the bytes of a UTF-8 encoded input to the parser. */
+typedef struct radlr_input {
+  /* Passed as the first argument of every callback. */
+  void* data;
+  /* Returns the number of bytes available for reading. */
+  uint32_t (*len)(void* data);
+  /* Returns the byte at `offset`, or `0` if `offset` is outside the input. */
+  uint8_t (*byte)(void* data, uint32_t offset);
+} radlr_input;
+
+/* A contiguous input buffer. */
+typedef struct radlr_buffer {
+  const uint8_t* bytes;
+  uint32_t len;
+} radlr_buffer;
+
+/* Returns an input that reads from `buffer`. The buffer must outlive the
+ * input. */
+radlr_input radlr_buffer_input(const radlr_buffer* buffer);
+
+/* Actions ------------------------------------------------------------------ */
+
+typedef enum radlr_action_type {
+  RADLR_ACTION_SHIFT,
+  RADLR_ACTION_SKIP,
+  RADLR_ACTION_REDUCE,
+  RADLR_ACTION_ACCEPT,
+  RADLR_ACTION_ERROR,
+  RADLR_ACTION_FORK,
+} radlr_action_type;
+
+/* The location of a token within the input. */
+typedef struct radlr_token {
+  uint32_t token_id;
+  uint32_t byte_offset;
+  uint32_t byte_length;
+  uint32_t line_num;
+  uint32_t line_offset;
+} radlr_token;
+
+/* A parse action yielded by `radlr_parser_next`.
+ *
+ * - `SHIFT` and `SKIP`: `token` is the shifted or skipped token, and `state`
+ *   is the address of the state that emitted a shift.
+ * - `REDUCE`: `symbol_count` symbols are reduced to `nonterminal_id` by the
+ *   rule `rule_id`.
+ * - `ACCEPT`: `nonterminal_id` is the accepted nonterminal, and
+ *   `token.byte_offset` is the offset at which the parser stopped.
+ * - `ERROR`: `token` is the location of the unexpected input, `state` is the
+ *   address of the failing state, and `nonterminal_id` is the last nonterminal
+ *   that was reduced.
+ * - `FORK`: The parser encountered an ambiguity, which this runtime does not
+ *   resolve. */
+typedef struct radlr_action {
+  radlr_action_type type;
+  radlr_token token;
+  uint32_t nonterminal_id;
+  uint32_t rule_id;
+  uint32_t symbol_count;
+  uint32_t state;
+} radlr_action;
+
+/* Parser ------------------------------------------------------------------- */
+
+/* The state of a parse. Initialize with `radlr_parser_init`, and release with
+ * `radlr_parser_free`. */
+typedef struct radlr_parser {
+  /* Pairs of `[address, is_state_entry]` */
+  uint32_t* stack;
+  uint32_t stack_len;
+  uint32_t stack_cap;
+  uint32_t anchor_ptr;
+  uint32_t sym_ptr;
+  uint32_t input_ptr;
+  uint32_t tok_id;
+  uint32_t recovery_tok_id;
+  uint32_t tok_byte_len;
+  uint32_t byte_len;
+  uint32_t nonterm;
+  uint32_t start_line_off;
+  uint32_t chkp_line_off;
+  uint32_t end_line_off;
+  uint32_t start_line_num;
+  uint32_t chkp_line_num;
+  uint32_t end_line_num;
+  int is_finished;
+  int out_of_memory;
+} radlr_parser;
+
+/* Prepares `parser` to parse from the entry point of the nonterminal
+ * `nonterm_id`. Returns `0` on success, or `-1` if `nonterm_id` is not an
+ * entry point or memory could not be allocated. */
+int radlr_parser_init(radlr_parser* parser, uint32_t nonterm_id);
+
+/* Writes the next parse action to `action`. Returns `1` if an action was
+ * written, `0` once the parser has accepted or failed, and `-1` if memory
+ * could not be allocated. */
+int radlr_parser_next(radlr_parser* parser, const radlr_input* input, radlr_action* action);
+
+/* Releases the memory held by `parser`. */
+void radlr_parser_free(radlr_parser* parser);
+
+/* Events ------------------------------------------------------------------- */
+
+/* Callbacks invoked by `radlr_parse`. Any callback may be `NULL`. A callback
+ * that returns a nonzero value stops the parse with `RADLR_ABORTED`. */
+typedef struct radlr_events {
+  /* Passed as the first argument of every callback. */
+  void* data;
+  int (*shift)(void* data, const radlr_token* token);
+  int (*skip)(void* data, const radlr_token* token);
+  int (*reduce)(void* data, uint32_t nonterminal_id, uint32_t rule_id, uint32_t symbol_count);
+} radlr_events;
+
+typedef enum radlr_status {
+  /* The input was parsed successfully. */
+  RADLR_OK,
+  /* The parser encountered input it did not expect. */
+  RADLR_UNEXPECTED_INPUT,
+  /* The parser accepted before reading the entire input. */
+  RADLR_INCOMPLETE_INPUT,
+  /* The parser accepted a nonterminal other than the requested entry point. */
+  RADLR_UNEXPECTED_NONTERMINAL,
+  /* The grammar is ambiguous at this point of the input. */
+  RADLR_FORK_UNSUPPORTED,
+  /* An event callback requested the parse to stop. */
+  RADLR_ABORTED,
+  /* The nonterminal id is not an entry point of this parser. */
+  RADLR_INVALID_ENTRY,
+  /* Memory for the parse stack could not be allocated. */
+  RADLR_OUT_OF_MEMORY,
+} radlr_status;
+
+/* Parses `input` from the entry point `nonterm_id`, and calls the callbacks of
+ * `events` for each shift, skip, and reduce action. If `last_action` is not
+ * `NULL`, the final action of the parser is written to it, which holds the
+ * location of the error if the parse failed. */
+radlr_status radlr_parse(uint32_t nonterm_id, const radlr_input* input, const radlr_events* events, radlr_action* last_action);
+
+/* Returns a description of `status`. */
+const char* radlr_status_message(radlr_status status);
+
+/* Symbols ------------------------------------------------------------------ */
+
+/* Returns the nonterminal id of the entry point `name`, or `-1` if the parser
+ * has no such entry point. */
+int64_t radlr_entry_id(const char* name);
+
+/* Returns the source string of the token `token_id`, or `NULL` if it is not
+ * known. */
+const char* radlr_token_name(uint32_t token_id);
+
+/* Returns the ids of the tokens expected by the state at `state`, and writes
+ * their number to `count`. Returns `NULL` if the state has no expected
+ * tokens. */
+const uint32_t* radlr_expected_tokens(uint32_t state, size_t* count);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -28,10 +28,46 @@ pub fn build_ast_source(
   })
 }
 
-pub fn build_parser_states(db: &RadlrDatabase, parser_config: ParserConfig) -> Result<RadlrIRParser, RadlrError> {
+/// Same as [build_ast_source], but appends the AST source to `output` instead
+/// of writing it to a new file.
+pub fn write_ast_source<W: Write>(
+  db: &RadlrDatabase,
+  script: &str,
+  output: W,
+  build_config: BuildConfig<'_>,
+  extra_config_properties: &[(&str, &str)],
+) -> RadlrResult<()> {
+  let adb: AscriptDatabase = db.into();
+  Ok(if let Some(errors) = adb.get_errors() {
+    for error in errors {
+      eprintln!("{}", error);
+    }
+  } else {
+    adb.format(script, output, 100, build_config.ast_struct_name, extra_config_properties)?.flush()?;
+  })
+}
+
+pub fn build_parser_states(
+  db: &RadlrDatabase,
+  build_config: &BuildConfig,
+  parser_config: ParserConfig,
+) -> Result<RadlrIRParser, RadlrError> {
   let pool = radlr_core::worker_pool::StandardPool::new_with_max_workers().unwrap();
-  let states = db.build_states(parser_config, &pool)?;
-  let parser = states.build_ir_parser(true, false, &pool)?;
+  let states = match build_config.state_cache_dir {
+    Some(cache_dir) => db.build_states_cached(parser_config, cache_dir, &pool)?,
+    None => db.build_states(parser_config, &pool)?,
+  };
+  let parser = match build_config.optimize_config {
+    Some(opt_config) => {
+      let parser = states.build_ir_parser_with_passes(Some(opt_config), false, &pool)?;
+      if opt_config.report_passes {
+        // Build scripts reserve stdout for cargo directives.
+        eprintln!("{}", parser.report.to_string());
+      }
+      parser
+    }
+    None => states.build_ir_parser(true, false, &pool)?,
+  };
   Ok(parser)
 }
 
@@ -41,9 +77,24 @@ pub fn build_parser_source(
   bytecode: radlr_rust_runtime::types::BytecodeParserDB,
   binary_out_path: std::path::PathBuf,
   parser_out_path: std::path::PathBuf,
+  extra_config_properties: &[(&str, &str)],
 ) -> Result<(), RadlrError> {
   let parser = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&parser_out_path)?;
 
+  write_parser_source(db, parser_script, bytecode, binary_out_path, parser_out_path, parser, extra_config_properties)
+}
+
+/// Same as [build_parser_source], but appends the parser source to `output`
+/// instead of writing it to `parser_out_path`.
+pub fn write_parser_source<W: Write>(
+  db: &RadlrDatabase,
+  parser_script: &str,
+  bytecode: radlr_rust_runtime::types::BytecodeParserDB,
+  binary_out_path: std::path::PathBuf,
+  parser_out_path: std::path::PathBuf,
+  parser: W,
+  extra_config_properties: &[(&str, &str)],
+) -> Result<(), RadlrError> {
   let s_store = db.get_internal().string_store();
 
   let mut ctx: FormatterContext = FormatterContext::new("RustForm", s_store.clone());
@@ -84,6 +135,12 @@ pub fn build_parser_source(
 
   let binary = bytecode.bytecode.as_slice();
 
+  let class_ranges = codepoint_class_ranges();
+  let binary_base64 = base64(binary).intern(s_store);
+  let class_ranges_base64 = base64(&class_ranges.iter().flat_map(|v| v.to_le_bytes()).collect::<Vec<_>>()).intern(s_store);
+  let binary_array = array_literal(binary.iter().map(|b| format!("0x{b:02X}")), 16).intern(s_store);
+  let class_ranges_array = array_literal(class_ranges.iter(), 16).intern(s_store);
+
   ctx.set_val("ir_token_lookup", Value::Obj(&states_lu));
   ctx.set_val("binary_path", Value::Str(bin_path));
   ctx.set_val("binary", Value::Obj(&binary));
@@ -92,8 +149,19 @@ pub fn build_parser_source(
   ctx.set_val("nonterm_id_to_address", Value::Obj(&nonterm_id_to_address));
   ctx.set_val("state_to_token_ids_map", Value::Obj(&state_to_token_ids_map));
   ctx.set_val("token_maps", Value::Obj(&token_maps));
+  ctx.set_val("binary_base64", Value::Str(binary_base64));
+  ctx.set_val("class_ranges", Value::Obj(&class_ranges));
+  ctx.set_val("class_ranges_base64", Value::Str(class_ranges_base64));
+  ctx.set_val("binary_array", Value::Str(binary_array));
+  ctx.set_val("class_ranges_array", Value::Str(class_ranges_array));
+  ctx.set_val("default_entry", Value::Int(bytecode.default_entry.nonterm_id as isize));
+  ctx.set_val("grammar_hash", Value::Str(format!("0x{:016X}", bytecode.grammar_hash).intern(s_store)));
   ctx.set_val("ALLOW_UPPER_ATTRIBUTES", Value::Int(0));
 
+  for (name, value) in extra_config_properties {
+    ctx.set_val(name, Value::Str(value.intern(s_store)));
+  }
+
   ctx.max_width = 100;
 
   let f: Formatter = FormatterResult::from(parser_script).into_result()?;
@@ -103,6 +171,186 @@ pub fn build_parser_source(
   Ok(())
 }
 
+/// Run-length encodes the codepoint class table of the Rust runtime into
+/// `[first_codepoint, class, ...]` pairs, for targets that do not have
+/// access to the full lookup table.
+pub fn codepoint_class_ranges() -> Vec<u32> {
+  use radlr_rust_runtime::utf8::lookup_table::CHAR_LU_TABLE;
+
+  let mut ranges = vec![];
+  let mut last_class = u32::MAX;
+
+  for (codepoint, class) in CHAR_LU_TABLE.iter().enumerate() {
+    let class = (class & 0x1F) as u32;
+    if class != last_class {
+      ranges.push(codepoint as u32);
+      ranges.push(class);
+      last_class = class;
+    }
+  }
+
+  ranges
+}
+
+/// Encodes `bytes` as a standard, padded base64 string. Used to embed binary
+/// data in the source of targets that lack an `include_bytes!` equivalent.
+pub fn base64(bytes: &[u8]) -> String {
+  const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+  let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+  for chunk in bytes.chunks(3) {
+    let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+    let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+
+    out.push(ALPHABET[(n >> 18) as usize & 0x3F] as char);
+    out.push(ALPHABET[(n >> 12) as usize & 0x3F] as char);
+    out.push(if chunk.len() > 1 { ALPHABET[(n >> 6) as usize & 0x3F] as char } else { '=' });
+    out.push(if chunk.len() > 2 { ALPHABET[n as usize & 0x3F] as char } else { '=' });
+  }
+
+  out
+}
+
+/// Formats `values` as a brace delimited array literal with `per_row` values
+/// on each line. The formatter wraps long arrays one value per line, so large
+/// tables are passed to scripts as preformatted strings instead.
+pub fn array_literal<T: std::fmt::Display>(values: impl Iterator<Item = T>, per_row: usize) -> String {
+  let values = values.map(|v| v.to_string()).collect::<Vec<_>>();
+
+  let mut out = String::from("{\n");
+
+  for row in values.chunks(per_row) {
+    out += "  ";
+    out += &row.join(", ");
+    out += ",\n";
+  }
+
+  out += "}";
+  out
+}
+
+/// The string literal syntax of a target language.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StringSyntax {
+  /// A C99 or C++17 string literal. Everything but printable ASCII is
+  /// written as a three digit octal escape, which, unlike `\x`, can not
+  /// absorb a following hex digit. `?` is escaped so that sequences such as
+  /// `??=` are not read as trigraphs.
+  C,
+  /// A JavaScript or TypeScript string literal. Control characters are
+  /// written as `\xNN` escapes, and the line and paragraph separators as
+  /// `\uNNNN` escapes. Other characters are written as is.
+  JavaScript,
+}
+
+/// Formats `value` as a double quoted string literal of the target language.
+/// All grammar provided strings, such as token names, must be embedded in
+/// generated sources through this function.
+pub fn string_literal(value: &str, syntax: StringSyntax) -> String {
+  use std::fmt::Write;
+
+  let mut out = String::with_capacity(value.len() + 2);
+  out.push('"');
+
+  match syntax {
+    StringSyntax::C => {
+      for byte in value.bytes() {
+        match byte {
+          b'"' => out += "\\\"",
+          b'\\' => out += "\\\\",
+          b'?' => out += "\\?",
+          b'\n' => out += "\\n",
+          b'\t' => out += "\\t",
+          b' '..=b'~' => out.push(byte as char),
+          _ => write!(out, "\\{byte:03o}").unwrap(),
+        }
+      }
+    }
+    StringSyntax::JavaScript => {
+      for char in value.chars() {
+        match char {
+          '"' => out += "\\\"",
+          '\\' => out += "\\\\",
+          '\n' => out += "\\n",
+          '\t' => out += "\\t",
+          '\u{2028}' | '\u{2029}' => write!(out, "\\u{:04X}", char as u32).unwrap(),
+          c if c.is_control() && (c as u32) < 0x100 => write!(out, "\\x{:02X}", c as u32).unwrap(),
+          c => out.push(c),
+        }
+      }
+    }
+  }
+
+  out.push('"');
+  out
+}
+
+/// Formats the token strings of a parser as the `[id, "string"]` entries of a
+/// JavaScript array literal. Shared by the TypeScript and JavaScript targets.
+pub fn js_token_strings(bytecode: &radlr_rust_runtime::types::BytecodeParserDB) -> String {
+  let mut tokens = bytecode.token_id_to_str.iter().collect::<Vec<_>>();
+  tokens.sort();
+
+  tokens
+    .into_iter()
+    .map(|(id, name)| format!("\n  [{id}, {}],", string_literal(name, StringSyntax::JavaScript)))
+    .collect()
+}
+
+/// The symbol lookup tables of a parser, as C array literals. Shared by the C
+/// and C++ targets.
+pub struct LookupTables {
+  pub entry_addresses: String,
+  pub entry_names:     String,
+  pub entry_ids:       String,
+  pub token_names:     String,
+  pub state_tokens:    String,
+  pub token_lists:     String,
+}
+
+impl LookupTables {
+  pub fn new(bytecode: &radlr_rust_runtime::types::BytecodeParserDB) -> Self {
+    let mut entry_addresses = bytecode.nonterm_id_to_address.iter().map(|(id, address)| [*id, *address]).collect::<Vec<_>>();
+    entry_addresses.sort();
+
+    let mut entries = bytecode.nonterm_name_to_id.iter().collect::<Vec<_>>();
+    entries.sort();
+
+    let token_count = bytecode.token_id_to_str.keys().max().map(|id| *id as usize + 1).unwrap_or_default();
+    let token_names = (0..token_count as u32).map(|id| match bytecode.token_id_to_str.get(&id) {
+      Some(name) => string_literal(name, StringSyntax::C),
+      None => "NULL".to_string(),
+    });
+
+    // The `[0, 0, 0]` triple keeps the table from being empty. Address `0` is
+    // never a valid state.
+    let mut state_tokens = vec![[0, 0, 0]];
+    let mut token_lists = vec![];
+
+    let mut states = bytecode.state_to_token_ids_map.iter().collect::<Vec<_>>();
+    states.sort();
+
+    for (state, token_ids) in states {
+      state_tokens.push([*state, token_lists.len() as u32, token_ids.len() as u32]);
+      token_lists.extend(token_ids.iter().cloned());
+    }
+
+    if token_lists.is_empty() {
+      token_lists.push(0);
+    }
+
+    Self {
+      entry_addresses: array_literal(entry_addresses.into_iter().flatten(), 16),
+      entry_names:     array_literal(entries.iter().map(|(name, _)| string_literal(name, StringSyntax::C)), 4),
+      entry_ids:       array_literal(entries.iter().map(|(_, id)| **id), 16),
+      token_names:     array_literal(token_names, 4),
+      state_tokens:    array_literal(state_tokens.into_iter().flatten(), 15),
+      token_lists:     array_literal(token_lists.into_iter(), 16),
+    }
+  }
+}
+
 fn path_relative_from_abs(path: &Path, base: &Path) -> Option<PathBuf> {
   use std::path::Component;
 
@@ -191,3 +439,29 @@ fn relative_paths() {
 
   assert_eq!(path_c.as_os_str().to_str().unwrap(), ".");
 }
+
+#[test]
+fn string_literals_escape_quotes_and_backslashes() {
+  assert_eq!(string_literal(r#"a"b\c"#, StringSyntax::C), r#""a\"b\\c""#);
+  assert_eq!(string_literal(r#"a"b\c"#, StringSyntax::JavaScript), r#""a\"b\\c""#);
+  assert_eq!(string_literal("", StringSyntax::C), r#""""#);
+}
+
+#[test]
+fn c_string_literals_escape_trigraphs() {
+  assert_eq!(string_literal("??=", StringSyntax::C), r#""\?\?=""#);
+  assert_eq!(string_literal("a??/b", StringSyntax::C), r#""a\?\?/b""#);
+  assert_eq!(string_literal("??=", StringSyntax::JavaScript), r#""??=""#);
+}
+
+#[test]
+fn string_literals_escape_control_and_non_ascii_characters() {
+  assert_eq!(string_literal("\n\t\r\0\x7F", StringSyntax::C), r#""\n\t\015\000\177""#);
+  assert_eq!(string_literal("é", StringSyntax::C), r#""\303\251""#);
+  // Octal escapes are at most three digits, so a following digit is not
+  // absorbed into the escape.
+  assert_eq!(string_literal("\x011", StringSyntax::C), r#""\0011""#);
+
+  assert_eq!(string_literal("\n\t\r\0\x7F", StringSyntax::JavaScript), r#""\n\t\x0D\x00\x7F""#);
+  assert_eq!(string_literal("é\u{2028}`${", StringSyntax::JavaScript), r#""é\u2028`${""#);
+}
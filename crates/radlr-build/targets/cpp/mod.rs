@@ -0,0 +1,69 @@
+use super::common::{build_ast_source, build_parser_source, build_parser_states, LookupTables};
+use crate::BuildConfig;
+use radlr_core::*;
+use std::{fs::*, io::Write};
+
+const SCRIPT: &'static str = include_str!("cpp_ast_script.atat");
+const BC_SCRIPT: &'static str = include_str!("cpp_bytecode_script.atat");
+const RUNTIME: &'static str = include_str!("radlr_runtime.hpp");
+
+/// Writes a C++17 header holding the parser tables, and a header declaring the
+/// AST classes of the grammar along with the reduce functions that build them.
+///
+/// Both headers include the header-only runtime, which is written to
+/// `radlr_runtime.hpp`. The declarations of each header are placed in a
+/// namespace named after the header's file stem.
+pub fn build(db: &RadlrDatabase, build_config: BuildConfig, parser_config: ParserConfig) -> RadlrResult<()> {
+  let out_dir = build_config.source_out;
+
+  let (parser_stem, ast_stem) = if let Some(name_prefix) = build_config.name_prefix {
+    (format!("{name_prefix}_parser"), format!("{name_prefix}_ast"))
+  } else {
+    ("parser".to_string(), "ast".to_string())
+  };
+
+  if build_config.build_parser || build_config.build_ast {
+    let mut runtime =
+      OpenOptions::new().append(false).truncate(true).write(true).create(true).open(out_dir.join("radlr_runtime.hpp"))?;
+    runtime.write_all(RUNTIME.as_bytes())?;
+    runtime.flush()?;
+  }
+
+  if build_config.build_parser {
+    let parser_path = out_dir.join(format!("{parser_stem}.hpp"));
+
+    let parser = build_parser_states(db, &build_config, parser_config)?;
+
+    let bytecode = radlr_bytecode::compile_bytecode(&parser, false)?;
+
+    let header_guard = header_guard(&parser_stem);
+    let tables = LookupTables::new(&bytecode);
+
+    let properties = [
+      ("namespace", parser_stem.as_str()),
+      ("header_guard", header_guard.as_str()),
+      ("entry_addresses", tables.entry_addresses.as_str()),
+      ("entry_names", tables.entry_names.as_str()),
+      ("entry_ids", tables.entry_ids.as_str()),
+      ("token_names", tables.token_names.as_str()),
+      ("state_tokens", tables.state_tokens.as_str()),
+      ("token_lists", tables.token_lists.as_str()),
+    ];
+
+    build_parser_source(db, BC_SCRIPT, bytecode, parser_path.clone(), parser_path, &properties)?;
+  }
+
+  if build_config.build_ast {
+    let ast_path = out_dir.join(format!("{ast_stem}.hpp"));
+
+    let header_guard = header_guard(&ast_stem);
+
+    build_ast_source(db, SCRIPT, ast_path, build_config, &[("namespace", ast_stem.as_str()), ("header_guard", &header_guard)])?;
+  }
+
+  Ok(())
+}
+
+fn header_guard(stem: &str) -> String {
+  format!("{stem}_HPP").to_uppercase().replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+}
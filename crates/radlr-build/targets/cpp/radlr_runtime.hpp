@@ -0,0 +1,972 @@
+/// ### `radlr` C++ Runtime
+///
+/// A header-only C++17 port of the radlr bytecode kernel. This header is
+/// shared by all C++ parsers generated by radlr and can be used to step
+/// through the parse actions of a bytecode parser or to build an AST using
+/// the reduce functions declared in a generated `ast.hpp` header.
+///
+/// #### WARNING:
+///
+/// This is a generated file. Any changes to this file may be **overwritten
+/// without notice**.
+///
+/// #### License:
+/// Copyright (c) 2020-2024 Anthony Weathersby
+///
+/// Permission is hereby granted, free of charge, to any person obtaining a copy
+/// of this software and associated documentation files (the 'Software'), to
+/// deal in the Software without restriction, including without limitation the
+/// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+/// sell copies of the Software, and to permit persons to whom the Software is
+/// furnished to do so, subject to the following conditions:
+///
+/// The above copyright notice and this permission notice shall be included in
+/// all copies or substantial portions of the Software.
+///
+/// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+/// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+/// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+/// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+/// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+/// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+/// IN THE SOFTWARE
+
+#ifndef RADLR_RUNTIME_HPP
+#define RADLR_RUNTIME_HPP
+
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <cstring>
+#include <iterator>
+#include <map>
+#include <memory>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <type_traits>
+#include <utility>
+#include <variant>
+#include <vector>
+
+namespace radlr {
+
+namespace detail {
+
+// Opcodes ---------------------------------------------------------------------
+
+constexpr uint8_t OP_NO_OP = 0;
+constexpr uint8_t OP_PASS = 1;
+constexpr uint8_t OP_FAIL = 2;
+constexpr uint8_t OP_SHIFT_CHAR = 3;
+constexpr uint8_t OP_SHIFT_TOKEN = 4;
+constexpr uint8_t OP_SHIFT_TOKEN_SCANLESS = 5;
+constexpr uint8_t OP_PEEK_TOKEN = 6;
+constexpr uint8_t OP_PEEK_TOKEN_SCANLESS = 7;
+constexpr uint8_t OP_SKIP_TOKEN = 8;
+constexpr uint8_t OP_SKIP_TOKEN_SCANLESS = 9;
+constexpr uint8_t OP_PEEK_SKIP_TOKEN = 10;
+constexpr uint8_t OP_PEEK_SKIP_TOKEN_SCANLESS = 11;
+constexpr uint8_t OP_PEEK_RESET = 12;
+constexpr uint8_t OP_ACCEPT = 13;
+constexpr uint8_t OP_POP_GOTO = 14;
+constexpr uint8_t OP_PUSH_GOTO = 15;
+constexpr uint8_t OP_PUSH_EXCEPTION_HANDLER = 16;
+constexpr uint8_t OP_GOTO = 17;
+constexpr uint8_t OP_ASSIGN_TOKEN = 18;
+constexpr uint8_t OP_REDUCE = 19;
+constexpr uint8_t OP_VECTOR_BRANCH = 20;
+constexpr uint8_t OP_HASH_BRANCH = 21;
+constexpr uint8_t OP_BYTE_SEQUENCE = 22;
+constexpr uint8_t OP_FORK = 23;
+constexpr uint8_t OP_READ_CODEPOINT = 24;
+
+// Match input types -----------------------------------------------------------
+
+constexpr uint32_t INPUT_NONTERMINAL = 0;
+constexpr uint32_t INPUT_TOKEN = 1;
+constexpr uint32_t INPUT_CLASS = 2;
+constexpr uint32_t INPUT_CODEPOINT = 3;
+constexpr uint32_t INPUT_BYTE = 4;
+constexpr uint32_t INPUT_END_OF_FILE = 5;
+constexpr uint32_t INPUT_BYTE_SCANLESS = 7;
+constexpr uint32_t INPUT_CODEPOINT_SCANLESS = 8;
+constexpr uint32_t INPUT_CLASS_SCANLESS = 9;
+constexpr uint32_t INPUT_CST_NODE = 11;
+
+/// Results of a dispatch that did not produce a parse action.
+enum class OpResult { None, Action, CompleteState, FailState };
+
+constexpr uint32_t NORMAL_STATE_FLAG = 1u << 0;
+constexpr uint32_t STATE_HEADER = 1u << 2;
+
+}  // namespace detail
+
+// Parser database -------------------------------------------------------------
+
+/// A view of a table embedded in a generated parser.
+template <class T>
+struct Table {
+  const T* data = nullptr;
+  size_t len = 0;
+
+  constexpr const T& operator[](size_t index) const { return data[index]; }
+};
+
+template <class T, size_t N>
+constexpr Table<T> table(const T (&array)[N]) {
+  return Table<T>{array, N};
+}
+
+/// The tables of a generated parser, as declared in a radlr `parser.hpp`
+/// header.
+struct ParserDB {
+  Table<uint8_t> bytecode;
+  /// Pairs of `[first codepoint, character class]`, sorted by codepoint.
+  Table<uint32_t> class_ranges;
+  /// Pairs of `[nonterminal id, address]` of the parser's entry points.
+  Table<uint32_t> entry_addresses;
+  /// The names of the entry points, and their nonterminal ids.
+  Table<const char*> entry_names;
+  Table<uint32_t> entry_ids;
+  /// The source strings of tokens, indexed by token id.
+  Table<const char*> token_names;
+  /// Triples of `[state address, token list offset, token count]`, sorted by
+  /// address. The token lists are stored in `token_lists`.
+  Table<uint32_t> state_tokens;
+  Table<uint32_t> token_lists;
+  /// The nonterminal id of the entry point used when none is specified.
+  uint32_t default_entry = 0;
+
+  /// Returns the bytecode address of the entry point of `nonterm_id`, or `0`
+  /// if `nonterm_id` is not an entry point.
+  uint32_t nonterm_address(uint32_t nonterm_id) const {
+    for (size_t i = 0; i + 1 < entry_addresses.len; i += 2) {
+      if (entry_addresses[i] == nonterm_id) return entry_addresses[i + 1];
+    }
+    return 0;
+  }
+
+  /// Returns the nonterminal id of the entry point `name`.
+  std::optional<uint32_t> entry_id(std::string_view name) const {
+    for (size_t i = 0; i < entry_names.len; i++) {
+      if (name == entry_names[i]) return entry_ids[i];
+    }
+    return std::nullopt;
+  }
+
+  /// Returns the source string of a token, or `nullptr` if the id is unknown.
+  const char* token_name(uint32_t token_id) const {
+    return token_id < token_names.len ? token_names[token_id] : nullptr;
+  }
+
+  /// Returns the ids of the tokens expected by the state at `state`.
+  std::vector<uint32_t> expected_tokens(uint32_t state) const {
+    size_t low = 0;
+    size_t high = state_tokens.len / 3;
+
+    while (low < high) {
+      size_t mid = (low + high) >> 1;
+      uint32_t address = state_tokens[mid * 3];
+      if (address == state) {
+        const uint32_t* start = token_lists.data + state_tokens[mid * 3 + 1];
+        return std::vector<uint32_t>(start, start + state_tokens[mid * 3 + 2]);
+      } else if (address < state) {
+        low = mid + 1;
+      } else {
+        high = mid;
+      }
+    }
+
+    return {};
+  }
+};
+
+// Input -----------------------------------------------------------------------
+
+class ParserInput {
+ public:
+  virtual ~ParserInput() = default;
+  /// The number of bytes available for reading.
+  virtual uint32_t len() const = 0;
+  /// Returns the byte at `cursor`, or `0` if the cursor is outside the input.
+  virtual uint8_t byte(uint32_t cursor) const = 0;
+  /// Returns the input bytes.
+  virtual std::string_view bytes() const = 0;
+};
+
+/// A parser input backed by a UTF-8 encoded string. The string must outlive
+/// the input, and any tokens read from it.
+class StringInput : public ParserInput {
+ public:
+  explicit StringInput(std::string_view input) : input(input) {}
+
+  uint32_t len() const override { return static_cast<uint32_t>(input.size()); }
+
+  uint8_t byte(uint32_t cursor) const override {
+    return cursor < input.size() ? static_cast<uint8_t>(input[cursor]) : 0;
+  }
+
+  std::string_view bytes() const override { return input; }
+
+ private:
+  std::string_view input;
+};
+
+namespace detail {
+
+inline uint32_t leading_ones(uint8_t byte) {
+  uint32_t count = 0;
+  while (count < 8 && (byte & (0x80 >> count)) != 0) count++;
+  return count;
+}
+
+inline uint32_t codepoint(const ParserInput& input, uint32_t cursor) {
+  uint8_t header_byte = input.byte(cursor);
+  uint32_t ones = leading_ones(header_byte);
+
+  if (ones == 0) return header_byte;
+
+  uint32_t num = header_byte & (0x7F >> ones);
+  for (uint32_t i = 0; i + 1 < ones; i++) {
+    num = (num << 6) | (input.byte(cursor + 1 + i) & 0x3F);
+  }
+  return num;
+}
+
+inline uint32_t utf8_byte_length(uint32_t cp) {
+  if (cp == 0) return 0;
+  if ((cp & 0x7F) == cp) return 1;
+  if ((cp & 0x7FF) == cp) return 2;
+  if ((cp & 0xFFFF) == cp) return 3;
+  return 4;
+}
+
+inline uint32_t codepoint_class(const Table<uint32_t>& class_ranges, uint32_t cp) {
+  size_t low = 0;
+  size_t high = class_ranges.len / 2 - 1;
+  while (low < high) {
+    size_t mid = (low + high + 1) >> 1;
+    if (class_ranges[mid << 1] <= cp) low = mid;
+    else high = mid - 1;
+  }
+  return class_ranges[(low << 1) + 1];
+}
+
+}  // namespace detail
+
+// Tokens ----------------------------------------------------------------------
+
+/// A span of the parser input. Tokens refer to the input they were read from,
+/// which must outlive them.
+struct Token {
+  std::string_view source;
+  uint32_t off = 0;
+  uint32_t len = 0;
+  uint32_t line_num = 0;
+  uint32_t line_off = 0;
+
+  /// Creates a token that spans from the start of the first token to the end
+  /// of the last token in `tokens`.
+  static Token from_slice(const Token* tokens, size_t count) {
+    if (count == 0) return Token();
+    if (count == 1) return tokens[0];
+    const Token& start = tokens[0];
+    const Token& end = tokens[count - 1];
+    return Token{start.source, start.off, end.off - start.off + end.len, start.line_num, start.line_off};
+  }
+
+  static Token from_slice(const std::vector<Token>& tokens) { return from_slice(tokens.data(), tokens.size()); }
+
+  /// Returns a new token with `start` bytes removed from the beginning and `end`
+  /// bytes removed from the end of this token.
+  Token trim(uint32_t start, uint32_t end) const {
+    uint32_t s = start < len ? start : len;
+    uint32_t e = end < len - s ? end : len - s;
+    return Token{source, off + s, len - s - e, line_num, line_off};
+  }
+
+  /// Returns the input bytes covered by this token.
+  std::string_view view() const {
+    return off < source.size() ? source.substr(off, len) : std::string_view();
+  }
+
+  std::string to_string() const { return std::string(view()); }
+};
+
+inline bool operator<(const Token& a, const Token& b) { return a.view() < b.view(); }
+
+// Errors ----------------------------------------------------------------------
+
+class ParserError : public std::runtime_error {
+ public:
+  ParserError(const std::string& message, std::string inline_message, uint32_t last_nonterminal, Token loc)
+      : std::runtime_error(message),
+        inline_message(std::move(inline_message)),
+        last_nonterminal(last_nonterminal),
+        loc(loc) {}
+
+  std::string inline_message;
+  uint32_t last_nonterminal;
+  Token loc;
+};
+
+// Parse actions ---------------------------------------------------------------
+
+enum class ActionType { Shift, Skip, Reduce, Accept, Error, Fork };
+
+struct ParseAction {
+  ActionType type = ActionType::Error;
+  /// The shifted or skipped token. For `Accept` and `Error` actions this is
+  /// the location at which the parser stopped.
+  uint32_t token_id = 0;
+  uint32_t byte_offset = 0;
+  uint32_t byte_length = 0;
+  uint32_t line_num = 0;
+  uint32_t line_offset = 0;
+  /// The reduced nonterminal for `Reduce` and `Accept` actions, and the last
+  /// reduced nonterminal for `Error` actions.
+  uint32_t nonterminal_id = 0;
+  uint32_t rule_id = 0;
+  uint32_t symbol_count = 0;
+  /// The state that emitted a `Shift`, the failed state of an `Error`, or the
+  /// address of the fork instruction of a `Fork`.
+  uint32_t state = 0;
+};
+
+// Kernel ----------------------------------------------------------------------
+
+struct ParserContext {
+  /// Pairs of `[address, is_state_entry]`
+  std::vector<uint32_t> stack;
+  uint32_t anchor_ptr = 0;
+  uint32_t sym_ptr = 0;
+  uint32_t input_ptr = 0;
+  uint32_t tok_id = 0;
+  uint32_t recovery_tok_id = 0;
+  uint32_t tok_byte_len = 0;
+  uint32_t byte_len = 0;
+  bool is_finished = false;
+  uint32_t nonterm = 0;
+  uint32_t start_line_off = 0;
+  uint32_t chkp_line_off = 0;
+  uint32_t end_line_off = 0;
+  uint32_t start_line_num = 0;
+  uint32_t chkp_line_num = 0;
+  uint32_t end_line_num = 0;
+};
+
+/// Yields parse actions from the bytecode of a radlr parser.
+class ByteCodeParser {
+ public:
+  explicit ByteCodeParser(const ParserDB& db) : db(db), bc(db.bytecode) {}
+
+  /// Creates a new parse context that starts parsing at the entry point of
+  /// the nonterminal `nonterm_id`.
+  ParserContext init(uint32_t nonterm_id) const {
+    uint32_t address = db.nonterm_address(nonterm_id);
+    if (address == 0) throw std::invalid_argument("Invalid nonterminal id " + std::to_string(nonterm_id));
+    ParserContext ctx;
+    ctx.stack = {0, 0, address, 1};
+    return ctx;
+  }
+
+  /// Returns the next action of the parser, or nothing once the parser has
+  /// accepted or failed.
+  std::optional<ParseAction> next(const ParserInput& input, ParserContext& ctx) const {
+    if (ctx.is_finished) return std::nullopt;
+
+    uint32_t address = pop_state(ctx);
+
+    while (true) {
+      ParseAction action;
+
+      if (address < 1) {
+        ctx.is_finished = true;
+        set_error(ctx, address, action);
+        return action;
+      }
+
+      uint32_t next = 0, block = 0;
+      detail::OpResult result = dispatch(address, ctx, input, action, next, block);
+
+      switch (result) {
+        case detail::OpResult::CompleteState:
+          address = pop_state(ctx);
+          break;
+        case detail::OpResult::FailState:
+          ctx.is_finished = true;
+          set_error(ctx, block, action);
+          return action;
+        default:
+          if (next > 0) ctx.stack.insert(ctx.stack.end(), {next, 0});
+          return action;
+      }
+    }
+  }
+
+ private:
+  const ParserDB& db;
+  Table<uint8_t> bc;
+
+  uint32_t u16(uint32_t address) const { return uint32_t(bc[address]) | (uint32_t(bc[address + 1]) << 8); }
+
+  uint32_t u32(uint32_t address) const {
+    return uint32_t(bc[address]) | (uint32_t(bc[address + 1]) << 8) | (uint32_t(bc[address + 2]) << 16)
+         | (uint32_t(bc[address + 3]) << 24);
+  }
+
+  uint32_t next_address(uint32_t address) const {
+    switch (bc[address]) {
+      case detail::OP_FORK: return address + 3 + (u16(address + 1) << 2);
+      case detail::OP_BYTE_SEQUENCE: return address + 7 + u16(address + 1);
+      case detail::OP_VECTOR_BRANCH: case detail::OP_HASH_BRANCH: return address + u32(address + 2);
+      case detail::OP_REDUCE: return address + 11;
+      case detail::OP_GOTO: case detail::OP_PUSH_GOTO: case detail::OP_PUSH_EXCEPTION_HANDLER: return address + 6;
+      case detail::OP_ASSIGN_TOKEN: return address + 5;
+      default: return address + 1;
+    }
+  }
+
+  /// Pops the top `[address, is_state_entry]` pair from the goto stack and
+  /// returns its address.
+  static uint32_t pop_state(ParserContext& ctx) {
+    if (ctx.stack.size() < 2) return 0;
+    uint32_t address = ctx.stack[ctx.stack.size() - 2];
+    ctx.stack.resize(ctx.stack.size() - 2);
+    return address;
+  }
+
+  static void set_error(const ParserContext& ctx, uint32_t state, ParseAction& action) {
+    action.type = ActionType::Error;
+    action.nonterminal_id = ctx.nonterm;
+    action.state = state;
+    action.token_id = ctx.tok_id;
+    action.byte_offset = ctx.sym_ptr;
+    action.byte_length = ctx.tok_byte_len;
+    action.line_num = ctx.chkp_line_num;
+    action.line_offset = ctx.chkp_line_off;
+  }
+
+  static void skip_token(ParserContext& ctx, ParseAction& action) {
+    uint32_t original_offset = ctx.sym_ptr;
+    uint32_t tok_len = ctx.tok_byte_len;
+    action.type = ActionType::Skip;
+    action.token_id = ctx.tok_id;
+    action.byte_offset = original_offset;
+    action.byte_length = tok_len;
+    action.line_num = ctx.start_line_num;
+    action.line_offset = ctx.start_line_off;
+    ctx.input_ptr = ctx.sym_ptr = original_offset + tok_len;
+    ctx.tok_id = ctx.recovery_tok_id = 0;
+  }
+
+  detail::OpResult dispatch(uint32_t base_address, ParserContext& ctx, const ParserInput& input, ParseAction& action,
+                            uint32_t& next_out, uint32_t& block_out) const {
+    using namespace detail;
+
+    uint32_t block_base = base_address;
+    uint32_t i = base_address;
+
+    while (true) {
+      OpResult result = OpResult::None;
+      uint32_t next = 0;
+      bool is_goto = false;
+
+      switch (bc[i]) {
+        case OP_BYTE_SEQUENCE: {
+          uint32_t length = u16(i + 1);
+          uint32_t default_offset = u32(i + 3);
+          uint32_t offset = ctx.input_ptr;
+          uint32_t line_incr = 0;
+          uint32_t line_offset = ctx.end_line_off;
+          bool matched = true;
+          for (uint32_t b = 0; b < length; b++) {
+            uint8_t byte = bc[i + 7 + b];
+            if (byte != input.byte(offset + b)) {
+              matched = false;
+              break;
+            }
+            if (byte == 10) {
+              line_incr++;
+              line_offset = offset + b;
+            }
+          }
+          if (matched) {
+            ctx.end_line_off = line_offset;
+            ctx.end_line_num += line_incr;
+            ctx.byte_len = length;
+            next = next_address(i);
+          } else if (default_offset > 0) {
+            next = i + default_offset;
+          } else {
+            result = OpResult::FailState;
+          }
+        } break;
+        case OP_SHIFT_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+          [[fallthrough]];
+        case OP_SHIFT_TOKEN: {
+          uint32_t new_offset = ctx.sym_ptr + ctx.tok_byte_len;
+          action.type = ActionType::Shift;
+          action.token_id = ctx.tok_id;
+          action.byte_offset = ctx.sym_ptr;
+          action.byte_length = ctx.tok_byte_len;
+          action.line_num = ctx.start_line_num;
+          action.line_offset = ctx.start_line_off;
+          action.state = base_address;
+          result = OpResult::Action;
+          ctx.start_line_num = ctx.end_line_num = ctx.chkp_line_num;
+          ctx.start_line_off = ctx.end_line_off = ctx.chkp_line_off;
+          ctx.anchor_ptr = ctx.sym_ptr = ctx.input_ptr = new_offset;
+          ctx.tok_id = ctx.recovery_tok_id = ctx.tok_byte_len = 0;
+          next = next_address(i);
+        } break;
+        case OP_SHIFT_CHAR:
+          ctx.input_ptr += ctx.byte_len;
+          ctx.byte_len = 0;
+          next = next_address(i);
+          break;
+        case OP_SKIP_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+          [[fallthrough]];
+        case OP_SKIP_TOKEN:
+          skip_token(ctx, action);
+          result = OpResult::Action;
+          ctx.end_line_num = ctx.start_line_num = ctx.chkp_line_num;
+          ctx.end_line_off = ctx.start_line_off = ctx.chkp_line_off;
+          next = block_base;
+          break;
+        case OP_PEEK_SKIP_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+          [[fallthrough]];
+        case OP_PEEK_SKIP_TOKEN: {
+          ParseAction skipped;
+          skip_token(ctx, skipped);
+          next = block_base;
+        } break;
+        case OP_PEEK_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+          [[fallthrough]];
+        case OP_PEEK_TOKEN: {
+          uint32_t offset = ctx.sym_ptr + ctx.tok_byte_len;
+          ctx.sym_ptr = ctx.input_ptr = offset;
+          ctx.tok_id = ctx.recovery_tok_id = ctx.tok_byte_len = 0;
+          next = next_address(i);
+        } break;
+        case OP_PEEK_RESET:
+          ctx.sym_ptr = ctx.input_ptr = ctx.anchor_ptr;
+          ctx.tok_id = ctx.recovery_tok_id = ctx.tok_byte_len = ctx.byte_len = 0;
+          ctx.end_line_off = ctx.chkp_line_off = ctx.start_line_off;
+          ctx.end_line_num = ctx.chkp_line_num = ctx.start_line_num;
+          next = next_address(i);
+          break;
+        case OP_REDUCE:
+          ctx.nonterm = u32(i + 1);
+          action.type = ActionType::Reduce;
+          action.nonterminal_id = ctx.nonterm;
+          action.rule_id = u32(i + 5);
+          action.symbol_count = u16(i + 9);
+          result = OpResult::Action;
+          next = next_address(i);
+          break;
+        case OP_GOTO:
+          next = u32(i + 2);
+          is_goto = true;
+          break;
+        case OP_PUSH_GOTO:
+        case OP_PUSH_EXCEPTION_HANDLER:
+          ctx.stack.insert(ctx.stack.end(), {u32(i + 2), 1});
+          next = next_address(i);
+          break;
+        case OP_POP_GOTO:
+          pop_state(ctx);
+          next = next_address(i);
+          break;
+        case OP_ASSIGN_TOKEN:
+          ctx.tok_id = u32(i + 1);
+          ctx.tok_byte_len = ctx.input_ptr - ctx.sym_ptr;
+          ctx.chkp_line_num = ctx.end_line_num;
+          ctx.chkp_line_off = ctx.end_line_off;
+          next = next_address(i);
+          break;
+        case OP_VECTOR_BRANCH:
+          next = vector_branch(i, ctx, input);
+          break;
+        case OP_HASH_BRANCH:
+          next = hash_branch(i, ctx, input);
+          break;
+        case OP_READ_CODEPOINT: {
+          bool is_nl;
+          uint32_t cp = get_input_value(INPUT_CODEPOINT, 0, ctx, input, is_nl);
+          if (cp == 0) {
+            result = OpResult::FailState;
+          } else {
+            if (is_nl) {
+              ctx.chkp_line_num += 1;
+              ctx.chkp_line_off = ctx.input_ptr;
+            }
+            next = next_address(i);
+          }
+        } break;
+        case OP_PASS:
+          result = OpResult::CompleteState;
+          break;
+        case OP_FORK:
+          action.type = ActionType::Fork;
+          action.state = i;
+          result = OpResult::Action;
+          break;
+        case OP_ACCEPT:
+          ctx.is_finished = true;
+          action.type = ActionType::Accept;
+          action.nonterminal_id = ctx.nonterm;
+          action.token_id = 0;
+          action.byte_offset = ctx.sym_ptr;
+          action.byte_length = 0;
+          action.line_num = ctx.end_line_num;
+          action.line_offset = ctx.end_line_off;
+          result = OpResult::Action;
+          break;
+        case OP_NO_OP:
+          next = next_address(i);
+          break;
+        default:
+          // OP_FAIL, or an invalid opcode.
+          result = OpResult::FailState;
+          break;
+      }
+
+      if (result == OpResult::None) {
+        if (is_goto) block_base = next;
+        i = next;
+      } else {
+        next_out = next;
+        block_out = block_base;
+        return result;
+      }
+    }
+  }
+
+  uint32_t hash_branch(uint32_t i, ParserContext& ctx, const ParserInput& input) const {
+    uint32_t input_type = bc[i + 1];
+    uint32_t default_block = i + u32(i + 2);
+    uint32_t scan_address = u32(i + 6);
+    uint32_t hash_mask = (1u << u32(i + 14)) - 1;
+    uint32_t table_start = i + 18;
+    bool is_nl;
+    uint32_t input_value = get_input_value(input_type, scan_address, ctx, input, is_nl);
+    int32_t hash_index = static_cast<int32_t>(input_value & hash_mask);
+
+    while (true) {
+      uint32_t cell = u32(table_start + static_cast<uint32_t>(hash_index) * 4);
+      uint32_t value = cell & 0x7FF;
+      uint32_t off = (cell >> 11) & 0x7FF;
+      int32_t next = static_cast<int32_t>((cell >> 22) & 0x3FF) - 512;
+
+      if (value == input_value) {
+        if (is_nl) {
+          ctx.end_line_num += 1;
+          ctx.end_line_off = ctx.input_ptr;
+        }
+        return i + off;
+      } else if (next != 0) {
+        hash_index += next;
+      } else {
+        return default_block;
+      }
+    }
+  }
+
+  uint32_t vector_branch(uint32_t i, ParserContext& ctx, const ParserInput& input) const {
+    uint32_t input_type = bc[i + 1];
+    uint32_t default_block = i + u32(i + 2);
+    uint32_t scan_address = u32(i + 6);
+    uint32_t table_length = u32(i + 10);
+    uint32_t value_offset = u32(i + 14);
+    uint32_t table_start = i + 18;
+    bool is_nl;
+    uint32_t input_value = get_input_value(input_type, scan_address, ctx, input, is_nl);
+    uint32_t value_index = input_value - value_offset;
+
+    if (input_value >= value_offset && value_index < table_length) {
+      if (is_nl) {
+        ctx.end_line_num += 1;
+        ctx.end_line_off = ctx.input_ptr;
+      }
+      return i + u32(table_start + value_index * 4);
+    } else {
+      return default_block;
+    }
+  }
+
+  uint32_t get_input_value(uint32_t input_type, uint32_t scan_address, ParserContext& ctx, const ParserInput& input,
+                           bool& is_nl) const {
+    using namespace detail;
+
+    is_nl = false;
+    switch (input_type) {
+      case INPUT_NONTERMINAL:
+        return ctx.nonterm;
+      case INPUT_END_OF_FILE:
+        return ctx.input_ptr >= input.len() ? 1 : 0;
+      case INPUT_TOKEN:
+        if (ctx.recovery_tok_id > 0) {
+          ctx.tok_id = ctx.recovery_tok_id;
+          ctx.tok_byte_len = 0;
+          ctx.byte_len = 0;
+        } else {
+          token_scan(scan_address, ctx, input);
+        }
+        return ctx.tok_id;
+      case INPUT_CST_NODE:
+        return 0xFFFFFFFF;
+      case INPUT_BYTE: {
+        uint8_t byte = input.byte(ctx.input_ptr);
+        ctx.byte_len = byte > 0 ? 1 : 0;
+        is_nl = byte == 10;
+        return byte;
+      }
+      case INPUT_BYTE_SCANLESS: {
+        uint8_t byte = input.byte(ctx.input_ptr);
+        ctx.tok_byte_len = byte > 0 ? 1 : 0;
+        is_nl = byte == 10;
+        return byte;
+      }
+      default: {
+        uint32_t cp = codepoint(input, ctx.input_ptr);
+        uint32_t len = utf8_byte_length(cp);
+        is_nl = cp == 10;
+        switch (input_type) {
+          case INPUT_CLASS_SCANLESS:
+            ctx.tok_byte_len = len;
+            return cp > 0 ? codepoint_class(db.class_ranges, cp) : 0;
+          case INPUT_CLASS:
+            ctx.byte_len = len;
+            return cp > 0 ? codepoint_class(db.class_ranges, cp) : 0;
+          case INPUT_CODEPOINT_SCANLESS:
+            ctx.tok_byte_len = len;
+            return cp;
+          default:
+            ctx.byte_len = len;
+            return cp;
+        }
+      }
+    }
+  }
+
+  void token_scan(uint32_t scan_address, ParserContext& ctx, const ParserInput& input) const {
+    using namespace detail;
+
+    uint32_t stack[4];
+    uint32_t stack_len = 0;
+    ParseAction action;
+
+    ctx.tok_id = 0;
+    ctx.input_ptr = ctx.sym_ptr;
+
+    stack[stack_len++] = 0;
+    stack[stack_len++] = 0;
+    stack[stack_len++] = NORMAL_STATE_FLAG | STATE_HEADER;
+    stack[stack_len++] = scan_address;
+
+    uint32_t address = stack[--stack_len];
+    uint32_t state = stack[--stack_len];
+
+    while (state >= 1) {
+      uint32_t next = 0, block = 0;
+      OpResult result = dispatch(address, ctx, input, action, next, block);
+
+      if (result == OpResult::FailState) break;
+
+      if (result != OpResult::CompleteState && next > 0) {
+        stack[stack_len++] = NORMAL_STATE_FLAG;
+        stack[stack_len++] = next;
+      }
+
+      address = stack[--stack_len];
+      state = stack[--stack_len];
+    }
+
+    ctx.input_ptr = ctx.sym_ptr;
+  }
+};
+
+// AST construction ------------------------------------------------------------
+
+/// Builds an AST node from the nodes and tokens of a reduced rule. `nodes` and
+/// `tokens` point to the first of the rule's symbols.
+template <class Node>
+using Reducer = Node (*)(Node* nodes, const Token* tokens, const Token& nterm_tok);
+
+/// Resolves an entry point name to its nonterminal id, or returns the default
+/// entry point if the name is empty.
+inline uint32_t get_entry(const ParserDB& db, std::string_view entry_name) {
+  if (entry_name.empty()) return db.default_entry;
+  if (auto id = db.entry_id(entry_name)) return *id;
+  throw std::invalid_argument("Invalid entry point " + std::string(entry_name));
+}
+
+/// Parses `input` from the entry point `entry`, and returns the node produced by
+/// the reducer of the final rule. `Node` must be constructible from a `Token`.
+template <class Node>
+Node build_ast(const ParserDB& db, const ParserInput& input, uint32_t entry, const Reducer<Node>* reduce_rules) {
+  std::vector<Token> tokens;
+  std::vector<Node> nodes;
+  std::string_view source = input.bytes();
+  ByteCodeParser parser(db);
+  ParserContext ctx = parser.init(entry);
+
+  while (std::optional<ParseAction> action = parser.next(input, ctx)) {
+    switch (action->type) {
+      case ActionType::Accept: {
+        Token loc{source, action->byte_offset, 1, action->line_num, action->line_offset};
+        if (action->byte_offset != input.len()) {
+          throw ParserError("Failed to read entire input",
+                            "Failed to read entire input " + std::to_string(input.len()) + " "
+                                + std::to_string(action->byte_offset),
+                            action->nonterminal_id, loc);
+        } else if (action->nonterminal_id != entry) {
+          throw ParserError("CST is incorrect", "Top symbol did not match the target nonterminal",
+                            action->nonterminal_id, loc);
+        }
+        return std::move(nodes.back());
+      }
+      case ActionType::Error: {
+        Token loc{source, action->byte_offset, action->byte_length, action->line_num, action->line_offset};
+        uint32_t cp_len = detail::utf8_byte_length(detail::codepoint(input, action->byte_offset));
+        std::string found = action->byte_offset < source.size() ? std::string(source.substr(action->byte_offset, cp_len)) : "";
+        std::vector<uint32_t> expected_tokens = db.expected_tokens(action->state);
+        if (!expected_tokens.empty()) {
+          std::string token_strings;
+          for (uint32_t id : expected_tokens) {
+            const char* name = db.token_name(id);
+            if (name == nullptr) continue;
+            if (!token_strings.empty()) token_strings += " | ";
+            token_strings += '"';
+            for (const char* c = name; *c; c++) {
+              if (*c == '"') token_strings += '\\';
+              token_strings += *c;
+            }
+            token_strings += '"';
+          }
+          std::string inline_message = expected_tokens.size() > 1
+                                           ? "Expected one of  [ " + token_strings + " ] got [ \"" + found + "\" ] instead"
+                                           : "Expected [ " + token_strings + " ] got [ \"" + found + "\" ] instead";
+          throw ParserError("Encountered an unexpected character", inline_message, action->nonterminal_id, loc);
+        }
+        throw ParserError("Encountered an unexpected character",
+                          "Did not expect to encounter character [ \"" + found + "\" ] at this point",
+                          action->nonterminal_id, loc);
+      }
+      case ActionType::Fork:
+        throw std::runtime_error("No implementation of fork resolution is available");
+      case ActionType::Skip:
+        break;
+      case ActionType::Shift: {
+        Token tok{source, action->byte_offset, action->byte_length, action->line_num, action->line_offset};
+        tokens.push_back(tok);
+        nodes.emplace_back(tok);
+      } break;
+      case ActionType::Reduce: {
+        size_t count = action->symbol_count;
+        size_t start = nodes.size() - count;
+        Token nterm_tok = Token::from_slice(tokens.data() + start, count);
+        Node node = reduce_rules[action->rule_id](nodes.data() + start, tokens.data() + start, nterm_tok);
+        nodes.resize(start);
+        tokens.resize(start);
+        nodes.push_back(std::move(node));
+        tokens.push_back(nterm_tok);
+      } break;
+    }
+  }
+
+  throw std::runtime_error("Unexpected end of parse actions");
+}
+
+// AST conversions -------------------------------------------------------------
+
+namespace detail {
+
+template <class T, class = void>
+struct is_node_variant : std::false_type {};
+
+template <class T>
+struct is_node_variant<T, std::void_t<typename T::variant_type>> : std::true_type {};
+
+template <class T, class Variant>
+struct is_alternative : std::false_type {};
+
+template <class T, class... Ts>
+struct is_alternative<T, std::variant<Ts...>> : std::disjunction<std::is_same<T, Ts>...> {};
+
+/// `true` if the node type `Node` holds values of type `T`.
+template <class Node, class T, class = void>
+struct accepts : std::false_type {};
+
+template <class Node, class T>
+struct accepts<Node, T, std::void_t<typename Node::variant_type>> : is_alternative<T, typename Node::variant_type> {};
+
+template <class T>
+struct is_vector : std::false_type {};
+
+template <class T, class A>
+struct is_vector<std::vector<T, A>> : std::true_type {};
+
+}  // namespace detail
+
+/// Converts an AST value into the type `To`.
+///
+/// AST nodes, and the multi-types of the AST, are structs that hold the
+/// alternatives of their type in a `value` variant. Values are moved into the
+/// node types that declare them as alternatives, moved out of node types that
+/// hold them, and converted element-wise between vectors. Conversions that
+/// cannot succeed produce a default constructed `To`.
+template <class To, class From>
+To convert(From&& from) {
+  using F = std::decay_t<From>;
+
+  if constexpr (std::is_same_v<To, F>) {
+    return std::move(from);
+  } else if constexpr (detail::accepts<To, F>::value) {
+    To to;
+    to.value.template emplace<F>(std::move(from));
+    return to;
+  } else if constexpr (detail::is_node_variant<F>::value) {
+    return std::visit([](auto&& value) -> To { return convert<To>(std::move(value)); }, std::move(from.value));
+  } else if constexpr (detail::is_vector<To>::value && detail::is_vector<F>::value) {
+    To to;
+    to.reserve(from.size());
+    for (auto& value : from) to.push_back(convert<typename To::value_type>(std::move(value)));
+    return to;
+  } else if constexpr (std::is_arithmetic_v<To> && std::is_arithmetic_v<F>) {
+    return static_cast<To>(from);
+  } else {
+    return To{};
+  }
+}
+
+/// Moves the value of an AST node out of `node` and converts it into `To`.
+template <class To, class Node>
+To take(Node& node) {
+  return convert<To>(std::move(node));
+}
+
+/// Parses the number at the start of `str`, or returns `0` if `str` does not
+/// start with a number.
+template <class T>
+T parse_number(std::string_view str) {
+  std::string s(str);
+  if constexpr (std::is_floating_point_v<T>) {
+    return static_cast<T>(std::strtod(s.c_str(), nullptr));
+  } else if constexpr (std::is_signed_v<T>) {
+    return static_cast<T>(std::strtoll(s.c_str(), nullptr, 10));
+  } else {
+    return static_cast<T>(std::strtoull(s.c_str(), nullptr, 10));
+  }
+}
+
+}  // namespace radlr
+
+#endif  // RADLR_RUNTIME_HPP
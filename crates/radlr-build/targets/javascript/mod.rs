@@ -1,30 +1,43 @@
-#![allow(unused)]
-use std::fs::OpenOptions;
-
-use super::common::{build_ast_source, build_parser_source};
+use super::common::{build_parser_states, js_token_strings, write_ast_source, write_parser_source};
 use crate::BuildConfig;
 use radlr_core::*;
+use std::{fs::*, io::Write};
 
+const RUNTIME: &'static str = include_str!("radlr_runtime.js");
 const PARSER_SCRIPT: &'static str = include_str!("javascript_bytecode_script.atat");
 const AST_SCRIPT: &'static str = include_str!("javascript_ast_script.atat");
 
+/// Writes a self-contained ES module holding the bytecode interpreter, the
+/// parser tables and, if `build_ast` is set, the AST classes and reducers.
 pub fn build(db: &RadlrDatabase, build_config: BuildConfig, parser_config: ParserConfig) -> RadlrResult<()> {
-  let (out_dir, lib_dir) = (build_config.source_out, build_config.lib_out);
+  let out_dir = build_config.source_out;
+
+  let parser_path = if let Some(name_prefix) = build_config.name_prefix {
+    out_dir.join(format!("{name_prefix}_parser.js"))
+  } else {
+    out_dir.join("parser.js")
+  };
+
+  let mut output = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&parser_path)?;
 
-  let pool = radlr_core::worker_pool::SingleThreadPool {};
-  let states = db.build_states(Default::default(), &pool)?;
-  let parser = states.build_ir_parser(true, false, &pool)?;
+  output.write_all(RUNTIME.as_bytes())?;
 
-  let bytecode = radlr_bytecode::compile_bytecode(&parser, false)?;
+  if build_config.build_parser {
+    let parser = build_parser_states(db, &build_config, parser_config)?;
 
-  let parser_path = out_dir.join("parser.js");
-  let ast_path = out_dir.join("ast.js");
+    let bytecode = radlr_bytecode::compile_bytecode(&parser, false)?;
 
-  build_parser_source(db, PARSER_SCRIPT, bytecode, parser_path.clone(), parser_path)?;
+    let token_strings = js_token_strings(&bytecode);
+    let properties = [("token_strings", token_strings.as_str())];
+
+    write_parser_source(db, PARSER_SCRIPT, bytecode, parser_path.clone(), parser_path, &mut output, &properties)?;
+  }
 
   if build_config.build_ast {
-    build_ast_source(db, AST_SCRIPT, ast_path, build_config, &[])?;
+    write_ast_source(db, AST_SCRIPT, &mut output, build_config, &[])?;
   }
 
+  output.flush()?;
+
   Ok(())
 }
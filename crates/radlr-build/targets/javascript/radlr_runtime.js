@@ -0,0 +1,732 @@
+/// ### `radlr` JavaScript Parser
+///
+/// A self-contained ES module holding a port of the radlr bytecode kernel,
+/// the bytecode and tables of the parser, and, when built with AST support,
+/// the AST node classes and the reduce functions used to build them.
+///
+/// #### WARNING:
+///
+/// This is a generated file. Any changes to this file may be **overwritten
+/// without notice**.
+///
+/// #### License:
+/// Copyright (c) 2020-2024 Anthony Weathersby
+///
+/// Permission is hereby granted, free of charge, to any person obtaining a copy
+/// of this software and associated documentation files (the 'Software'), to
+/// deal in the Software without restriction, including without limitation the
+/// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+/// sell copies of the Software, and to permit persons to whom the Software is
+/// furnished to do so, subject to the following conditions:
+///
+/// The above copyright notice and this permission notice shall be included in
+/// all copies or substantial portions of the Software.
+///
+/// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+/// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+/// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+/// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+/// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+/// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+/// IN THE SOFTWARE
+
+// Opcodes ---------------------------------------------------------------------
+
+const OP_NO_OP = 0;
+const OP_PASS = 1;
+const OP_FAIL = 2;
+const OP_SHIFT_CHAR = 3;
+const OP_SHIFT_TOKEN = 4;
+const OP_SHIFT_TOKEN_SCANLESS = 5;
+const OP_PEEK_TOKEN = 6;
+const OP_PEEK_TOKEN_SCANLESS = 7;
+const OP_SKIP_TOKEN = 8;
+const OP_SKIP_TOKEN_SCANLESS = 9;
+const OP_PEEK_SKIP_TOKEN = 10;
+const OP_PEEK_SKIP_TOKEN_SCANLESS = 11;
+const OP_PEEK_RESET = 12;
+const OP_ACCEPT = 13;
+const OP_POP_GOTO = 14;
+const OP_PUSH_GOTO = 15;
+const OP_PUSH_EXCEPTION_HANDLER = 16;
+const OP_GOTO = 17;
+const OP_ASSIGN_TOKEN = 18;
+const OP_REDUCE = 19;
+const OP_VECTOR_BRANCH = 20;
+const OP_HASH_BRANCH = 21;
+const OP_BYTE_SEQUENCE = 22;
+const OP_FORK = 23;
+const OP_READ_CODEPOINT = 24;
+
+// Match input types -----------------------------------------------------------
+
+const INPUT_NONTERMINAL = 0;
+const INPUT_TOKEN = 1;
+const INPUT_CLASS = 2;
+const INPUT_CODEPOINT = 3;
+const INPUT_BYTE = 4;
+const INPUT_END_OF_FILE = 5;
+const INPUT_BYTE_SCANLESS = 7;
+const INPUT_CODEPOINT_SCANLESS = 8;
+const INPUT_CLASS_SCANLESS = 9;
+const INPUT_CST_NODE = 11;
+
+// Parse actions ---------------------------------------------------------------
+
+const NONE = { type: "None" };
+const COMPLETE_STATE = { type: "CompleteState" };
+const FAIL_STATE = { type: "FailState" };
+
+// Parser database -------------------------------------------------------------
+
+/// The tables of the parser:
+///
+/// - `bytecode`: `Uint8Array`
+/// - `default_entry`: The nonterminal id of the default entry point
+/// - `nonterm_name_to_id`: `Map<string, number>`
+/// - `nonterm_id_to_address`: `Map<number, number>`
+/// - `token_id_to_str`: `Map<number, string>`
+/// - `state_to_token_ids`: `Map<number, number[]>`
+/// - `class_ranges`: `Uint32Array` of `[first codepoint, character class]`
+///   pairs, sorted by codepoint.
+
+/// Decodes the base64 encoded tables embedded in generated parsers.
+export function decode_base64(data) {
+  const bin = atob(data);
+  const bytes = new globalThis.Uint8Array(bin.length);
+  for (let i = 0; i < bin.length; i++) bytes[i] = bin.charCodeAt(i);
+  return bytes;
+}
+
+// Input -----------------------------------------------------------------------
+
+/// Parser inputs implement the following methods:
+///
+/// - `len()`: The number of bytes available for reading.
+/// - `byte(cursor)`: Returns the byte at `cursor`, or `0` if the cursor is
+///   outside the input.
+/// - `bytes()`: Returns the input bytes as a `Uint8Array`.
+
+/// A parser input backed by the UTF-8 encoding of a string.
+export class StringInput {
+  constructor(input) {
+    this.input = typeof input == "string" ? new TextEncoder().encode(input) : input;
+  }
+
+  len() {
+    return this.input.length;
+  }
+
+  byte(cursor) {
+    return cursor < this.input.length ? this.input[cursor] : 0;
+  }
+
+  bytes() {
+    return this.input;
+  }
+}
+
+function leading_ones(byte) {
+  let count = 0;
+  while (count < 8 && (byte & (0x80 >> count)) != 0) count++;
+  return count;
+}
+
+function codepoint(input, cursor) {
+  const header_byte = input.byte(cursor);
+  const ones = leading_ones(header_byte);
+
+  if (ones == 0) return header_byte;
+
+  let num = header_byte & (0x7F >> ones);
+  for (let i = 0; i < ones - 1; i++) {
+    num = (num << 6) | (input.byte(cursor + 1 + i) & 0x3F);
+  }
+  return num;
+}
+
+function utf8_byte_length(cp) {
+  if (cp == 0) return 0;
+  if ((cp & 0x7F) == cp) return 1;
+  if ((cp & 0x7FF) == cp) return 2;
+  if ((cp & 0xFFFF) == cp) return 3;
+  return 4;
+}
+
+function codepoint_class(class_ranges, cp) {
+  let low = 0;
+  let high = (class_ranges.length >> 1) - 1;
+  while (low < high) {
+    const mid = (low + high + 1) >> 1;
+    if (class_ranges[mid << 1] <= cp) low = mid;
+    else high = mid - 1;
+  }
+  return class_ranges[(low << 1) + 1];
+}
+
+// Tokens ----------------------------------------------------------------------
+
+const decoder = new globalThis.TextDecoder();
+
+export class Token {
+  constructor(source = new globalThis.Uint8Array(), off = 0, len = 0, line_num = 0, line_off = 0) {
+    this.source = source;
+    this.off = off;
+    this.len = len;
+    this.line_num = line_num;
+    this.line_off = line_off;
+  }
+
+  /// Creates a token that spans from the start of the first token to the end
+  /// of the last token in `tokens`.
+  static from_slice(tokens) {
+    if (tokens.length == 0) return new Token();
+    if (tokens.length == 1) return tokens[0];
+    const start = tokens[0];
+    const end = tokens[tokens.length - 1];
+    return new Token(start.source, start.off, end.off - start.off + end.len, start.line_num, start.line_off);
+  }
+
+  /// Returns a new token with `start` bytes removed from the beginning and `end`
+  /// bytes removed from the end of this token.
+  trim(start, end) {
+    const s = globalThis.Math.min(start, this.len);
+    const e = globalThis.Math.min(end, this.len - s);
+    return new Token(this.source, this.off + s, this.len - s - e, this.line_num, this.line_off);
+  }
+
+  to_token() {
+    return this;
+  }
+
+  toString() {
+    return decoder.decode(this.source.subarray(this.off, this.off + this.len));
+  }
+}
+
+// Errors ----------------------------------------------------------------------
+
+export class ParserError extends globalThis.Error {
+  constructor(message, inline_message, last_nonterminal, loc) {
+    super(message);
+    this.inline_message = inline_message;
+    this.last_nonterminal = last_nonterminal;
+    this.loc = loc;
+  }
+}
+
+// Kernel ----------------------------------------------------------------------
+
+const NORMAL_STATE_FLAG = 1 << 0;
+const STATE_HEADER = 1 << 2;
+
+export class ParserContext {
+  /// Pairs of `[address, is_state_entry]`
+  stack = [];
+  anchor_ptr = 0;
+  sym_ptr = 0;
+  input_ptr = 0;
+  tok_id = 0;
+  recovery_tok_id = 0;
+  tok_byte_len = 0;
+  byte_len = 0;
+  is_finished = false;
+  nonterm = 0;
+  start_line_off = 0;
+  chkp_line_off = 0;
+  end_line_off = 0;
+  start_line_num = 0;
+  chkp_line_num = 0;
+  end_line_num = 0;
+}
+
+function u16(bc, address) {
+  return bc[address] | (bc[address + 1] << 8);
+}
+
+function u32(bc, address) {
+  return (bc[address] | (bc[address + 1] << 8) | (bc[address + 2] << 16) | (bc[address + 3] << 24)) >>> 0;
+}
+
+function next_address(bc, address) {
+  switch (bc[address]) {
+    case OP_FORK: return address + 3 + (u16(bc, address + 1) << 2);
+    case OP_BYTE_SEQUENCE: return address + 7 + u16(bc, address + 1);
+    case OP_VECTOR_BRANCH: case OP_HASH_BRANCH: return address + u32(bc, address + 2);
+    case OP_REDUCE: return address + 11;
+    case OP_GOTO: case OP_PUSH_GOTO: case OP_PUSH_EXCEPTION_HANDLER: return address + 6;
+    case OP_ASSIGN_TOKEN: return address + 5;
+    default: return address + 1;
+  }
+}
+
+/// Yields parse actions from the bytecode of a radlr parser.
+export class ByteCodeParser {
+  constructor(db) {
+    this.db = db;
+    this.bc = db.bytecode;
+  }
+
+  /// Creates a new parse context that starts parsing at the entry point of
+  /// the nonterminal `nonterm_id`.
+  init(nonterm_id) {
+    const address = this.db.nonterm_id_to_address.get(nonterm_id);
+    if (!address) throw new globalThis.Error(`Invalid nonterminal id ${nonterm_id}`);
+    const ctx = new ParserContext();
+    ctx.stack.push(0, 0, address, 1);
+    return ctx;
+  }
+
+  /// Returns the next action of the parser, or `null` once the parser has
+  /// accepted or failed.
+  next(input, ctx) {
+    if (ctx.is_finished) return null;
+
+    let address = pop_state(ctx);
+
+    while (true) {
+      if (address < 1) {
+        ctx.is_finished = true;
+        return {
+          type: "Error",
+          last_nonterminal: ctx.nonterm,
+          last_state: address,
+          byte_offset: ctx.sym_ptr,
+          byte_length: ctx.tok_byte_len,
+          token_line_count: ctx.chkp_line_num,
+          token_line_offset: ctx.chkp_line_off,
+        };
+      }
+
+      const [action, next, block_address] = this.dispatch(address, ctx, input, false);
+
+      switch (action.type) {
+        case "CompleteState":
+          address = pop_state(ctx);
+          break;
+        case "FailState":
+          ctx.is_finished = true;
+          return {
+            type: "Error",
+            last_nonterminal: ctx.nonterm,
+            last_state: block_address,
+            byte_offset: ctx.sym_ptr,
+            byte_length: ctx.tok_byte_len,
+            token_line_count: ctx.chkp_line_num,
+            token_line_offset: ctx.chkp_line_off,
+          };
+        case "None":
+          throw new globalThis.Error("Expected a parse action");
+        default:
+          if (next > 0) ctx.stack.push(next, 0);
+          return action;
+      }
+    }
+  }
+
+  dispatch(base_address, ctx, input, is_scanner) {
+    const bc = this.bc;
+    let block_base = base_address;
+    let i = base_address;
+
+    while (true) {
+      let result = NONE;
+      let next = 0;
+      let is_goto = false;
+
+      switch (bc[i]) {
+        case OP_BYTE_SEQUENCE: {
+          const length = u16(bc, i + 1);
+          const default_offset = u32(bc, i + 3);
+          const offset = ctx.input_ptr;
+          let line_incr = 0;
+          let line_offset = ctx.end_line_off;
+          let matched = true;
+          for (let b = 0; b < length; b++) {
+            const byte = bc[i + 7 + b];
+            if (byte != input.byte(offset + b)) {
+              matched = false;
+              break;
+            }
+            if (byte == 10) {
+              line_incr++;
+              line_offset = offset + b;
+            }
+          }
+          if (matched) {
+            ctx.end_line_off = line_offset;
+            ctx.end_line_num += line_incr;
+            ctx.byte_len = length;
+            next = next_address(bc, i);
+          } else if (default_offset > 0) {
+            next = i + default_offset;
+          } else {
+            result = FAIL_STATE;
+          }
+        } break;
+        case OP_SHIFT_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+        // falls through
+        case OP_SHIFT_TOKEN: {
+          result = {
+            type: "Shift",
+            byte_offset: ctx.sym_ptr,
+            byte_length: ctx.tok_byte_len,
+            token_line_count: ctx.start_line_num,
+            token_line_offset: ctx.start_line_off,
+            token_id: ctx.tok_id,
+            emitting_state: base_address,
+            next_instruction_address: next_address(bc, i),
+          };
+          ctx.start_line_num = ctx.end_line_num = ctx.chkp_line_num;
+          ctx.start_line_off = ctx.end_line_off = ctx.chkp_line_off;
+          const new_offset = ctx.sym_ptr + ctx.tok_byte_len;
+          ctx.anchor_ptr = ctx.sym_ptr = ctx.input_ptr = new_offset;
+          ctx.tok_id = ctx.recovery_tok_id = ctx.tok_byte_len = 0;
+          next = next_address(bc, i);
+        } break;
+        case OP_SHIFT_CHAR:
+          ctx.input_ptr += ctx.byte_len;
+          ctx.byte_len = 0;
+          next = next_address(bc, i);
+          break;
+        case OP_SKIP_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+        // falls through
+        case OP_SKIP_TOKEN:
+          result = skip_token(ctx);
+          ctx.end_line_num = ctx.start_line_num = ctx.chkp_line_num;
+          ctx.end_line_off = ctx.start_line_off = ctx.chkp_line_off;
+          next = block_base;
+          break;
+        case OP_PEEK_SKIP_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+        // falls through
+        case OP_PEEK_SKIP_TOKEN:
+          skip_token(ctx);
+          next = block_base;
+          break;
+        case OP_PEEK_TOKEN_SCANLESS:
+          ctx.tok_byte_len = ctx.byte_len;
+        // falls through
+        case OP_PEEK_TOKEN: {
+          const offset = ctx.sym_ptr + ctx.tok_byte_len;
+          ctx.sym_ptr = ctx.input_ptr = offset;
+          ctx.tok_id = ctx.recovery_tok_id = ctx.tok_byte_len = 0;
+          next = next_address(bc, i);
+        } break;
+        case OP_PEEK_RESET:
+          ctx.sym_ptr = ctx.input_ptr = ctx.anchor_ptr;
+          ctx.tok_id = ctx.recovery_tok_id = ctx.tok_byte_len = ctx.byte_len = 0;
+          ctx.end_line_off = ctx.chkp_line_off = ctx.start_line_off;
+          ctx.end_line_num = ctx.chkp_line_num = ctx.start_line_num;
+          next = next_address(bc, i);
+          break;
+        case OP_REDUCE: {
+          const nonterminal_id = u32(bc, i + 1);
+          ctx.nonterm = nonterminal_id;
+          result = { type: "Reduce", nonterminal_id, rule_id: u32(bc, i + 5), symbol_count: u16(bc, i + 9) };
+          next = next_address(bc, i);
+        } break;
+        case OP_GOTO:
+          next = u32(bc, i + 2);
+          is_goto = true;
+          break;
+        case OP_PUSH_GOTO:
+        case OP_PUSH_EXCEPTION_HANDLER:
+          ctx.stack.push(u32(bc, i + 2), 1);
+          next = next_address(bc, i);
+          break;
+        case OP_POP_GOTO:
+          pop_state(ctx);
+          next = next_address(bc, i);
+          break;
+        case OP_ASSIGN_TOKEN:
+          ctx.tok_id = u32(bc, i + 1);
+          ctx.tok_byte_len = ctx.input_ptr - ctx.sym_ptr;
+          ctx.chkp_line_num = ctx.end_line_num;
+          ctx.chkp_line_off = ctx.end_line_off;
+          next = next_address(bc, i);
+          break;
+        case OP_VECTOR_BRANCH:
+          next = this.vector_branch(i, ctx, input);
+          break;
+        case OP_HASH_BRANCH:
+          next = this.hash_branch(i, ctx, input);
+          break;
+        case OP_READ_CODEPOINT: {
+          const [cp, is_nl] = this.get_input_value(INPUT_CODEPOINT, 0, ctx, input);
+          if (cp == 0) {
+            result = FAIL_STATE;
+          } else {
+            if (is_nl) {
+              ctx.chkp_line_num += 1;
+              ctx.chkp_line_off = ctx.input_ptr;
+            }
+            next = next_address(bc, i);
+          }
+        } break;
+        case OP_FAIL:
+          result = FAIL_STATE;
+          break;
+        case OP_PASS:
+          result = COMPLETE_STATE;
+          break;
+        case OP_FORK: {
+          const states = [];
+          const length = u16(bc, i + 1);
+          for (let j = 0; j < length; j++) states.push(u32(bc, i + 3 + j * 4));
+          result = { type: "Fork", states };
+        } break;
+        case OP_ACCEPT:
+          ctx.is_finished = true;
+          result = {
+            type: "Accept",
+            nonterminal_id: ctx.nonterm,
+            final_offset: ctx.sym_ptr,
+            token_line_count: ctx.end_line_num,
+            token_line_offset: ctx.end_line_off,
+          };
+          break;
+        case OP_NO_OP:
+          next = next_address(bc, i);
+          break;
+        default:
+          throw new globalThis.Error(`Invalid opcode ${bc[i]} at address ${i}`);
+      }
+
+      if (result.type == "None") {
+        if (is_goto) block_base = next;
+        i = next;
+      } else {
+        return [result, next, block_base];
+      }
+    }
+  }
+
+  hash_branch(i, ctx, input) {
+    const bc = this.bc;
+    const input_type = bc[i + 1];
+    const default_block = i + u32(bc, i + 2);
+    const scan_address = u32(bc, i + 6);
+    const hash_mask = (1 << u32(bc, i + 14)) - 1;
+    const table_start = i + 18;
+
+    const [input_value, is_nl] = this.get_input_value(input_type, scan_address, ctx, input);
+
+    let hash_index = input_value & hash_mask;
+    while (true) {
+      const cell = u32(bc, table_start + hash_index * 4);
+      const value = cell & 0x7FF;
+      const off = (cell >>> 11) & 0x7FF;
+      const next = ((cell >>> 22) & 0x3FF) - 512;
+
+      if (value == input_value) {
+        if (is_nl) {
+          ctx.end_line_num += 1;
+          ctx.end_line_off = ctx.input_ptr;
+        }
+        return i + off;
+      } else if (next != 0) {
+        hash_index += next;
+      } else {
+        return default_block;
+      }
+    }
+  }
+
+  vector_branch(i, ctx, input) {
+    const bc = this.bc;
+    const input_type = bc[i + 1];
+    const default_block = i + u32(bc, i + 2);
+    const scan_address = u32(bc, i + 6);
+    const table_length = u32(bc, i + 10);
+    const value_offset = u32(bc, i + 14);
+    const table_start = i + 18;
+
+    const [input_value, is_nl] = this.get_input_value(input_type, scan_address, ctx, input);
+
+    const value_index = input_value - value_offset;
+    if (value_index >= 0 && value_index < table_length) {
+      if (is_nl) {
+        ctx.end_line_num += 1;
+        ctx.end_line_off = ctx.input_ptr;
+      }
+      return i + u32(bc, table_start + value_index * 4);
+    } else {
+      return default_block;
+    }
+  }
+
+  get_input_value(input_type, scan_address, ctx, input) {
+    switch (input_type) {
+      case INPUT_NONTERMINAL:
+        return [ctx.nonterm, false];
+      case INPUT_END_OF_FILE:
+        return [ctx.input_ptr >= input.len() ? 1 : 0, false];
+      case INPUT_TOKEN:
+        if (ctx.recovery_tok_id > 0) {
+          ctx.tok_id = ctx.recovery_tok_id;
+          ctx.tok_byte_len = 0;
+          ctx.byte_len = 0;
+        } else {
+          this.token_scan(scan_address, ctx, input);
+        }
+        return [ctx.tok_id, false];
+      case INPUT_CST_NODE:
+        return [0xFFFFFFFF, false];
+      case INPUT_BYTE: {
+        const byte = input.byte(ctx.input_ptr);
+        ctx.byte_len = byte > 0 ? 1 : 0;
+        return [byte, byte == 10];
+      }
+      case INPUT_BYTE_SCANLESS: {
+        const byte = input.byte(ctx.input_ptr);
+        ctx.tok_byte_len = byte > 0 ? 1 : 0;
+        return [byte, byte == 10];
+      }
+      default: {
+        const cp = codepoint(input, ctx.input_ptr);
+        const len = utf8_byte_length(cp);
+        const is_nl = cp == 10;
+        switch (input_type) {
+          case INPUT_CLASS_SCANLESS:
+            ctx.tok_byte_len = len;
+            return [cp > 0 ? codepoint_class(this.db.class_ranges, cp) : 0, is_nl];
+          case INPUT_CLASS:
+            ctx.byte_len = len;
+            return [cp > 0 ? codepoint_class(this.db.class_ranges, cp) : 0, is_nl];
+          case INPUT_CODEPOINT_SCANLESS:
+            ctx.tok_byte_len = len;
+            return [cp, is_nl];
+          case INPUT_CODEPOINT:
+            ctx.byte_len = len;
+            return [cp, is_nl];
+          default:
+            throw new globalThis.Error(`Invalid input type ${input_type}`);
+        }
+      }
+    }
+  }
+
+  token_scan(scan_address, ctx, input) {
+    ctx.tok_id = 0;
+    ctx.input_ptr = ctx.sym_ptr;
+
+    const stack = [0, 0, NORMAL_STATE_FLAG | STATE_HEADER, scan_address];
+
+    let address = stack.pop();
+    let state = stack.pop();
+
+    while (state >= 1) {
+      const [action, next] = this.dispatch(address, ctx, input, true);
+
+      if (action.type == "FailState") break;
+
+      if (action.type != "CompleteState" && next > 0) {
+        stack.push(NORMAL_STATE_FLAG, next);
+      }
+
+      address = stack.pop();
+      state = stack.pop();
+    }
+
+    ctx.input_ptr = ctx.sym_ptr;
+  }
+}
+
+/// Pops the top `[address, is_state_entry]` pair from the goto stack and
+/// returns its address.
+function pop_state(ctx) {
+  ctx.stack.pop();
+  return ctx.stack.pop();
+}
+
+function skip_token(ctx) {
+  const original_offset = ctx.sym_ptr;
+  const tok_len = ctx.tok_byte_len;
+  const token_id = ctx.tok_id;
+  ctx.input_ptr = ctx.sym_ptr = ctx.sym_ptr + tok_len;
+  ctx.tok_id = ctx.recovery_tok_id = 0;
+  return {
+    type: "Skip",
+    byte_offset: original_offset,
+    byte_length: tok_len,
+    token_line_count: ctx.start_line_num,
+    token_line_offset: ctx.start_line_off,
+    token_id,
+  };
+}
+
+// AST construction ------------------------------------------------------------
+
+/// Resolves an entry point name to its nonterminal id, or returns the default
+/// entry point if no name is provided.
+export function get_entry(db, entry_name) {
+  if (entry_name === undefined) return db.default_entry;
+  const id = db.nonterm_name_to_id.get(entry_name);
+  if (id === undefined) throw new globalThis.Error(`Invalid entry name "${entry_name}"`);
+  return id;
+}
+
+/// Parses `input` and builds an AST using the `reduce_rules` functions of
+/// the parser.
+export function build_ast(db, input, entry, reduce_rules) {
+  const tokens = [];
+  const nodes = [];
+  const source = input.bytes();
+  const parser = new ByteCodeParser(db);
+  const ctx = parser.init(entry);
+
+  let action;
+
+  while ((action = parser.next(input, ctx))) {
+    switch (action.type) {
+      case "Accept": {
+        if (action.final_offset != input.len()) {
+          const loc = new Token(source, action.final_offset, 1, action.token_line_count, action.token_line_offset);
+          throw new ParserError("Failed to read entire input", `Failed to read entire input ${input.len()} ${action.final_offset}`, action.nonterminal_id, loc);
+        } else if (action.nonterminal_id != entry) {
+          const loc = new Token(source, action.final_offset, 1, action.token_line_count, action.token_line_offset);
+          throw new ParserError("CST is incorrect", "Top symbol did not match the target nonterminal", action.nonterminal_id, loc);
+        }
+        return nodes.pop();
+      }
+      case "Error": {
+        const loc = new Token(source, action.byte_offset, action.byte_length, action.token_line_count, action.token_line_offset);
+        const found = globalThis.String.fromCodePoint(codepoint(input, action.byte_offset));
+        const expected_tokens = db.state_to_token_ids.get(action.last_state);
+        if (expected_tokens) {
+          const token_strings = expected_tokens
+            .map((id) => db.token_id_to_str.get(id))
+            .filter((s) => s !== undefined)
+            .map((s) => `"${s.replace(/"/g, "\\\"")}"`)
+            .join(" | ");
+          const inline_message = expected_tokens.length > 1
+            ? `Expected one of  [ ${token_strings} ] got [ "${found}" ] instead`
+            : `Expected [ ${token_strings} ] got [ "${found}" ] instead`;
+          throw new ParserError("Encountered an unexpected character", inline_message, action.last_nonterminal, loc);
+        }
+        throw new ParserError("Encountered an unexpected character", `Did not expect to encounter character [ "${found}" ] at this point`, action.last_nonterminal, loc);
+      }
+      case "Fork":
+        throw new globalThis.Error("No implementation of fork resolution is available");
+      case "Skip":
+        break;
+      case "Shift":
+        tokens.push(new Token(source, action.byte_offset, action.byte_length, action.token_line_count, action.token_line_offset));
+        nodes.push(undefined);
+        break;
+      case "Reduce": {
+        const { rule_id, symbol_count } = action;
+        const node_slice = nodes.splice(nodes.length - symbol_count, symbol_count);
+        const token_slice = tokens.splice(tokens.length - symbol_count, symbol_count);
+        const nterm_tok = Token.from_slice(token_slice);
+        nodes.push(reduce_rules[rule_id](node_slice, token_slice, nterm_tok));
+        tokens.push(nterm_tok);
+      } break;
+    }
+  }
+
+  throw new globalThis.Error("Unexpected end of parse actions");
+}
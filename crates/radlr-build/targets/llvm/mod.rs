@@ -0,0 +1,531 @@
+use super::{
+  c::write_header,
+  common::{build_parser_states, codepoint_class_ranges},
+};
+use crate::BuildConfig;
+use radlr_core::{proxy::OrderedMap, *};
+use radlr_rust_runtime::types::{bytecode::MatchInputType, BytecodeParserDB, EntryPoint};
+use std::{collections::BTreeMap, fmt::Write as _, fs::*, io::Write};
+
+const RUNTIME: &'static str = include_str!("radlr_runtime.ll");
+
+/// Writes the parser as a module of LLVM IR text, and a C header declaring its
+/// API.
+///
+/// Each IR parse state of the `RadlrIRParser` is lowered to basic blocks of a
+/// native dispatch function, following the rules the bytecode compiler uses
+/// to lower the states to bytecode, so the module makes the same parse
+/// decisions as the bytecode kernels. States, and the points at which a parse
+/// resumes after an action is yielded, are identified by ids that take the
+/// place of bytecode addresses. The module implements the API of the C target,
+/// and can be compiled to an object file with `llc`, or `clang`. It uses opaque
+/// pointers, so LLVM 14 requires the `-opaque-pointers` flag.
+pub fn build(db: &RadlrDatabase, build_config: BuildConfig, parser_config: ParserConfig) -> RadlrResult<()> {
+  if !build_config.build_parser {
+    return Ok(());
+  }
+
+  let out_dir = build_config.source_out;
+
+  let (header_name, module_name) = if let Some(name_prefix) = build_config.name_prefix {
+    (format!("{name_prefix}_parser.h"), format!("{name_prefix}_parser.ll"))
+  } else {
+    ("parser.h".to_string(), "parser.ll".to_string())
+  };
+
+  let (header_path, module_path) = (out_dir.join(&header_name), out_dir.join(&module_name));
+
+  let parser = build_parser_states(db, &build_config, parser_config)?;
+
+  let lowering = lower_states(&parser)?;
+
+  let tables = parser_tables(&parser, &lowering)?;
+
+  let header_guard = header_name.to_uppercase().replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+  write_header(db, tables.clone(), header_path, &[
+    ("header_name", header_name.as_str()),
+    ("header_guard", header_guard.as_str()),
+  ])?;
+
+  let mut module = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&module_path)?;
+
+  module.write_all(RUNTIME.as_bytes())?;
+
+  write_tables(&mut module, &tables)?;
+
+  write_dispatch(&mut module, &lowering)?;
+
+  module.flush()?;
+
+  Ok(())
+}
+
+/// Writes the symbol lookup functions of the parser, which replace the lookup
+/// tables of the C target.
+fn write_tables<W: Write>(w: &mut W, tables: &BytecodeParserDB) -> std::io::Result<()> {
+  writeln!(w, "\n; Parser tables ----------------------------------------------------------------\n")?;
+
+  // Pairs of `[first codepoint, character class]`, sorted by codepoint.
+  let class_ranges = codepoint_class_ranges();
+
+  writeln!(w, "@radlr.class_ranges = internal constant {}", i32_array(&class_ranges))?;
+  writeln!(w, "@radlr.class_ranges.len = internal constant i32 {}\n", class_ranges.len())?;
+
+  // Entry points.
+  let mut entry_addresses = tables.nonterm_id_to_address.iter().map(|(id, address)| (*id, *address)).collect::<Vec<_>>();
+  entry_addresses.sort();
+
+  writeln!(w, "define internal i32 @radlr.nonterm_address(i32 %nonterm_id) {{\nentry:")?;
+  writeln!(w, "  switch i32 %nonterm_id, label %none [")?;
+  for (id, _) in &entry_addresses {
+    writeln!(w, "    i32 {id}, label %nt{id}")?;
+  }
+  writeln!(w, "  ]\n")?;
+  for (id, address) in &entry_addresses {
+    writeln!(w, "nt{id}:\n  ret i32 {address}\n")?;
+  }
+  writeln!(w, "none:\n  ret i32 0\n}}\n")?;
+
+  let mut entries = tables.nonterm_name_to_id.iter().collect::<Vec<_>>();
+  entries.sort();
+
+  for (index, (name, _)) in entries.iter().enumerate() {
+    writeln!(w, "@radlr.entry.{index} = private unnamed_addr constant {}", c_string(name.as_bytes()))?;
+  }
+
+  writeln!(w, "\ndefine i64 @radlr_entry_id(ptr %name) {{\nentry:\n  br label %e0\n")?;
+  for (index, (_, id)) in entries.iter().enumerate() {
+    writeln!(w, "e{index}:")?;
+    writeln!(w, "  %cmp{index} = call i32 @strcmp(ptr @radlr.entry.{index}, ptr %name)")?;
+    writeln!(w, "  %is_entry{index} = icmp eq i32 %cmp{index}, 0")?;
+    writeln!(w, "  br i1 %is_entry{index}, label %id{index}, label %e{}\n", index + 1)?;
+    writeln!(w, "id{index}:\n  ret i64 {id}\n")?;
+  }
+  writeln!(w, "e{}:\n  ret i64 -1\n}}\n", entries.len())?;
+
+  // The source strings of tokens, indexed by token id.
+  let mut token_names = tables.token_id_to_str.iter().collect::<Vec<_>>();
+  token_names.sort();
+
+  for (id, name) in &token_names {
+    writeln!(w, "@radlr.token.{id} = private unnamed_addr constant {}", c_string(name.as_bytes()))?;
+  }
+
+  writeln!(w, "\ndefine ptr @radlr_token_name(i32 %token_id) {{\nentry:")?;
+  writeln!(w, "  switch i32 %token_id, label %none [")?;
+  for (id, _) in &token_names {
+    writeln!(w, "    i32 {id}, label %tok{id}")?;
+  }
+  writeln!(w, "  ]\n")?;
+  for (id, _) in &token_names {
+    writeln!(w, "tok{id}:\n  ret ptr @radlr.token.{id}\n")?;
+  }
+  writeln!(w, "none:\n  ret ptr null\n}}\n")?;
+
+  // The expected tokens of each state, stored in a single list.
+  let mut states = tables.state_to_token_ids_map.iter().filter(|(_, ids)| !ids.is_empty()).collect::<Vec<_>>();
+  states.sort();
+
+  let token_lists = states.iter().flat_map(|(_, ids)| ids.iter().cloned()).collect::<Vec<_>>();
+  let token_lists_type = format!("[{} x i32]", token_lists.len());
+
+  writeln!(w, "@radlr.token_lists = internal constant {}\n", i32_array(&token_lists))?;
+
+  writeln!(w, "define ptr @radlr_expected_tokens(i32 %state, ptr %count) {{\nentry:")?;
+  writeln!(w, "  switch i32 %state, label %none [")?;
+  for (state, _) in &states {
+    writeln!(w, "    i32 {state}, label %s{state}")?;
+  }
+  writeln!(w, "  ]\n")?;
+  let mut offset = 0;
+  for (state, ids) in &states {
+    writeln!(w, "s{state}:\n  store i64 {}, ptr %count", ids.len())?;
+    writeln!(w, "  ret ptr getelementptr inbounds ({token_lists_type}, ptr @radlr.token_lists, i64 0, i64 {offset})\n")?;
+    offset += ids.len();
+  }
+  writeln!(w, "none:\n  store i64 0, ptr %count\n  ret ptr null\n}}")?;
+
+  Ok(())
+}
+
+/// The ids of the parse states, and of the points within them at which a
+/// parse resumes after an action is yielded. These ids take the place of
+/// bytecode addresses on the goto stack of the parser.
+#[derive(Default)]
+struct Lowering {
+  state_ids:      OrderedMap<IString, u32>,
+  /// `[id, label]` pairs of the blocks that `radlr.dispatch` can be called
+  /// with.
+  entries:        Vec<(u32, String)>,
+  /// `[label, bytes]` pairs of the byte sequences matched by the parse states.
+  byte_sequences: Vec<(String, Vec<u8>)>,
+  /// The body of `radlr.dispatch`.
+  body:           String,
+}
+
+impl Lowering {
+  /// Adds a block that `radlr.dispatch` can be called with, returning its id.
+  fn add_entry(&mut self, label: &str) -> u32 {
+    let id = (self.state_ids.len() + self.entries.len() + 1) as u32;
+    self.entries.push((id, label.to_string()));
+    id
+  }
+
+  /// Returns the id of the state named `name`, or `0`, which fails the parse,
+  /// if there is no such state.
+  fn state_id(&self, name: &str) -> u32 {
+    self.state_ids.get(&name.to_token()).cloned().unwrap_or_default()
+  }
+
+  fn state_label(&self, name: &str) -> String {
+    match self.state_id(name) {
+      0 => "fail".to_string(),
+      id => format!("s{id}"),
+    }
+  }
+
+  /// Lowers `stmt` to the block `label`, and to the blocks of the statements
+  /// nested in its match branches.
+  ///
+  /// Mirrors `build_statement` of the bytecode compiler, so the module makes
+  /// the same parse decisions as the bytecode kernels.
+  fn lower_statement(&mut self, label: &str, state_id: u32, stmt: &parser::Statement) -> RadlrResult<()> {
+    use parser::ASTNode;
+
+    let parser::Statement { branch, non_branch, transitive, pop } = stmt;
+
+    writeln!(self.body, "\n{label}:")?;
+
+    // The block the remaining operations of the statement are written to. A
+    // shifted token or a reduced rule is yielded to the caller of
+    // `radlr.dispatch`, which resumes the parse at a new block.
+    let mut block = label.to_string();
+
+    if let Some(transitive) = transitive {
+      match transitive {
+        ASTNode::Shift(shift) => match (shift.ptr_type.as_str(), shift.skip) {
+          ("char", false) => writeln!(self.body, "  call void @radlr.shift_char(ptr %ctx)")?,
+          ("tok", false) => {
+            writeln!(self.body, "  call void @radlr.shift_token(ptr %ctx, ptr %action, i32 %base)")?;
+            block = self.yield_action(label, 0)?;
+          }
+          ("tok", true) => {
+            // The skipped token is yielded, and the parse resumes at the start
+            // of the block.
+            writeln!(self.body, "  call void @radlr.skip_token(ptr %ctx, ptr %action)")?;
+            writeln!(self.body, "  call void @radlr.commit_lines(ptr %ctx)")?;
+            writeln!(self.body, "  %{label}.block = load i32, ptr %block")?;
+            writeln!(self.body, "  store i32 %{label}.block, ptr %next")?;
+            writeln!(self.body, "  br label %exit.action")?;
+            return Ok(());
+          }
+          _ => return Err(RadlrError::from("The LLVM target does not support skipping characters")),
+        },
+        ASTNode::Peek(peek) => match (peek.ptr_type.as_str(), peek.skip) {
+          ("tok", false) => writeln!(self.body, "  call void @radlr.peek_token(ptr %ctx)")?,
+          ("tok", true) => {
+            writeln!(self.body, "  call void @radlr.skip_token(ptr %ctx, ptr %skipped)")?;
+            writeln!(self.body, "  %{label}.block = load i32, ptr %block")?;
+            writeln!(self.body, "  store i32 %{label}.block, ptr %addr")?;
+            writeln!(self.body, "  br label %jump")?;
+            return Ok(());
+          }
+          _ => return Err(RadlrError::from("The LLVM target does not support peeking characters")),
+        },
+        ASTNode::Reset(..) => writeln!(self.body, "  call void @radlr.peek_reset(ptr %ctx)")?,
+        _ => unreachable!(),
+      }
+    }
+
+    for (index, non_branch) in non_branch.iter().enumerate() {
+      match non_branch {
+        ASTNode::ReduceRaw(r) => {
+          let (nonterm, rule_id, len) = (r.nonterminal_id, r.rule_id, r.len);
+          writeln!(self.body, "  call void @radlr.reduce(ptr %ctx, ptr %action, i32 {nonterm}, i32 {rule_id}, i32 {len})")?;
+          block = self.yield_action(label, index + 1)?;
+        }
+        ASTNode::SetTokenId(st) => writeln!(self.body, "  call void @radlr.assign_token(ptr %ctx, i32 {})", st.id)?,
+        ASTNode::SetLine(_) => { /* ignored in bytecode parsers */ }
+        _ => unreachable!(),
+      }
+    }
+
+    if let Some(pop) = pop {
+      for index in 0..pop.count.max(1) {
+        writeln!(self.body, "  %{block}.popped{index} = call i32 @radlr.pop_state(ptr %ctx)")?;
+      }
+    }
+
+    match branch {
+      None | Some(ASTNode::Pass(..)) => writeln!(self.body, "  br label %exit.complete")?,
+      Some(ASTNode::Fail(..)) => writeln!(self.body, "  br label %fail")?,
+      Some(ASTNode::Accept(..)) => {
+        writeln!(self.body, "  call void @radlr.accept(ptr %ctx, ptr %action)")?;
+        writeln!(self.body, "  br label %exit.action")?
+      }
+      Some(ASTNode::Gotos(gotos)) => {
+        for push in &gotos.pushes {
+          let id = self.state_id(&push.name);
+          writeln!(self.body, "  call void @radlr.push_state(ptr %ctx, i32 {id}, i32 1)")?;
+        }
+
+        if let Some(goto) = &gotos.goto {
+          let (id, target) = (self.state_id(&goto.name), self.state_label(&goto.name));
+          writeln!(self.body, "  store i32 {id}, ptr %block")?;
+          writeln!(self.body, "  br label %{target}")?
+        } else if gotos.fork.is_some() {
+          writeln!(self.body, "  call void @radlr.fork(ptr %action, i32 {state_id})")?;
+          writeln!(self.body, "  br label %exit.action")?
+        } else {
+          writeln!(self.body, "  br label %exit.complete")?
+        }
+      }
+      Some(ASTNode::Matches(matches)) => self.lower_match(&block, state_id, matches)?,
+      Some(_) => unreachable!(),
+    }
+
+    Ok(())
+  }
+
+  /// Yields the action set by the preceding operation, and starts the block
+  /// the parse resumes at, returning its label.
+  fn yield_action(&mut self, label: &str, index: usize) -> RadlrResult<String> {
+    let resume = format!("{label}.r{index}");
+    let id = self.add_entry(&resume);
+    writeln!(self.body, "  store i32 {id}, ptr %next")?;
+    writeln!(self.body, "  br label %exit.action")?;
+    writeln!(self.body, "\n{resume}:")?;
+    Ok(resume)
+  }
+
+  /// Lowers the branches of a match statement, mirroring `build_match` of the
+  /// bytecode compiler.
+  fn lower_match(&mut self, label: &str, state_id: u32, matches: &parser::Matches) -> RadlrResult<()> {
+    use parser::ASTNode;
+
+    let (input_type, scanner) = match matches.mode.as_str() {
+      MatchInputType::TOKEN_STR => (MatchInputType::Token, self.state_id(&matches.scanner)),
+      mode => (MatchInputType::from(mode), 0),
+    };
+
+    // The first branch of a value, and the first default branch, take
+    // precedence.
+    let mut default = None;
+    let mut branches = Vec::new();
+    let mut values = BTreeMap::new();
+
+    for m in &matches.matches {
+      match m {
+        ASTNode::DefaultMatch(d) => {
+          default.get_or_insert(d.statement.as_ref());
+        }
+        ASTNode::IntMatch(im) => {
+          let target = format!("{label}.b{}", branches.len());
+          for val in &im.vals {
+            values.entry(*val as u32).or_insert(target.clone());
+          }
+          branches.push((target, &im.vals, im.statement.as_ref()));
+        }
+        _ => {}
+      }
+    }
+
+    let default_label = default.map(|_| format!("{label}.default")).unwrap_or("fail".to_string());
+
+    if branches.is_empty() {
+      let Some(_) = default else { unreachable!("Match statement is empty") };
+      writeln!(self.body, "  %{label}.read = call i1 @radlr.read_codepoint(ptr %ctx, ptr %input)")?;
+      writeln!(self.body, "  br i1 %{label}.read, label %{default_label}, label %fail")?;
+    } else if input_type == MatchInputType::ByteSequence {
+      debug_assert_eq!(branches.len(), 1, "Expected match statement _BYTE_SEQUENCE_  to have exactly 1 branch");
+
+      let (target, bytes, _) = &branches[0];
+      let bytes = bytes.iter().map(|b| *b as u8).collect::<Vec<_>>();
+      let sequence = format!("@radlr.seq.{label}");
+
+      writeln!(
+        self.body,
+        "  %{label}.matched = call i1 @radlr.byte_sequence(ptr %ctx, ptr %input, ptr {sequence}, i32 {})",
+        bytes.len()
+      )?;
+      writeln!(self.body, "  br i1 %{label}.matched, label %{target}, label %{default_label}")?;
+
+      self.byte_sequences.push((sequence, bytes));
+    } else {
+      writeln!(
+        self.body,
+        "  %{label}.value = call i32 @radlr.input_value(i32 {}, i32 {scanner}, ptr %ctx, ptr %input, ptr %is_nl)",
+        input_type as u32
+      )?;
+      writeln!(self.body, "  switch i32 %{label}.value, label %{default_label} [")?;
+      for (value, target) in &values {
+        writeln!(self.body, "    i32 {value}, label %{target}.matched")?;
+      }
+      writeln!(self.body, "  ]")?;
+
+      // A matched value updates the line positions before control passes to
+      // the statement of the branch.
+      for (target, ..) in &branches {
+        writeln!(self.body, "\n{target}.matched:")?;
+        writeln!(self.body, "  call void @radlr.branch_matched(ptr %ctx, ptr %is_nl)")?;
+        writeln!(self.body, "  br label %{target}")?;
+      }
+    }
+
+    for (target, _, stmt) in branches {
+      self.lower_statement(&target, state_id, stmt)?;
+    }
+
+    if let Some(stmt) = default {
+      self.lower_statement(&default_label, state_id, stmt)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Builds the entry, token, and expected token tables of the parser. Parse
+/// states are identified by the ids of `lowering` in place of bytecode
+/// addresses.
+fn parser_tables(parser: &RadlrIRParser, lowering: &Lowering) -> RadlrResult<BytecodeParserDB> {
+  let db = parser.get_db();
+  let s_store = db.string_store();
+  let mut tables = BytecodeParserDB::default();
+
+  let entry_nterms = db.entry_nterm_keys();
+
+  let Some(default_entry) = entry_nterms.first() else {
+    return Err(RadlrError::from("The grammar has no entry points to build an LLVM module for"));
+  };
+
+  tables.default_entry = EntryPoint { nonterm_id: default_entry.to_val() };
+  tables.token_id_to_str = db.tokens().iter().map(|tok| (tok.tok_id.to_val(), tok.name.to_string(s_store))).collect();
+
+  for (name, state) in parser.get_states() {
+    if let Some(scanner) = state.get_scanner() {
+      tables.state_to_token_ids_map.insert(
+        lowering.state_ids[&name],
+        scanner.symbols.iter().filter(|s| !s.0.is_skipped()).map(|s| s.0.tok().to_val()).collect(),
+      );
+    }
+  }
+
+  for ep in db.entry_points().iter().filter(|ep| parser.get_config().EXPORT_ALL_NONTERMS || ep.is_export) {
+    tables.nonterm_name_to_id.insert(ep.entry_name.to_string(s_store), ep.nonterm_key.to_val());
+    if let Some(id) = lowering.state_ids.get(&ep.nonterm_entry_name) {
+      tables.nonterm_id_to_address.insert(ep.nonterm_key.to_val(), *id);
+    }
+  }
+
+  Ok(tables)
+}
+
+/// Lowers the IR parse states to the basic blocks of `radlr.dispatch`.
+fn lower_states(parser: &RadlrIRParser) -> RadlrResult<Lowering> {
+  let mut lowering = Lowering::default();
+
+  for (index, (name, _)) in parser.get_states().enumerate() {
+    lowering.state_ids.insert(name, index as u32 + 1);
+  }
+
+  for (name, state) in parser.get_states() {
+    let id = lowering.state_ids[&name];
+    lowering.lower_statement(&format!("s{id}"), id, &state.get_ast()?.statement)?;
+  }
+
+  Ok(lowering)
+}
+
+/// Writes the `radlr.dispatch` function, which runs the parse block with a given
+/// id until it yields an action, completes, or fails.
+fn write_dispatch<W: Write>(w: &mut W, lowering: &Lowering) -> std::io::Result<()> {
+  writeln!(w, "\n; Parse states -----------------------------------------------------------------\n")?;
+
+  for (name, bytes) in &lowering.byte_sequences {
+    writeln!(w, "{name} = private unnamed_addr constant {}", byte_array(bytes))?;
+  }
+
+  writeln!(
+    w,
+    "
+define internal i32 @radlr.dispatch(i32 %base, ptr %ctx, ptr %input, ptr %action, ptr %next_out, ptr %block_out) {{
+entry:
+  %block = alloca i32
+  %addr = alloca i32
+  %next = alloca i32
+  %is_nl = alloca i32
+  %skipped = alloca %radlr_action
+  store i32 %base, ptr %block
+  store i32 %base, ptr %addr
+  store i32 0, ptr %next
+  br label %jump
+
+jump:
+  %jump.addr = load i32, ptr %addr
+  switch i32 %jump.addr, label %fail ["
+  )?;
+  let mut state_ids = lowering.state_ids.values().collect::<Vec<_>>();
+  state_ids.sort();
+
+  for id in state_ids {
+    writeln!(w, "    i32 {id}, label %s{id}")?;
+  }
+  for (id, label) in &lowering.entries {
+    writeln!(w, "    i32 {id}, label %{label}")?;
+  }
+  writeln!(w, "  ]")?;
+
+  for (label, result) in [("exit.action", 1), ("exit.complete", 2), ("fail", 3)] {
+    writeln!(w, "\n{label}:")?;
+    if result == 1 {
+      writeln!(w, "  %{label}.next = load i32, ptr %next")?;
+      writeln!(w, "  store i32 %{label}.next, ptr %next_out")?;
+    } else {
+      writeln!(w, "  store i32 0, ptr %next_out")?;
+    }
+    writeln!(w, "  %{label}.block = load i32, ptr %block")?;
+    writeln!(w, "  store i32 %{label}.block, ptr %block_out")?;
+    writeln!(w, "  ret i32 {result}")?;
+  }
+
+  w.write_all(lowering.body.as_bytes())?;
+
+  writeln!(w, "}}")
+}
+
+/// Formats `values` as an LLVM array constant of `i32` values.
+fn i32_array(values: &[u32]) -> String {
+  if values.is_empty() {
+    return "[0 x i32] zeroinitializer".to_string();
+  }
+
+  let rows = values.chunks(16).map(|row| row.iter().map(|v| format!("i32 {v}")).collect::<Vec<_>>().join(", "));
+
+  format!("[{} x i32] [\n  {}\n]", values.len(), rows.collect::<Vec<_>>().join(",\n  "))
+}
+
+/// Formats `bytes` as an LLVM array constant of `i8` values.
+fn byte_array(bytes: &[u8]) -> String {
+  if bytes.is_empty() {
+    "[0 x i8] zeroinitializer".to_string()
+  } else {
+    format!("[{} x i8] c\"{}\"", bytes.len(), escape(bytes))
+  }
+}
+
+/// Formats `bytes` as a null terminated LLVM string constant.
+fn c_string(bytes: &[u8]) -> String {
+  format!("[{} x i8] c\"{}\\00\"", bytes.len() + 1, escape(bytes))
+}
+
+fn escape(bytes: &[u8]) -> String {
+  bytes
+    .iter()
+    .map(|b| match *b {
+      b'"' | b'\\' => format!("\\{b:02X}"),
+      0x20..=0x7E => (*b as char).to_string(),
+      _ => format!("\\{b:02X}"),
+    })
+    .collect()
+}
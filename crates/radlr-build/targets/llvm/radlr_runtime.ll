@@ -0,0 +1,999 @@
+; ### `radlr` LLVM Parser
+;
+; - **GENERATOR**: radlr 1.0.1-beta2
+; - **SOURCE**: UNDEFINED
+;
+; #### WARNING:
+;
+; This is a generated file. Any changes to this file may be **overwritten
+; without notice**.
+;
+; #### License:
+; Copyright (c) 2020-2024 Anthony Weathersby
+;
+; Permission is hereby granted, free of charge, to any person obtaining a copy
+; of this software and associated documentation files (the 'Software'), to
+; deal in the Software without restriction, including without limitation the
+; rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+; sell copies of the Software, and to permit persons to whom the Software is
+; furnished to do so, subject to the following conditions:
+;
+; The above copyright notice and this permission notice shall be included in
+; all copies or substantial portions of the Software.
+;
+; THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+; IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+; FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+; AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+; LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+; FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+; IN THE SOFTWARE
+
+; Runtime ----------------------------------------------------------------------
+;
+; Implements the parser API declared in the C header of this parser, and
+; assumes a 64-bit target. The parse states are lowered to the `radlr.dispatch`
+; function at the end of this module, which is called wherever the C runtime
+; interprets bytecode.
+
+%radlr_input = type { ptr, ptr, ptr }
+%radlr_buffer = type { ptr, i32 }
+%radlr_token = type { i32, i32, i32, i32, i32 }
+%radlr_action = type { i32, %radlr_token, i32, i32, i32, i32 }
+%radlr_events = type { ptr, ptr, ptr, ptr }
+
+; Fields:  0 stack,          1 stack_len,      2 stack_cap,       3 anchor_ptr,
+;          4 sym_ptr,        5 input_ptr,      6 tok_id,          7 recovery_tok_id,
+;          8 tok_byte_len,   9 byte_len,      10 nonterm,        11 start_line_off,
+;         12 chkp_line_off, 13 end_line_off,  14 start_line_num, 15 chkp_line_num,
+;         16 end_line_num,  17 is_finished,   18 out_of_memory
+%radlr_parser = type { ptr, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32 }
+
+declare ptr @realloc(ptr, i64)
+declare void @free(ptr)
+declare i32 @strcmp(ptr, ptr)
+declare i8 @llvm.ctlz.i8(i8, i1)
+
+; Input ------------------------------------------------------------------------
+
+define internal i32 @radlr.buffer_len(ptr %data) {
+  %len.ptr = getelementptr inbounds %radlr_buffer, ptr %data, i32 0, i32 1
+  %len = load i32, ptr %len.ptr
+  ret i32 %len
+}
+
+define internal zeroext i8 @radlr.buffer_byte(ptr %data, i32 %offset) {
+entry:
+  %len.ptr = getelementptr inbounds %radlr_buffer, ptr %data, i32 0, i32 1
+  %len = load i32, ptr %len.ptr
+  %in_bounds = icmp ult i32 %offset, %len
+  br i1 %in_bounds, label %read, label %out_of_bounds
+
+read:
+  %bytes = load ptr, ptr %data
+  %index = zext i32 %offset to i64
+  %byte.ptr = getelementptr inbounds i8, ptr %bytes, i64 %index
+  %byte = load i8, ptr %byte.ptr
+  ret i8 %byte
+
+out_of_bounds:
+  ret i8 0
+}
+
+define void @radlr_buffer_input(ptr noalias sret(%radlr_input) %input, ptr %buffer) {
+  store ptr %buffer, ptr %input
+  %len.ptr = getelementptr inbounds %radlr_input, ptr %input, i32 0, i32 1
+  store ptr @radlr.buffer_len, ptr %len.ptr
+  %byte.ptr = getelementptr inbounds %radlr_input, ptr %input, i32 0, i32 2
+  store ptr @radlr.buffer_byte, ptr %byte.ptr
+  ret void
+}
+
+define internal i32 @radlr.input_len(ptr %input) {
+  %data = load ptr, ptr %input
+  %len.ptr = getelementptr inbounds %radlr_input, ptr %input, i32 0, i32 1
+  %len.fn = load ptr, ptr %len.ptr
+  %len = call i32 %len.fn(ptr %data)
+  ret i32 %len
+}
+
+define internal i8 @radlr.input_byte(ptr %input, i32 %offset) {
+  %data = load ptr, ptr %input
+  %byte.ptr = getelementptr inbounds %radlr_input, ptr %input, i32 0, i32 2
+  %byte.fn = load ptr, ptr %byte.ptr
+  %byte = call zeroext i8 %byte.fn(ptr %data, i32 %offset)
+  ret i8 %byte
+}
+
+define internal i32 @radlr.codepoint(ptr %input, i32 %cursor) {
+entry:
+  %header = call i8 @radlr.input_byte(ptr %input, i32 %cursor)
+  %header.32 = zext i8 %header to i32
+  %inverted = xor i8 %header, -1
+  %ones.8 = call i8 @llvm.ctlz.i8(i8 %inverted, i1 false)
+  %ones = zext i8 %ones.8 to i32
+  %is_ascii = icmp eq i32 %ones, 0
+  br i1 %is_ascii, label %ascii, label %multi_byte
+
+ascii:
+  ret i32 %header.32
+
+multi_byte:
+  %mask = lshr i32 127, %ones
+  %num.init = and i32 %header.32, %mask
+  %first = add i32 %cursor, 1
+  br label %loop
+
+loop:
+  %i = phi i32 [ 0, %multi_byte ], [ %i.next, %body ]
+  %num = phi i32 [ %num.init, %multi_byte ], [ %num.next, %body ]
+  %i.next = add i32 %i, 1
+  %more = icmp ult i32 %i.next, %ones
+  br i1 %more, label %body, label %done
+
+body:
+  %offset = add i32 %first, %i
+  %byte = call i8 @radlr.input_byte(ptr %input, i32 %offset)
+  %byte.32 = zext i8 %byte to i32
+  %bits = and i32 %byte.32, 63
+  %shifted = shl i32 %num, 6
+  %num.next = or i32 %shifted, %bits
+  br label %loop
+
+done:
+  ret i32 %num
+}
+
+define internal i32 @radlr.utf8_byte_length(i32 %cp) {
+  %is_zero = icmp eq i32 %cp, 0
+  %is_one = icmp ult i32 %cp, 128
+  %is_two = icmp ult i32 %cp, 2048
+  %is_three = icmp ult i32 %cp, 65536
+  %len.3 = select i1 %is_three, i32 3, i32 4
+  %len.2 = select i1 %is_two, i32 2, i32 %len.3
+  %len.1 = select i1 %is_one, i32 1, i32 %len.2
+  %len = select i1 %is_zero, i32 0, i32 %len.1
+  ret i32 %len
+}
+
+define internal i32 @radlr.codepoint_class(i32 %cp) {
+entry:
+  %values = load i32, ptr @radlr.class_ranges.len
+  %pairs = lshr i32 %values, 1
+  %high.init = sub i32 %pairs, 1
+  br label %loop
+
+loop:
+  %low = phi i32 [ 0, %entry ], [ %low.next, %step ]
+  %high = phi i32 [ %high.init, %entry ], [ %high.next, %step ]
+  %more = icmp ult i32 %low, %high
+  br i1 %more, label %step, label %done
+
+step:
+  %sum = add i32 %low, %high
+  %sum.1 = add i32 %sum, 1
+  %mid = lshr i32 %sum.1, 1
+  %mid.index = shl i32 %mid, 1
+  %start.ptr = getelementptr inbounds i32, ptr @radlr.class_ranges, i32 %mid.index
+  %start = load i32, ptr %start.ptr
+  %below = icmp ule i32 %start, %cp
+  %mid.prev = sub i32 %mid, 1
+  %low.next = select i1 %below, i32 %mid, i32 %low
+  %high.next = select i1 %below, i32 %high, i32 %mid.prev
+  br label %loop
+
+done:
+  %low.index = shl i32 %low, 1
+  %class.index = add i32 %low.index, 1
+  %class.ptr = getelementptr inbounds i32, ptr @radlr.class_ranges, i32 %class.index
+  %class = load i32, ptr %class.ptr
+  ret i32 %class
+}
+
+; Kernel -----------------------------------------------------------------------
+
+define internal void @radlr.push_state(ptr %ctx, i32 %address, i32 %is_state_entry) {
+entry:
+  %len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 1
+  %cap.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 2
+  %len = load i32, ptr %len.ptr
+  %cap = load i32, ptr %cap.ptr
+  %len.next = add i32 %len, 2
+  %is_full = icmp ugt i32 %len.next, %cap
+  br i1 %is_full, label %grow, label %push
+
+grow:
+  %has_cap = icmp ugt i32 %cap, 0
+  %cap.double = shl i32 %cap, 1
+  %cap.next = select i1 %has_cap, i32 %cap.double, i32 64
+  %stack.old = load ptr, ptr %ctx
+  %cap.64 = zext i32 %cap.next to i64
+  %size = shl i64 %cap.64, 2
+  %stack.new = call ptr @realloc(ptr %stack.old, i64 %size)
+  %failed = icmp eq ptr %stack.new, null
+  br i1 %failed, label %out_of_memory, label %grown
+
+out_of_memory:
+  %oom.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 18
+  store i32 1, ptr %oom.ptr
+  ret void
+
+grown:
+  store ptr %stack.new, ptr %ctx
+  store i32 %cap.next, ptr %cap.ptr
+  br label %push
+
+push:
+  %stack = load ptr, ptr %ctx
+  %index.0 = zext i32 %len to i64
+  %slot.0 = getelementptr inbounds i32, ptr %stack, i64 %index.0
+  store i32 %address, ptr %slot.0
+  %slot.1 = getelementptr inbounds i32, ptr %slot.0, i64 1
+  store i32 %is_state_entry, ptr %slot.1
+  store i32 %len.next, ptr %len.ptr
+  ret void
+}
+
+; Pops the top `[address, is_state_entry]` pair from the goto stack and returns
+; its address.
+define internal i32 @radlr.pop_state(ptr %ctx) {
+entry:
+  %len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 1
+  %len = load i32, ptr %len.ptr
+  %is_empty = icmp ult i32 %len, 2
+  br i1 %is_empty, label %empty, label %pop
+
+empty:
+  ret i32 0
+
+pop:
+  %len.next = sub i32 %len, 2
+  store i32 %len.next, ptr %len.ptr
+  %stack = load ptr, ptr %ctx
+  %index = zext i32 %len.next to i64
+  %slot = getelementptr inbounds i32, ptr %stack, i64 %index
+  %address = load i32, ptr %slot
+  ret i32 %address
+}
+
+define internal void @radlr.set_token(ptr %action, i32 %tok_id, i32 %offset, i32 %length, i32 %line_num, i32 %line_off) {
+  %tok_id.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 1, i32 0
+  store i32 %tok_id, ptr %tok_id.ptr
+  %offset.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 1, i32 1
+  store i32 %offset, ptr %offset.ptr
+  %length.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 1, i32 2
+  store i32 %length, ptr %length.ptr
+  %line_num.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 1, i32 3
+  store i32 %line_num, ptr %line_num.ptr
+  %line_off.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 1, i32 4
+  store i32 %line_off, ptr %line_off.ptr
+  ret void
+}
+
+define internal void @radlr.set_error(ptr %ctx, i32 %state, ptr %action) {
+  store i32 4, ptr %action
+  %nonterm.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 10
+  %nonterm = load i32, ptr %nonterm.ptr
+  %action.nonterm = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 2
+  store i32 %nonterm, ptr %action.nonterm
+  %action.state = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 5
+  store i32 %state, ptr %action.state
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  %tok_id = load i32, ptr %tok_id.ptr
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  %sym_ptr = load i32, ptr %sym_ptr.ptr
+  %tok_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 8
+  %tok_len = load i32, ptr %tok_len.ptr
+  %line_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 15
+  %line_num = load i32, ptr %line_num.ptr
+  %line_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 12
+  %line_off = load i32, ptr %line_off.ptr
+  call void @radlr.set_token(ptr %action, i32 %tok_id, i32 %sym_ptr, i32 %tok_len, i32 %line_num, i32 %line_off)
+  ret void
+}
+
+define internal void @radlr.skip_token(ptr %ctx, ptr %action) {
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  %offset = load i32, ptr %sym_ptr.ptr
+  %tok_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 8
+  %tok_len = load i32, ptr %tok_len.ptr
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  %tok_id = load i32, ptr %tok_id.ptr
+  %line_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 14
+  %line_num = load i32, ptr %line_num.ptr
+  %line_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 11
+  %line_off = load i32, ptr %line_off.ptr
+  store i32 1, ptr %action
+  call void @radlr.set_token(ptr %action, i32 %tok_id, i32 %offset, i32 %tok_len, i32 %line_num, i32 %line_off)
+  %offset.next = add i32 %offset, %tok_len
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  store i32 %offset.next, ptr %input_ptr.ptr
+  store i32 %offset.next, ptr %sym_ptr.ptr
+  store i32 0, ptr %tok_id.ptr
+  %recovery.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 7
+  store i32 0, ptr %recovery.ptr
+  ret void
+}
+
+; Resets the start and end line positions to the checkpoint position after a
+; token is shifted or skipped.
+define internal void @radlr.commit_lines(ptr %ctx) {
+  %chkp_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 12
+  %chkp_off = load i32, ptr %chkp_off.ptr
+  %chkp_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 15
+  %chkp_num = load i32, ptr %chkp_num.ptr
+  %start_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 11
+  store i32 %chkp_off, ptr %start_off.ptr
+  %end_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 13
+  store i32 %chkp_off, ptr %end_off.ptr
+  %start_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 14
+  store i32 %chkp_num, ptr %start_num.ptr
+  %end_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 16
+  store i32 %chkp_num, ptr %end_num.ptr
+  ret void
+}
+
+define internal void @radlr.token_scan(i32 %scan_address, ptr %ctx, ptr %input) {
+entry:
+  %action = alloca %radlr_action
+  %next = alloca i32
+  %block = alloca i32
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  store i32 0, ptr %tok_id.ptr
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  %sym_ptr = load i32, ptr %sym_ptr.ptr
+  store i32 %sym_ptr, ptr %input_ptr.ptr
+  br label %loop
+
+loop:
+  %address = phi i32 [ %scan_address, %entry ], [ %next.address, %continue ]
+  store i32 0, ptr %next
+  %result = call i32 @radlr.dispatch(i32 %address, ptr %ctx, ptr %input, ptr %action, ptr %next, ptr %block)
+  %is_action = icmp eq i32 %result, 1
+  br i1 %is_action, label %check_next, label %done
+
+check_next:
+  %next.address = load i32, ptr %next
+  %has_next = icmp ugt i32 %next.address, 0
+  br i1 %has_next, label %continue, label %done
+
+continue:
+  br label %loop
+
+done:
+  %sym_ptr.end = load i32, ptr %sym_ptr.ptr
+  store i32 %sym_ptr.end, ptr %input_ptr.ptr
+  ret void
+}
+
+; Reads the input value of a branch. `is_nl` is set to `1` if the value was read
+; from a newline character.
+define internal i32 @radlr.input_value(i32 %input_type, i32 %scan_address, ptr %ctx, ptr %input, ptr %is_nl) {
+entry:
+  store i32 0, ptr %is_nl
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  %input_ptr = load i32, ptr %input_ptr.ptr
+  %tok_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 8
+  %byte_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 9
+  switch i32 %input_type, label %codepoint [
+    i32 0, label %nonterminal
+    i32 5, label %end_of_file
+    i32 1, label %token
+    i32 11, label %cst_node
+    i32 4, label %byte
+    i32 7, label %byte
+  ]
+
+nonterminal:
+  %nonterm.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 10
+  %nonterm = load i32, ptr %nonterm.ptr
+  ret i32 %nonterm
+
+end_of_file:
+  %len = call i32 @radlr.input_len(ptr %input)
+  %at_end = icmp uge i32 %input_ptr, %len
+  %at_end.32 = zext i1 %at_end to i32
+  ret i32 %at_end.32
+
+token:
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  %recovery.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 7
+  %recovery = load i32, ptr %recovery.ptr
+  %has_recovery = icmp ugt i32 %recovery, 0
+  br i1 %has_recovery, label %recover, label %scan
+
+recover:
+  store i32 %recovery, ptr %tok_id.ptr
+  store i32 0, ptr %tok_len.ptr
+  store i32 0, ptr %byte_len.ptr
+  ret i32 %recovery
+
+scan:
+  call void @radlr.token_scan(i32 %scan_address, ptr %ctx, ptr %input)
+  %tok_id = load i32, ptr %tok_id.ptr
+  ret i32 %tok_id
+
+cst_node:
+  ret i32 -1
+
+byte:
+  %byte.8 = call i8 @radlr.input_byte(ptr %input, i32 %input_ptr)
+  %byte.32 = zext i8 %byte.8 to i32
+  %byte.has_len = icmp ugt i32 %byte.32, 0
+  %byte.len = zext i1 %byte.has_len to i32
+  %byte.is_scanless = icmp eq i32 %input_type, 7
+  %byte.len.ptr = select i1 %byte.is_scanless, ptr %tok_len.ptr, ptr %byte_len.ptr
+  store i32 %byte.len, ptr %byte.len.ptr
+  %byte.is_nl = icmp eq i32 %byte.32, 10
+  %byte.is_nl.32 = zext i1 %byte.is_nl to i32
+  store i32 %byte.is_nl.32, ptr %is_nl
+  ret i32 %byte.32
+
+codepoint:
+  %cp = call i32 @radlr.codepoint(ptr %input, i32 %input_ptr)
+  %cp.len = call i32 @radlr.utf8_byte_length(i32 %cp)
+  %cp.is_nl = icmp eq i32 %cp, 10
+  %cp.is_nl.32 = zext i1 %cp.is_nl to i32
+  store i32 %cp.is_nl.32, ptr %is_nl
+  %is_class = icmp eq i32 %input_type, 2
+  %is_class_scanless = icmp eq i32 %input_type, 9
+  %is_cp_scanless = icmp eq i32 %input_type, 8
+  %is_scanless = or i1 %is_class_scanless, %is_cp_scanless
+  %cp.len.ptr = select i1 %is_scanless, ptr %tok_len.ptr, ptr %byte_len.ptr
+  store i32 %cp.len, ptr %cp.len.ptr
+  %wants_class = or i1 %is_class, %is_class_scanless
+  br i1 %wants_class, label %class, label %cp.done
+
+class:
+  %cp.is_zero = icmp eq i32 %cp, 0
+  br i1 %cp.is_zero, label %cp.done, label %class.lookup
+
+class.lookup:
+  %class.value = call i32 @radlr.codepoint_class(i32 %cp)
+  ret i32 %class.value
+
+cp.done:
+  ret i32 %cp
+}
+
+; Called when a branch matches its input value.
+define internal void @radlr.branch_matched(ptr %ctx, ptr %is_nl) {
+entry:
+  %nl = load i32, ptr %is_nl
+  %is_newline = icmp ne i32 %nl, 0
+  br i1 %is_newline, label %newline, label %done
+
+newline:
+  %end_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 16
+  %end_num = load i32, ptr %end_num.ptr
+  %end_num.next = add i32 %end_num, 1
+  store i32 %end_num.next, ptr %end_num.ptr
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  %input_ptr = load i32, ptr %input_ptr.ptr
+  %end_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 13
+  store i32 %input_ptr, ptr %end_off.ptr
+  br label %done
+
+done:
+  ret void
+}
+
+define internal i1 @radlr.byte_sequence(ptr %ctx, ptr %input, ptr %bytes, i32 %length) {
+entry:
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  %offset = load i32, ptr %input_ptr.ptr
+  %end_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 13
+  %end_off = load i32, ptr %end_off.ptr
+  br label %loop
+
+loop:
+  %i = phi i32 [ 0, %entry ], [ %i.next, %next ]
+  %line_incr = phi i32 [ 0, %entry ], [ %line_incr.next, %next ]
+  %line_off = phi i32 [ %end_off, %entry ], [ %line_off.next, %next ]
+  %more = icmp ult i32 %i, %length
+  br i1 %more, label %compare, label %matched
+
+compare:
+  %index = zext i32 %i to i64
+  %expected.ptr = getelementptr inbounds i8, ptr %bytes, i64 %index
+  %expected = load i8, ptr %expected.ptr
+  %position = add i32 %offset, %i
+  %actual = call i8 @radlr.input_byte(ptr %input, i32 %position)
+  %equal = icmp eq i8 %expected, %actual
+  br i1 %equal, label %next, label %failed
+
+next:
+  %is_nl = icmp eq i8 %expected, 10
+  %incr = zext i1 %is_nl to i32
+  %line_incr.next = add i32 %line_incr, %incr
+  %line_off.next = select i1 %is_nl, i32 %position, i32 %line_off
+  %i.next = add i32 %i, 1
+  br label %loop
+
+matched:
+  store i32 %line_off, ptr %end_off.ptr
+  %end_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 16
+  %end_num = load i32, ptr %end_num.ptr
+  %end_num.next = add i32 %end_num, %line_incr
+  store i32 %end_num.next, ptr %end_num.ptr
+  %byte_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 9
+  store i32 %length, ptr %byte_len.ptr
+  ret i1 true
+
+failed:
+  ret i1 false
+}
+
+define internal void @radlr.shift_token(ptr %ctx, ptr %action, i32 %state) {
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  %sym_ptr = load i32, ptr %sym_ptr.ptr
+  %tok_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 8
+  %tok_len = load i32, ptr %tok_len.ptr
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  %tok_id = load i32, ptr %tok_id.ptr
+  %line_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 14
+  %line_num = load i32, ptr %line_num.ptr
+  %line_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 11
+  %line_off = load i32, ptr %line_off.ptr
+  store i32 0, ptr %action
+  call void @radlr.set_token(ptr %action, i32 %tok_id, i32 %sym_ptr, i32 %tok_len, i32 %line_num, i32 %line_off)
+  %action.state = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 5
+  store i32 %state, ptr %action.state
+  call void @radlr.commit_lines(ptr %ctx)
+  %offset = add i32 %sym_ptr, %tok_len
+  %anchor_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 3
+  store i32 %offset, ptr %anchor_ptr.ptr
+  store i32 %offset, ptr %sym_ptr.ptr
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  store i32 %offset, ptr %input_ptr.ptr
+  store i32 0, ptr %tok_id.ptr
+  %recovery.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 7
+  store i32 0, ptr %recovery.ptr
+  store i32 0, ptr %tok_len.ptr
+  ret void
+}
+
+define internal void @radlr.shift_char(ptr %ctx) {
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  %input_ptr = load i32, ptr %input_ptr.ptr
+  %byte_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 9
+  %byte_len = load i32, ptr %byte_len.ptr
+  %input_ptr.next = add i32 %input_ptr, %byte_len
+  store i32 %input_ptr.next, ptr %input_ptr.ptr
+  store i32 0, ptr %byte_len.ptr
+  ret void
+}
+
+define internal void @radlr.peek_token(ptr %ctx) {
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  %sym_ptr = load i32, ptr %sym_ptr.ptr
+  %tok_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 8
+  %tok_len = load i32, ptr %tok_len.ptr
+  %offset = add i32 %sym_ptr, %tok_len
+  store i32 %offset, ptr %sym_ptr.ptr
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  store i32 %offset, ptr %input_ptr.ptr
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  store i32 0, ptr %tok_id.ptr
+  %recovery.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 7
+  store i32 0, ptr %recovery.ptr
+  store i32 0, ptr %tok_len.ptr
+  ret void
+}
+
+define internal void @radlr.peek_reset(ptr %ctx) {
+  %anchor_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 3
+  %anchor_ptr = load i32, ptr %anchor_ptr.ptr
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  store i32 %anchor_ptr, ptr %sym_ptr.ptr
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  store i32 %anchor_ptr, ptr %input_ptr.ptr
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  store i32 0, ptr %tok_id.ptr
+  %recovery.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 7
+  store i32 0, ptr %recovery.ptr
+  %tok_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 8
+  store i32 0, ptr %tok_len.ptr
+  %byte_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 9
+  store i32 0, ptr %byte_len.ptr
+  %start_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 11
+  %start_off = load i32, ptr %start_off.ptr
+  %chkp_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 12
+  store i32 %start_off, ptr %chkp_off.ptr
+  %end_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 13
+  store i32 %start_off, ptr %end_off.ptr
+  %start_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 14
+  %start_num = load i32, ptr %start_num.ptr
+  %chkp_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 15
+  store i32 %start_num, ptr %chkp_num.ptr
+  %end_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 16
+  store i32 %start_num, ptr %end_num.ptr
+  ret void
+}
+
+define internal void @radlr.reduce(ptr %ctx, ptr %action, i32 %nonterm, i32 %rule_id, i32 %symbol_count) {
+  %nonterm.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 10
+  store i32 %nonterm, ptr %nonterm.ptr
+  store i32 2, ptr %action
+  %action.nonterm = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 2
+  store i32 %nonterm, ptr %action.nonterm
+  %action.rule = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 3
+  store i32 %rule_id, ptr %action.rule
+  %action.count = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 4
+  store i32 %symbol_count, ptr %action.count
+  ret void
+}
+
+define internal void @radlr.assign_token(ptr %ctx, i32 %tok_id) {
+  %tok_id.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 6
+  store i32 %tok_id, ptr %tok_id.ptr
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  %input_ptr = load i32, ptr %input_ptr.ptr
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  %sym_ptr = load i32, ptr %sym_ptr.ptr
+  %tok_len = sub i32 %input_ptr, %sym_ptr
+  %tok_len.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 8
+  store i32 %tok_len, ptr %tok_len.ptr
+  %end_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 16
+  %end_num = load i32, ptr %end_num.ptr
+  %chkp_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 15
+  store i32 %end_num, ptr %chkp_num.ptr
+  %end_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 13
+  %end_off = load i32, ptr %end_off.ptr
+  %chkp_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 12
+  store i32 %end_off, ptr %chkp_off.ptr
+  ret void
+}
+
+define internal i1 @radlr.read_codepoint(ptr %ctx, ptr %input) {
+entry:
+  %is_nl = alloca i32
+  %cp = call i32 @radlr.input_value(i32 3, i32 0, ptr %ctx, ptr %input, ptr %is_nl)
+  %is_end = icmp eq i32 %cp, 0
+  br i1 %is_end, label %failed, label %read
+
+read:
+  %nl = load i32, ptr %is_nl
+  %is_newline = icmp ne i32 %nl, 0
+  br i1 %is_newline, label %newline, label %done
+
+newline:
+  %chkp_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 15
+  %chkp_num = load i32, ptr %chkp_num.ptr
+  %chkp_num.next = add i32 %chkp_num, 1
+  store i32 %chkp_num.next, ptr %chkp_num.ptr
+  %input_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 5
+  %input_ptr = load i32, ptr %input_ptr.ptr
+  %chkp_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 12
+  store i32 %input_ptr, ptr %chkp_off.ptr
+  br label %done
+
+done:
+  ret i1 true
+
+failed:
+  ret i1 false
+}
+
+define internal void @radlr.fork(ptr %action, i32 %state) {
+  store i32 5, ptr %action
+  %action.state = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 5
+  store i32 %state, ptr %action.state
+  ret void
+}
+
+define internal void @radlr.accept(ptr %ctx, ptr %action) {
+  %is_finished.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 17
+  store i32 1, ptr %is_finished.ptr
+  store i32 3, ptr %action
+  %nonterm.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 10
+  %nonterm = load i32, ptr %nonterm.ptr
+  %action.nonterm = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 2
+  store i32 %nonterm, ptr %action.nonterm
+  %sym_ptr.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 4
+  %sym_ptr = load i32, ptr %sym_ptr.ptr
+  %end_num.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 16
+  %end_num = load i32, ptr %end_num.ptr
+  %end_off.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 13
+  %end_off = load i32, ptr %end_off.ptr
+  call void @radlr.set_token(ptr %action, i32 0, i32 %sym_ptr, i32 0, i32 %end_num, i32 %end_off)
+  ret void
+}
+
+; Parser -----------------------------------------------------------------------
+
+define void @radlr_parser_free(ptr %parser) {
+  %stack = load ptr, ptr %parser
+  call void @free(ptr %stack)
+  store ptr null, ptr %parser
+  %len.ptr = getelementptr inbounds %radlr_parser, ptr %parser, i32 0, i32 1
+  store i32 0, ptr %len.ptr
+  %cap.ptr = getelementptr inbounds %radlr_parser, ptr %parser, i32 0, i32 2
+  store i32 0, ptr %cap.ptr
+  ret void
+}
+
+define i32 @radlr_parser_init(ptr %parser, i32 %nonterm_id) {
+entry:
+  %address = call i32 @radlr.nonterm_address(i32 %nonterm_id)
+  store %radlr_parser zeroinitializer, ptr %parser
+  %is_invalid = icmp eq i32 %address, 0
+  br i1 %is_invalid, label %failed, label %push
+
+push:
+  call void @radlr.push_state(ptr %parser, i32 0, i32 0)
+  call void @radlr.push_state(ptr %parser, i32 %address, i32 1)
+  %oom.ptr = getelementptr inbounds %radlr_parser, ptr %parser, i32 0, i32 18
+  %oom = load i32, ptr %oom.ptr
+  %is_oom = icmp ne i32 %oom, 0
+  br i1 %is_oom, label %release, label %done
+
+release:
+  call void @radlr_parser_free(ptr %parser)
+  br label %failed
+
+done:
+  ret i32 0
+
+failed:
+  ret i32 -1
+}
+
+define i32 @radlr_parser_next(ptr %ctx, ptr %input, ptr %action) {
+entry:
+  %next = alloca i32
+  %block = alloca i32
+  %is_finished.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 17
+  %oom.ptr = getelementptr inbounds %radlr_parser, ptr %ctx, i32 0, i32 18
+  %is_finished = load i32, ptr %is_finished.ptr
+  %finished = icmp ne i32 %is_finished, 0
+  br i1 %finished, label %none, label %start
+
+none:
+  ret i32 0
+
+start:
+  %address.init = call i32 @radlr.pop_state(ptr %ctx)
+  br label %loop
+
+loop:
+  %address = phi i32 [ %address.init, %start ], [ %address.popped, %complete ]
+  %is_empty = icmp ult i32 %address, 1
+  br i1 %is_empty, label %exhausted, label %dispatch
+
+exhausted:
+  store i32 1, ptr %is_finished.ptr
+  call void @radlr.set_error(ptr %ctx, i32 %address, ptr %action)
+  ret i32 1
+
+dispatch:
+  store i32 0, ptr %next
+  store i32 0, ptr %block
+  %result = call i32 @radlr.dispatch(i32 %address, ptr %ctx, ptr %input, ptr %action, ptr %next, ptr %block)
+  %oom = load i32, ptr %oom.ptr
+  %is_oom = icmp ne i32 %oom, 0
+  br i1 %is_oom, label %out_of_memory, label %handle
+
+out_of_memory:
+  store i32 1, ptr %is_finished.ptr
+  ret i32 -1
+
+handle:
+  switch i32 %result, label %yield [
+    i32 2, label %complete
+    i32 3, label %failed
+  ]
+
+complete:
+  %address.popped = call i32 @radlr.pop_state(ptr %ctx)
+  br label %loop
+
+failed:
+  store i32 1, ptr %is_finished.ptr
+  %block.address = load i32, ptr %block
+  call void @radlr.set_error(ptr %ctx, i32 %block.address, ptr %action)
+  ret i32 1
+
+yield:
+  %next.address = load i32, ptr %next
+  %has_next = icmp ugt i32 %next.address, 0
+  br i1 %has_next, label %push_next, label %yielded
+
+push_next:
+  call void @radlr.push_state(ptr %ctx, i32 %next.address, i32 0)
+  %oom.after = load i32, ptr %oom.ptr
+  %is_oom.after = icmp ne i32 %oom.after, 0
+  %status = select i1 %is_oom.after, i32 -1, i32 1
+  ret i32 %status
+
+yielded:
+  ret i32 1
+}
+
+; Events -----------------------------------------------------------------------
+
+; Calls the event callback at `index` of `events` with `token`. Returns nonzero
+; if the callback requested the parse to stop.
+define internal i32 @radlr.emit_token(ptr %events, i32 %index, ptr %token) {
+entry:
+  %has_events = icmp ne ptr %events, null
+  br i1 %has_events, label %load, label %none
+
+load:
+  %callback.ptr = getelementptr inbounds ptr, ptr %events, i32 %index
+  %callback = load ptr, ptr %callback.ptr
+  %has_callback = icmp ne ptr %callback, null
+  br i1 %has_callback, label %call, label %none
+
+call:
+  %data = load ptr, ptr %events
+  %stop = call i32 %callback(ptr %data, ptr %token)
+  ret i32 %stop
+
+none:
+  ret i32 0
+}
+
+define internal i32 @radlr.emit_reduce(ptr %events, ptr %action) {
+entry:
+  %has_events = icmp ne ptr %events, null
+  br i1 %has_events, label %load, label %none
+
+load:
+  %callback.ptr = getelementptr inbounds %radlr_events, ptr %events, i32 0, i32 3
+  %callback = load ptr, ptr %callback.ptr
+  %has_callback = icmp ne ptr %callback, null
+  br i1 %has_callback, label %call, label %none
+
+call:
+  %data = load ptr, ptr %events
+  %nonterm.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 2
+  %nonterm = load i32, ptr %nonterm.ptr
+  %rule.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 3
+  %rule = load i32, ptr %rule.ptr
+  %count.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 4
+  %count = load i32, ptr %count.ptr
+  %stop = call i32 %callback(ptr %data, i32 %nonterm, i32 %rule, i32 %count)
+  ret i32 %stop
+
+none:
+  ret i32 0
+}
+
+define i32 @radlr_parse(i32 %nonterm_id, ptr %input, ptr %events, ptr %last_action) {
+entry:
+  %parser = alloca %radlr_parser
+  %action = alloca %radlr_action
+  store %radlr_action zeroinitializer, ptr %action
+  %init = call i32 @radlr_parser_init(ptr %parser, i32 %nonterm_id)
+  %init_failed = icmp ne i32 %init, 0
+  br i1 %init_failed, label %init_error, label %loop
+
+init_error:
+  %address = call i32 @radlr.nonterm_address(i32 %nonterm_id)
+  %is_invalid = icmp eq i32 %address, 0
+  %init_status = select i1 %is_invalid, i32 6, i32 7
+  ret i32 %init_status
+
+loop:
+  %status = phi i32 [ 1, %entry ], [ %status.next, %continue ]
+  %result = call i32 @radlr_parser_next(ptr %parser, ptr %input, ptr %action)
+  %has_action = icmp sgt i32 %result, 0
+  br i1 %has_action, label %handle, label %finish
+
+handle:
+  %type = load i32, ptr %action
+  %token = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 1
+  switch i32 %type, label %continue [
+    i32 0, label %shift
+    i32 1, label %skip
+    i32 2, label %reduce
+    i32 3, label %accept
+    i32 4, label %error
+    i32 5, label %fork
+  ]
+
+shift:
+  %shift.stop = call i32 @radlr.emit_token(ptr %events, i32 1, ptr %token)
+  br label %check_stop
+
+skip:
+  %skip.stop = call i32 @radlr.emit_token(ptr %events, i32 2, ptr %token)
+  br label %check_stop
+
+reduce:
+  %reduce.stop = call i32 @radlr.emit_reduce(ptr %events, ptr %action)
+  br label %check_stop
+
+check_stop:
+  %stop = phi i32 [ %shift.stop, %shift ], [ %skip.stop, %skip ], [ %reduce.stop, %reduce ]
+  %should_stop = icmp ne i32 %stop, 0
+  br i1 %should_stop, label %aborted, label %continue
+
+accept:
+  %offset.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 1, i32 1
+  %offset = load i32, ptr %offset.ptr
+  %len = call i32 @radlr.input_len(ptr %input)
+  %is_incomplete = icmp ne i32 %offset, %len
+  %accepted.ptr = getelementptr inbounds %radlr_action, ptr %action, i32 0, i32 2
+  %accepted = load i32, ptr %accepted.ptr
+  %is_unexpected = icmp ne i32 %accepted, %nonterm_id
+  %accept.nonterm_status = select i1 %is_unexpected, i32 3, i32 0
+  %accept.status = select i1 %is_incomplete, i32 2, i32 %accept.nonterm_status
+  br label %continue
+
+error:
+  br label %continue
+
+fork:
+  %is_finished.ptr = getelementptr inbounds %radlr_parser, ptr %parser, i32 0, i32 17
+  store i32 1, ptr %is_finished.ptr
+  br label %continue
+
+continue:
+  %status.next = phi i32 [ %status, %handle ], [ %status, %check_stop ], [ %accept.status, %accept ], [ 1, %error ], [ 4, %fork ]
+  br label %loop
+
+aborted:
+  br label %finish
+
+finish:
+  %status.final = phi i32 [ %status, %loop ], [ 5, %aborted ]
+  %is_oom = icmp slt i32 %result, 0
+  %status.out = select i1 %is_oom, i32 7, i32 %status.final
+  %has_last = icmp ne ptr %last_action, null
+  br i1 %has_last, label %copy, label %release
+
+copy:
+  %copied = load %radlr_action, ptr %action
+  store %radlr_action %copied, ptr %last_action
+  br label %release
+
+release:
+  call void @radlr_parser_free(ptr %parser)
+  ret i32 %status.out
+}
+
+@radlr.status.0 = private unnamed_addr constant [34 x i8] c"The input was parsed successfully\00"
+@radlr.status.1 = private unnamed_addr constant [36 x i8] c"Encountered an unexpected character\00"
+@radlr.status.2 = private unnamed_addr constant [28 x i8] c"Failed to read entire input\00"
+@radlr.status.3 = private unnamed_addr constant [48 x i8] c"Top symbol did not match the target nonterminal\00"
+@radlr.status.4 = private unnamed_addr constant [50 x i8] c"No implementation of fork resolution is available\00"
+@radlr.status.5 = private unnamed_addr constant [43 x i8] c"The parse was stopped by an event callback\00"
+@radlr.status.6 = private unnamed_addr constant [20 x i8] c"Invalid entry point\00"
+@radlr.status.7 = private unnamed_addr constant [35 x i8] c"Failed to allocate the parse stack\00"
+@radlr.status.unknown = private unnamed_addr constant [15 x i8] c"Unknown status\00"
+
+define ptr @radlr_status_message(i32 %status) {
+entry:
+  switch i32 %status, label %unknown [
+    i32 0, label %s0
+    i32 1, label %s1
+    i32 2, label %s2
+    i32 3, label %s3
+    i32 4, label %s4
+    i32 5, label %s5
+    i32 6, label %s6
+    i32 7, label %s7
+  ]
+
+s0:
+  ret ptr @radlr.status.0
+s1:
+  ret ptr @radlr.status.1
+s2:
+  ret ptr @radlr.status.2
+s3:
+  ret ptr @radlr.status.3
+s4:
+  ret ptr @radlr.status.4
+s5:
+  ret ptr @radlr.status.5
+s6:
+  ret ptr @radlr.status.6
+s7:
+  ret ptr @radlr.status.7
+unknown:
+  ret ptr @radlr.status.unknown
+}
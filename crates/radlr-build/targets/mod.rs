@@ -1,4 +1,7 @@
+pub mod c;
 mod common;
+pub mod cpp;
 pub mod javascript;
+pub mod llvm;
 pub mod rust;
 pub mod typescript;
@@ -28,17 +28,17 @@ pub fn build(db: &RadlrDatabase, build_config: BuildConfig, parser_config: Parse
   };
 
   if build_config.build_parser {
-    let parser = build_parser_states(db, parser_config)?;
+    let parser = build_parser_states(db, &build_config, parser_config)?;
 
     let bytecode = radlr_bytecode::compile_bytecode(&parser, false)?;
 
     {
       let mut parser_binary = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&binary_path)?;
-      bytecode.write_binary(&mut parser_binary)?;
+      bytecode.write_package(&mut parser_binary)?;
       parser_binary.flush()?;
     }
 
-    build_parser_source(db, BC_SCRIPT, bytecode, binary_path, parser_path)?;
+    build_parser_source(db, BC_SCRIPT, bytecode, binary_path, parser_path, &[])?;
   }
 
   if build_config.build_ast {
@@ -1,32 +1,40 @@
-#![allow(unused)]
-use super::common::build_ast_source;
+use super::common::{build_ast_source, build_parser_source, build_parser_states, js_token_strings};
 use crate::BuildConfig;
 use radlr_core::*;
+use std::{fs::*, io::Write};
 
 const SCRIPT: &'static str = include_str!("typescript_ast_script.atat");
+const BC_SCRIPT: &'static str = include_str!("typescript_bytecode_script.atat");
+const RUNTIME: &'static str = include_str!("radlr_runtime.ts");
 
 pub fn build(db: &RadlrDatabase, build_config: BuildConfig, parser_config: ParserConfig) -> RadlrResult<()> {
-  let (out_dir, lib_dir) = (build_config.source_out, build_config.lib_out);
-
-  let pool = radlr_core::worker_pool::SingleThreadPool {};
-
-  let states = db.build_states(Default::default(), &pool)?;
-  let parser = states.build_ir_parser(true, false, &pool)?;
+  let out_dir = build_config.source_out;
+
+  let (parser_path, ast_path) = if let Some(name_prefix) = build_config.name_prefix {
+    (out_dir.join(format!("{name_prefix}_parser.ts")), out_dir.join(format!("{name_prefix}_ast.ts")))
+  } else {
+    (out_dir.join("parser.ts"), out_dir.join("ast.ts"))
+  };
+
+  if build_config.build_parser || build_config.build_ast {
+    // Both the parser and the AST modules import from the shared runtime.
+    let mut runtime =
+      OpenOptions::new().append(false).truncate(true).write(true).create(true).open(out_dir.join("radlr_runtime.ts"))?;
+    runtime.write_all(RUNTIME.as_bytes())?;
+    runtime.flush()?;
+  }
 
-  let bytecode = radlr_bytecode::compile_bytecode(&parser, false)?;
+  if build_config.build_parser {
+    let parser = build_parser_states(db, &build_config, parser_config)?;
 
-  let parser_path = out_dir.join("parser.ts");
-  let ast_path = out_dir.join("ast.ts");
+    let bytecode = radlr_bytecode::compile_bytecode(&parser, false)?;
 
-  /* {
-    {
-      let mut parser_binary = OpenOptions::new().append(false).truncate(true).write(true).create(true).open(&binary_path)?;
-      bytecode.write_binary(&mut parser_binary)?;
-      parser_binary.flush()?;
-    }
+    let token_strings = js_token_strings(&bytecode);
 
-    build_parser_source(db, BC_SCRIPT, bytecode, binary_path, parser_path)?;
-  } */
+    // The bytecode is embedded in the parser module, so there is no separate
+    // binary to reference.
+    build_parser_source(db, BC_SCRIPT, bytecode, parser_path.clone(), parser_path, &[("token_strings", &token_strings)])?;
+  }
 
   if build_config.build_ast {
     build_ast_source(db, SCRIPT, ast_path, build_config, &[])?;
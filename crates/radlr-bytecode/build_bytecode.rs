@@ -61,6 +61,7 @@ pub fn compile_bytecode<T: ParserStore>(store: &T, add_debug_symbols: bool) -> R
       })
       .collect(),
     default_entry:          EntryPoint { nonterm_id: db.entry_nterm_keys().first().unwrap().to_val() },
+    grammar_hash:           db.grammar_hash(),
   };
 
   if add_debug_symbols {
@@ -6,9 +6,9 @@ use std::{
 
 use crate::{
   compile::states::build_graph::graph::Graphs,
-  grammar::{build_compile_db, compile_grammar_from_str, load_grammar, remove_grammar_mut, utils::resolve_grammar_path},
+  grammar::{analyze_grammar, build_compile_db, compile_grammar_from_str, load_grammar, remove_grammar_mut, utils::resolve_grammar_path},
   o_to_r,
-  proxy::{Array, DeduplicateIterator, Queue, Set},
+  proxy::{Array, DeduplicateIterator, Map, Queue, Set},
   types::{worker_pool::WorkerPool, *},
   GrammarIdentities,
   GrammarSoup,
@@ -28,7 +28,12 @@ use crate::{
 
 /// Pre-compiled Grammar compilation context
 pub struct RadlrGrammar {
-  soup: std::sync::Arc<GrammarSoup>,
+  soup:               std::sync::Arc<GrammarSoup>,
+  include_paths:      Vec<PathBuf>,
+  /// The `IMPORT` statements of grammars added from source strings whose
+  /// sources could not be found, keyed by the importing grammar. These may be
+  /// satisfied by sources that are added later.
+  unresolved_imports: Map<GrammarId, Vec<(GrammarId, RadlrError)>>,
 }
 
 fn blend_soups(soup: std::sync::Arc<GrammarSoup>, other: GrammarSoup) -> RadlrResult<()> {
@@ -59,8 +64,45 @@ fn blend_soups(soup: std::sync::Arc<GrammarSoup>, other: GrammarSoup) -> RadlrRe
 }
 
 impl RadlrGrammar {
+  /// Environment variable holding a list of directories to search for
+  /// imported grammars, separated as in the `PATH` variable of the platform.
+  pub const INCLUDE_PATH_VAR: &'static str = "RADLR_INCLUDE_PATH";
+
   pub fn new() -> Self {
-    Self { soup: GrammarSoup::new() }
+    Self { soup: GrammarSoup::new(), include_paths: Default::default(), unresolved_imports: Default::default() }
+  }
+
+  /// Adds a directory to search for grammars imported with `IMPORT`.
+  ///
+  /// The directory of the importing grammar is searched first, followed by the
+  /// include paths in the order they were added, then the directories of the
+  /// [RadlrGrammar::INCLUDE_PATH_VAR] environment variable, and finally the
+  /// current working directory.
+  pub fn add_include_path<T: Into<PathBuf>>(&mut self, path: T) -> &mut Self {
+    self.include_paths.push(path.into());
+    self
+  }
+
+  /// Returns the directories searched for imported grammars, in search order.
+  pub fn include_paths(&self) -> Vec<PathBuf> {
+    self.include_paths_with_var(std::env::var_os(Self::INCLUDE_PATH_VAR))
+  }
+
+  /// Same as [RadlrGrammar::include_paths], with `var` in place of the value of
+  /// the [RadlrGrammar::INCLUDE_PATH_VAR] environment variable.
+  fn include_paths_with_var(&self, var: Option<std::ffi::OsString>) -> Vec<PathBuf> {
+    let env_paths = var.map(|paths| std::env::split_paths(&paths).collect::<Vec<_>>());
+    self.include_paths.iter().cloned().chain(env_paths.into_iter().flatten().filter(|p| !p.as_os_str().is_empty())).collect()
+  }
+
+  /// Returns the source file paths of every grammar that has been loaded,
+  /// including grammars loaded through `IMPORT` statements. Grammars that
+  /// were added from a source string without a backing file are not
+  /// included.
+  pub fn source_paths(&self) -> RadlrResult<Vec<PathBuf>> {
+    let RadlrGrammar { soup, .. } = self;
+    let headers = soup.grammar_headers.read().map_err(|e| RadlrError::from(e))?;
+    Ok(headers.values().map(|header| header.identity.path.to_path(&soup.string_store)).filter(|path| path.is_file()).collect())
   }
 
   pub fn path_to_id<T: Into<PathBuf>>(&self, path: T) -> GrammarIdentities {
@@ -81,8 +123,9 @@ impl RadlrGrammar {
   pub fn add_source<T: Into<PathBuf>>(&mut self, path: T) -> RadlrResult<&mut Self> {
     let mut errors = Vec::new();
     let id = self.path_to_id(path);
+    let include_paths = self.include_paths();
 
-    let RadlrGrammar { soup } = self;
+    let RadlrGrammar { soup, .. } = self;
 
     let mut queue = Queue::from_iter([id]);
 
@@ -90,13 +133,14 @@ impl RadlrGrammar {
 
     while let Some(id) = queue.pop_front() {
       if known_imports.insert(id.guid) {
-        match load_grammar(id, soup.string_store.clone()) {
-          Ok((new_soup, new_imports)) => {
+        match load_grammar(id, soup.string_store.clone(), &include_paths) {
+          Ok((new_soup, new_imports, import_errors)) => {
             blend_soups(
               soup.clone(),
               std::sync::Arc::into_inner(new_soup).expect("There should be only one reference for this"),
             )?;
             queue.append(&mut new_imports.into_iter().collect());
+            errors.extend(import_errors);
           }
           Err(err) => errors.extend(err.flatten()),
         }
@@ -117,27 +161,30 @@ impl RadlrGrammar {
     let path: PathBuf = grammar_path.into();
 
     let id = self.path_to_id(&path);
+    let include_paths = self.include_paths();
 
-    let RadlrGrammar { soup } = self;
+    let RadlrGrammar { soup, .. } = self;
 
     let mut known_imports = Set::from_iter(soup.grammar_headers.read().map_err(|e| RadlrError::from(e))?.iter().map(|i| *i.0));
 
     if !known_imports.contains(&id.guid) {
-      match compile_grammar_from_str(source, path.to_owned(), soup.string_store.clone()) {
-        Ok((soup, ids)) => {
+      match compile_grammar_from_str(source, path.to_owned(), soup.string_store.clone(), &include_paths) {
+        Ok((soup, ids, import_errors)) => {
           known_imports.insert(id.guid);
+          errors.extend(import_errors.into_iter().map(|(_, err)| err));
 
           let mut queue = Queue::from_iter(ids);
 
           while let Some(id) = queue.pop_front() {
             if known_imports.insert(id.guid) {
-              match load_grammar(id, soup.string_store.clone()) {
-                Ok((new_soup, new_imports)) => {
+              match load_grammar(id, soup.string_store.clone(), &include_paths) {
+                Ok((new_soup, new_imports, import_errors)) => {
                   blend_soups(
                     soup.clone(),
                     std::sync::Arc::into_inner(new_soup).expect("There should be only one reference for this"),
                   )?;
                   queue.append(&mut new_imports.into_iter().collect());
+                  errors.extend(import_errors);
                 }
                 Err(err) => errors.extend(err.flatten()),
               }
@@ -155,6 +202,11 @@ impl RadlrGrammar {
     }
   }
 
+  /// Adds a grammar to the soup from a source string.
+  ///
+  /// The grammars imported by the source are not loaded. Imports that can not
+  /// be found are reported by [RadlrGrammar::build_db] if no other added
+  /// source satisfies them.
   pub fn add_source_from_string<T: Into<PathBuf>>(
     &mut self,
     source: &str,
@@ -164,15 +216,17 @@ impl RadlrGrammar {
     let mut errors = Vec::new();
     let path: PathBuf = grammar_path.into();
     let id = self.path_to_id(&path);
+    let include_paths = self.include_paths();
 
-    let RadlrGrammar { soup } = self;
+    let RadlrGrammar { soup, unresolved_imports, .. } = self;
 
     let known_imports = Set::from_iter(soup.grammar_headers.read().map_err(|e| RadlrError::from(e))?.iter().map(|i| *i.0));
 
     if replace || !known_imports.contains(&id.guid) {
-      match compile_grammar_from_str(source, path.to_owned(), soup.string_store.clone()) {
-        Ok((new_soup, _)) => {
+      match compile_grammar_from_str(source, path.to_owned(), soup.string_store.clone(), &include_paths) {
+        Ok((new_soup, _, import_errors)) => {
           blend_soups(soup.clone(), std::sync::Arc::into_inner(new_soup).expect("There should be only one reference for this"))?;
+          unresolved_imports.insert(id.guid, import_errors);
         }
         Err(err) => errors.extend(err.flatten()),
       };
@@ -189,24 +243,46 @@ impl RadlrGrammar {
     let path: PathBuf = root_grammar.into();
     let id = self.path_to_id(&path);
 
-    let RadlrGrammar { soup } = self;
+    let RadlrGrammar { soup, include_paths, mut unresolved_imports } = self;
 
     let mut soup = o_to_r(std::sync::Arc::into_inner(soup), "could not get exclusive access to soup")?;
 
     remove_grammar_mut(id.guid, &mut soup)?;
+    unresolved_imports.remove(&id.guid);
 
-    Ok(Self { soup: std::sync::Arc::new(soup) })
+    Ok(Self { soup: std::sync::Arc::new(soup), include_paths, unresolved_imports })
   }
 
+  /// Builds a parser database from the grammar at `root_grammar` and the
+  /// grammars it imports.
+  ///
+  /// If the database can not be built, the errors of imports of string
+  /// sources that no added source satisfies are reported before the build
+  /// errors.
   pub fn build_db<T: Into<PathBuf>>(&self, root_grammar: T, config: ParserConfig) -> RadlrResult<RadlrDatabase> {
-    let RadlrGrammar { soup } = self;
+    let RadlrGrammar { soup, unresolved_imports, .. } = self;
     let path: PathBuf = root_grammar.into();
 
     let id = GrammarIdentities::from_path(&path, &soup.string_store);
 
     match build_compile_db(id, soup, &config) {
-      Err(errors) => Err(errors),
-      Ok(db) => Ok(RadlrDatabase { db: std::sync::Arc::new(db) }),
+      Err(err) => {
+        let known_grammars = soup.grammar_headers.read()?;
+        let mut errors: Vec<_> = unresolved_imports
+          .values()
+          .flatten()
+          .filter(|(import_id, _)| !known_grammars.contains_key(import_id))
+          .map(|(_, err)| err.clone())
+          .collect();
+
+        if errors.is_empty() {
+          Err(err)
+        } else {
+          errors.extend(err.flatten());
+          Err(RadlrError::Multi(errors))
+        }
+      }
+      Ok(db) => Ok(RadlrDatabase { db: std::sync::Arc::new(db), soup: soup.clone() }),
     }
   }
 }
@@ -219,7 +295,8 @@ impl RadlrGrammar {
 /// into parsers
 #[derive(Clone)]
 pub struct RadlrDatabase {
-  db: SharedParserDatabase,
+  db:   SharedParserDatabase,
+  soup: std::sync::Arc<GrammarSoup>,
 }
 
 impl RadlrDatabase {
@@ -235,10 +312,38 @@ impl RadlrDatabase {
 
   /// Constructs parser and scanner graphs for this variant of the grammar.
   pub fn build_states<Pool: WorkerPool>(&self, config: ParserConfig, pool: &Pool) -> RadlrResult<RadlrParseGraph> {
-    let RadlrDatabase { db } = self;
+    self.build_states_with_cache(config, None, pool)
+  }
+
+  /// Same as [RadlrDatabase::build_states], but the graphs of non-terminals
+  /// whose states are stored in `cache_dir` are not built. Instead, their
+  /// stored states are added to the parser built by
+  /// [RadlrParseGraph::build_ir_parser], which also stores the states of the
+  /// non-terminals that were built.
+  ///
+  /// The states of a non-terminal are keyed by a hash of the rules that can
+  /// influence them, so changing a rule only rebuilds the non-terminals that
+  /// depend on it.
+  pub fn build_states_cached<Pool: WorkerPool>(
+    &self,
+    config: ParserConfig,
+    cache_dir: &Path,
+    pool: &Pool,
+  ) -> RadlrResult<RadlrParseGraph> {
+    let cache = crate::compile::cache::StateCache::load(cache_dir, &self.db, &config)?;
+    self.build_states_with_cache(config, Some(cache), pool)
+  }
 
-    match crate::compile::states::build_states::compile_parser_states(db.clone(), config, pool) {
-      Ok(graph) => Ok(RadlrParseGraph { graph, db: db.clone(), config }),
+  fn build_states_with_cache<Pool: WorkerPool>(
+    &self,
+    config: ParserConfig,
+    cache: Option<crate::compile::cache::StateCache>,
+    pool: &Pool,
+  ) -> RadlrResult<RadlrParseGraph> {
+    let RadlrDatabase { db, .. } = self;
+
+    match crate::compile::states::build_states::compile_parser_states(db.clone(), config, cache.as_ref(), pool) {
+      Ok(graph) => Ok(RadlrParseGraph { graph, db: db.clone(), config, cache: cache.map(std::sync::Arc::new) }),
       Err(err) => {
         let mut errors = err.flatten();
         if errors.len() > 1 {
@@ -250,6 +355,16 @@ impl RadlrDatabase {
     }
   }
 
+  /// Analyzes the grammar of this database, returning warnings for
+  /// unreachable non-terminals, unproductive rules, nullable non-terminals,
+  /// left and right recursion cycles, and the precedence tokens and `IGNORE`
+  /// symbols of the root grammar and its imports that no reachable parser rule
+  /// uses.
+  pub fn analyze(&self) -> RadlrResult<Vec<RadlrError>> {
+    let RadlrDatabase { db, soup } = self;
+    analyze_grammar(db, soup)
+  }
+
   pub fn print_terminals(&self) {
     let RadlrDatabase { db, .. } = self;
 
@@ -268,23 +383,46 @@ pub struct RadlrParseGraph {
   pub(crate) db:     SharedParserDatabase,
   pub(crate) config: ParserConfig,
   pub(crate) graph:  std::sync::Arc<Graphs>,
+  pub(crate) cache:  Option<std::sync::Arc<crate::compile::cache::StateCache>>,
 }
 
 impl RadlrParseGraph {
+  /// Returns the number of non-terminals whose states were loaded from the
+  /// state cache instead of being built. These non-terminals do not have
+  /// nodes in the graphs.
+  pub fn cached_nonterm_count(&self) -> usize {
+    self.cache.as_ref().map(|cache| cache.cached_count()).unwrap_or_default()
+  }
+
   pub fn build_ir_parser<Pool: WorkerPool>(
     &self,
     optimize: bool,
     optimize_for_debugging: bool,
     pool: &Pool,
+  ) -> RadlrResult<RadlrIRParser> {
+    self.build_ir_parser_with_passes(optimize.then(Default::default).as_ref(), optimize_for_debugging, pool)
+  }
+
+  /// Same as [RadlrParseGraph::build_ir_parser], but runs the optimization
+  /// passes of `opt_config` instead of the default passes. If `opt_config` is
+  /// `None`, only unreachable states are removed.
+  pub fn build_ir_parser_with_passes<Pool: WorkerPool>(
+    &self,
+    opt_config: Option<&OptimizeConfig>,
+    optimize_for_debugging: bool,
+    pool: &Pool,
   ) -> RadlrResult<RadlrIRParser> {
     match crate::compile::ir::build_ir_concurrent(pool, self.graph.clone(), self.config, &self.db) {
-      Ok((classification, ir_states)) => {
-        let Self { config, db, .. } = self;
+      Ok((mut classification, mut ir_states)) => {
+        let Self { config, db, cache, graph } = self;
 
-        let (states, report): (Vec<_>, _) = if optimize {
-          crate::compile::ir::optimize(db, config, ir_states, optimize_for_debugging, pool)?
-        } else {
-          crate::compile::ir::sweep(db, config, ir_states, optimize_for_debugging)?
+        if let Some(cache) = cache {
+          cache.merge(db, graph, &mut ir_states, &mut classification)?;
+        }
+
+        let (states, report): (Vec<_>, _) = match opt_config {
+          Some(opt_config) => crate::compile::ir::optimize(db, config, opt_config, ir_states, optimize_for_debugging, pool)?,
+          None => crate::compile::ir::sweep(db, config, ir_states, optimize_for_debugging)?,
         };
 
         Ok(RadlrIRParser {
@@ -292,7 +430,7 @@ impl RadlrParseGraph {
           config: *config,
           db: db.clone(),
           states,
-          is_optimized: optimize,
+          is_optimized: opt_config.is_some(),
           report,
         })
       }
@@ -308,6 +446,14 @@ impl RadlrParseGraph {
     }
   }
 
+  /// Writes debug dumps of the graphs and grammar to `output_path`:
+  ///
+  /// - `parser_states.debug.txt`: The nodes of the graphs.
+  /// - `item_sets.debug.txt` & `item_sets.debug.json`: The kernel and closure
+  ///   items of each node of the graphs.
+  /// - `first_follow.debug.txt` & `first_follow.debug.json`: The FIRST and
+  ///   FOLLOW sets of each parser non-terminal.
+  /// - `parser_states.debug.dot`: A Graphviz rendering of the graphs.
   pub fn write_debug_file(&self, output_path: &Path) -> RadlrResult<()> {
     let file_path = output_path.join("parser_states.debug.txt");
 
@@ -319,8 +465,34 @@ impl RadlrParseGraph {
 
     file.flush()?;
 
+    let Self { db, graph, .. } = self;
+
+    for (file_name, data) in [
+      ("item_sets.debug.txt", graph.item_sets_string(db)),
+      ("item_sets.debug.json", graph.item_sets_json(db)),
+      ("first_follow.debug.txt", db.first_follow_string()),
+      ("first_follow.debug.json", db.first_follow_json()),
+      ("parser_states.debug.dot", self.to_dot(None)?),
+    ] {
+      let mut file = OpenOptions::new().truncate(true).write(true).create(true).open(output_path.join(file_name))?;
+      file.write_all(data.as_bytes())?;
+      file.flush()?;
+    }
+
     Ok(())
   }
+
+  /// Renders the graphs in the Graphviz DOT format. If `entry` is the name of
+  /// an entry point, only the parser states reachable from that entry point
+  /// are rendered.
+  pub fn to_dot(&self, entry: Option<&str>) -> RadlrResult<String> {
+    let entry = match entry {
+      Some(entry) => Some(crate::compile::dot::find_entry_point(&self.db, entry)?.nonterm_key),
+      None => None,
+    };
+
+    Ok(crate::compile::dot::graphs_to_dot(&self.graph, &self.db, entry))
+  }
 }
 
 // ----------------------------------------------------------------------------------------
@@ -338,7 +510,7 @@ pub struct RadlrIRParser {
 
 impl ParserStore for RadlrIRParser {
   fn report(&self) -> OptimizationReport {
-    self.report
+    self.report.clone()
   }
 
   fn get_config(&self) -> &ParserConfig {
@@ -372,6 +544,18 @@ impl RadlrIRParser {
   pub fn get_states(&self) -> impl Iterator<Item = (IString, &ParseState)> {
     self.states.iter().map(|(a, b)| (*a, b.as_ref()))
   }
+
+  /// Renders the IR states in the Graphviz DOT format. If `entry` is the name
+  /// of an entry point, only the states reachable from that entry point are
+  /// rendered.
+  pub fn to_dot(&self, entry: Option<&str>) -> RadlrResult<String> {
+    let entry = match entry {
+      Some(entry) => Some(crate::compile::dot::find_entry_point(&self.db, entry)?),
+      None => None,
+    };
+
+    Ok(crate::compile::dot::ir_states_to_dot(self.get_states(), &self.db, entry))
+  }
 }
 
 // ----------------------------------------------------------------------------------------
@@ -384,6 +568,17 @@ pub fn empty_source_path() -> RadlrResult<()> {
   Ok(())
 }
 
+#[test]
+pub fn include_path_variable_is_searched_after_added_include_paths() {
+  let mut grammar = RadlrGrammar::new();
+  grammar.add_include_path("/added");
+
+  let var = std::env::join_paths(["/var/a", "", "/var/b"]).unwrap();
+
+  assert_eq!(grammar.include_paths_with_var(Some(var)), [PathBuf::from("/added"), "/var/a".into(), "/var/b".into()]);
+  assert_eq!(grammar.include_paths_with_var(None), [PathBuf::from("/added")]);
+}
+
 pub struct TestPackage {
   pub states:         ParseStatesVec,
   pub db:             SharedParserDatabase,
@@ -394,7 +589,7 @@ pub struct TestPackage {
 
 impl ParserStore for TestPackage {
   fn report(&self) -> OptimizationReport {
-    self.report
+    self.report.clone()
   }
 
   fn get_config(&self) -> &ParserConfig {
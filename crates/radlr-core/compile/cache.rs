@@ -0,0 +1,435 @@
+//! An on-disk cache of the IR states of non-terminals, used to avoid rebuilding
+//! the graphs of non-terminals whose rules have not changed since a previous
+//! build.
+//!
+//! Each parser non-terminal is keyed by a hash of every part of the grammar
+//! that can influence the states built for it:
+//!
+//! - The rules of the non-terminal and, transitively, the rules of the
+//!   non-terminals and token non-terminals they reference.
+//! - The rules that reference the non-terminal and, transitively, the rules
+//!   that reference their non-terminals. These define the follow context that
+//!   out-of-scope items are drawn from.
+//! - The ids, precedences, and associativities of the tokens those rules use.
+//! - The parser configuration and the version of radlr.
+//!
+//! Changing a rule only changes the keys of the non-terminals whose states may
+//! depend on it, so only the graphs of those non-terminals are rebuilt. Since
+//! states refer to rules, tokens, and non-terminals by their ids, edits that add
+//! or remove rules or tokens renumber the ids that follow them, and change the
+//! keys of every non-terminal that uses those ids.
+
+use super::states::{
+  build_graph::graph::{GraphType, Graphs, ScannerData},
+  build_states::NORMAL_GRAPH,
+};
+use crate::{
+  parser::{ASTNode, Matches, ProductionMatches, Statement, TerminalMatches},
+  types::*,
+};
+use radlr_rust_runtime::types::bytecode::MatchInputType;
+use std::{
+  collections::VecDeque,
+  hash::{Hash, Hasher},
+  path::{Path, PathBuf},
+};
+
+const CACHE_HEADER: &str = concat!("radlr-state-cache ", env!("CARGO_PKG_VERSION"), " 1");
+
+/// The cache keys of the parser non-terminals of a grammar, and the states of
+/// the non-terminals that were found in the cache.
+pub(crate) struct StateCache {
+  dir:    PathBuf,
+  keys:   OrderedMap<DBNonTermKey, u64>,
+  cached: OrderedMap<DBNonTermKey, CachedStates>,
+}
+
+struct CachedStates {
+  classification: ParserClassification,
+  states:         Array<ParseState>,
+}
+
+impl StateCache {
+  /// Computes the cache keys of the parser non-terminals of `db` and loads the
+  /// states of every non-terminal that has an entry in `dir`.
+  ///
+  /// Entries that can not be read are treated as missing.
+  pub(crate) fn load(dir: &Path, db: &ParserDatabase, config: &ParserConfig) -> RadlrResult<Self> {
+    std::fs::create_dir_all(dir)?;
+
+    let mut base = StandardHasher::new();
+    CACHE_HEADER.hash(&mut base);
+    config.hash(&mut base);
+
+    let mut keys = OrderedMap::new();
+    let mut cached = OrderedMap::new();
+
+    for nonterm in db.parser_nonterms().into_iter().filter(|nt| db.custom_state(*nt).is_none()) {
+      let key = closure_hash(db, nonterm, base.clone());
+
+      if let Ok(data) = std::fs::read_to_string(entry_path(dir, key)) {
+        if let Some(states) = read_entry(&data, db) {
+          cached.insert(nonterm, states);
+        }
+      }
+
+      keys.insert(nonterm, key);
+    }
+
+    Ok(Self { dir: dir.to_path_buf(), keys, cached })
+  }
+
+  /// Returns `true` if the states of `nonterm` were loaded from the cache,
+  /// in which case its graph does not need to be built.
+  pub(crate) fn is_cached(&self, nonterm: DBNonTermKey) -> bool {
+    self.cached.contains_key(&nonterm)
+  }
+
+  /// Returns the number of non-terminals whose states were loaded from the
+  /// cache.
+  pub(crate) fn cached_count(&self) -> usize {
+    self.cached.len()
+  }
+
+  /// Stores the states built from `graphs` for each non-terminal that was not
+  /// cached, then adds the cached states of the remaining non-terminals to
+  /// `states`.
+  ///
+  /// Non-terminals whose graphs were invalidated, or rebuilt as LR fallbacks
+  /// of other non-terminals, are not stored.
+  pub(crate) fn merge(
+    &self,
+    db: &ParserDatabase,
+    graphs: &Graphs,
+    states: &mut ParseStatesMap,
+    classification: &mut ParserClassification,
+  ) -> RadlrResult<()> {
+    let mut built = OrderedMap::<DBNonTermKey, (bool, ParserClassification)>::new();
+
+    for node in graphs.successors.keys().filter(|n| n.graph_type == GraphType::Parser) {
+      let root = node.get_root();
+      let (storable, class) = built.entry(root.root_data.db_key).or_insert((true, Default::default()));
+      *storable &= root.root_data.version == NORMAL_GRAPH && !root.invalid.load(std::sync::atomic::Ordering::Acquire);
+      *class |= node.get_classification();
+    }
+
+    let roots = self.keys.keys().map(|nt| db.nonterm_guid_name(*nt)).collect::<Set<_>>();
+
+    for (nonterm, (storable, class)) in &built {
+      let (Some(key), true) = (self.keys.get(nonterm), *storable) else { continue };
+      let root = db.nonterm_guid_name(*nonterm);
+
+      if states.contains_key(&root) {
+        let entry = write_entry(db, *class, reachable_states(root, states, &roots).into_iter().filter_map(|n| states.get(&n)));
+        let path = entry_path(&self.dir, *key);
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, entry)?;
+        std::fs::rename(temp_path, path)?;
+      }
+    }
+
+    for (_, CachedStates { classification: class, states: cached }) in
+      self.cached.iter().filter(|(nonterm, _)| !built.contains_key(*nonterm))
+    {
+      *classification |= *class;
+
+      for state in cached {
+        if !states.contains_key(&state.guid_name) {
+          let mut state = Box::new(state.clone());
+          state.build_ast(db)?;
+          states.insert(state.guid_name, state);
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn entry_path(dir: &Path, key: u64) -> PathBuf {
+  dir.join(format!("{key:016X}.states"))
+}
+
+/// Hashes the rules and tokens that can influence the states of `nonterm`.
+fn closure_hash(db: &ParserDatabase, nonterm: DBNonTermKey, mut hasher: StandardHasher) -> u64 {
+  let entries = db.entry_nterm_map();
+  let mut rules = OrderedSet::<DBRuleKey>::new();
+  let mut visited = Set::<(DBRuleKey, usize)>::new();
+  let mut tokens = OrderedSet::<DBTermKey>::new();
+
+  let mut forward = OrderedSet::from_iter([nonterm]);
+  let mut context = OrderedSet::from_iter([nonterm]);
+  let mut forward_queue = VecDeque::from_iter([nonterm]);
+  let mut context_queue = VecDeque::from_iter([nonterm]);
+
+  loop {
+    // Pairs of rules and the index of the first of their symbols that can
+    // influence the states of `nonterm`.
+    let rule_keys = if let Some(nonterm) = context_queue.pop_front() {
+      // Only the symbols that follow the non-terminal in the rules that use it
+      // are part of its follow context.
+      let users = db.get_nonterm_symbol_to_rules().get(&nonterm).cloned().unwrap_or_default();
+      let mut rule_keys = Array::new();
+      for rule in users {
+        let user = db.rule_nonterm(rule);
+        if context.insert(user) {
+          context_queue.push_back(user);
+        }
+
+        let symbols = &db.rule(rule).symbols;
+        let start = symbols.iter().position(|s| s.id == SymbolId::DBNonTerminal { key: nonterm }).unwrap_or_default();
+        rule_keys.push((rule, start + 1));
+      }
+      rule_keys
+    } else if let Some(nonterm) = forward_queue.pop_front() {
+      db.nonterm_rules(nonterm).cloned().unwrap_or_default().into_iter().map(|rule| (rule, 0)).collect()
+    } else {
+      break;
+    };
+
+    for (rule_key, start) in rule_keys {
+      let rule = db.rule(rule_key);
+      rules.insert(rule_key);
+
+      if !visited.insert((rule_key, start)) {
+        continue;
+      }
+
+      let mut referenced = Array::new();
+
+      for sym in rule.symbols.iter().skip(start).map(|s| s.id).chain(rule.skipped.iter().cloned()) {
+        match sym {
+          SymbolId::DBNonTerminal { key } => referenced.push(key),
+          SymbolId::DBNonTerminalToken { nonterm_key, sym_key } => {
+            referenced.push(nonterm_key);
+            tokens.extend(sym_key);
+          }
+          SymbolId::DBToken { key } => {
+            tokens.insert(key);
+          }
+          _ => {}
+        }
+      }
+
+      for key in referenced {
+        if forward.insert(key) {
+          forward_queue.push_back(key);
+        }
+      }
+    }
+
+    // The rules of the scanner non-terminals of tokens define the scanner states.
+    for tok in &tokens {
+      let scanner = db.token(*tok).nonterm_id;
+      if Into::<usize>::into(scanner) < db.nonterms_len() && forward.insert(scanner) {
+        forward_queue.push_back(scanner);
+      }
+    }
+  }
+
+  nonterm.hash(&mut hasher);
+
+  for nonterm in &context {
+    (nonterm, entries.contains_key(nonterm)).hash(&mut hasher);
+  }
+
+  for rule_key in rules {
+    let rule = db.rule(rule_key);
+    (rule_key, db.rule_nonterm(rule_key), db.rule_peg_choice(rule_key)).hash(&mut hasher);
+    for sym in &rule.symbols {
+      (sym.id, sym.token_precedence, sym.symbol_precedence).hash(&mut hasher);
+    }
+    rule.skipped.hash(&mut hasher);
+  }
+
+  for tok in tokens {
+    let DBTokenData { sym_id, name, nonterm_id, tok_id } = db.token(tok);
+    (tok, sym_id, name, nonterm_id, tok_id, db.token_operator_precedence(tok)).hash(&mut hasher);
+  }
+
+  hasher.finish()
+}
+
+/// Returns the names of the states reachable from `root`, without entering the
+/// root states of other non-terminals, which are cached separately.
+fn reachable_states(root: IString, states: &ParseStatesMap, roots: &Set<IString>) -> Array<IString> {
+  let mut seen = Set::from_iter([root]);
+  let mut queue = VecDeque::from_iter([root]);
+  let mut out = Array::new();
+
+  while let Some(name) = queue.pop_front() {
+    let Some(state) = states.get(&name) else { continue };
+    out.push(name);
+
+    let mut referenced = Array::new();
+
+    if let Ok(ast) = state.get_ast() {
+      referenced_states(&ast.statement, &mut referenced);
+    }
+
+    for name in referenced {
+      if !roots.contains(&name) && seen.insert(name) {
+        queue.push_back(name);
+      }
+    }
+  }
+
+  out
+}
+
+fn referenced_states(stmt: &Statement, referenced: &mut Array<IString>) {
+  match &stmt.branch {
+    Some(ASTNode::Matches(box Matches { matches, mode, scanner, .. })) => {
+      if mode.as_str() == MatchInputType::TOKEN_STR {
+        referenced.push(scanner.to_token());
+      }
+      matches.iter().filter_map(match_statement).for_each(|s| referenced_states(s, referenced));
+    }
+    Some(ASTNode::ProductionMatches(box ProductionMatches { matches }))
+    | Some(ASTNode::TerminalMatches(box TerminalMatches { matches })) => {
+      matches.iter().filter_map(match_statement).for_each(|s| referenced_states(s, referenced));
+    }
+    Some(ASTNode::Gotos(gotos)) => {
+      referenced.extend(gotos.pushes.iter().map(|p| p.name.to_token()));
+      referenced.extend(gotos.goto.iter().map(|g| g.name.to_token()));
+      referenced.extend(gotos.fork.iter().flat_map(|f| f.paths.iter().map(|p| p.name.to_token())));
+    }
+    _ => {}
+  }
+}
+
+fn match_statement(node: &ASTNode) -> Option<&Statement> {
+  match node {
+    ASTNode::IntMatch(m) => Some(&m.statement),
+    ASTNode::DefaultMatch(m) => Some(&m.statement),
+    ASTNode::TermMatch(m) => Some(&m.statement),
+    ASTNode::NonTermMatch(m) => Some(&m.statement),
+    _ => None,
+  }
+}
+
+/// Serializes states to the cache entry format:
+///
+/// ```text
+/// radlr-state-cache <version> <format>
+/// classification <max_k> <bottom_up> <gotos> <calls> <peeks> <forks>
+/// state <name> <root> <precedence> <code length>
+/// [scanner <hash> <skipped tokens...>]
+/// [symbol <token> <precedence> <skipped> [<follow token> <precedence> <skipped>...]]
+/// <code>
+/// ```
+fn write_entry<'a>(db: &ParserDatabase, class: ParserClassification, states: impl Iterator<Item = &'a Box<ParseState>>) -> String {
+  let s_store = db.string_store();
+  let ParserClassification { max_k, bottom_up, gotos_present, calls_present, peeks_present, forks_present } = class;
+
+  let mut string = CACHE_HEADER.to_string() + "\n";
+  string += &format!(
+    "classification {max_k} {} {} {} {} {}\n",
+    bottom_up as u8, gotos_present as u8, calls_present as u8, peeks_present as u8, forks_present as u8
+  );
+
+  for state in states {
+    string += &format!(
+      "state {} {} {} {}\n",
+      state.guid_name.to_string(s_store),
+      state.root as u8,
+      state.precedence,
+      state.code.len()
+    );
+
+    if let Some(scanner) = state.get_scanner() {
+      string += &format!("scanner {}", scanner.hash);
+      for tok in &scanner.skipped {
+        string += &format!(" {}", tok.to_val());
+      }
+      string += "\n";
+
+      for (sym, follow) in &scanner.symbols {
+        string += "symbol";
+        for term in std::iter::once(sym).chain(follow.iter()) {
+          string += &format!(" {} {} {}", term.tok().to_val(), term.precedence(), term.is_skipped() as u8);
+        }
+        string += "\n";
+      }
+    }
+
+    string += &state.code;
+    string += "\n";
+  }
+
+  string
+}
+
+/// Deserializes a cache entry written by [write_entry], returning `None` if the
+/// entry is malformed or was written by a different version of radlr.
+fn read_entry(data: &str, db: &ParserDatabase) -> Option<CachedStates> {
+  let s_store = db.string_store();
+  let mut rest = data.strip_prefix(CACHE_HEADER)?.strip_prefix('\n')?;
+
+  let next_line = |rest: &mut &str| -> Option<Array<String>> {
+    let (line, remainder) = rest.split_once('\n')?;
+    *rest = remainder;
+    Some(line.split(' ').map(|s| s.to_string()).collect())
+  };
+
+  let class = next_line(&mut rest)?;
+  let flag = |index: usize| class.get(index).map(|f| f == "1");
+  let classification = match class.first()?.as_str() {
+    "classification" => ParserClassification {
+      max_k:         class.get(1)?.parse().ok()?,
+      bottom_up:     flag(2)?,
+      gotos_present: flag(3)?,
+      calls_present: flag(4)?,
+      peeks_present: flag(5)?,
+      forks_present: flag(6)?,
+    },
+    _ => return None,
+  };
+
+  let mut states = Array::new();
+
+  while !rest.is_empty() {
+    let header = next_line(&mut rest)?;
+    let ["state", name, root, precedence, code_len] = header.iter().map(|s| s.as_str()).collect::<Array<_>>()[..] else {
+      return None;
+    };
+
+    let mut state = ParseState {
+      guid_name: name.intern(s_store),
+      root: root == "1",
+      precedence: precedence.parse().ok()?,
+      ..Default::default()
+    };
+
+    if rest.starts_with("scanner ") {
+      let line = next_line(&mut rest)?;
+      let mut scanner = ScannerData {
+        hash:    line.get(1)?.parse().ok()?,
+        skipped: line[2..].iter().map(|t| t.parse::<u32>().ok().map(DBTermKey::from)).collect::<Option<_>>()?,
+        symbols: Default::default(),
+      };
+
+      while rest.starts_with("symbol ") {
+        let line = next_line(&mut rest)?;
+        let mut terms = line[1..].chunks(3).map(|t| -> Option<PrecedentDBTerm> {
+          match t {
+            [tok, prec, skipped] => Some((DBTermKey::from(tok.parse::<u32>().ok()?), prec.parse().ok()?, skipped == "1").into()),
+            _ => None,
+          }
+        });
+        let sym = terms.next()??;
+        scanner.symbols.insert(sym, terms.collect::<Option<_>>()?);
+      }
+
+      state.scanner = Some(scanner);
+    }
+
+    let code_len: usize = code_len.parse().ok()?;
+    state.code = rest.get(..code_len)?.to_string();
+    rest = rest.get(code_len..)?.strip_prefix('\n')?;
+
+    states.push(state);
+  }
+
+  Some(CachedStates { classification, states })
+}
@@ -0,0 +1,376 @@
+//! Graphviz DOT renderings of the parse graphs of a grammar, and of the IR
+//! states built from them.
+
+use super::states::{
+  build_graph::graph::{GraphNode, GraphType, Graphs, Origin, StateType},
+  dump::{item_string, symbol_name},
+};
+use crate::{
+  parser::{ASTNode, DefaultMatch, IntMatch, Matches, NonTermMatch, ProductionMatches, Statement, TermMatch, TerminalMatches},
+  types::*,
+};
+use radlr_rust_runtime::types::bytecode::MatchInputType;
+use std::collections::VecDeque;
+
+/// Finds the entry point whose entry name, or non-terminal name, is `name`.
+pub(crate) fn find_entry_point<'db>(db: &'db ParserDatabase, name: &str) -> RadlrResult<&'db DBEntryPoint> {
+  let s_store = db.string_store();
+  o_to_r(
+    db.entry_points().into_iter().find(|e| {
+      e.entry_name.to_string(s_store) == name || db.nonterm_friendly_name_string(e.nonterm_key) == name
+    }),
+    &format!("Could not find an entry point named `{name}`"),
+  )
+}
+
+/// Renders the parser and scanner graphs. If `entry` is given, only the parser
+/// graph states reachable from the root states of the `entry` non-terminal,
+/// directly or through calls, are rendered.
+///
+/// Peek states are grouped into a cluster for each peek group, and forked
+/// states are labeled with the rules they were forked for.
+pub(crate) fn graphs_to_dot(graphs: &Graphs, db: &ParserDatabase, entry: Option<DBNonTermKey>) -> String {
+  let mut nodes = OrderedMap::<u64, (&GraphNode, Array<&GraphNode>)>::new();
+
+  for (node, successors) in &graphs.successors {
+    let mut successors = successors.iter().map(|s| s.as_ref()).collect::<Array<_>>();
+    successors.sort_by_key(|s| s.hash_id);
+    nodes.insert(node.hash_id, (node.as_ref(), successors));
+  }
+
+  for successor in graphs.successors.values().flatten() {
+    nodes.entry(successor.hash_id).or_insert((successor.as_ref(), Default::default()));
+  }
+
+  let roots = nodes
+    .values()
+    .filter(|(n, _)| n.is_root() && n.graph_type == GraphType::Parser)
+    .map(|(n, _)| (n.root_data.db_key, n.hash_id))
+    .fold(OrderedMap::<DBNonTermKey, Array<u64>>::new(), |mut roots, (nonterm, hash)| {
+      roots.entry(nonterm).or_default().push(hash);
+      roots
+    });
+
+  let calls = |node: &GraphNode| match node.ty {
+    StateType::KernelCall(nonterm) | StateType::InternalCall(nonterm) => roots.get(&nonterm).cloned().unwrap_or_default(),
+    _ => Default::default(),
+  };
+
+  let included = match entry {
+    Some(entry) => {
+      let mut seen = Set::new();
+      let mut queue = VecDeque::from_iter(roots.get(&entry).cloned().unwrap_or_default());
+
+      while let Some(hash) = queue.pop_front() {
+        if seen.insert(hash) {
+          if let Some((node, successors)) = nodes.get(&hash) {
+            queue.extend(successors.iter().map(|s| s.hash_id));
+            queue.extend(calls(node));
+          }
+        }
+      }
+
+      seen
+    }
+    None => nodes.keys().cloned().collect(),
+  };
+
+  let mut peek_groups = OrderedMap::<u32, Array<&GraphNode>>::new();
+  let mut string = "digraph graphs {\n  node [shape=box fontname=monospace];\n".to_string();
+
+  for (node, _) in nodes.values().filter(|(n, _)| included.contains(&n.hash_id)) {
+    match node.ty {
+      StateType::Peek(_) | StateType::PeekEndComplete(_) => {
+        if let Some(group) = node.kernel.iter().find_map(|i| match i.origin {
+          Origin::Peek(group) => Some(group),
+          _ => None,
+        }) {
+          peek_groups.entry(group).or_default().push(node);
+          continue;
+        }
+      }
+      _ => {}
+    }
+
+    string += &graph_node_to_dot(node, db, "  ");
+  }
+
+  for (group, nodes) in &peek_groups {
+    string += &format!("  subgraph cluster_peek_{group:08X} {{\n    label=\"peek group {group:08X}\";\n    color=blue;\n");
+    for node in nodes {
+      string += &graph_node_to_dot(node, db, "    ");
+    }
+    string += "  }\n";
+  }
+
+  for (node, successors) in nodes.values().filter(|(n, _)| included.contains(&n.hash_id)) {
+    for successor in successors.iter().filter(|s| included.contains(&s.hash_id)) {
+      let sym = successor.sym.sym();
+      let label = if sym.is_default() || sym == SymbolId::Undefined { String::new() } else { symbol_name(sym, db) };
+      string += &format!("  n{:016X} -> n{:016X} [label={}];\n", node.hash_id, successor.hash_id, dot_string(&label));
+    }
+
+    for root in calls(node).into_iter().filter(|r| included.contains(r)) {
+      string += &format!("  n{:016X} -> n{root:016X} [style=dashed label=\"call\"];\n", node.hash_id);
+    }
+  }
+
+  string + "}\n"
+}
+
+fn graph_node_to_dot(node: &GraphNode, db: &ParserDatabase, indent: &str) -> String {
+  let root = node.get_root();
+  let invalid = root.invalid.load(std::sync::atomic::Ordering::Relaxed);
+
+  let mut label = format!(
+    "{} {} {:?}\\n{}\\n{:?}",
+    if node.graph_type == GraphType::Scanner { "scanner" } else { "parser" },
+    node.id.index(),
+    node.id.subtype(),
+    db.nonterm_friendly_name_string(root.root_data.db_key),
+    node.ty
+  );
+
+  let mut fork_origins = node
+    .kernel
+    .iter()
+    .filter_map(|i| match i.origin {
+      Origin::Fork(rule) => Some(item_string(Item::from((rule, db)), db)),
+      _ => None,
+    })
+    .collect::<Array<_>>();
+  fork_origins.dedup();
+
+  for origin in &fork_origins {
+    label += &("\\nfork of: ".to_string() + &dot_escape(origin));
+  }
+
+  label += "\\l";
+
+  for item in node.kernel.iter() {
+    label += &(dot_escape(&item_string(*item, db)) + "\\l");
+  }
+
+  let mut attributes = vec![format!("label=\"{label}\"")];
+
+  if node.is_root() {
+    attributes.push("peripheries=2".into());
+  }
+
+  if !fork_origins.is_empty() || matches!(node.ty, StateType::ForkInitiator | StateType::ForkedState) {
+    attributes.push("color=red".into());
+  } else if matches!(node.ty, StateType::Peek(_) | StateType::PeekEndComplete(_)) {
+    attributes.push("color=blue".into());
+  }
+
+  if invalid {
+    attributes.push("style=dashed".into());
+  }
+
+  format!("{indent}n{:016X} [{}];\n", node.hash_id, attributes.join(" "))
+}
+
+/// Renders the IR states. If `entry` is given, only the states reachable from
+/// the entry state of `entry` are rendered.
+///
+/// Gotos are drawn as solid edges, pushes (the return states of calls) as
+/// dashed edges, fork paths as red edges, and scanner states as dotted edges.
+/// Pops are drawn as edges to a shared `pop` node.
+pub(crate) fn ir_states_to_dot<'a>(
+  states: impl Iterator<Item = (IString, &'a ParseState)>,
+  db: &ParserDatabase,
+  entry: Option<&DBEntryPoint>,
+) -> String {
+  let s_store = db.string_store();
+  let states = states.collect::<OrderedMap<_, _>>();
+
+  let mut edges = OrderedMap::<IString, Array<(IString, String, &'static str)>>::new();
+  let mut pops = OrderedMap::<IString, Array<String>>::new();
+
+  for (name, state) in &states {
+    if let Ok(ast) = state.get_ast() {
+      let mut state_edges = Array::new();
+      let mut state_pops = Array::new();
+      collect_ir_edges(&ast.statement, db, "", &mut state_edges, &mut state_pops);
+      edges.insert(*name, state_edges);
+      pops.insert(*name, state_pops);
+    }
+  }
+
+  let included = match entry {
+    Some(entry) => {
+      let mut seen = Set::new();
+      let mut queue = VecDeque::from_iter([entry.nonterm_entry_name]);
+
+      while let Some(name) = queue.pop_front() {
+        if states.contains_key(&name) && seen.insert(name) {
+          queue.extend(edges.get(&name).into_iter().flatten().map(|(target, ..)| *target));
+        }
+      }
+
+      seen
+    }
+    None => states.keys().cloned().collect(),
+  };
+
+  let mut state_labels = Map::<IString, String>::new();
+
+  for entry in db.entry_points() {
+    let name = entry.entry_name.to_string(s_store);
+    state_labels.insert(entry.nonterm_entry_name, "entry of ".to_string() + &name);
+    state_labels.insert(entry.nonterm_exit_name, "exit of ".to_string() + &name);
+  }
+
+  for nonterm in db.parser_nonterms() {
+    state_labels.entry(db.nonterm_guid_name(nonterm)).or_insert_with(|| db.nonterm_friendly_name_string(nonterm));
+  }
+
+  let mut string = "digraph ir {\n  node [shape=box fontname=monospace];\n  pop [shape=doublecircle label=\"pop\"];\n".to_string();
+
+  for name in states.keys().filter(|n| included.contains(*n)) {
+    let mut label = dot_escape(&name.to_string(s_store));
+
+    if let Some(friendly) = state_labels.get(name) {
+      label += &("\\n".to_string() + &dot_escape(friendly));
+    }
+
+    let mut attributes = vec![format!("label=\"{label}\"")];
+
+    if db.entry_points().iter().any(|e| e.nonterm_entry_name == *name) {
+      attributes.push("peripheries=2".into());
+    }
+
+    string += &format!("  {} [{}];\n", dot_id(*name, s_store), attributes.join(" "));
+  }
+
+  for name in states.keys().filter(|n| included.contains(*n)) {
+    for (target, label, style) in edges.get(name).into_iter().flatten() {
+      if included.contains(target) {
+        string += &format!(
+          "  {} -> {} [label={} {style}];\n",
+          dot_id(*name, s_store),
+          dot_id(*target, s_store),
+          dot_string(label)
+        );
+      }
+    }
+
+    for label in pops.get(name).into_iter().flatten() {
+      string += &format!("  {} -> pop [label={} style=bold];\n", dot_id(*name, s_store), dot_string(label));
+    }
+  }
+
+  string + "}\n"
+}
+
+/// Collects the goto, push, fork, and scanner references of a statement and
+/// the statements of its match branches, labeling each with the conditions of
+/// the branch that leads to it.
+fn collect_ir_edges(
+  stmt: &Statement,
+  db: &ParserDatabase,
+  condition: &str,
+  edges: &mut Array<(IString, String, &'static str)>,
+  pops: &mut Array<String>,
+) {
+  for pop in stmt.non_branch.iter().filter_map(|n| n.as_Pop()).chain(stmt.pop.as_deref()) {
+    pops.push(join_label(condition, &format!("pop {}", pop.count)));
+  }
+
+  let Some(branch) = &stmt.branch else { return };
+
+  match branch {
+    ASTNode::Matches(box Matches { matches, mode, scanner, .. }) => {
+      let mode = MatchInputType::from(mode.as_str());
+
+      if mode == MatchInputType::Token && !scanner.is_empty() {
+        edges.push((scanner.to_token(), join_label(condition, "scanner"), "style=dotted"));
+      }
+
+      collect_match_edges(matches, db, condition, mode, edges, pops);
+    }
+    ASTNode::ProductionMatches(box ProductionMatches { matches }) => {
+      collect_match_edges(matches, db, condition, MatchInputType::NonTerminal, edges, pops);
+    }
+    ASTNode::TerminalMatches(box TerminalMatches { matches }) => {
+      collect_match_edges(matches, db, condition, MatchInputType::Token, edges, pops);
+    }
+    ASTNode::Gotos(gotos) => {
+      for push in &gotos.pushes {
+        edges.push((push.name.to_token(), join_label(condition, "push"), "style=dashed"));
+      }
+
+      if let Some(goto) = &gotos.goto {
+        edges.push((goto.name.to_token(), join_label(condition, "goto"), "style=solid"));
+      }
+
+      if let Some(fork) = &gotos.fork {
+        for path in &fork.paths {
+          edges.push((path.name.to_token(), join_label(condition, "fork"), "color=red"));
+        }
+      }
+    }
+    _ => {}
+  }
+}
+
+fn collect_match_edges(
+  matches: &[ASTNode],
+  db: &ParserDatabase,
+  condition: &str,
+  mode: MatchInputType,
+  edges: &mut Array<(IString, String, &'static str)>,
+  pops: &mut Array<String>,
+) {
+  for m in matches {
+    let (stmt, label) = match m {
+      ASTNode::IntMatch(box IntMatch { statement, vals }) => {
+        (statement, vals.iter().map(|v| match_value_string(*v, mode, db)).collect::<Array<_>>().join(" | "))
+      }
+      ASTNode::DefaultMatch(box DefaultMatch { statement }) => (statement, "default".to_string()),
+      ASTNode::TermMatch(box TermMatch { statement, .. }) => (statement, "terminal".to_string()),
+      ASTNode::NonTermMatch(box NonTermMatch { statement, .. }) => (statement, "non-terminal".to_string()),
+      _ => continue,
+    };
+
+    collect_ir_edges(stmt, db, &join_label(condition, &label), edges, pops);
+  }
+}
+
+/// Renders a match value as the token, non-terminal, or character it matches.
+fn match_value_string(val: u64, mode: MatchInputType, db: &ParserDatabase) -> String {
+  match mode {
+    MatchInputType::Token if (val as usize) < db.tokens().len() => {
+      symbol_name(SymbolId::DBToken { key: DBTermKey::from(val as u32) }, db)
+    }
+    MatchInputType::NonTerminal if (val as usize) < db.nonterms_len() => {
+      db.nonterm_friendly_name_string(DBNonTermKey::from(val as u32))
+    }
+    MatchInputType::Byte | MatchInputType::ByteScanless | MatchInputType::Codepoint | MatchInputType::CodepointScanless
+      if val > 32 && val < 127 =>
+    {
+      format!("'{}'", val as u8 as char)
+    }
+    _ => val.to_string(),
+  }
+}
+
+fn join_label(condition: &str, label: &str) -> String {
+  if condition.is_empty() {
+    label.to_string()
+  } else {
+    condition.to_string() + " > " + label
+  }
+}
+
+fn dot_id(name: IString, s_store: &IStringStore) -> String {
+  dot_string(&name.to_string(s_store))
+}
+
+fn dot_string(value: &str) -> String {
+  "\"".to_string() + &dot_escape(value) + "\""
+}
+
+fn dot_escape(value: &str) -> String {
+  value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
@@ -32,11 +32,14 @@ pub(crate) fn build_ir_concurrent<T: WorkerPool>(
   let states = Arc::new(RwLock::new(OrderedMap::new()));
   let classification = Arc::new(RwLock::new(ParserClassification::default()));
 
+  let cancel = pool.cancellation_token();
+
   pool.run(|num_of_threads| {
     let graphs = graphs.clone();
     let db = db.clone();
     let final_states = states.clone();
     let classification = classification.clone();
+    let cancel = cancel.clone();
 
     move |thread_id| {
       let chunk_size = graphs.successors.len().div_ceil(num_of_threads);
@@ -52,6 +55,8 @@ pub(crate) fn build_ir_concurrent<T: WorkerPool>(
         }
 
         if index >= chunk_start {
+          cancel.check()?;
+
           if !(par.get_root().invalid.load(std::sync::atomic::Ordering::Acquire)) {
             let precursor = IRPrecursorGroup {
               node:          par.clone(),
@@ -351,6 +356,7 @@ fn convert_state_to_ir<'graph: 'graph>(
         StateType::NonTerminalComplete
           | StateType::NonTermCompleteOOS
           | StateType::ScannerCompleteOOS
+          | StateType::Fail
           | StateType::CompleteToken
           | StateType::AssignToken(..)
       );
@@ -532,6 +538,10 @@ fn build_body<'graph: 'graph>(
         body_string.push("pass".into());
         HALT
       }
+      StateType::Fail => {
+        body_string.push("fail".into());
+        HALT
+      }
       StateType::Reduce(rule_id, completes) => {
         debug_assert!(!successor.kernel_items().iter().any(|i| i.is_oos()));
 
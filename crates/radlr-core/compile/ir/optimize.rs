@@ -40,79 +40,37 @@ pub(crate) fn sweep<'db, R: FromIterator<(IString, Box<ParseState>)>>(
 /// Performance various transformation on the parse state graph
 /// to reduce the number of steps between transient actions, and to
 /// reduce the number of parse states overall.
+///
+/// The passes of `opt_config` are run in order. Unreachable states are
+/// always removed from the output, regardless of the configured passes.
 pub(crate) fn optimize<'db, R: FromIterator<(IString, Box<ParseState>)>, Pool: WorkerPool>(
   db: &'db ParserDatabase,
   config: &ParserConfig,
+  opt_config: &OptimizeConfig,
   parse_states: ParseStatesMap,
   optimize_for_debugging: bool,
-  _pool_: &Pool,
+  pool: &Pool,
 ) -> RadlrResult<(R, OptimizationReport)> {
+  let cancel = pool.cancellation_token();
+
   let mut report = OptimizationReport {
     start: ComplexityMarker::from_map_iter(db, parse_states.iter()),
     ..Default::default()
   };
 
-  let parse_states = garbage_collect(db, config, parse_states, None)?.0;
-
-  let parse_states = canonicalize_states(db, config, parse_states, false)?.0;
-
-  let parse_states = merge_branches(db, parse_states)?;
-
-  let parse_states = combine_state_branches(db, parse_states)?;
-
-  let parse_states = canonicalize_states(db, config, parse_states, false)?.0;
-
-  let parse_states = inline_states(db, config, parse_states)?;
-
-  let parse_states = if config.ALLOW_SCANNER_INLINING { inline_scanners(db, config, parse_states)? } else { parse_states };
-
-  let parse_states = if config.ALLOW_BYTE_SEQUENCES { create_byte_sequences(db, config, parse_states)? } else { parse_states };
-
-  let parse_states = merge_branches(db, parse_states)?;
-
-  let parse_states = remove_redundant_defaults(db, config, parse_states)?;
-
-  let parse_states = canonicalize_states(db, config, parse_states, false)?.0;
-
-  let parse_states = combine_state_branches(db, parse_states)?;
+  let mut parse_states = parse_states;
 
-  // Perform final rounds of canonicalization, removing as many redundant states
-  // as possible.
-  let parse_states = {
-    report.canonical_rounds += 3;
-    let mut reset = false;
-    let mut states = parse_states;
-    loop {
-      let mut remove_self_recursive = false;
-      states = loop {
-        match canonicalize_states(db, config, states, remove_self_recursive)? {
-          (s, true) => {
-            report.canonical_rounds += 1;
-            states = s;
-          }
-          (s, false) => {
-            if !remove_self_recursive {
-              // Perform a rounds of canonicalization that merge states that are
-              // identical but are also self-recursive
-              remove_self_recursive = true;
+  for pass in opt_config.passes.iter().cloned() {
+    cancel.check()?;
 
-              states = s;
-            } else {
-              break s;
-            }
-          }
-        }
-      };
+    parse_states = run_pass(db, config, pass, parse_states, &mut report)?;
 
-      if !reset {
-        reset = true;
-      } else {
-        break states;
-      }
+    if opt_config.report_passes {
+      report.passes.push(PassReport { pass, marker: ComplexityMarker::from_map_iter(db, parse_states.iter()) });
     }
-  };
+  }
 
-  if cfg!(debug_assertions) {
+  if cfg!(debug_assertions) && opt_config.passes.last() == Some(&OptimizationPass::CanonicalizeFull) {
     // Ensure all states are unique at this point
     let mut hashes = Set::new();
     for (_, state) in parse_states.iter() {
@@ -125,11 +83,72 @@ pub(crate) fn optimize<'db, R: FromIterator<(IString, Box<ParseState>)>, Pool: W
     }
   }
 
-  let parse_states: ParseStatesMap = parse_states;
-
   finish(optimize_for_debugging, parse_states, db, config, report)
 }
 
+fn run_pass<'db>(
+  db: &'db ParserDatabase,
+  config: &ParserConfig,
+  pass: OptimizationPass,
+  parse_states: ParseStatesMap,
+  report: &mut OptimizationReport,
+) -> RadlrResult<ParseStatesMap> {
+  use OptimizationPass::*;
+  match pass {
+    GarbageCollect => Ok(garbage_collect(db, config, parse_states, None)?.0),
+    Canonicalize => Ok(canonicalize_states(db, config, parse_states, false)?.0),
+    CanonicalizeFull => canonicalize_fully(db, config, parse_states, report),
+    MergeBranches => merge_branches(db, parse_states),
+    CombineBranches => combine_state_branches(db, parse_states),
+    InlineStates => inline_states(db, config, parse_states),
+    InlineScanners if config.ALLOW_SCANNER_INLINING => inline_scanners(db, config, parse_states),
+    ByteSequences if config.ALLOW_BYTE_SEQUENCES => create_byte_sequences(db, config, parse_states),
+    InlineScanners | ByteSequences => Ok(parse_states),
+    RemoveRedundantDefaults => remove_redundant_defaults(db, config, parse_states),
+  }
+}
+
+/// Perform final rounds of canonicalization, removing as many redundant states
+/// as possible.
+fn canonicalize_fully<'db>(
+  db: &'db ParserDatabase,
+  config: &ParserConfig,
+  parse_states: ParseStatesMap,
+  report: &mut OptimizationReport,
+) -> RadlrResult<ParseStatesMap> {
+  report.canonical_rounds += 3;
+  let mut reset = false;
+  let mut states = parse_states;
+  loop {
+    let mut remove_self_recursive = false;
+    states = loop {
+      match canonicalize_states(db, config, states, remove_self_recursive)? {
+        (s, true) => {
+          report.canonical_rounds += 1;
+          states = s;
+        }
+        (s, false) => {
+          if !remove_self_recursive {
+            // Perform a rounds of canonicalization that merge states that are
+            // identical but are also self-recursive
+            remove_self_recursive = true;
+
+            states = s;
+          } else {
+            break s;
+          }
+        }
+      }
+    };
+
+    if !reset {
+      reset = true;
+    } else {
+      break Ok(states);
+    }
+  }
+}
+
 fn finish<'db, R: FromIterator<(IString, Box<ParseState>)>>(
   optimize_for_debugging: bool,
   parse_states: BTreeMap<IString, Box<ParseState>>,
@@ -1,4 +1,6 @@
 //! Handles the compilation of a grammar's states and IR.
 
+pub(crate) mod cache;
+pub(crate) mod dot;
 pub(crate) mod ir;
 pub(crate) mod states;
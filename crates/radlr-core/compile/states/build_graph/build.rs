@@ -1,4 +1,5 @@
 use super::{
+  errors::undeterministic_peek_error,
   flow::{
     get_kernel_items_from_peek_origin,
     handle_fork,
@@ -57,13 +58,6 @@ pub(crate) fn handle_kernel_items(
   let states_queued = gb.commit(update_gotos, Some(pred), config, true)?;
 
   if pred.state_type().is_peek() && pred.state_type().peek_level() > 0 && states_queued == 0 {
-    // Todo(anthony) : if peeking, determine if the peek has terminated in a
-    // non-deterministic way. If so, produce a NonDeterministicPeek error.
-    //panic!("Undeterministic PARSE");
-    let root_data = pred.root_data.db_key;
-
-    let root_node = gb.db().nonterm_sym(root_data);
-
     let mut peek_items = vec![];
 
     for peek_item in pred.kernel_items() {
@@ -74,14 +68,7 @@ pub(crate) fn handle_kernel_items(
 
     Err(RadlrError::StateConstructionError(crate::compile::states::build_states::StateConstructionError::NonDeterministicPeek(
       pred.get_root_shared(),
-      Box::new(
-        format!(
-          "Testing Messagine. Peek has no successors! \n Error occurred in nonterminal {} \n Unresolved leaf items: \n {}",
-          root_node.debug_string(gb.db()),
-          peek_items.to_debug_string(gb.db(), "\n")
-        )
-        .into(),
-      ),
+      Box::new(undeterministic_peek_error(gb, pred, &peek_items)),
     )))
   } else {
     Ok(())
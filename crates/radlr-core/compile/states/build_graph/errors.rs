@@ -1,32 +1,151 @@
-use super::graph::*;
-use crate::types::*;
+use super::{flow::get_kernel_items_from_peek_item, graph::*};
+use crate::{compile::states::counterexample::find_counterexample, types::*};
 
 use ErrorClass::*;
 
-pub(super) fn _create_reduce_reduce_error(_gb: &ConcurrentGraphBuilder, _end_items: ItemSet) -> RadlrError {
-  /*   j.report_mut().add_error(RadlrError::SourcesError {
-    id:       "reduce-conflict",
-    msg:      "Unresolvable parse conflict encountered".into(),
-    ps_msg:   {
-      let mut string = "Enable the following configs to use an alternative parse strategy".into();
-
-      if !graph.is_scanner() {
-      } else {
-        let nterm = &goals.first().expect("Should have at least one goal").nonterm_index();
-        let name = db.nonterm_guid_name_string(*nterm).as_str().to_string();
-
-        string +=
-          ("\n - Turn non-terminal <".to_string() + &name + " > into a PEG by using one of the PEG mode specifiers:").as_str();
-        string += "\n    [ FIRST_MATCH | LONGEST_MATCH | SHORTEST_MATCH ]";
-        string += format!("\n\n    Example: <> {name} FIRST_MATCH >  ...",).as_str();
-      }
-      string
-    },
+fn lookahead_string(sym: SymbolId, db: &ParserDatabase) -> String {
+  if sym.is_default() {
+    "end of input".into()
+  } else {
+    sym.debug_string(db)
+  }
+}
+
+/// Prepends a counterexample of the conflict between `items` on `lookahead` to
+/// `ps_msg`, if one can be found.
+fn with_counterexample<I: IntoIterator<Item = Item>>(
+  gb: &ConcurrentGraphBuilder,
+  node: &SharedGraphNode,
+  items: I,
+  lookahead: SymbolId,
+  ps_msg: &str,
+) -> String {
+  let d = gb.db();
+
+  match (node.graph_type() == GraphType::Parser)
+    .then(|| find_counterexample(d, node.root_data.db_key, items, lookahead))
+    .flatten()
+  {
+    Some(counterexample) if ps_msg.is_empty() => counterexample.to_string(d),
+    Some(counterexample) => counterexample.to_string(d) + "\n\n" + ps_msg,
+    None => ps_msg.into(),
+  }
+}
+
+/// Produces an error for completed items that can all be reduced on the same
+/// lookahead symbol, and whose conflict could not be resolved.
+pub(crate) fn reduce_reduce_conflict_error(
+  gb: &ConcurrentGraphBuilder,
+  node: &SharedGraphNode,
+  prec_sym: PrecedentSymbol,
+  conflicting: &Lookaheads,
+  submessage: &str,
+) -> RadlrError {
+  let d = gb.db();
+  let sym = lookahead_string(prec_sym.sym(), d);
+
+  RadlrError::SourcesError {
+    id:       (GraphConstruction, 1, "reduce-reduce-conflict").into(),
+    msg:      "Found ".to_string()
+      + &conflicting.iter().map(|p| p.kernel.rule_id()).collect::<OrderedSet<_>>().len().to_string()
+      + " rules that can be reduced on the lookahead ["
+      + &sym
+      + "]",
+    ps_msg:   with_counterexample(gb, node, conflicting.iter().map(|p| p.kernel), prec_sym.sym(), submessage),
     severity: RadlrErrorSeverity::Critical,
-    sources:  Default::default(),
-  }); */
+    sources:  conflicting
+      .iter()
+      .map(|p| {
+        let i = p.kernel;
+        (
+          i.rule(d).tok.clone(),
+          i.rule(d).g_id.path.to_path(d.string_store()),
+          "Reduce to [".to_string()
+            + &d.nonterm_friendly_name_string(i.nonterm_index(d))
+            + "] on ["
+            + &sym
+            + "]? | "
+            + &i.to_canonical()._debug_string_w_db_(d),
+        )
+      })
+      .dedup(),
+  }
+}
+
+/// Produces an error for a peek state that must be converted into a fork state
+/// to resolve its conflict.
+pub(crate) fn peek_to_fork_error(gb: &ConcurrentGraphBuilder, node: &SharedGraphNode) -> RadlrError {
+  let d = gb.db();
+  let sym = lookahead_string(node.sym.sym(), d);
+
+  RadlrError::SourcesError {
+    id:       (GraphConstruction, 2, "peek-to-fork-conversion").into(),
+    msg:      "The conflict on the lookahead [".to_string() + &sym + "] cannot be resolved by peeking",
+    ps_msg:   "Converting a peek state into a fork state is not supported. Rewrite these rules to remove the ambiguity.".into(),
+    severity: RadlrErrorSeverity::Critical,
+    sources:  node
+      .kernel_items()
+      .iter()
+      .flat_map(|i| get_kernel_items_from_peek_item(gb, i).items.into_iter())
+      .map(|i| {
+        (
+          i.rule(d).tok.clone(),
+          i.rule(d).g_id.path.to_path(d.string_store()),
+          "Lookahead [".to_string() + &sym + "] | " + &i.to_canonical()._debug_string_w_db_(d),
+        )
+      })
+      .collect(),
+  }
+}
+
+/// Produces an error for a peek state whose items are still in conflict after
+/// all of the lookahead symbols have been peeked.
+pub(crate) fn undeterministic_peek_error(gb: &ConcurrentGraphBuilder, node: &SharedGraphNode, items: &[Item]) -> RadlrError {
+  let d = gb.db();
+  let sym = lookahead_string(node.sym.sym(), d);
+
+  RadlrError::SourcesError {
+    id:       (GraphConstruction, 3, "undeterministic-peek").into(),
+    msg:      "Could not build a parser for [".to_string()
+      + &d.nonterm_friendly_name_string(node.root_data.db_key)
+      + "]. These rules are still in conflict after peeking the lookahead ["
+      + &sym
+      + "]",
+    ps_msg:   with_counterexample(
+      gb,
+      node,
+      items.iter().cloned(),
+      peek_root_symbol(node),
+      "Enable forking, or rewrite these rules to remove the ambiguity.",
+    ),
+    severity: RadlrErrorSeverity::Critical,
+    sources:  items
+      .iter()
+      .map(|i| {
+        (
+          if !i.is_complete() { i.rule(d).symbols[i.sym_index() as usize].loc.clone() } else { i.rule(d).tok.clone() },
+          i.rule(d).g_id.path.to_path(d.string_store()),
+          if i.is_complete() {
+            "Reduce to [".to_string() + &d.nonterm_friendly_name_string(i.nonterm_index(d)) + "]? | "
+          } else {
+            "Continue shifting? | ".to_string()
+          } + &i.to_canonical()._debug_string_w_db_(d),
+        )
+      })
+      .dedup(),
+  }
+}
+
+/// Returns the symbol of the first peek state leading to `node`, which is the
+/// lookahead the peeked items are in conflict on.
+fn peek_root_symbol(node: &SharedGraphNode) -> SymbolId {
+  let mut peek_root: &GraphNode = node;
+
+  while let Some(pred) = peek_root.predecessor.as_ref().filter(|p| p.state_type().is_peek()) {
+    peek_root = pred;
+  }
 
-  RadlrError::StaticText("TODO - create error message for reduce-reduce conflicts")
+  peek_root.sym.sym()
 }
 
 /// Produces errors that result from the banning of LR states.
@@ -96,14 +215,16 @@ pub(crate) fn conflicting_symbols_error(
 
 pub(crate) fn peek_not_allowed_error<T>(
   gb: &ConcurrentGraphBuilder,
+  node: &SharedGraphNode,
   conflicting_groups: &[Vec<TransitionPair>],
   submessage: &str,
 ) -> RadlrResult<T> {
-  Err(peek_not_allowed_error_internal(gb, conflicting_groups, submessage))
+  Err(peek_not_allowed_error_internal(gb, node, conflicting_groups, submessage))
 }
 
 fn peek_not_allowed_error_internal(
   gb: &ConcurrentGraphBuilder,
+  node: &SharedGraphNode,
   conflicting_groups: &[Vec<TransitionPair>],
   submessage: &str,
 ) -> RadlrError {
@@ -111,7 +232,13 @@ fn peek_not_allowed_error_internal(
   RadlrError::SourcesError {
     id:       (ForbiddenPeek, 0, "disabled-peeking").into(),
     msg:      "The following items cannot be resolved within k=1 lookahead when peeking is disabled".into(),
-    ps_msg:   if submessage.is_empty() { "Enable peeking to disambiguate these states.".into() } else { submessage.into() },
+    ps_msg:   with_counterexample(
+      gb,
+      node,
+      conflicting_groups.iter().flatten().map(|p| p.kernel),
+      conflicting_groups.iter().flatten().map(|p| p.sym).next().unwrap_or_default(),
+      if submessage.is_empty() { "Enable peeking to disambiguate these states." } else { submessage },
+    ),
     severity: RadlrErrorSeverity::Critical,
     sources:  conflicting_groups
       .iter()
@@ -15,7 +15,7 @@ use crate::{
     build_graph,
     build_graph::{
       build::handle_completed_groups,
-      errors::{conflicting_symbols_error, peek_not_allowed_error},
+      errors::{conflicting_symbols_error, peek_not_allowed_error, reduce_reduce_conflict_error},
       items::{get_follow, get_follow_internal, FollowType},
       stack_vec::StackVec,
     },
@@ -30,13 +30,14 @@ pub(super) enum ShiftReduceConflictResolution {
   Reduce,
   Peek(u16),
   Fork,
+  /// Neither shift nor reduce, making the lookahead a syntax error.
+  Neither,
 }
 
 const MAX_EVAL_K_RR: usize = 8;
 const MAX_EVAL_K_SR: usize = 64;
 
 pub(super) enum ReduceReduceConflictResolution {
-  Reduce(Item),
   Fork(Lookaheads),
   Peek(u16, Lookaheads),
   Nothing,
@@ -60,12 +61,14 @@ pub(super) fn resolve_reduce_reduce_conflict(
           }
           return peek_not_allowed_error(
             gb,
+            node,
             follow_pairs.into_iter().map(|i| vec![i]).collect::<Vec<_>>().as_slice(),
             &format!("Either peeking or forking must be enabled to resolve this ambiguity, which requires a lookahead of k={k}"),
           );
         } else if k as u32 > config.max_k {
           return peek_not_allowed_error(
             gb,
+            node,
             follow_pairs.into_iter().map(|i| vec![i]).collect::<Vec<_>>().as_slice(),
             &format!(
               "A lookahead of k={k} is required, but lookahead cannot be greater than k={} with the current configuration",
@@ -76,41 +79,41 @@ pub(super) fn resolve_reduce_reduce_conflict(
 
         Ok(ReduceReduceConflictResolution::Peek(k as u16, follow_pairs))
       }
-      _ => {
-        return Ok(ReduceReduceConflictResolution::Peek(100 as u16, follow_pairs));
-        #[cfg(debug_assertions)]
-        {
-          for (i, follow_pair) in follow_pairs.iter().enumerate() {
-            let mut item = follow_pair.kernel;
-            println!("-------------- \n\n");
-
-            loop {
-              println!("{}", item._debug_string_w_db_(&gb.db()));
-              let origin_state_id = item.origin_state;
-
-              if let Some(origin_state) = gb.get_state(origin_state_id.0 as u64) {
-                let kernel_items = origin_state.kernel_items();
-
-                if item.is_initial() && origin_state_id.is_root() {
-                  break;
-                }
-
-                if let Some(i) = kernel_items.iter().find(|i| item.is_successor_of(i)) {
-                  item = *i;
-                } else {
-                  break;
-                }
-              } else {
-                break;
-              }
-            }
-
-            println!("{i} {}", follow_pair._debug_string_(&gb.db()));
-          }
-          todo!("Resolve lookahead conflicts: \n prec_sym{prec_sym:#?}  \nfollow_pairs: {follow_pairs:#?}\n\n");
-        }
-        todo!("Resolve lookahead conflicts: Use debug build to print a more verbose error message.");
+      KCalcResults::LargerThanMaxLimit(k) if config.ALLOW_PEEKING && k as u32 <= config.max_k => {
+        Ok(ReduceReduceConflictResolution::Peek(k as u16, follow_pairs))
       }
+      _ if config.ALLOW_CONTEXT_SPLITTING => Ok(ReduceReduceConflictResolution::Fork(follow_pairs)),
+      KCalcResults::RecursiveAt(k) => Err(reduce_reduce_conflict_error(
+        gb,
+        node,
+        prec_sym,
+        &follow_pairs,
+        &format!("This is undeterministic at k>={k}. Enable forking to resolve this ambiguity."),
+      )),
+      KCalcResults::LargerThanMaxLimit(k) if config.ALLOW_PEEKING => Err(reduce_reduce_conflict_error(
+        gb,
+        node,
+        prec_sym,
+        &follow_pairs,
+        &format!(
+          "A lookahead of k>={k} is required, but lookahead cannot be greater than k={} with the current configuration",
+          config.max_k
+        ),
+      )),
+      KCalcResults::LargerThanMaxLimit(k) => Err(reduce_reduce_conflict_error(
+        gb,
+        node,
+        prec_sym,
+        &follow_pairs,
+        &format!("Either peeking or forking must be enabled to resolve this ambiguity, which requires a lookahead of k>={k}"),
+      )),
+      _ => Err(reduce_reduce_conflict_error(
+        gb,
+        node,
+        prec_sym,
+        &follow_pairs,
+        "These rules can not be disambiguated with lookahead. Enable forking to resolve this ambiguity.",
+      )),
     }
   }
 }
@@ -122,9 +125,36 @@ pub(super) fn resolve_shift_reduce_conflict<'a, T: TransitionPairRefIter<'a> + C
   shifts: T,
   reduces: T,
 ) -> RadlrResult<ShiftReduceConflictResolution> {
+  // Conflicts between the ordered choices of a PEG non-terminal are won by the
+  // earliest choice. A completed choice has matched, so it is reduced at once.
+  // An incomplete choice may still fail to match, so the parser backtracks to
+  // the later choice either by peeking ahead or by forking.
+  match earliest_peg_conflict_choice(shifts.clone(), reduces.clone(), gb.db()) {
+    Some(PegChoice::Reduce) => return Ok(ShiftReduceConflictResolution::Reduce),
+    Some(PegChoice::Shift) => {
+      return match config.ALLOW_PEEKING.then(|| calculate_k(gb, node, reduces.clone(), shifts.clone(), MAX_EVAL_K_SR)) {
+        Some(KCalcResults::K(k)) if k as u32 <= config.max_k => Ok(ShiftReduceConflictResolution::Peek(k as u16)),
+        _ if config.ALLOW_CONTEXT_SPLITTING => Ok(ShiftReduceConflictResolution::Fork),
+        _ => peek_not_allowed_error(
+          gb,
+          node,
+          &[shifts.cloned().collect(), reduces.cloned().collect()],
+          "Lookahead can not tell whether the earlier choice of this PEG non-terminal matches. Enable forking to backtrack to the later choice",
+        ),
+      };
+    }
+    None => {}
+  }
+
   let db = gb.db();
   let mode = node.graph_type();
 
+  if mode == GraphType::Parser {
+    if let Some(resolution) = resolve_operator_precedence_conflict(shifts.clone(), reduces.clone(), db) {
+      return Ok(resolution);
+    }
+  }
+
   let compl_prec = reduces.clone().map(|i| i.kernel.decrement().unwrap().precedence(mode, db)).max().unwrap_or_default();
   let incom_prec = shifts.clone().max_precedence();
 
@@ -183,12 +213,13 @@ pub(super) fn resolve_shift_reduce_conflict<'a, T: TransitionPairRefIter<'a> + C
         } else {
           peek_not_allowed_error(
             gb,
+            node,
             &[shifts.cloned().collect(), reduces.cloned().collect()],
             &format!("Either peeking or forking must be enabled to resolve this ambiguity, which requires a lookahead of k={k}"),
           )
         }
       } else if k as u32 > config.max_k {
-        peek_not_allowed_error(gb, &[shifts.cloned().collect(), reduces.cloned().collect()], "")
+        peek_not_allowed_error(gb, node, &[shifts.cloned().collect(), reduces.cloned().collect()], "")
       } else {
         Ok(ShiftReduceConflictResolution::Peek(k as u16))
       }
@@ -203,6 +234,7 @@ pub(super) fn resolve_shift_reduce_conflict<'a, T: TransitionPairRefIter<'a> + C
           Box::new(
             peek_not_allowed_error::<()>(
               gb,
+              node,
               &[shifts.cloned().collect(), reduces.cloned().collect()],
               &format!("This is undeterministic at k>={k}"),
             )
@@ -219,6 +251,7 @@ pub(super) fn resolve_shift_reduce_conflict<'a, T: TransitionPairRefIter<'a> + C
         }
         peek_not_allowed_error(
           gb,
+          node,
           &[shifts.cloned().collect(), reduces.cloned().collect()],
           &format!(
             "Either peeking or forking must be enabled to resolve ambiguity, which requires a lookahead of k>={k} to resolve"
@@ -234,6 +267,7 @@ pub(super) fn resolve_shift_reduce_conflict<'a, T: TransitionPairRefIter<'a> + C
       } else {
         peek_not_allowed_error(
           gb,
+          node,
           &[shifts.cloned().collect(), reduces.cloned().collect()],
           &format!("Forking must be enabled to resolve ambiguity"),
         )
@@ -242,6 +276,61 @@ pub(super) fn resolve_shift_reduce_conflict<'a, T: TransitionPairRefIter<'a> + C
   }
 }
 
+enum PegChoice {
+  Shift,
+  Reduce,
+}
+
+/// Returns the PEG non-terminal and the earliest of its ordered choices that
+/// the items belong to, or `None` if any item does not belong to the ordered
+/// choices of that same PEG non-terminal.
+fn earliest_peg_choice<'a, I: Iterator<Item = &'a Item>>(items: I, db: &ParserDatabase) -> Option<(DBNonTermKey, usize)> {
+  let mut earliest: Option<(DBNonTermKey, usize)> = None;
+
+  for item in items {
+    let (nonterm, choice) = item.peg_choice(db)?;
+    earliest = match earliest {
+      Some((other, _)) if other != nonterm => return None,
+      Some((_, other_choice)) => Some((nonterm, choice.min(other_choice))),
+      None => Some((nonterm, choice)),
+    };
+  }
+
+  earliest
+}
+
+fn earliest_peg_conflict_choice<'a, T: TransitionPairRefIter<'a> + Clone>(
+  shifts: T,
+  reduces: T,
+  db: &ParserDatabase,
+) -> Option<PegChoice> {
+  let (shift_nonterm, shift_choice) = earliest_peg_choice(shifts.to_kernel(), db)?;
+  let (reduce_nonterm, reduce_choice) = earliest_peg_choice(reduces.to_kernel(), db)?;
+
+  if shift_nonterm != reduce_nonterm || shift_choice == reduce_choice {
+    None
+  } else if reduce_choice < shift_choice {
+    Some(PegChoice::Reduce)
+  } else {
+    Some(PegChoice::Shift)
+  }
+}
+
+/// If the completed items all belong to the ordered choices of the same PEG
+/// non-terminal, returns the lookaheads of the earliest choice, which wins the
+/// conflict.
+pub(super) fn resolve_peg_reduce_reduce_conflict(lookahead_pairs: &Lookaheads, db: &ParserDatabase) -> Option<Lookaheads> {
+  let (_, choice) = earliest_peg_choice(lookahead_pairs.iter().to_kernel(), db)?;
+
+  let (winner, losers): (Lookaheads, Lookaheads) =
+    lookahead_pairs.iter().partition(|pair| pair.kernel.peg_choice(db).is_some_and(|(_, c)| c == choice));
+
+  // The conflict is only resolved here if it is between choices. Conflicts
+  // within a single choice are resolved as they would be in any other
+  // non-terminal.
+  (!losers.is_empty() && winner.iter().to_kernel().items_are_the_same_rule()).then_some(winner)
+}
+
 enum KCalcResults {
   /// Return the k if it is less or equal to then the maximum eval k
   K(usize),
@@ -447,3 +536,33 @@ pub fn resolve_token_assign_id<I: Iterator<Item = DBTermKey>>(set_token: I, db:
     None
   }
 }
+
+/// Resolves the conflict with the operator precedence declared in `LEFT`,
+/// `RIGHT`, and `NONASSOC` clauses, as yacc would. The precedence of the
+/// lookahead token is compared with the precedence of the rule being reduced,
+/// and a tie is broken by the associativity of the precedence level.
+///
+/// Returns `None` if the token or the rule has no declared precedence.
+fn resolve_operator_precedence_conflict<'a, T: TransitionPairRefIter<'a> + Clone>(
+  shifts: T,
+  reduces: T,
+  db: &ParserDatabase,
+) -> Option<ShiftReduceConflictResolution> {
+  fn single<T: PartialEq>(mut iter: impl Iterator<Item = T>) -> Option<T> {
+    let first = iter.next()?;
+    iter.all(|i| i == first).then_some(first)
+  }
+
+  let (tok_level, _) = single(shifts.map(|i| i.sym.tok_db_key().and_then(|tok| db.token_operator_precedence(tok))))??;
+  let (rule_level, assoc) = single(reduces.map(|i| db.rule_operator_precedence(i.kernel.rule_id())))??;
+
+  Some(match tok_level.cmp(&rule_level) {
+    std::cmp::Ordering::Greater => ShiftReduceConflictResolution::Shift,
+    std::cmp::Ordering::Less => ShiftReduceConflictResolution::Reduce,
+    std::cmp::Ordering::Equal => match assoc {
+      Associativity::Left => ShiftReduceConflictResolution::Reduce,
+      Associativity::Right => ShiftReduceConflictResolution::Shift,
+      Associativity::NonAssoc => ShiftReduceConflictResolution::Neither,
+    },
+  })
+}
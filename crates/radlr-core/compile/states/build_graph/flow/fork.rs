@@ -2,6 +2,7 @@
 
 use super::super::{
   build::{GroupedFirsts, TransitionGroup},
+  errors::peek_to_fork_error,
   graph::*,
 };
 use crate::{
@@ -40,23 +41,8 @@ pub(crate) fn handle_fork<'a, 'db: 'a>(gb: &mut ConcurrentGraphBuilder, pred: &S
   }
 }
 
+/// Peek states can not yet be converted into fork states, so the conflict of
+/// the peek state is reported instead.
 pub(crate) fn convert_peek_root_state_to_fork(gb: &mut ConcurrentGraphBuilder, pred: &SharedGraphNode) -> Result<(), RadlrError> {
-  Ok(loop {
-    todo!("Convert root peek state to fork");
-    /* if matches!(pred.state_type(), StateType::Peek(INITIAL_PEEK_K)) {
-      let items = state.get_peek_resolve_items().unwrap().flat_map(|(_, items)| items.items.iter()).cloned().collect::<Vec<_>>();
-      let sym = state.get_symbol();
-      let parent = state.get_parent();
-      if gb.detach_state(state_id) {
-        let mut state = create_fork(gb, sym, items.into_iter())?;
-        state.set_parent(parent);
-        state.to_enqueued();
-      }
-      break;
-    } else if state_id.is_root() {
-      unreachable!()
-    } else {
-      state_id = state.get_parent()
-    } */
-  })
+  Err(peek_to_fork_error(gb, pred))
 }
@@ -10,6 +10,7 @@ use super::{
   create_peek,
   handle_completed_item,
   resolve_conflicting_tokens,
+  resolve_peg_reduce_reduce_conflict,
   resolve_reduce_reduce_conflict,
   resolve_shift_reduce_conflict,
   CreateCallResult,
@@ -54,6 +55,22 @@ fn create_out_of_scope_complete_state<'a, T: TransitionPairRefIter<'a> + Clone>(
   }
 }
 
+/// Creates a leaf state that rejects the input when `sym` is encountered.
+fn create_fail_state<'a, T: TransitionPairRefIter<'a> + Clone>(
+  gb: &mut ConcurrentGraphBuilder,
+  pred: &SharedGraphNode,
+  items: T,
+  sym: PrecedentSymbol,
+) {
+  StagedNode::new(gb)
+    .parent(pred.clone())
+    .sym(sym)
+    .ty(StateType::Fail)
+    .kernel_items(items.to_kernel().cloned())
+    .make_leaf()
+    .commit(gb);
+}
+
 pub(crate) fn handle_regular_incomplete_items(
   gb: &mut ConcurrentGraphBuilder,
   pred: &SharedGraphNode,
@@ -81,7 +98,7 @@ pub(crate) fn handle_regular_incomplete_items(
     }
     (1.., 1..) => {
       if !____allow_peek____ {
-        peek_not_allowed_error(gb, &[out_of_scope.cloned().collect(), in_scope.cloned().collect()], "")?;
+        peek_not_allowed_error(gb, pred, &[out_of_scope.cloned().collect(), in_scope.cloned().collect()], "")?;
       } else {
         create_peek(gb, pred, config, prec_sym, group.iter(), None)?.include_with_goto_state().commit(gb);
       }
@@ -224,6 +241,8 @@ pub(crate) fn handle_regular_complete_groups(
         // We may be able to continue parsing using follow items, after we
         // determine whether we have symbol ambiguities.
         resolve_conflicting_tokens(gb, pred, config, sym, lookahead_pairs.iter())?;
+      } else if let Some(lookahead_pairs) = resolve_peg_reduce_reduce_conflict(&lookahead_pairs, gb.db()) {
+        handle_completed_item(gb, pred, config, lookahead_pairs, prec_sym)?;
       } else if prec_sym.sym() == SymbolId::Default {
         if lookahead_pairs.iter().to_kernel().items_are_the_same_rule() {
           handle_completed_item(gb, pred, config, lookahead_pairs, prec_sym)?;
@@ -246,9 +265,6 @@ pub(crate) fn handle_regular_complete_groups(
               .include_with_goto_state()
               .commit(gb);
           }
-          ReduceReduceConflictResolution::Reduce(item) => {
-            todo!("Reduce result from a reduce-reduce  resolution")
-          }
           ReduceReduceConflictResolution::Peek(max_k, follow_pairs) => {
             create_peek(gb, pred, config, prec_sym, [].iter(), Some(follow_pairs.iter()))?.include_with_goto_state().commit(gb);
           }
@@ -282,6 +298,9 @@ pub(crate) fn handle_regular_complete_groups(
               .include_with_goto_state()
               .commit(gb);
           }
+          ShiftReduceConflictResolution::Neither => {
+            create_fail_state(gb, pred, lookahead_pairs.iter(), prec_sym);
+          }
         }
       }
     }
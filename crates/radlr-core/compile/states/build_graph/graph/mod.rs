@@ -274,6 +274,10 @@ pub enum StateType {
   _PeekNonTerminalCompleteOOS,
   /// Creates a leaf state that has a single `pass` instruction.
   ScannerCompleteOOS,
+  /// Creates a leaf state that has a single `fail` instruction. This rejects
+  /// a lookahead that is a syntax error, such as a chained `NONASSOC`
+  /// operator.
+  Fail,
   _FirstMatch,
   _LongestMatch,
   _ShortestMatch,
@@ -2,7 +2,10 @@ use super::build_graph::{
   build::handle_kernel_items,
   graph::{ConcurrentGraphBuilder, GraphType, Graphs, Origin, SharedGraphNode, StagedNode, StateType},
 };
-use crate::types::{worker_pool::WorkerPool, *};
+use crate::{
+  compile::cache::StateCache,
+  types::{worker_pool::WorkerPool, *},
+};
 use std::sync::Arc;
 
 #[derive(Debug, Clone, Hash)]
@@ -11,7 +14,7 @@ pub(crate) enum StateConstructionError {
   _OtherErrors(Vec<RadlrError>),
 }
 
-const NORMAL_GRAPH: i16 = 0;
+pub(crate) const NORMAL_GRAPH: i16 = 0;
 const LR_FALLBACK_GRAPH: i16 = 10;
 
 /// Add a root node to the graph queue. This type of node is also added to the
@@ -35,9 +38,12 @@ pub fn add_root(
   Ok(())
 }
 
+/// Builds the graphs of the parser non-terminals of `db`, skipping the
+/// non-terminals whose states were loaded from `cache`.
 pub(crate) fn compile_parser_states<Pool: WorkerPool>(
   db: Arc<ParserDatabase>,
   config: ParserConfig,
+  cache: Option<&StateCache>,
   pool: &Pool,
 ) -> RadlrResult<Arc<Graphs>> {
   // Create entry nodes.
@@ -59,7 +65,7 @@ pub(crate) fn compile_parser_states<Pool: WorkerPool>(
 
     let kernel_items = ItemSet::start_items(nt_id, &db).to_origin(Origin::NonTermGoal(nt_id));
 
-    if sym.is_term() {
+    if sym.is_term() || cache.is_some_and(|cache| cache.is_cached(nt_id)) {
       return None;
     }
 
@@ -86,22 +92,44 @@ pub(crate) fn compile_parser_states<Pool: WorkerPool>(
   Ok(Arc::new(gb.into()))
 }
 
+/// Signals the other workers that this worker has failed if it is dropped
+/// while the job is panicking, so they do not wait for it to finish.
+struct PanicGuard {
+  sync_tracker: Arc<std::sync::atomic::AtomicUsize>,
+  error_count:  usize,
+}
+
+impl Drop for PanicGuard {
+  fn drop(&mut self) {
+    if std::thread::panicking() {
+      self.sync_tracker.fetch_add(self.error_count, std::sync::atomic::Ordering::Relaxed);
+    }
+  }
+}
+
 fn build_states<Pool: WorkerPool>(
   pool: &Pool,
   gb: &ConcurrentGraphBuilder,
   sync_tracker: Arc<std::sync::atomic::AtomicUsize>,
 ) -> Result<(), RadlrError> {
+  let cancel = pool.cancellation_token();
+
   pool.run(|_num_of_threads_| {
     let mut gb = gb.clone();
 
     let sync_tracker = sync_tracker.clone();
-    let have_errors = std::sync::atomic::AtomicBool::new(false);
     let error_count = _num_of_threads_ + 1;
+    let cancel = cancel.clone();
 
     move |_| {
+      let _guard = PanicGuard { sync_tracker: sync_tracker.clone(), error_count };
       let mut retries = 0;
+      let mut errors = Vec::new();
       loop {
-        let have_errors = &have_errors;
+        if cancel.is_cancelled() {
+          sync_tracker.fetch_add(error_count, std::sync::atomic::Ordering::Relaxed);
+          return Err(RadlrError::Cancelled);
+        }
 
         if let Some(((node, config), _is_local_work)) =
           gb.get_local_work().map(|w| (w, true)).or_else(|| gb.get_global_work().map(|w| (w, false)))
@@ -159,12 +187,9 @@ fn build_states<Pool: WorkerPool>(
                 let mut poisoned = vec![root.root_data.db_key];
 
                 if db.entry_nterm_map().contains_key(&nonterm_key) {
-                  // Ensures all other threads are halted when the active queue is exhausted.
-                  have_errors.store(true, std::sync::atomic::Ordering::Release);
-                  sync_tracker.fetch_add(error_count, std::sync::atomic::Ordering::Release);
-                  return Err(
-                    format!("Entry parser {} {_err} is non-deterministic", db.nonterm_friendly_name_string(nonterm_key),).into(),
-                  );
+                  // There is no fallback for an entry parser. Record the error and continue
+                  // building the remaining graphs.
+                  errors.push(*_err);
                 } else {
                   if let Some(nonterms) = db.get_nonterminal_predecessors(nonterm_key) {
                     poisoned.extend(nonterms.iter());
@@ -198,14 +223,20 @@ fn build_states<Pool: WorkerPool>(
                     return Err(format!("Todo: Report critical failure during compilation (Orphaned non-terminal)").into());
                   }
                 }
+              } else {
+                // Without LR parsing there is no fallback for this non-terminal.
+                node.get_root().invalid.store(true, std::sync::atomic::Ordering::Release);
+                gb.drop_uncommitted();
+                errors.push(*_err);
               }
             }
             Err(err) => {
-              have_errors.store(true, std::sync::atomic::Ordering::Release);
-
-              // Ensures other threads are halted when the active queue is exhausted.
-              sync_tracker.fetch_add(error_count, std::sync::atomic::Ordering::Relaxed);
-              return Err(format!("Todo: Report critical failure during compilation : {err:?}").into());
+              // The graph of this non-terminal cannot be completed. Record the error and
+              // drop the graph, then continue building the remaining graphs so that
+              // their errors are reported as well.
+              node.get_root().invalid.store(true, std::sync::atomic::Ordering::Release);
+              gb.drop_uncommitted();
+              errors.push(err);
             }
             _ => {}
           }
@@ -233,7 +264,11 @@ fn build_states<Pool: WorkerPool>(
         }
       }
 
-      Ok(())
+      if errors.is_empty() {
+        Ok(())
+      } else {
+        Err(RadlrError::Multi(errors))
+      }
     }
   })?;
 
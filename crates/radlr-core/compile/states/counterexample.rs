@@ -0,0 +1,392 @@
+//! Counterexamples for parse conflicts.
+//!
+//! A counterexample is found by searching the LR(0) automaton of the goal
+//! non-terminal for the shortest input prefix that reaches a state holding all
+//! of the conflicting items. Every item of that state is valid for the prefix,
+//! so tracing each conflicting item back to the goal produces a derivation of
+//! the same prefix, in the manner of Bison's `-Wcounterexamples`.
+
+use super::build_graph::graph::GraphType;
+use crate::types::*;
+use std::collections::VecDeque;
+
+/// The maximum number of LR(0) states searched for the conflict.
+const MAX_SEARCH_STATES: usize = 4096;
+
+/// A shared input prefix, and the derivations of that prefix which lead to
+/// each of the conflicting items.
+#[derive(Debug)]
+pub(crate) struct Counterexample {
+  /// The symbols that are shifted before the conflict is reached.
+  pub prefix:      Array<SymbolId>,
+  /// The symbol that is in conflict.
+  pub lookahead:   SymbolId,
+  /// A derivation for each of the conflicting items.
+  pub derivations: Array<Derivation>,
+}
+
+/// A chain of items leading from the goal non-terminal to a conflicting item.
+#[derive(Debug)]
+pub(crate) struct Derivation {
+  /// Each item, except the last, is positioned at the non-terminal that
+  /// derives the next item. The last item is the conflicting item.
+  pub items: Array<Item>,
+}
+
+impl Derivation {
+  /// The conflicting item of this derivation.
+  pub fn item(&self) -> Item {
+    *self.items.last().expect("A derivation should have at least one item")
+  }
+
+  /// Renders the input of the derivation, with a `•` at the point of conflict.
+  pub fn example_string(&self, db: &ParserDatabase) -> String {
+    let mut before = vec![];
+    let mut after = vec![];
+
+    for (index, item) in self.items.iter().enumerate() {
+      let symbols = &item.rule(db).symbols;
+      let split = item.sym_index() as usize;
+      let is_last = index == self.items.len() - 1;
+
+      before.extend(symbols[..split].iter().map(|s| symbol_string(s.id, db)));
+
+      let rest = if is_last { split } else { split + 1 };
+      after.push(symbols[rest..].iter().map(|s| symbol_string(s.id, db)).collect::<Vec<_>>());
+    }
+
+    before.push("•".into());
+    before.extend(after.into_iter().rev().flatten());
+    before.join(" ")
+  }
+
+  /// Renders the derivation as nested rules, e.g. `e ::= [ e "+" e ::= [ e •
+  /// "+" e ] ]`
+  pub fn tree_string(&self, db: &ParserDatabase) -> String {
+    let mut string = String::new();
+
+    for (index, item) in self.items.iter().enumerate() {
+      let symbols = &item.rule(db).symbols;
+      let split = item.sym_index() as usize;
+
+      string += &db.nonterm_friendly_name_string(item.nonterm_index(db));
+      string += " ::= [ ";
+
+      for sym in &symbols[..split] {
+        string += &symbol_string(sym.id, db);
+        string += " ";
+      }
+
+      if index == self.items.len() - 1 {
+        string += "•";
+        for sym in &symbols[split..] {
+          string += " ";
+          string += &symbol_string(sym.id, db);
+        }
+      }
+    }
+
+    for (index, item) in self.items.iter().enumerate().rev() {
+      let symbols = &item.rule(db).symbols;
+
+      if index < self.items.len() - 1 {
+        for sym in &symbols[item.sym_index() as usize + 1..] {
+          string += " ";
+          string += &symbol_string(sym.id, db);
+        }
+      }
+
+      string += " ]";
+    }
+
+    string
+  }
+}
+
+impl Counterexample {
+  /// Renders the counterexample for inclusion in an error message.
+  pub fn to_string(&self, db: &ParserDatabase) -> String {
+    let prefix = self.prefix.iter().map(|s| symbol_string(*s, db)).collect::<Vec<_>>().join(" ");
+
+    let mut string = "Counterexample for the lookahead [".to_string()
+      + &lookahead_string(self.lookahead, db)
+      + "], where each derivation shares the prefix `"
+      + &prefix
+      + "`:";
+
+    for derivation in &self.derivations {
+      let item = derivation.item();
+      let action = if item.is_complete() {
+        "Reduce to [".to_string() + &db.nonterm_friendly_name_string(item.nonterm_index(db)) + "]"
+      } else {
+        "Shift [".to_string() + &symbol_string(item.sym_id(db), db) + "]"
+      };
+
+      string += "\n  ";
+      string += &action;
+      string += ":\n    ";
+      string += &derivation.example_string(db);
+      string += "\n    ";
+      string += &derivation.tree_string(db);
+    }
+
+    string
+  }
+}
+
+/// Finds a counterexample for the conflict between `items` on `lookahead`
+/// within the parser of the `goal` non-terminal.
+///
+/// Returns `None` if the items can not be reached together from the goal,
+/// which is the case for conflicts with items that are out of the scope of
+/// the goal.
+pub(crate) fn find_counterexample<I: IntoIterator<Item = Item>>(
+  db: &ParserDatabase,
+  goal: DBNonTermKey,
+  items: I,
+  lookahead: SymbolId,
+) -> Option<Counterexample> {
+  let mut targets = Array::new();
+
+  for item in items {
+    if item.is_oos() {
+      return None;
+    }
+
+    let item = Item::from((item.index, db));
+
+    if !targets.contains(&item) {
+      targets.push(item);
+    }
+  }
+
+  if targets.len() < 2 {
+    return None;
+  }
+
+  let automaton = Automaton::new(db, goal)?;
+
+  // A completed item is the most constrained, as the lookahead must be able
+  // to follow it, so the prefix is chosen to suit it.
+  let primary = *targets.iter().find(|i| i.is_complete()).unwrap_or(&targets[0]);
+  let primary_lookahead = primary.is_complete().then_some(lookahead);
+
+  for state in (0..automaton.states.len()).filter(|s| targets.iter().all(|i| automaton.states[*s].contains(i))) {
+    let Some(path) = automaton.find_path(db, goal, state, primary, primary_lookahead) else {
+      continue;
+    };
+
+    let derivations = targets
+      .iter()
+      .map(|item| {
+        let item_lookahead = item.is_complete().then_some(lookahead);
+        let items = trace_item(db, goal, &path, path.len() - 1, *item, item_lookahead, &mut Set::new())
+          .or_else(|| trace_item(db, goal, &path, path.len() - 1, *item, None, &mut Set::new()))?;
+        Some(Derivation { items })
+      })
+      .collect::<Option<Array<_>>>();
+
+    if let Some(derivations) = derivations {
+      let prefix = path.iter().skip(1).map(|(sym, _)| *sym).collect();
+      return Some(Counterexample { prefix, lookahead, derivations });
+    }
+  }
+
+  None
+}
+
+/// The LR(0) automaton of a goal non-terminal.
+struct Automaton {
+  /// The closure of each state. The first state is the start state.
+  states:       Array<OrderedSet<Item>>,
+  /// The states, and the symbol, that transition into each state.
+  predecessors: Array<Array<(usize, SymbolId)>>,
+}
+
+impl Automaton {
+  /// Builds the automaton of `goal`, or returns `None` if it has more than
+  /// [MAX_SEARCH_STATES] states.
+  fn new(db: &ParserDatabase, goal: DBNonTermKey) -> Option<Self> {
+    let start = db.nonterm_rules(goal).ok()?.iter().map(|rule| Item::from((*rule, db))).collect::<OrderedSet<_>>();
+
+    let mut automaton = Self { states: vec![closure(&start, db)], predecessors: vec![vec![]] };
+    let mut kernels = OrderedMap::from_iter([(start, 0)]);
+    let mut queue = VecDeque::from_iter([0]);
+
+    while let Some(index) = queue.pop_front() {
+      let mut transitions = OrderedMap::<SymbolId, OrderedSet<Item>>::new();
+
+      for item in automaton.states[index].iter().filter(|i| !i.is_complete()) {
+        transitions.entry(item.sym_id(db)).or_default().insert(Item::from((item.try_increment().index, db)));
+      }
+
+      for (sym, kernel) in transitions {
+        let next = match kernels.get(&kernel) {
+          Some(next) => *next,
+          None if automaton.states.len() >= MAX_SEARCH_STATES => return None,
+          None => {
+            automaton.states.push(closure(&kernel, db));
+            automaton.predecessors.push(vec![]);
+            kernels.insert(kernel, automaton.states.len() - 1);
+            queue.push_back(automaton.states.len() - 1);
+            automaton.states.len() - 1
+          }
+        };
+
+        automaton.predecessors[next].push((index, sym));
+      }
+    }
+
+    Some(automaton)
+  }
+
+  /// Searches backwards from `item` in `state` for the shortest path from the
+  /// start state that derives `item`, and, if `lookahead` is set, on which
+  /// the lookahead can follow `item`. Returns the closures of the states
+  /// along the path, and the symbol that transitions into each of them.
+  fn find_path(
+    &self,
+    db: &ParserDatabase,
+    goal: DBNonTermKey,
+    state: usize,
+    item: Item,
+    lookahead: Option<SymbolId>,
+  ) -> Option<Array<(SymbolId, OrderedSet<Item>)>> {
+    // Each node is a state, an item within that state, the lookahead that must
+    // follow the item, the node it was reached from, and the symbol that was
+    // shifted to reach that node, if any.
+    let mut nodes = vec![(state, item, lookahead, usize::MAX, None)];
+    let mut seen = Set::from_iter([(state, item, lookahead)]);
+    let mut queue = VecDeque::from_iter([0]);
+
+    while let Some(index) = queue.pop_front() {
+      let (state, item, lookahead, ..) = nodes[index];
+
+      let mut next = vec![];
+
+      if item.sym_index() > 0 {
+        let prev = Item::from((item.decrement()?.index, db));
+        for (pred, sym) in &self.predecessors[state] {
+          if *sym == prev.sym_id(db) {
+            next.push((*pred, prev, lookahead, Some(*sym)));
+          }
+        }
+      } else if state == 0 && is_goal_item(item, goal, lookahead, db) {
+        let mut path = vec![(SymbolId::Default, self.states[0].clone())];
+        let mut current = index;
+
+        while current != usize::MAX {
+          let (_, _, _, parent, sym) = nodes[current];
+          if let Some(sym) = sym {
+            path.push((sym, self.states[nodes[parent].0].clone()));
+          }
+          current = parent;
+        }
+
+        return Some(path);
+      } else {
+        for parent in self.states[state].iter().filter(|i| i.nonterm_index_at_sym_parser(db) == Some(item.nonterm_index(db))) {
+          if let Some(lookahead) = parent_lookahead(*parent, lookahead, db) {
+            next.push((state, *parent, lookahead, None));
+          }
+        }
+      }
+
+      for (state, item, lookahead, sym) in next {
+        if seen.insert((state, item, lookahead)) {
+          nodes.push((state, item, lookahead, index, sym));
+          queue.push_back(nodes.len() - 1);
+        }
+      }
+    }
+
+    None
+  }
+}
+
+fn closure(kernel: &OrderedSet<Item>, db: &ParserDatabase) -> OrderedSet<Item> {
+  kernel.iter().flat_map(|i| i.closure_iter(db)).map(|i| Item::from((i.index, db))).collect()
+}
+
+/// Traces `item`, which is within the state at `depth` of the `path`, back to
+/// an initial item of the `goal` non-terminal.
+///
+/// If `lookahead` is set, only the items that can be followed by the lookahead
+/// symbol are considered when choosing the item that derives `item`.
+fn trace_item(
+  db: &ParserDatabase,
+  goal: DBNonTermKey,
+  path: &[(SymbolId, OrderedSet<Item>)],
+  depth: usize,
+  item: Item,
+  lookahead: Option<SymbolId>,
+  visited: &mut Set<(usize, Item, Option<SymbolId>)>,
+) -> Option<Array<Item>> {
+  let origin = depth.checked_sub(item.sym_index() as usize)?;
+
+  if !visited.insert((origin, item, lookahead)) {
+    return None;
+  }
+
+  if origin == 0 && is_goal_item(item, goal, lookahead, db) {
+    return Some(vec![item]);
+  }
+
+  for parent in path[origin].1.iter().filter(|i| i.nonterm_index_at_sym_parser(db) == Some(item.nonterm_index(db))) {
+    let Some(lookahead) = parent_lookahead(*parent, lookahead, db) else {
+      continue;
+    };
+
+    if let Some(mut items) = trace_item(db, goal, path, origin, *parent, lookahead, visited) {
+      items.push(item);
+      return Some(items);
+    }
+  }
+
+  None
+}
+
+/// Returns `true` if `item` is a rule of the goal non-terminal that can be
+/// followed by `lookahead`. Only the end of the input follows the goal.
+fn is_goal_item(item: Item, goal: DBNonTermKey, lookahead: Option<SymbolId>, db: &ParserDatabase) -> bool {
+  item.nonterm_index(db) == goal && lookahead.map_or(true, |l| l.is_default() || l == SymbolId::EndOfFile)
+}
+
+/// Returns the lookahead that must follow `parent` for the non-terminal it
+/// derives to be followed by `lookahead`, or `None` if the lookahead cannot
+/// follow the non-terminal within `parent`.
+fn parent_lookahead(parent: Item, lookahead: Option<SymbolId>, db: &ParserDatabase) -> Option<Option<SymbolId>> {
+  let next = parent.try_increment();
+  match lookahead {
+    Some(lookahead) if next.is_complete() => Some(Some(lookahead)),
+    Some(lookahead) if !first_contains(next, lookahead, db) => None,
+    _ => Some(None),
+  }
+}
+
+/// Returns `true` if `lookahead` can be the first terminal shifted from `item`.
+fn first_contains(item: Item, lookahead: SymbolId, db: &ParserDatabase) -> bool {
+  let Some(lookahead) = lookahead.tok_db_key() else {
+    return false;
+  };
+
+  item.closure_iter(db).any(|i| i.term_index_at_sym(GraphType::Parser, db) == Some(lookahead))
+}
+
+pub(crate) fn symbol_string(sym: SymbolId, db: &ParserDatabase) -> String {
+  match sym {
+    SymbolId::DBToken { key } => match db.sym(key) {
+      SymbolId::Token { val } => "\"".to_string() + &val.to_string(db.string_store()) + "\"",
+      sym => sym.debug_string(db).trim().to_string(),
+    },
+    sym => sym.debug_string(db).trim().to_string(),
+  }
+}
+
+fn lookahead_string(sym: SymbolId, db: &ParserDatabase) -> String {
+  if sym.is_default() || sym == SymbolId::EndOfFile {
+    "end of input".into()
+  } else {
+    symbol_string(sym, db)
+  }
+}
@@ -0,0 +1,250 @@
+//! Human readable and JSON dumps of the FIRST and FOLLOW sets of a grammar,
+//! and of the item sets of the nodes of its parse graphs.
+
+use super::{
+  build_graph::graph::{ConcurrentGraphBuilder, GraphNode, GraphType, Graphs, SharedGraphNode},
+  counterexample::symbol_string,
+};
+use crate::types::*;
+use std::collections::HashSet;
+
+impl ParserDatabase {
+  /// Renders the FIRST and FOLLOW sets of every parser non-terminal.
+  pub fn first_follow_string(&self) -> String {
+    let mut string = String::new();
+
+    for (nonterm, nullable, first, follow) in self.first_follow_rows() {
+      string += &self.nonterm_friendly_name_string(nonterm);
+      string += if nullable { " (nullable)\n" } else { "\n" };
+      string += &("  FIRST  : { ".to_string() + &first.join(" ") + " }\n");
+      string += &("  FOLLOW : { ".to_string() + &follow.join(" ") + " }\n\n");
+    }
+
+    string
+  }
+
+  /// Renders the FIRST and FOLLOW sets of every parser non-terminal as a JSON
+  /// array of `{ name, nullable, first, follow }` objects.
+  pub fn first_follow_json(&self) -> String {
+    let rows = self
+      .first_follow_rows()
+      .into_iter()
+      .map(|(nonterm, nullable, first, follow)| {
+        format!(
+          "{{\"name\":{},\"nullable\":{nullable},\"first\":{},\"follow\":{}}}",
+          json_string(&self.nonterm_friendly_name_string(nonterm)),
+          json_array(first.iter().map(|s| json_string(s))),
+          json_array(follow.iter().map(|s| json_string(s))),
+        )
+      })
+      .collect::<Array<_>>();
+
+    "[".to_string() + &rows.join(",") + "]"
+  }
+
+  fn first_follow_rows(&self) -> Array<(DBNonTermKey, bool, Array<String>, Array<String>)> {
+    let nullable = self.nullable_nonterms();
+    let first = self.first_sets();
+    let follow = self.follow_sets();
+
+    let term_names = |set: &OrderedSet<DBTermKey>| {
+      set
+        .iter()
+        .map(|tok| if *tok == DBTermKey::default_sym() { "{EOF}".to_string() } else { symbol_name(SymbolId::DBToken { key: *tok }, self) })
+        .collect::<Array<_>>()
+    };
+
+    self
+      .parser_nonterms()
+      .into_iter()
+      .map(|nonterm| {
+        let index: usize = nonterm.into();
+        (nonterm, nullable[index], term_names(&first[index]), term_names(&follow[index]))
+      })
+      .collect()
+  }
+}
+
+impl Graphs {
+  /// Renders the kernel and closure items of every node of the parser and
+  /// scanner graphs.
+  pub fn item_sets_string(&self, db: &ParserDatabase) -> String {
+    item_sets_string(&self.successors, db)
+  }
+
+  /// Renders the kernel and closure items of every node of the parser and
+  /// scanner graphs as a JSON array.
+  pub fn item_sets_json(&self, db: &ParserDatabase) -> String {
+    item_sets_json(&self.successors, db)
+  }
+}
+
+#[allow(unused)]
+impl ConcurrentGraphBuilder {
+  /// Renders the kernel and closure items of every node committed to the
+  /// graph so far.
+  pub fn item_sets_string(&self) -> String {
+    match self.graph.read() {
+      Ok(graph) => item_sets_string(&graph, self.db()),
+      Err(err) => panic!("{err}"),
+    }
+  }
+
+  /// Renders the kernel and closure items of every node committed to the
+  /// graph so far as a JSON array.
+  pub fn item_sets_json(&self) -> String {
+    match self.graph.read() {
+      Ok(graph) => item_sets_json(&graph, self.db()),
+      Err(err) => panic!("{err}"),
+    }
+  }
+}
+
+/// The items and edges of a graph node.
+struct NodeItemSet<'a> {
+  node:       &'a GraphNode,
+  kernel:     Array<String>,
+  closure:    Array<String>,
+  successors: Array<u64>,
+}
+
+fn item_sets_string(graph: &Map<SharedGraphNode, HashSet<SharedGraphNode>>, db: &ParserDatabase) -> String {
+  let mut string = String::new();
+
+  for NodeItemSet { node, kernel, closure, successors } in collect_item_sets(graph, db) {
+    string += &format!(
+      "== {:?} {} {:?} [{:016X}]{}\n",
+      node.graph_type,
+      node.id.index(),
+      node.id.subtype(),
+      node.hash_id,
+      if node.get_root().invalid.load(std::sync::atomic::Ordering::Relaxed) { " !!POISONED!!" } else { "" }
+    );
+    string += &format!("  root       : {}\n", db.nonterm_friendly_name_string(node.get_root().root_data.db_key));
+    string += &format!("  type       : {:?}\n", node.ty);
+    string += &format!("  symbol     : {}\n", symbol_name(node.sym.sym(), db));
+    string += "  kernel     :\n";
+    for item in &kernel {
+      string += &("    ".to_string() + item + "\n");
+    }
+    string += "  closure    :\n";
+    for item in &closure {
+      string += &("    ".to_string() + item + "\n");
+    }
+    string += &format!(
+      "  successors : [{}]\n\n",
+      successors.iter().map(|s| format!("{s:016X}")).collect::<Array<_>>().join(", ")
+    );
+  }
+
+  string
+}
+
+fn item_sets_json(graph: &Map<SharedGraphNode, HashSet<SharedGraphNode>>, db: &ParserDatabase) -> String {
+  let nodes = collect_item_sets(graph, db)
+    .into_iter()
+    .map(|NodeItemSet { node, kernel, closure, successors }| {
+      format!(
+        concat!(
+          "{{\"hash\":\"{:016X}\",\"graph\":\"{:?}\",\"id\":{},\"subtype\":\"{:?}\",\"root\":{},\"type\":{},",
+          "\"symbol\":{},\"invalid\":{},\"kernel\":{},\"closure\":{},\"successors\":{}}}"
+        ),
+        node.hash_id,
+        node.graph_type,
+        node.id.index(),
+        node.id.subtype(),
+        json_string(&db.nonterm_friendly_name_string(node.get_root().root_data.db_key)),
+        json_string(&format!("{:?}", node.ty)),
+        json_string(&symbol_name(node.sym.sym(), db)),
+        node.get_root().invalid.load(std::sync::atomic::Ordering::Relaxed),
+        json_array(kernel.iter().map(|i| json_string(i))),
+        json_array(closure.iter().map(|i| json_string(i))),
+        json_array(successors.iter().map(|s| format!("\"{s:016X}\""))),
+      )
+    })
+    .collect::<Array<_>>();
+
+  "[".to_string() + &nodes.join(",") + "]"
+}
+
+/// Collects every node of the graph, including leaf nodes that only appear as
+/// successors, ordered by graph type and state index.
+fn collect_item_sets<'a>(
+  graph: &'a Map<SharedGraphNode, HashSet<SharedGraphNode>>,
+  db: &ParserDatabase,
+) -> Array<NodeItemSet<'a>> {
+  let mut nodes = OrderedMap::<(GraphType, usize, u64), (&'a GraphNode, Option<&'a HashSet<SharedGraphNode>>)>::new();
+
+  for (node, successors) in graph {
+    nodes.insert((node.graph_type, node.id.index(), node.hash_id), (node.as_ref(), Some(successors)));
+    for node in successors {
+      nodes.entry((node.graph_type, node.id.index(), node.hash_id)).or_insert((node.as_ref(), None));
+    }
+  }
+
+  nodes
+    .into_values()
+    .map(|(node, successors)| {
+      let kernel = node.kernel.iter().map(|i| i.to_canonical()).collect::<OrderedSet<_>>();
+      let mut closure = OrderedSet::new();
+      for item in node.kernel.iter().filter(|i| !i.is_complete()) {
+        closure.extend(db.get_closure(item).map(|i| i.to_canonical()).filter(|i| !kernel.contains(i)));
+      }
+
+      let mut successors = successors.into_iter().flatten().map(|s| s.hash_id).collect::<Array<_>>();
+      successors.sort();
+
+      NodeItemSet {
+        node,
+        kernel: kernel.into_iter().map(|i| item_string(i, db)).collect(),
+        closure: closure.into_iter().map(|i| item_string(i, db)).collect(),
+        successors,
+      }
+    })
+    .collect()
+}
+
+/// Renders an item as `A > b • C d`.
+pub(crate) fn item_string(item: Item, db: &ParserDatabase) -> String {
+  let symbols = &item.rule(db).symbols;
+  let split = item.sym_index() as usize;
+
+  let mut parts = vec![db.nonterm_friendly_name_string(item.nonterm_index(db)), ">".to_string()];
+  parts.extend(symbols[..split].iter().map(|s| symbol_name(s.id, db)));
+  parts.push("•".to_string());
+  parts.extend(symbols[split..].iter().map(|s| symbol_name(s.id, db)));
+  parts.join(" ")
+}
+
+/// Renders a symbol, naming terminals defined by token non-terminals after
+/// their non-terminal rather than as `tk:nonterm`.
+pub(crate) fn symbol_name(sym: SymbolId, db: &ParserDatabase) -> String {
+  match sym {
+    SymbolId::DBToken { key } if !matches!(db.sym(key), SymbolId::Token { .. }) => {
+      db.token(key).name.to_string(db.string_store())
+    }
+    sym => symbol_string(sym, db),
+  }
+}
+
+fn json_array(values: impl Iterator<Item = String>) -> String {
+  "[".to_string() + &values.collect::<Array<_>>().join(",") + "]"
+}
+
+fn json_string(value: &str) -> String {
+  let mut string = String::with_capacity(value.len() + 2);
+  string.push('"');
+  for char in value.chars() {
+    match char {
+      '"' => string += "\\\"",
+      '\\' => string += "\\\\",
+      '\n' => string += "\\n",
+      '\r' => string += "\\r",
+      '\t' => string += "\\t",
+      char if (char as u32) < 0x20 => string += &format!("\\u{:04x}", char as u32),
+      char => string.push(char),
+    }
+  }
+  string.push('"');
+  string
+}
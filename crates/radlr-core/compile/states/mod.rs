@@ -1,4 +1,6 @@
 pub(crate) mod build_graph;
 pub(crate) mod build_states;
+pub(crate) mod counterexample;
+pub(crate) mod dump;
 #[cfg(test)]
 mod test;
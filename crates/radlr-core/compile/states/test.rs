@@ -30,7 +30,7 @@ fn build_json_graph() -> RadlrResult<()> {
   #[cfg(feature = "wasm-target")]
   let pool = crate::types::worker_pool::SingleThreadPool {};
 
-  let graph = compile_parser_states(db.clone(), config, &pool)?;
+  let graph = compile_parser_states(db.clone(), config, None, &pool)?;
 
   let mut ir = build_ir_concurrent(&pool, graph.clone(), config, &db)?;
 
@@ -39,7 +39,7 @@ fn build_json_graph() -> RadlrResult<()> {
     // println!("{}", ir.print(&db, true)?);
   }
 
-  let ir: (Vec<_>, _) = optimize(&db, &config, ir.1, false, &pool)?;
+  let ir: (Vec<_>, _) = optimize(&db, &config, &Default::default(), ir.1, false, &pool)?;
 
   println!("{}", ir.1.to_string());
 
@@ -107,7 +107,7 @@ pub fn peek_hybrid_graph() -> RadlrResult<()> {
   #[cfg(feature = "wasm-target")]
   let pool = crate::types::worker_pool::SingleThreadPool {};
 
-  let graph = compile_parser_states(db.clone(), config, &pool)?;
+  let graph = compile_parser_states(db.clone(), config, None, &pool)?;
 
   let mut ir = build_ir_concurrent(&pool, graph.clone(), config, &db)?;
 
@@ -117,7 +117,7 @@ pub fn peek_hybrid_graph() -> RadlrResult<()> {
     println!("{}", ir.print(&db, true)?);
   }
 
-  let ir: (Vec<_>, _) = optimize(&db, &config, ir.1, false, &pool)?;
+  let ir: (Vec<_>, _) = optimize(&db, &config, &Default::default(), ir.1, false, &pool)?;
 
   println!("{}", ir.1.to_string());
 
@@ -0,0 +1,333 @@
+//! Static analysis of a grammar, reporting constructs that are likely to be
+//! mistakes, or that restrict the kinds of parsers that can be built from the
+//! grammar, as warnings.
+
+use crate::{
+  types::{
+    error_types::{
+      create_nullable_nonterminal_warning,
+      create_recursion_cycle_warning,
+      create_unproductive_rule_warning,
+      create_unreachable_nonterminal_warning,
+      create_unused_ignore_symbol_warning,
+      create_unused_precedence_token_warning,
+    },
+    *,
+  },
+  o_to_r,
+};
+
+/// Analyzes the grammar of a database, returning a warning for every
+/// unreachable non-terminal, unproductive rule, nullable non-terminal, left or
+/// right recursion cycle, unused token, and unused `IGNORE` symbol.
+///
+/// A token declared in a precedence clause is unused if no reachable parser
+/// rule shifts it, and an `IGNORE` symbol is unused if no reachable parser rule
+/// of its grammar skips it. Both are checked for the root grammar and every
+/// grammar it imports.
+///
+/// Reachability is determined from the source grammars in `gs`, since
+/// unreachable non-terminals are never added to the database. All other
+/// properties are determined from the rules of the database.
+pub(crate) fn analyze_grammar(db: &ParserDatabase, gs: &GrammarSoup) -> RadlrResult<Array<RadlrError>> {
+  let s_store = db.string_store();
+  let grammar_headers = gs.grammar_headers.read()?;
+  let nonterminals = gs.nonterminals.read()?;
+  let custom_states = gs.custom_states.read()?;
+
+  let root = o_to_r(grammar_headers.get(&db.root_grammar_id.guid), "Could not find the root grammar of the database")?;
+
+  let mut nterm_map = OrderedMap::<NonTermId, Array<&NonTerminal>>::new();
+  for nterm in nonterminals.iter() {
+    nterm_map.entry(nterm.id).or_default().push(nterm);
+  }
+
+  let mut warnings = Array::new();
+
+  // Unreachable non-terminals -------------------------------------------------
+  let (parser_nterms, token_nterms) = reachable_nonterminals(root, &nterm_map, &custom_states);
+
+  for (id, nterms) in &nterm_map {
+    let nterm = nterms[0];
+    if nterm.g_id == root.identity.guid && !parser_nterms.contains(id) && !token_nterms.contains(id) {
+      warnings.push(create_unreachable_nonterminal_warning(
+        nterm.tok.clone(),
+        root.identity.path,
+        &nterm.friendly_name.to_string(s_store),
+        s_store,
+      ));
+    }
+  }
+
+  // Unproductive rules and nullable non-terminals -----------------------------
+  let custom_state_nterms = (0..db.nonterms_len()).map(|i| db.custom_state(i.into()).is_some()).collect::<Array<_>>();
+
+  let productive = rule_fixpoint(db, custom_state_nterms, |sym, productive| match sym {
+    SymbolId::DBNonTerminal { key } => productive[Into::<usize>::into(*key)],
+    _ => true,
+  });
+
+  let nullable = db.nullable_nonterms();
+
+  // Scanner non-terminals share the rules, and the source locations, of the
+  // non-terminals they are derived from.
+  let mut reported = Set::new();
+
+  for db_rule in db.rules() {
+    let DBRule { rule, nonterm, .. } = db_rule;
+
+    let is_productive = rule.symbols.iter().all(|s| match s.id {
+      SymbolId::DBNonTerminal { key } => productive[Into::<usize>::into(key)],
+      _ => true,
+    });
+
+    if !is_productive && reported.insert((rule.g_id.path, rule.tok.clone(), 0)) {
+      warnings.push(create_unproductive_rule_warning(rule, &db.nonterm_friendly_name_string(*nonterm), s_store));
+    }
+  }
+
+  for (index, is_nullable) in nullable.iter().enumerate() {
+    let nonterm = DBNonTermKey::from(index);
+    if *is_nullable {
+      let nullable_rule = db.nonterm_rules(nonterm)?.iter().map(|r| db.rule(*r)).find(|rule| {
+        rule.symbols.iter().all(|s| matches!(s.id, SymbolId::DBNonTerminal { key } if nullable[Into::<usize>::into(key)]))
+      });
+
+      if let Some(rule) = nullable_rule {
+        if reported.insert((rule.g_id.path, rule.tok.clone(), 1)) {
+          warnings.push(create_nullable_nonterminal_warning(rule, &db.nonterm_friendly_name_string(nonterm), s_store));
+        }
+      }
+    }
+  }
+
+  // Recursion cycles ---------------------------------------------------------
+  for left in [true, false] {
+    for cycle in recursion_cycles(db, &nullable, left) {
+      let cycle_names = cycle
+        .iter()
+        .chain(cycle.first())
+        .map(|(nonterm, _)| format!("`{}`", db.nonterm_friendly_name_string(*nonterm)))
+        .collect::<Array<_>>();
+
+      let position = if left { "start" } else { "end" };
+
+      let sources = cycle
+        .iter()
+        .zip(cycle_names.iter().skip(1))
+        .map(|((_, rule), next)| {
+          let rule = db.rule(*rule);
+          (rule.tok.clone(), rule.g_id.path.to_path(s_store), format!("derives {next} at the {position} of this rule"))
+        })
+        .collect();
+
+      warnings.push(create_recursion_cycle_warning(sources, left, &cycle_names.join(" > ")));
+    }
+  }
+
+  // Unused tokens and IGNORE symbols ----------------------------------------
+  // The database only contains the rules of reachable non-terminals, and the
+  // rules of a grammar skip the symbols of that grammar's `IGNORE` clauses.
+  let parser_rules = db.rules().iter().filter(|r| !r.is_scanner);
+
+  let used_syms = parser_rules
+    .clone()
+    .flat_map(|r| r.rule.symbols.iter())
+    .filter_map(|s| s.id.tok_db_key().map(|tok| db.sym(tok)))
+    .collect::<Set<_>>();
+
+  let skipped_syms = parser_rules
+    .flat_map(|r| r.rule.skipped.iter().map(|s| (r.rule.g_id.guid, s)))
+    .filter_map(|(g_id, s)| s.tok_db_key().map(|tok| (g_id, db.sym(tok))))
+    .collect::<Set<_>>();
+
+  let mut grammars = Queue::from_iter([root.identity.guid]);
+  let mut seen_grammars = Set::new();
+
+  while let Some(g_id) = grammars.pop_front() {
+    if !seen_grammars.insert(g_id) {
+      continue;
+    }
+
+    let Some(header) = grammar_headers.get(&g_id) else { continue };
+
+    grammars.extend(header.imports.iter().cloned());
+
+    for (_, syms, tok) in header.precedence.iter() {
+      for sym in syms.iter().filter(|sym| !used_syms.contains(sym)) {
+        let name = match sym {
+          SymbolId::Token { val } => format!("\"{}\"", val.to_string(s_store)),
+          sym => sym.debug_string(db),
+        };
+        warnings.push(create_unused_precedence_token_warning(tok.clone(), header.identity.path, &name, s_store));
+      }
+    }
+
+    for (sym, tok) in header.ignored.iter() {
+      if !skipped_syms.contains(&(g_id, *sym)) {
+        warnings.push(create_unused_ignore_symbol_warning(tok.clone(), header.identity.path, s_store));
+      }
+    }
+  }
+
+  Ok(warnings)
+}
+
+/// Returns the non-terminals reachable from the entry points of the root
+/// grammar as parser non-terminals, and those that are only reachable
+/// through `tk:` token non-terminals.
+fn reachable_nonterminals(
+  root: &GrammarHeader,
+  nterm_map: &OrderedMap<NonTermId, Array<&NonTerminal>>,
+  custom_states: &Map<NonTermId, Box<CustomState>>,
+) -> (OrderedSet<NonTermId>, OrderedSet<NonTermId>) {
+  let mut parser_nterms = OrderedSet::new();
+  let mut token_nterms = OrderedSet::new();
+  let mut queue = Queue::from_iter(root.pub_nterms.values().map(|(id, _)| (base_nonterm(*id), false)));
+
+  while let Some((id, is_token)) = queue.pop_front() {
+    if !if is_token { token_nterms.insert(id) } else { parser_nterms.insert(id) } {
+      continue;
+    }
+
+    if let Some(state) = custom_states.get(&id) {
+      queue.extend(state.nterm_refs.iter().map(|(_, _, id)| (base_nonterm(*id), is_token)));
+    }
+
+    for nterm in nterm_map.get(&id).into_iter().flatten() {
+      let rules = nterm.rules.iter().chain(nterm.sub_nterms.iter().flat_map(|s| s.rules.iter()));
+      for sym in rules.flat_map(|r| r.symbols.iter()) {
+        match sym.id {
+          SymbolId::NonTerminal { id } | SymbolId::NonTerminalState { id } => queue.push_back((base_nonterm(id), is_token)),
+          SymbolId::NonTerminalToken { id } => queue.push_back((base_nonterm(id), true)),
+          _ => {}
+        }
+      }
+    }
+  }
+
+  for id in &parser_nterms {
+    token_nterms.remove(id);
+  }
+
+  (parser_nterms, token_nterms)
+}
+
+/// Returns the id of the standard parser non-terminal that defines `id`.
+fn base_nonterm(id: NonTermId) -> NonTermId {
+  match id {
+    NonTermId::Standard(id, _) | NonTermId::Sub(id, ..) => NonTermId::Standard(id, NonTermSubType::Parser),
+  }
+}
+
+/// Starting from `init`, marks every non-terminal that has a rule whose symbols
+/// all satisfy `pred`, until no more non-terminals can be marked.
+fn rule_fixpoint(db: &ParserDatabase, init: Array<bool>, pred: impl Fn(&SymbolId, &[bool]) -> bool) -> Array<bool> {
+  let mut marked = init;
+  let mut changed = true;
+
+  while changed {
+    changed = false;
+    for DBRule { rule, nonterm, .. } in db.rules() {
+      let index: usize = (*nonterm).into();
+      if !marked[index] && rule.symbols.iter().all(|s| pred(&s.id, &marked)) {
+        marked[index] = true;
+        changed = true;
+      }
+    }
+  }
+
+  marked
+}
+
+/// Returns the distinct cycles of parser non-terminals that derive themselves
+/// at the start (`left`) or end of their rules, skipping over nullable
+/// symbols. Each member of a cycle is paired with the rule that derives the
+/// next member.
+///
+/// Cycles made up entirely of the non-terminals generated for lists and
+/// groups are not reported, since they are the expected expansion of
+/// `sym(+)` symbols.
+fn recursion_cycles(db: &ParserDatabase, nullable: &[bool], left: bool) -> Array<Array<(DBNonTermKey, DBRuleKey)>> {
+  let nonterms_len = db.nonterms_len();
+  let mut edges = vec![Array::<(DBNonTermKey, DBRuleKey)>::new(); nonterms_len];
+
+  for (index, DBRule { rule, nonterm, is_scanner }) in db.rules().iter().enumerate() {
+    if *is_scanner {
+      continue;
+    }
+
+    let symbols: Box<dyn Iterator<Item = &SymbolRef>> =
+      if left { Box::new(rule.symbols.iter()) } else { Box::new(rule.symbols.iter().rev()) };
+
+    for sym in symbols {
+      let SymbolId::DBNonTerminal { key } = sym.id else { break };
+      let successors = &mut edges[Into::<usize>::into(*nonterm)];
+      if !successors.iter().any(|(n, _)| *n == key) {
+        successors.push((key, DBRuleKey::from(index)));
+      }
+      if !nullable[Into::<usize>::into(key)] {
+        break;
+      }
+    }
+  }
+
+  let is_generated = |key: DBNonTermKey| {
+    matches!(db.nonterm_symbols()[Into::<usize>::into(key)], SymbolId::NonTerminal { id: NonTermId::Sub(..) })
+  };
+
+  let mut seen = Set::new();
+  let mut cycles = Array::new();
+
+  for start in (0..nonterms_len).map(DBNonTermKey::from) {
+    // Breadth first search for the shortest path leading back to `start`.
+    let mut parents = OrderedMap::<DBNonTermKey, (DBNonTermKey, DBRuleKey)>::new();
+    let mut queue = Queue::from_iter([start]);
+    let mut found = false;
+
+    'search: while let Some(nonterm) = queue.pop_front() {
+      for (successor, rule) in &edges[Into::<usize>::into(nonterm)] {
+        if *successor == start {
+          parents.insert(start, (nonterm, *rule));
+          found = true;
+          break 'search;
+        }
+        if !parents.contains_key(successor) {
+          parents.insert(*successor, (nonterm, *rule));
+          queue.push_back(*successor);
+        }
+      }
+    }
+
+    if !found {
+      continue;
+    }
+
+    let mut cycle = Array::new();
+    let mut nonterm = start;
+    loop {
+      let (prev, rule) = parents[&nonterm];
+      cycle.push((prev, rule));
+      nonterm = prev;
+      if nonterm == start {
+        break;
+      }
+    }
+    cycle.reverse();
+
+    // Rotate the cycle to begin at its lowest key, so that each cycle is only
+    // reported once.
+    let min_index = cycle.iter().enumerate().min_by_key(|(_, (n, _))| *n).map(|(i, _)| i).unwrap_or_default();
+    cycle.rotate_left(min_index);
+
+    if cycle.iter().all(|(n, _)| is_generated(*n)) {
+      continue;
+    }
+
+    if seen.insert(cycle.iter().map(|(n, _)| *n).collect::<Array<_>>()) {
+      cycles.push(cycle);
+    }
+  }
+
+  cycles
+}
@@ -107,6 +107,9 @@ pub(crate) fn build_compile_db<'a>(
   let mut c_states_owned = Array::new();
   let c_states = &mut c_states_owned;
 
+  // Non-terminals whose rules are ordered choices
+  let mut peg_nonterms = OrderedSet::new();
+
   // Keeps track of the names of token_nonterminals.
   let mut token_names = OrderedMap::new();
   let mut token_nonterminals: VecDeque<TrackedNonterm> = VecDeque::new();
@@ -128,6 +131,10 @@ pub(crate) fn build_compile_db<'a>(
             false,
           );
 
+          if nterm.type_ == NonTermType::Peg {
+            peg_nonterms.insert(DBNonTermKey::from(p_map[&nterm_id.as_sym()]));
+          }
+
           add_nterm_name(nterm_name_lu, g_name, f_name);
           add_empty_custom_state(c_states);
 
@@ -429,6 +436,16 @@ pub(crate) fn build_compile_db<'a>(
     )
   }));
 
+  // Precedence levels of the root grammar, numbered from 1 so that higher levels
+  // bind tighter. Tokens that are not used by any rule are ignored.
+  let operator_precedence = root_grammar
+    .precedence
+    .iter()
+    .enumerate()
+    .flat_map(|(level, (assoc, syms, _))| syms.iter().map(move |sym| (sym, (level as u16 + 1, *assoc))))
+    .filter_map(|(sym, prec)| symbols.get(sym).map(|(_, index)| (DBTermKey::from(*index as u32), prec)))
+    .collect::<OrderedMap<_, _>>();
+
   // Convert convert GUID symbol ids to local indices. ------------------------
   convert_rule_symbol_ids(r_table, p_map, symbols);
 
@@ -494,6 +511,8 @@ pub(crate) fn build_compile_db<'a>(
     entry_points,
     s_store.clone(),
     c_states_owned,
+    peg_nonterms,
+    operator_precedence,
     errors.is_empty(),
   );
 
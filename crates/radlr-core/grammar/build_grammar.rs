@@ -6,7 +6,7 @@ use super::{
   utils::{get_symbol_details, SymbolData},
 };
 use crate::{
-  grammar::utils::resolve_grammar_path,
+  grammar::utils::resolve_import_path,
   parser::{ast::escaped_from, NonTerminal_Symbol},
   types::error_types::{create_empty_rule_error, create_invalid_import_source_error},
   utils::create_u64_hash,
@@ -58,15 +58,40 @@ pub struct GrammarData {
   pub exports:        Array<(IString, (NonTermId, Token))>,
   pub global_skipped: Array<ASTNode>,
   pub grammar:        Box<Grammar>,
+  /// Errors of `IMPORT` statements whose sources could not be found.
+  pub import_errors:  Array<(GrammarId, RadlrError)>,
+  /// Operator precedence levels, from the lowest to the highest precedence.
+  pub precedence:     Array<(Associativity, Array<SymbolId>, Token)>,
 }
 
-pub fn convert_grammar_data_to_header(import_id: GrammarIdentities, g_data: GrammarData) -> Box<GrammarHeader> {
+impl GrammarData {
+  /// Returns the identities of the imported grammars that should be loaded,
+  /// and the errors of the imports that could not be resolved.
+  pub fn imports_to_load(&self) -> (Vec<GrammarIdentities>, Vec<RadlrError>) {
+    let imports =
+      self.imports.values().filter(|id| !self.import_errors.iter().any(|(guid, _)| *guid == id.guid)).cloned().collect();
+    (imports, self.import_errors.iter().map(|(_, err)| err.clone()).collect())
+  }
+}
+
+pub fn convert_grammar_data_to_header(
+  import_id: GrammarIdentities,
+  g_data: GrammarData,
+  s_store: &IStringStore,
+) -> Box<GrammarHeader> {
   let mut identity = import_id;
   identity.guid_name = g_data.id.guid_name;
+  let ignored = g_data
+    .global_skipped
+    .iter()
+    .filter_map(|sym_node| get_symbol_id(sym_node, &g_data, s_store).ok().map(|sym| (sym, sym_node.to_token())))
+    .collect();
   Box::new(GrammarHeader {
     identity,
     pub_nterms: g_data.exports.into_iter().collect(),
     imports: g_data.imports.values().map(|v| v.guid).collect(),
+    precedence: g_data.precedence,
+    ignored,
   })
 }
 
@@ -80,6 +105,7 @@ pub fn create_grammar_data(
   grammar: Box<Grammar>,
   grammar_path: &PathBuf,
   string_store: &IStringStore,
+  include_paths: &[PathBuf],
 ) -> RadlrResult<GrammarData> {
   const EXTENSIONS: [&str; 3] = ["sg", "radlr", "hcg"];
 
@@ -88,6 +114,7 @@ pub fn create_grammar_data(
   let mut imports = Map::default();
   let mut exports = Array::default();
   let mut skipped = Array::default();
+  let mut import_errors = Array::default();
   let mut name = grammar_path.file_stem().and_then(|d| d.to_str()).unwrap_or("default");
 
   for preamble in &grammar.preamble {
@@ -96,13 +123,14 @@ pub fn create_grammar_data(
         let parser::Import { reference, uri, .. } = import.as_ref();
         let path = PathBuf::from(uri);
 
-        match resolve_grammar_path(&path, &source_dir, &EXTENSIONS) {
-          RadlrResult::Ok(path) => {
+        match resolve_import_path(&path, &source_dir, include_paths, &EXTENSIONS) {
+          Ok(path) => {
             imports.insert(reference.intern(string_store), GrammarIdentities::from_path(&path, string_store));
           }
-          _ => {
-            create_invalid_import_source_error(import, &path, &source_dir);
-            imports.insert(reference.intern(string_store), GrammarIdentities::from_path(&path, string_store));
+          Err(tried_paths) => {
+            let id = GrammarIdentities::from_path(&path, string_store);
+            import_errors.push((id.guid, create_invalid_import_source_error(import, grammar_path, &tried_paths)));
+            imports.insert(reference.intern(string_store), id);
           }
         }
       }
@@ -141,7 +169,9 @@ pub fn create_grammar_data(
     global_skipped: skipped,
     grammar,
     imports,
+    import_errors,
     exports: Default::default(),
+    precedence: Default::default(),
   };
 
   // Once we have g_data, and more importantly g_data.imports, we can convert
@@ -890,8 +920,23 @@ fn record_symbol(
   g_data: &GrammarData,
   s_store: &IStringStore,
 ) -> RadlrResult<SymbolId> {
+  let id = get_symbol_id(sym_node, g_data, s_store)?;
+
+  // Bypass the registration of non-terminals as symbols.
+  if !matches!(
+    sym_node,
+    ASTNode::NonTerminal_Symbol(_) | ASTNode::NonTerminal_Import_Symbol(_) | ASTNode::NonTerminal_Terminal_Symbol(_)
+  ) {
+    n_data.symbols.insert(id);
+  }
+
+  RadlrResult::Ok(id)
+}
+
+/// Converts a Symbol AST node into a symbol object.
+fn get_symbol_id(sym_node: &ASTNode, g_data: &GrammarData, s_store: &IStringStore) -> RadlrResult<SymbolId> {
   let id = match sym_node {
-    ASTNode::AnnotatedSymbol(annotated) => record_symbol(&annotated.symbol, n_data, g_data, s_store)?,
+    ASTNode::AnnotatedSymbol(annotated) => get_symbol_id(&annotated.symbol, g_data, s_store)?,
 
     ASTNode::TerminalToken(terminal) => {
       let string = escaped_from((&terminal.val).into())?.join("");
@@ -922,15 +967,11 @@ fn record_symbol(
     },
 
     ASTNode::NonTerminal_Symbol(_) | ASTNode::NonTerminal_Import_Symbol(_) => {
-      let id = get_nonterminal_id_from_ast_node(g_data, sym_node, s_store)?.as_sym();
-      // Bypass the registration of this nonterminal as a symbol.
-      return RadlrResult::Ok(id);
+      get_nonterminal_id_from_ast_node(g_data, sym_node, s_store)?.as_sym()
     }
 
     ASTNode::NonTerminal_Terminal_Symbol(token_prod) => {
-      let id = get_nonterminal_id_from_ast_node(g_data, &token_prod.nonterminal, s_store)?.as_tok_sym();
-      // Bypass the registration of this nonterminal as a symbol.
-      return RadlrResult::Ok(id);
+      get_nonterminal_id_from_ast_node(g_data, &token_prod.nonterminal, s_store)?.as_tok_sym()
     }
 
     _ => {
@@ -943,8 +984,6 @@ fn record_symbol(
     }
   };
 
-  n_data.symbols.insert(id);
-
   RadlrResult::Ok(id)
 }
 
@@ -1110,7 +1149,7 @@ mod test {
   fn extract_nonterminals() -> RadlrResult<()> {
     let (g, path, s_store) = create_test_data(r##"  <> test-sweet-home-alabama > c:id{3} | ("test"{2} "2" :ast $1 ) :ast $1 "##)?;
 
-    let g_data = super::create_grammar_data(g, &path, &s_store)?;
+    let g_data = super::create_grammar_data(g, &path, &s_store, &[])?;
 
     let (mut nterms, templates, ..) = super::extract_nonterminals(&g_data, &s_store)?;
 
@@ -1138,7 +1177,7 @@ mod test {
        "##,
     )?;
 
-    let g_data = super::create_grammar_data(g, &path, &s_store)?;
+    let g_data = super::create_grammar_data(g, &path, &s_store, &[])?;
 
     let (nonterminals, _, mut parse_states) = super::extract_nonterminals(&g_data, &s_store)?;
 
@@ -1,11 +1,142 @@
 use radlr_rust_runtime::types::Token;
 
+use super::build_grammar::GrammarData;
 use crate::{
-  parser::{self, radlr_bc_ast},
+  parser::{
+    self,
+    ast::escaped_from,
+    radlr_bc_ast::{self, ignore_clause_list_Value, nonterminal_Value, preamble_Value},
+  },
+  types::{error_types::create_invalid_precedence_clause_error, *},
   RadlrResult,
 };
+use std::{borrow::Cow, path::PathBuf, sync::Arc};
 
 /// Parse grammar string and create a Grammar AST.
 pub fn parse_grammar(string_data: &str) -> RadlrResult<Box<radlr_bc_ast::Grammar<Token>>> {
   parser::parse_grammar(string_data)
 }
+
+/// Returns the `LEFT`, `RIGHT`, and `NONASSOC` clauses of a grammar's
+/// preamble, in the order they were declared.
+pub fn precedence_clauses(grammar: &radlr_bc_ast::Grammar<Token>) -> Vec<&radlr_bc_ast::PrecedenceClause<Token>> {
+  grammar
+    .preamble
+    .iter()
+    .filter_map(|preamble| match preamble {
+      preamble_Value::PrecedenceClause(clause) => Some(clause.as_ref()),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Replaces the precedence clauses of `source` with whitespace, keeping the
+/// offsets of the remaining source intact, for the parsers that predate these
+/// clauses.
+pub fn remove_precedence_clauses<'a>(source: &'a str, grammar: &radlr_bc_ast::Grammar<Token>) -> Cow<'a, str> {
+  let clauses = precedence_clauses(grammar);
+
+  if clauses.is_empty() {
+    return source.into();
+  }
+
+  let mut output = String::with_capacity(source.len());
+  let mut last = 0;
+
+  for clause in clauses {
+    let (start, end) = (clause.tok.get_start(), clause.tok.get_end());
+
+    output += &source[last..start];
+    for c in source[start..end].chars() {
+      match c {
+        '\n' => output.push('\n'),
+        c => output.extend(std::iter::repeat(' ').take(c.len_utf8())),
+      }
+    }
+    last = end;
+  }
+
+  output += &source[last..];
+
+  output.into()
+}
+
+/// Converts the symbols of the precedence clauses of a grammar into terminal
+/// symbol ids, one precedence level per clause.
+pub fn resolve_precedence_clauses(
+  grammar: &radlr_bc_ast::Grammar<Token>,
+  source: &str,
+  g_data: &GrammarData,
+  grammar_path: &PathBuf,
+  s_store: &IStringStore,
+) -> RadlrResult<Array<(Associativity, Array<SymbolId>, Token)>> {
+  let input: Arc<[u8]> = Arc::from(source.as_bytes());
+  let mut levels = Array::new();
+
+  for clause in precedence_clauses(grammar) {
+    let assoc = match clause.assoc.as_str() {
+      "LEFT" => Associativity::Left,
+      "RIGHT" => Associativity::Right,
+      _ => Associativity::NonAssoc,
+    };
+
+    let mut tok = clause.tok.clone();
+    tok.set_shared_source(input.clone());
+
+    let mut level = Array::new();
+
+    for sym in &clause.symbols {
+      match get_terminal_symbol_id(sym, g_data, s_store) {
+        Some(sym) => level.push(sym?),
+        None => {
+          return Err(create_invalid_precedence_clause_error(
+            tok,
+            grammar_path,
+            "Only terminals, classes, and token non-terminals can be assigned a precedence".into(),
+          ))
+        }
+      }
+    }
+
+    levels.push((assoc, level, tok));
+  }
+
+  Ok(levels)
+}
+
+/// Returns the id of a terminal symbol, or `None` if the symbol is a token
+/// group, which has no id outside of the rule that declares it.
+fn get_terminal_symbol_id(
+  sym: &ignore_clause_list_Value<Token>,
+  g_data: &GrammarData,
+  s_store: &IStringStore,
+) -> Option<RadlrResult<SymbolId>> {
+  Some(match sym {
+    ignore_clause_list_Value::TerminalToken(terminal) => {
+      escaped_from((&terminal.val).into()).map(|val| SymbolId::Token { val: val.join("").intern(s_store) }).map_err(Into::into)
+    }
+    ignore_clause_list_Value::ClassSymbol(class) => Ok(match class.val.as_str() {
+      "sp" => SymbolId::ClassSpace,
+      "tab" => SymbolId::ClassHorizontalTab,
+      "nl" => SymbolId::ClassNewLine,
+      "id" => SymbolId::ClassIdentifier,
+      "num" => SymbolId::ClassNumber,
+      "sym" => SymbolId::ClassSymbol,
+      "any" => SymbolId::Any,
+      _ => return None,
+    }),
+    ignore_clause_list_Value::NonTerminal_Terminal_Symbol(token_nterm) => match &token_nterm.nonterminal {
+      nonterminal_Value::NonTerminal_Symbol(nterm) => Ok(NonTermId::from((g_data.id.guid, nterm.name.as_str())).as_tok_sym()),
+      nonterminal_Value::NonTerminal_Import_Symbol(nterm) => match g_data.imports.get(&nterm.module.to_token()) {
+        Some(GrammarIdentities { guid, .. }) => Ok(NonTermId::from((*guid, nterm.name.as_str())).as_tok_sym()),
+        None => Err(RadlrError::Text(format!(
+          "in {} ,\n {}",
+          nterm.tok.path_ref(&g_data.id.path.to_path(s_store)),
+          nterm.tok.blame(1, 1, "Could not resolve nonterm id from symbol", None)
+        ))),
+      },
+      _ => return None,
+    },
+    _ => return None,
+  })
+}
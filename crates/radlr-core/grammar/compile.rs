@@ -1,6 +1,11 @@
 #![allow(unused_mut, unused)]
 use super::{
-  build_grammar::{create_grammar_data, extract_nonterminals, parse_grammar, GrammarData},
+  build_grammar::{
+    create_grammar_data,
+    extract_nonterminals,
+    parse_grammar,
+    GrammarData,
+  },
   build_grammar_2,
   utils::resolve_grammar_path,
 };
@@ -16,38 +21,46 @@ use std::{
 };
 
 /// Entrypoint for compiling a single of grammar from a source file.
+///
+/// Returns the grammars imported by the source grammar, and the ids and errors
+/// of any imports that could not be found in the grammar's directory or in
+/// `include_paths`.
 pub fn compile_grammar_from_str(
   source: &str,
   source_path: PathBuf,
   s_store: IStringStore,
-) -> RadlrResult<(Arc<GrammarSoup>, Vec<GrammarIdentities>)> {
+  include_paths: &[PathBuf],
+) -> RadlrResult<(Arc<GrammarSoup>, Vec<GrammarIdentities>, Vec<(GrammarId, RadlrError)>)> {
   let mut soup = GrammarSoup::from_string_store(s_store);
 
   let root_id = GrammarIdentities::from_path(&source_path, &soup.string_store);
 
-  let g_data = load_from_str(source, source_path, &soup)?;
+  let g_data = load_from_str(source, source_path, &soup, include_paths)?;
 
-  let imports = g_data.imports.values().cloned().collect();
+  let (imports, _) = g_data.imports_to_load();
+  let import_errors = g_data.import_errors.iter().cloned().collect();
 
   let grammar_id = compile_grammar_data(g_data, &soup)?;
 
-  Ok((soup, imports))
+  Ok((soup, imports, import_errors))
 }
 
 /// Imports a single grammar from a source location and merges it into the soup.
-/// Returns a list of grammars that are imported by the source grammar
+/// Returns a list of grammars that are imported by the source grammar, and the
+/// errors of any imports that could not be found.
 pub fn load_grammar(
   import_id: GrammarIdentities,
   s_store: IStringStore,
-) -> RadlrResult<(Arc<GrammarSoup>, Vec<GrammarIdentities>)> {
+  include_paths: &[PathBuf],
+) -> RadlrResult<(Arc<GrammarSoup>, Vec<GrammarIdentities>, Vec<RadlrError>)> {
   let mut g_c: Arc<GrammarSoup> = GrammarSoup::from_string_store(s_store);
   let GrammarIdentities { guid, guid_name, path, .. } = import_id;
 
   let grammar_source_path = PathBuf::from(path.to_str(&g_c.string_store).as_str());
 
-  match load_from_path(grammar_source_path, &g_c) {
+  match load_from_path(grammar_source_path, &g_c, include_paths) {
     RadlrResult::Ok(g_data) => {
-      let imports = g_data.imports.values().cloned().collect();
+      let (imports, import_errors) = g_data.imports_to_load();
 
       match compile_grammar_data(g_data, &g_c) {
         Ok(result) => Some(result),
@@ -57,7 +70,7 @@ pub fn load_grammar(
         }
       };
 
-      Ok((g_c, imports))
+      Ok((g_c, imports, import_errors))
     }
     RadlrResult::Err(err) => {
       eprintln!("{err}");
@@ -90,18 +103,19 @@ fn compile_grammar_data(g_data: GrammarData, g_s: &GrammarSoup) -> RadlrResult<G
   }
 
   {
-    g_s.grammar_headers.write().unwrap().insert(g_data.id.guid, convert_grammar_data_to_header(g_data.id, g_data));
+    let header = convert_grammar_data_to_header(g_data.id, g_data, &g_s.string_store);
+    g_s.grammar_headers.write().unwrap().insert(header.identity.guid, header);
   }
 
   RadlrResult::Ok(id)
 }
 
 #[track_caller]
-fn load_from_path(source_path: PathBuf, soup: &GrammarSoup) -> RadlrResult<GrammarData> {
+fn load_from_path(source_path: PathBuf, soup: &GrammarSoup, include_paths: &[PathBuf]) -> RadlrResult<GrammarData> {
   match std::fs::read_to_string(&source_path) {
     Ok(source) => {
       let source = std::fs::read_to_string(&source_path)?;
-      load_from_str(source.as_str(), source_path, soup)
+      load_from_str(source.as_str(), source_path, soup, include_paths)
     }
     Err(_) => RadlrResult::Err(if source_path.as_os_str().is_empty() {
       ("Source path for grammar is empty").into()
@@ -111,13 +125,14 @@ fn load_from_path(source_path: PathBuf, soup: &GrammarSoup) -> RadlrResult<Gramm
   }
 }
 
-fn load_from_str(source: &str, source_path: PathBuf, soup: &GrammarSoup) -> RadlrResult<GrammarData> {
-  match build_grammar_2::parse_grammar(source) {
-    Ok(g) => { /* println!("Parser2 built: {g:#?}") */ }
-    Err(g) => println!("Parser2 failed: {g:#?}"),
-  }
+fn load_from_str(source: &str, source_path: PathBuf, soup: &GrammarSoup, include_paths: &[PathBuf]) -> RadlrResult<GrammarData> {
+  let grammar_2 = build_grammar_2::parse_grammar(source)?;
+
+  // The legacy parser predates the `LEFT`, `RIGHT`, and `NONASSOC` clauses,
+  // which are read from the AST of `grammar_2` instead.
+  let legacy_source = build_grammar_2::remove_precedence_clauses(source, &grammar_2);
 
-  let root_grammar = match parse_grammar(&source) {
+  let root_grammar = match parse_grammar(&legacy_source) {
     RadlrResult::Ok(root_grammar) => root_grammar,
     RadlrResult::Err(err) => {
       eprintln!("{err}");
@@ -126,7 +141,9 @@ fn load_from_str(source: &str, source_path: PathBuf, soup: &GrammarSoup) -> Radl
     _ => return RadlrResult::Err(RadlrError::from("Failed Parse")),
   };
 
-  let g_data = create_grammar_data(root_grammar, &source_path, &soup.string_store)?;
+  let mut g_data = create_grammar_data(root_grammar, &source_path, &soup.string_store, include_paths)?;
+
+  g_data.precedence = build_grammar_2::resolve_precedence_clauses(&grammar_2, source, &g_data, &source_path, &soup.string_store)?;
 
   RadlrResult::Ok(g_data)
 }
@@ -1,12 +1,14 @@
 //!
 //! Handles the integration of Grammars into a GrammarSoup
 
+mod analysis;
 mod build_database;
 mod build_grammar;
 mod build_grammar_2;
 mod compile;
 pub(crate) mod utils;
 
+pub(crate) use analysis::analyze_grammar;
 pub(crate) use build_database::build_compile_db;
 pub use build_grammar::{create_grammar_data, parse_grammar, remove_grammar_mut};
 pub use compile::{compile_grammar_from_str, load_grammar};
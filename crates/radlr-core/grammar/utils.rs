@@ -43,6 +43,55 @@ pub(crate) fn resolve_grammar_path(path: &Path, current_grammar_dir: &Path, exte
   )
 }
 
+/// Resolves the path of an `IMPORT` statement to a grammar file.
+///
+/// A relative path is searched for in `current_grammar_dir`, then in each of
+/// `include_paths` in order, and finally in the current working directory. If a
+/// candidate path does not have an extension, it is also tried with each of
+/// `extension`. Candidates that repeat an earlier candidate are skipped.
+///
+/// Returns every path that was tried if none of them is a file.
+pub(crate) fn resolve_import_path(
+  path: &Path,
+  current_grammar_dir: &Path,
+  include_paths: &[PathBuf],
+  extension: &[&str],
+) -> Result<PathBuf, Vec<PathBuf>> {
+  let candidates: Vec<PathBuf> = if path.is_absolute() {
+    vec![path.to_owned()]
+  } else {
+    let mut seen = std::collections::HashSet::new();
+    std::iter::once(current_grammar_dir)
+      .chain(include_paths.iter().map(|p| p.as_path()))
+      .map(|dir| dir.join(path))
+      .chain(std::iter::once(path.to_owned()))
+      .filter(|candidate| seen.insert(candidate.clone()))
+      .collect()
+  };
+
+  let mut tried = Vec::new();
+
+  for candidate in candidates {
+    if candidate.is_file() {
+      return candidate.canonicalize().map_err(|_| vec![candidate]);
+    }
+
+    tried.push(candidate.clone());
+
+    if candidate.extension().is_none() {
+      for ext in extension {
+        let candidate = candidate.with_extension(ext);
+        if candidate.is_file() {
+          return candidate.canonicalize().map_err(|_| vec![candidate]);
+        }
+        tried.push(candidate);
+      }
+    }
+  }
+
+  Err(tried)
+}
+
 #[derive(Default)]
 pub struct SymbolData<'a> {
   pub annotation:        String,
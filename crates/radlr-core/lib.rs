@@ -24,6 +24,7 @@ pub use types::{
   proxy,
   worker_pool,
   ASTToken,
+  Associativity,
   CachedString,
   DBEntryPoint,
   DBNonTermKey,
@@ -41,6 +42,9 @@ pub use types::{
   Item,
   NonTermId,
   NonTerminal,
+  OptimizationPass,
+  OptimizationReport,
+  OptimizeConfig,
   ParseState,
   ParseStatesMap,
   ParseStatesVec,
@@ -48,6 +52,7 @@ pub use types::{
   ParserConfig,
   ParserDatabase,
   ParserMetrics,
+  PassReport,
   RadlrError,
   RadlrErrorSeverity,
   RadlrResult,
@@ -35,10 +35,7 @@ pub fn parse_grammar(input: &str) -> RadlrResult<Box<radlr_bc_ast::Grammar<Token
     parser_db.get_entry_data_from_name("grammar").unwrap(),
     radlr_bc_ast::ReduceRules::<radlr_rust_runtime::types::Token>::new(),
   ) {
-    Err(err) => {
-      println!("{err:?}");
-      Err("Failed to parse input".to_string().into())
-    }
+    Err(err) => Err(err.into()),
     Ok(node) => Ok(node.into_Grammar().unwrap()),
   }
 }
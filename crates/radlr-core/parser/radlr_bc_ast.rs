@@ -172,6 +172,7 @@ pub enum ASTNode<Token: Tk> {
   NotEmptySet(Box<NotEmptySet<Token>>),
   Statement(Box<Statement<Token>>),
   TemplateSym(Box<TemplateSym>),
+  PrecedenceClause(Box<PrecedenceClause<Token>>),
   AST_Token(Box<AST_Token>),
   AST_StringLiteral(Box<AST_StringLiteral<Token>>),
   TemplateRules(Box<TemplateRules<Token>>),
@@ -725,6 +726,7 @@ pub enum preamble_Value<Token: Tk> {
   Ignore(Box<Ignore<Token>>),
   Import(Box<Import<Token>>),
   Export(Box<Export<Token>>),
+  PrecedenceClause(Box<PrecedenceClause<Token>>),
 }
 
 #[derive(Clone, Debug, Default)]
@@ -1983,6 +1985,12 @@ impl<Token: Tk> From<Box<Export<Token>>> for preamble_Value<Token> {
     preamble_Value::Export(val)
   }
 }
+
+impl<Token: Tk> From<Box<PrecedenceClause<Token>>> for preamble_Value<Token> {
+  fn from(val: Box<PrecedenceClause<Token>>) -> Self {
+    preamble_Value::PrecedenceClause(val)
+  }
+}
 impl<Token: Tk> ASTNode<Token> {
   pub fn into_preamble_Value(self) -> Option<preamble_Value<Token>> {
     match self {
@@ -1991,6 +1999,7 @@ impl<Token: Tk> ASTNode<Token> {
       ASTNode::Ignore(val) => Some(preamble_Value::Ignore(val)),
       ASTNode::Import(val) => Some(preamble_Value::Import(val)),
       ASTNode::Export(val) => Some(preamble_Value::Export(val)),
+      ASTNode::PrecedenceClause(val) => Some(preamble_Value::PrecedenceClause(val)),
       _ => None,
     }
   }
@@ -2009,6 +2018,7 @@ impl<Token: Tk> preamble_Value<Token> {
       Self::Ignore(val) => ASTNode::Ignore(val),
       Self::Import(val) => ASTNode::Import(val),
       Self::Export(val) => ASTNode::Export(val),
+      Self::PrecedenceClause(val) => ASTNode::PrecedenceClause(val),
       _ => ASTNode::None,
     }
   }
@@ -4183,6 +4193,7 @@ impl<Token: Tk> ASTNode<Token> {
       ASTNode::ReduceRaw(n) => n.tok.clone(),
       ASTNode::TokenGroupRules(n) => n.tok.clone(),
       ASTNode::NotEmptySet(n) => n.tok.clone(),
+      ASTNode::PrecedenceClause(n) => n.tok.clone(),
       ASTNode::AST_StringLiteral(n) => n.tok.clone(),
       ASTNode::TemplateRules(n) => n.tok.clone(),
       ASTNode::Grouped_Rules(n) => n.tok.clone(),
@@ -4272,6 +4283,7 @@ impl<Token: Tk> ASTNode<Token> {
       ASTNode::NotEmptySet(n) => n.hash(hasher),
       ASTNode::Statement(n) => n.hash(hasher),
       ASTNode::TemplateSym(n) => n.hash(hasher),
+      ASTNode::PrecedenceClause(n) => n.hash(hasher),
       ASTNode::AST_Token(n) => n.hash(hasher),
       ASTNode::AST_StringLiteral(n) => n.hash(hasher),
       ASTNode::TemplateRules(n) => n.hash(hasher),
@@ -5649,6 +5661,28 @@ impl<Token: Tk> From<Box<TemplateSym>> for ASTNode<Token> {
   }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct PrecedenceClause<Token: Tk> {
+  pub tok:     Token,
+  pub assoc:   String,
+  pub symbols: Vec<ignore_clause_list_Value<Token>>,
+}
+
+impl<Token: Tk> ASTNode<Token> {
+  pub fn into_PrecedenceClause(self) -> Option<Box<PrecedenceClause<Token>>> {
+    match self {
+      ASTNode::PrecedenceClause(val) => Some(val),
+      _ => None,
+    }
+  }
+}
+
+impl<Token: Tk> From<Box<PrecedenceClause<Token>>> for ASTNode<Token> {
+  fn from(value: Box<PrecedenceClause<Token>>) -> Self {
+    Self::PrecedenceClause(value)
+  }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct AST_Token {
   pub range: Box<Range>,
@@ -5948,7 +5982,7 @@ impl<Token: Tk> From<Box<AST_String<Token>>> for ASTNode<Token> {
 #[derive(Clone, Debug, Default)]
 pub struct AnnotatedSymbol<Token: Tk> {
   pub tok:         Token,
-  pub symbol:      list_Value<Token>, /* 43 */
+  pub symbol:      list_Value<Token>, /* 44 */
   pub precedence:  Box<Precedence>,
   pub is_optional: bool,
   pub reference:   String,
@@ -6137,7 +6171,7 @@ pub struct List_Rules<Token: Tk> {
   pub tok:             Token,
   pub symbol:          symbol_Value<Token>, /* 16 */
   pub optional:        bool,
-  pub terminal_symbol: list_Value<Token>, /* 43 */
+  pub terminal_symbol: list_Value<Token>, /* 44 */
 }
 
 impl<Token: Tk> ASTNode<Token> {
@@ -7346,6 +7380,13 @@ fn rule_78<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
 
 fn rule_79<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+  let out = std::mem::take(&mut nodes[0]);
+  let out = unsafe { out.into_PrecedenceClause().unwrap_unchecked() };
+  out.into()
+}
+
+fn rule_80<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
@@ -7358,7 +7399,7 @@ fn rule_79<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::CFRules(Box::new(CFRules { tok, rules, name_sym }))
 }
 
-fn rule_80<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_81<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7372,7 +7413,7 @@ fn rule_80<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::PegRules(Box::new(PegRules { tok, rules, name_sym }))
 }
 
-fn rule_81<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_82<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7388,7 +7429,7 @@ fn rule_81<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AppendRules(Box::new(AppendRules { tok, rules, name_sym }))
 }
 
-fn rule_82<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_83<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7407,7 +7448,7 @@ fn rule_82<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::TemplateRules(Box::new(TemplateRules { tok, rules, name_sym, template_params }))
 }
 
-fn rule_83<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_84<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = std::mem::take(&mut nodes[0]);
@@ -7419,7 +7460,7 @@ fn rule_83<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   out.into()
 }
 
-fn rule_84<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_85<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = std::mem::take(&mut nodes[2]);
   let out_r = unsafe {
@@ -7434,15 +7475,15 @@ fn rule_84<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   out.into()
 }
 
-fn rule_85<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_86<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   ASTNode::DEFINED_TYPE_IDENT(DEFINED_TYPE_IDENT {})
 }
 
-fn rule_86<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_87<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   ASTNode::DEFINED_TYPE_NUM(DEFINED_TYPE_NUM {})
 }
 
-fn rule_87<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_88<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7460,7 +7501,7 @@ fn rule_87<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Add(Box::new(AST_Add { tok, left, right }))
 }
 
-fn rule_88<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_89<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7478,7 +7519,7 @@ fn rule_88<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Sub(Box::new(AST_Sub { tok, left, right }))
 }
 
-fn rule_89<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_90<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7496,7 +7537,7 @@ fn rule_89<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Div(Box::new(AST_Div { tok, left, right }))
 }
 
-fn rule_90<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_91<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7514,7 +7555,7 @@ fn rule_90<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Mul(Box::new(AST_Mul { tok, left, right }))
 }
 
-fn rule_91<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_92<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7532,7 +7573,7 @@ fn rule_91<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Mod(Box::new(AST_Mod { tok, left, right }))
 }
 
-fn rule_92<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_93<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7550,7 +7591,7 @@ fn rule_92<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Pow(Box::new(AST_Pow { tok, left, right }))
 }
 
-fn rule_93<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_94<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7563,7 +7604,7 @@ fn rule_93<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Neg(Box::new(AST_Neg { tok, expr }))
 }
 
-fn rule_94<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_95<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[1]);
   let out = unsafe {
@@ -7572,7 +7613,7 @@ fn rule_94<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   out.into()
 }
 
-fn rule_95<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_96<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -7581,7 +7622,7 @@ fn rule_95<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   out.into()
 }
 
-fn rule_96<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_97<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let id = nodes[0].clone();
@@ -7611,7 +7652,7 @@ fn rule_96<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Property(Box::new(AST_Property { id, tok, value, named_reference }))
 }
 
-fn rule_97<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_98<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let id = nodes[0].clone();
@@ -7639,7 +7680,7 @@ fn rule_97<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Property(Box::new(AST_Property { id, tok, value, named_reference }))
 }
 
-fn rule_98<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_99<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let id = nodes[0].clone();
@@ -7657,27 +7698,27 @@ fn rule_98<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok:
   ASTNode::AST_Property(Box::new(AST_Property { id, tok, value, named_reference }))
 }
 
-fn rule_99<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_100<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_Token().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_100<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_101<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[1].clone();
   let out = out.to_token().unwrap();
   ASTNode::Token(out)
 }
 
-fn rule_101<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_102<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_102<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_103<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7697,7 +7738,7 @@ fn rule_102<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ReduceRaw(Box::new(ReduceRaw { tok, len, rule_id, nonterminal_id }))
 }
 
-fn rule_103<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_104<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7717,7 +7758,7 @@ fn rule_103<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ReduceRaw(Box::new(ReduceRaw { tok, len, rule_id, nonterminal_id }))
 }
 
-fn rule_104<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_105<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7737,7 +7778,7 @@ fn rule_104<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ReduceRaw(Box::new(ReduceRaw { tok, len, rule_id, nonterminal_id }))
 }
 
-fn rule_105<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_106<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7757,7 +7798,7 @@ fn rule_105<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ReduceRaw(Box::new(ReduceRaw { tok, len, rule_id, nonterminal_id }))
 }
 
-fn rule_106<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_107<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7779,7 +7820,7 @@ fn rule_106<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Reduce(Box::new(Reduce { tok, len, ast, nonterminal }))
 }
 
-fn rule_107<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_108<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7801,7 +7842,7 @@ fn rule_107<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Reduce(Box::new(Reduce { tok, len, ast, nonterminal }))
 }
 
-fn rule_108<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_109<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7820,7 +7861,7 @@ fn rule_108<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Reduce(Box::new(Reduce { tok, len, ast, nonterminal }))
 }
 
-fn rule_109<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_110<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7839,7 +7880,7 @@ fn rule_109<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Reduce(Box::new(Reduce { tok, len, ast, nonterminal }))
 }
 
-fn rule_110<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_111<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let id = nodes[1].clone();
@@ -7851,7 +7892,7 @@ fn rule_110<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::SetTokenId(Box::new(SetTokenId { id, tok }))
 }
 
-fn rule_111<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_112<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let id = nodes[1].clone();
@@ -7861,31 +7902,33 @@ fn rule_111<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::SetTokenLen(Box::new(SetTokenLen { id }))
 }
 
-fn rule_112<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_113<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+
   let tok = nterm_tok.clone();
+
   ASTNode::SetLine(Box::new(SetLine { tok }))
 }
 
-fn rule_113<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_114<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[1].clone();
   ASTNode::Token(out)
 }
 
-fn rule_114<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_115<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[1].clone();
   ASTNode::Token(out)
 }
 
-fn rule_115<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_116<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[1].clone();
   ASTNode::Token(out)
 }
 
-fn rule_116<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_117<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[1]);
   let out = unsafe {
@@ -7894,7 +7937,7 @@ fn rule_116<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_117<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_118<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7906,7 +7949,7 @@ fn rule_117<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Pop(Box::new(Pop { tok, count }))
 }
 
-fn rule_118<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_119<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -7915,14 +7958,14 @@ fn rule_118<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_119<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_120<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_Gotos().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_120<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_121<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -7931,7 +7974,7 @@ fn rule_120<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_121<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_122<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7945,7 +7988,7 @@ fn rule_121<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Shift(Box::new(Shift { tok, skip, ptr_type }))
 }
 
-fn rule_122<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_123<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7959,7 +8002,7 @@ fn rule_122<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Shift(Box::new(Shift { tok, skip, ptr_type }))
 }
 
-fn rule_123<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_124<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7972,7 +8015,7 @@ fn rule_123<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Shift(Box::new(Shift { tok, skip, ptr_type }))
 }
 
-fn rule_124<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_125<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7985,7 +8028,7 @@ fn rule_124<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Shift(Box::new(Shift { tok, skip, ptr_type }))
 }
 
-fn rule_125<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_126<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -7999,7 +8042,7 @@ fn rule_125<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Peek(Box::new(Peek { tok, skip, ptr_type }))
 }
 
-fn rule_126<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_127<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8013,7 +8056,7 @@ fn rule_126<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Peek(Box::new(Peek { tok, skip, ptr_type }))
 }
 
-fn rule_127<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_128<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8026,7 +8069,7 @@ fn rule_127<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Peek(Box::new(Peek { tok, skip, ptr_type }))
 }
 
-fn rule_128<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_129<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8039,7 +8082,7 @@ fn rule_128<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Peek(Box::new(Peek { tok, skip, ptr_type }))
 }
 
-fn rule_129<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_130<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8050,7 +8093,7 @@ fn rule_129<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Reset(Box::new(Reset { tok, ptr_type }))
 }
 
-fn rule_130<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_131<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8061,12 +8104,6 @@ fn rule_130<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Reset(Box::new(Reset { tok, ptr_type }))
 }
 
-fn rule_131<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
-  let nodes = unsafe { &mut *nodes };
-  let out = tokens[0].clone();
-  ASTNode::Token(out)
-}
-
 fn rule_132<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
@@ -8099,6 +8136,12 @@ fn rule_136<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
 
 fn rule_137<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+  let out = tokens[0].clone();
+  ASTNode::Token(out)
+}
+
+fn rule_138<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
 
   let nonterminal = std::mem::take(&mut nodes[1]);
   let nonterminal = unsafe {
@@ -8112,7 +8155,7 @@ fn rule_137<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Export(Box::new(Export { nonterminal, reference }))
 }
 
-fn rule_138<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_139<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let nonterminal = std::mem::take(&mut nodes[1]);
@@ -8127,7 +8170,7 @@ fn rule_138<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Export(Box::new(Export { nonterminal, reference }))
 }
 
-fn rule_139<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_140<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let nonterminal = std::mem::take(&mut nodes[1]);
@@ -8140,33 +8183,33 @@ fn rule_139<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Export(Box::new(Export { nonterminal, reference }))
 }
 
-fn rule_140<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_141<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_141<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_142<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_142<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_143<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[1].clone();
   let out = out.to_token().unwrap();
   ASTNode::Token(out)
 }
 
-fn rule_143<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_144<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[1].clone();
   let out = out.to_token().unwrap();
   ASTNode::Token(out)
 }
 
-fn rule_144<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_145<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let uri = std::mem::take(&mut nodes[1]);
@@ -8182,7 +8225,7 @@ fn rule_144<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Import(Box::new(Import { uri, tok, reference }))
 }
 
-fn rule_145<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_146<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let uri = std::mem::take(&mut nodes[1]);
@@ -8198,25 +8241,25 @@ fn rule_145<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Import(Box::new(Import { uri, tok, reference }))
 }
 
-fn rule_146<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_147<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_147<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_148<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_148<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_149<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_149<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_150<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = tokens[0].clone();
@@ -8225,7 +8268,7 @@ fn rule_149<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::vec_Token(out)
 }
 
-fn rule_150<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_151<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = tokens[0].clone();
@@ -8234,7 +8277,7 @@ fn rule_150<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::vec_Token(out)
 }
 
-fn rule_151<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_152<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = tokens[0].clone();
@@ -8243,7 +8286,7 @@ fn rule_151<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::vec_Token(out)
 }
 
-fn rule_152<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_153<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = tokens[1].clone();
   let out_l = std::mem::take(&mut nodes[0]);
@@ -8253,7 +8296,7 @@ fn rule_152<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::vec_Token(out)
 }
 
-fn rule_153<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_154<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = tokens[1].clone();
   let out_l = std::mem::take(&mut nodes[0]);
@@ -8263,7 +8306,7 @@ fn rule_153<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::vec_Token(out)
 }
 
-fn rule_154<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_155<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = tokens[1].clone();
   let out_l = std::mem::take(&mut nodes[0]);
@@ -8273,19 +8316,19 @@ fn rule_154<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::vec_Token(out)
 }
 
-fn rule_155<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_156<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_156<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_157<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_157<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_158<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let name = nodes[1].clone();
@@ -8295,7 +8338,7 @@ fn rule_157<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Name(Box::new(Name { name }))
 }
 
-fn rule_158<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_159<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let symbols = std::mem::take(&mut nodes[2]);
@@ -8306,7 +8349,7 @@ fn rule_158<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Ignore(Box::new(Ignore { symbols }))
 }
 
-fn rule_159<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_160<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let symbols = Default::default();
@@ -8314,7 +8357,7 @@ fn rule_159<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Ignore(Box::new(Ignore { symbols }))
 }
 
-fn rule_160<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_161<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 42
   to val type: AscriptType
@@ -8336,7 +8379,7 @@ fn rule_160<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_161<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_162<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 42
   to val type: AscriptType
@@ -8364,32 +8407,36 @@ fn rule_161<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_162<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
-  let nodes = unsafe { &mut *nodes };
-  let out = std::mem::take(&mut nodes[0]);
-  let out = unsafe { out.into_vec_Rule().unwrap_unchecked() };
-  out.into()
-}
-
 fn rule_163<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
-  /* 1 */
-  let out_0 = std::mem::take(&mut nodes[0]);
-  let out_0 = unsafe { out_0.into_Rule().unwrap_unchecked() };
 
-  let out = vec![out_0];
-  out.into()
+  let tok = nterm_tok.clone();
+
+  let assoc = tokens[0].clone();
+  let assoc = assoc.to_string();
+
+  let symbols = std::mem::take(&mut nodes[2]);
+  let symbols = unsafe {
+    symbols.into_vec_ignore_clause_list_Value/*43*/().unwrap_unchecked()
+  };
+
+  ASTNode::PrecedenceClause(Box::new(PrecedenceClause { tok, assoc, symbols }))
 }
 
 fn rule_164<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
-  let out_r = std::mem::take(&mut nodes[2]);
-  let out_r = unsafe { out_r.into_Rule().unwrap_unchecked() };
-  let out_l = std::mem::take(&mut nodes[0]);
-  let out_l = unsafe { out_l.into_vec_Rule().unwrap_unchecked() };
-  let mut out = out_l;
-  out.push(out_r);
-  out.into()
+
+  let tok = nterm_tok.clone();
+
+  let assoc = tokens[0].clone();
+  let assoc = assoc.to_string();
+
+  let symbols = std::mem::take(&mut nodes[2]);
+  let symbols = unsafe {
+    symbols.into_vec_ignore_clause_list_Value/*43*/().unwrap_unchecked()
+  };
+
+  ASTNode::PrecedenceClause(Box::new(PrecedenceClause { tok, assoc, symbols }))
 }
 
 fn rule_165<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
@@ -8397,48 +8444,160 @@ fn rule_165<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
 
   let tok = nterm_tok.clone();
 
-  let rules = std::mem::take(&mut nodes[1]);
-  let rules = unsafe { rules.into_vec_Rule().unwrap_unchecked() };
+  let assoc = tokens[0].clone();
+  let assoc = assoc.to_string();
 
-  ASTNode::Grouped_Rules(Box::new(Grouped_Rules { tok, rules }))
+  let symbols = std::mem::take(&mut nodes[2]);
+  let symbols = unsafe {
+    symbols.into_vec_ignore_clause_list_Value/*43*/().unwrap_unchecked()
+  };
+
+  ASTNode::PrecedenceClause(Box::new(PrecedenceClause { tok, assoc, symbols }))
 }
 
 fn rule_166<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
-  let out = std::mem::take(&mut nodes[0]);
-  let out = unsafe { out.into_NonTerminal_Symbol().unwrap_unchecked() };
-  out.into()
+  let out = tokens[0].clone();
+  ASTNode::Token(out)
 }
 
 fn rule_167<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
-  let out = std::mem::take(&mut nodes[0]);
-  let out = unsafe { out.into_NonTerminal_Import_Symbol().unwrap_unchecked() };
-  out.into()
+  let out = tokens[0].clone();
+  ASTNode::Token(out)
 }
 
 fn rule_168<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
-
-  let val = nodes[0].clone();
-  let val = val.to_token().unwrap();
-  let val = val.to_string();
-
-  ASTNode::TemplateSym(Box::new(TemplateSym { val }))
+  let out = tokens[0].clone();
+  ASTNode::Token(out)
 }
 
 fn rule_169<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+  /*1*//*to index id: 43
+  to val type: AscriptType
+  to val type: Multi
+  to agg agg:
 
-  let val = nodes[0].clone();
-  let val = val.to_token().unwrap();
-  let val = val.to_string();
-
-  ASTNode::TemplateSym(Box::new(TemplateSym { val }))
-}
+  from index: Multi
+  from index: 28
+  from val: SymNode
+  from agg:
+  */
+  let out_0 = std::mem::take(&mut nodes[0]);
+  let out_0 = unsafe {
+    out_0.into_ignore_clause_list_Value/*28*/().unwrap_unchecked()
+  };
+  let out_0: ignore_clause_list_Value<Token>/*43*/= out_0.into();
+
+  let out = vec![out_0];
+  out.into()
+}
 
 fn rule_170<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+  /*to index id: 43
+  to val type: AscriptType
+  to val type: Multi
+  to agg agg: Vec
+
+  from index: Multi
+  from index: 28
+  from val: SymNode
+  from agg:
+  */
+  let out_r = std::mem::take(&mut nodes[1]);
+  let out_r = unsafe {
+    out_r.into_ignore_clause_list_Value/*28*/().unwrap_unchecked()
+  };
+  let out_r: ignore_clause_list_Value<Token>/*43*/= out_r.into();
+  let out_r = vec![out_r];
+
+  let out_l = std::mem::take(&mut nodes[0]);
+  let out_l = unsafe {
+    out_l.into_vec_ignore_clause_list_Value/*43*/().unwrap_unchecked()
+  };
+  let mut out = out_l;
+  out.extend(out_r);
+  out.into()
+}
+
+fn rule_171<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+  let out = std::mem::take(&mut nodes[0]);
+  let out = unsafe { out.into_vec_Rule().unwrap_unchecked() };
+  out.into()
+}
+
+fn rule_172<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+  /* 1 */
+  let out_0 = std::mem::take(&mut nodes[0]);
+  let out_0 = unsafe { out_0.into_Rule().unwrap_unchecked() };
+
+  let out = vec![out_0];
+  out.into()
+}
+
+fn rule_173<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+  let out_r = std::mem::take(&mut nodes[2]);
+  let out_r = unsafe { out_r.into_Rule().unwrap_unchecked() };
+  let out_l = std::mem::take(&mut nodes[0]);
+  let out_l = unsafe { out_l.into_vec_Rule().unwrap_unchecked() };
+  let mut out = out_l;
+  out.push(out_r);
+  out.into()
+}
+
+fn rule_174<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+
+  let tok = nterm_tok.clone();
+
+  let rules = std::mem::take(&mut nodes[1]);
+  let rules = unsafe { rules.into_vec_Rule().unwrap_unchecked() };
+
+  ASTNode::Grouped_Rules(Box::new(Grouped_Rules { tok, rules }))
+}
+
+fn rule_175<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+  let out = std::mem::take(&mut nodes[0]);
+  let out = unsafe { out.into_NonTerminal_Symbol().unwrap_unchecked() };
+  out.into()
+}
+
+fn rule_176<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+  let out = std::mem::take(&mut nodes[0]);
+  let out = unsafe { out.into_NonTerminal_Import_Symbol().unwrap_unchecked() };
+  out.into()
+}
+
+fn rule_177<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+
+  let val = nodes[0].clone();
+  let val = val.to_token().unwrap();
+  let val = val.to_string();
+
+  ASTNode::TemplateSym(Box::new(TemplateSym { val }))
+}
+
+fn rule_178<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
+
+  let val = nodes[0].clone();
+  let val = val.to_token().unwrap();
+  let val = val.to_string();
+
+  ASTNode::TemplateSym(Box::new(TemplateSym { val }))
+}
+
+fn rule_179<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+  let nodes = unsafe { &mut *nodes };
 
   let val = nodes[0].clone();
   let val = val.to_token().unwrap();
@@ -8447,13 +8606,13 @@ fn rule_170<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::TemplateASTType(Box::new(TemplateASTType { val }))
 }
 
-fn rule_171<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_180<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[1].clone();
   ASTNode::Token(out)
 }
 
-fn rule_172<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_181<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -8462,35 +8621,35 @@ fn rule_172<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_173<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_182<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_Map().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_174<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_183<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_Vector().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_175<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_184<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_String().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_176<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_185<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_Bool().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_177<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_186<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -8499,7 +8658,7 @@ fn rule_177<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_178<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_187<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -8508,14 +8667,14 @@ fn rule_178<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_179<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_188<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[0].clone();
   let out = out.to_token().unwrap();
   ASTNode::Token(out)
 }
 
-fn rule_180<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_189<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let range = std::mem::take(&mut nodes[1]);
@@ -8524,7 +8683,7 @@ fn rule_180<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Token(Box::new(AST_Token { range }))
 }
 
-fn rule_181<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_190<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let range = std::mem::take(&mut nodes[1]);
@@ -8533,7 +8692,7 @@ fn rule_181<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Token(Box::new(AST_Token { range }))
 }
 
-fn rule_182<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_191<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let range = std::mem::take(&mut nodes[1]);
@@ -8542,7 +8701,7 @@ fn rule_182<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Token(Box::new(AST_Token { range }))
 }
 
-fn rule_183<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_192<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let range = Default::default();
@@ -8550,7 +8709,7 @@ fn rule_183<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Token(Box::new(AST_Token { range }))
 }
 
-fn rule_184<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_193<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let range = Default::default();
@@ -8558,7 +8717,7 @@ fn rule_184<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Token(Box::new(AST_Token { range }))
 }
 
-fn rule_185<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_194<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let range = Default::default();
@@ -8566,39 +8725,39 @@ fn rule_185<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Token(Box::new(AST_Token { range }))
 }
 
-fn rule_186<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_195<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_187<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_196<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_188<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_197<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_189<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_198<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_Struct().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_190<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_199<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_Flag().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_191<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_200<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8611,7 +8770,7 @@ fn rule_191<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Statement(Box::new(AST_Statement { tok, expression }))
 }
 
-fn rule_192<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_201<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8624,34 +8783,34 @@ fn rule_192<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Statement(Box::new(AST_Statement { tok, expression }))
 }
 
-fn rule_193<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_202<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_194<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_203<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_Matches().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_195<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_204<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_ProductionMatches().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_196<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_205<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_TerminalMatches().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_197<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_206<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let fork = Default::default();
@@ -8665,7 +8824,7 @@ fn rule_197<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Gotos(Box::new(Gotos { fork, goto, pushes }))
 }
 
-fn rule_198<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_207<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let fork = Default::default();
@@ -8678,7 +8837,7 @@ fn rule_198<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Gotos(Box::new(Gotos { fork, goto, pushes }))
 }
 
-fn rule_199<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_208<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let fork = std::mem::take(&mut nodes[0]);
@@ -8691,7 +8850,7 @@ fn rule_199<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Gotos(Box::new(Gotos { fork, goto, pushes }))
 }
 
-fn rule_200<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_209<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = std::mem::take(&mut nodes[0]);
@@ -8701,7 +8860,7 @@ fn rule_200<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_201<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_210<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = std::mem::take(&mut nodes[2]);
   let out_r = unsafe { out_r.into_Push().unwrap_unchecked() };
@@ -8712,25 +8871,31 @@ fn rule_201<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_202<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_211<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+
   let tok = nterm_tok.clone();
+
   ASTNode::Fail(Box::new(Fail { tok }))
 }
 
-fn rule_203<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_212<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+
   let tok = nterm_tok.clone();
+
   ASTNode::Pass(Box::new(Pass { tok }))
 }
 
-fn rule_204<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_213<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
+
   let tok = nterm_tok.clone();
+
   ASTNode::Accept(Box::new(Accept { tok }))
 }
 
-fn rule_205<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_214<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8741,28 +8906,28 @@ fn rule_205<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::TokenGroupRules(Box::new(TokenGroupRules { tok, rules }))
 }
 
-fn rule_206<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_215<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_TerminalToken().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_207<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_216<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_NonTerminal_Terminal_Symbol().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_208<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_217<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_ClassSymbol().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_209<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_218<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8778,7 +8943,7 @@ fn rule_209<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Rule(Box::new(Rule { tok, ast, symbols }))
 }
 
-fn rule_210<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_219<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -8793,7 +8958,7 @@ fn rule_210<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Rule(Box::new(Rule { tok, ast, symbols }))
 }
 
-fn rule_211<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_220<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -8802,14 +8967,14 @@ fn rule_211<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_212<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_221<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_NotEmptySet().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_213<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_222<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 18
   to val type: AscriptType
@@ -8831,7 +8996,7 @@ fn rule_213<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_214<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_223<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 18
   to val type: AscriptType
@@ -8851,7 +9016,7 @@ fn rule_214<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_215<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_224<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 18
   to val type: AscriptType
@@ -8879,7 +9044,7 @@ fn rule_215<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_216<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_225<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 18
   to val type: AscriptType
@@ -8905,7 +9070,7 @@ fn rule_216<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_217<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_226<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 17
   to val type: AscriptType
@@ -8946,7 +9111,7 @@ fn rule_217<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_218<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_227<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 17
   to val type: AscriptType
@@ -8965,7 +9130,7 @@ fn rule_218<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_219<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_228<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 17
   to val type: AscriptType
@@ -8990,7 +9155,7 @@ fn rule_219<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_220<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_229<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9006,7 +9171,7 @@ fn rule_220<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::NonTerminal_Import_Symbol(Box::new(NonTerminal_Import_Symbol { tok, name, module }))
 }
 
-fn rule_221<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_230<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -9015,7 +9180,7 @@ fn rule_221<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_222<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_231<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let property = nodes[2].clone();
@@ -9029,7 +9194,7 @@ fn rule_222<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Member(Box::new(AST_Member { property, reference }))
 }
 
-fn rule_223<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_232<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9047,7 +9212,7 @@ fn rule_223<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Map(Box::new(AST_Map { tok, val, key }))
 }
 
-fn rule_224<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_233<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9060,7 +9225,7 @@ fn rule_224<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Vector(Box::new(AST_Vector { tok, initializer }))
 }
 
-fn rule_225<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_234<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9070,7 +9235,7 @@ fn rule_225<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Vector(Box::new(AST_Vector { tok, initializer }))
 }
 
-fn rule_226<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_235<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = std::mem::take(&mut nodes[0]);
@@ -9082,7 +9247,7 @@ fn rule_226<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_227<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_236<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = std::mem::take(&mut nodes[2]);
   let out_r = unsafe {
@@ -9097,7 +9262,7 @@ fn rule_227<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_228<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_237<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9108,7 +9273,7 @@ fn rule_228<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_String(Box::new(AST_String { tok, initializer }))
 }
 
-fn rule_229<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_238<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9118,7 +9283,7 @@ fn rule_229<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_String(Box::new(AST_String { tok, initializer }))
 }
 
-fn rule_230<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_239<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9129,7 +9294,7 @@ fn rule_230<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Bool(Box::new(AST_Bool { tok, initializer }))
 }
 
-fn rule_231<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_240<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9139,7 +9304,7 @@ fn rule_231<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Bool(Box::new(AST_Bool { tok, initializer }))
 }
 
-fn rule_232<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_241<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9150,7 +9315,7 @@ fn rule_232<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U8(Box::new(AST_U8 { tok, initializer }))
 }
 
-fn rule_233<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_242<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9160,7 +9325,7 @@ fn rule_233<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U8(Box::new(AST_U8 { tok, initializer }))
 }
 
-fn rule_234<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_243<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9171,7 +9336,7 @@ fn rule_234<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U16(Box::new(AST_U16 { tok, initializer }))
 }
 
-fn rule_235<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_244<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9181,7 +9346,7 @@ fn rule_235<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U16(Box::new(AST_U16 { tok, initializer }))
 }
 
-fn rule_236<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_245<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9192,7 +9357,7 @@ fn rule_236<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U32(Box::new(AST_U32 { tok, initializer }))
 }
 
-fn rule_237<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_246<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9202,7 +9367,7 @@ fn rule_237<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U32(Box::new(AST_U32 { tok, initializer }))
 }
 
-fn rule_238<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_247<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9213,7 +9378,7 @@ fn rule_238<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U64(Box::new(AST_U64 { tok, initializer }))
 }
 
-fn rule_239<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_248<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9223,7 +9388,7 @@ fn rule_239<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U64(Box::new(AST_U64 { tok, initializer }))
 }
 
-fn rule_240<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_249<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9234,7 +9399,7 @@ fn rule_240<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U128(Box::new(AST_U128 { tok, initializer }))
 }
 
-fn rule_241<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_250<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9244,7 +9409,7 @@ fn rule_241<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_U128(Box::new(AST_U128 { tok, initializer }))
 }
 
-fn rule_242<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_251<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9255,7 +9420,7 @@ fn rule_242<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I8(Box::new(AST_I8 { tok, initializer }))
 }
 
-fn rule_243<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_252<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9265,7 +9430,7 @@ fn rule_243<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I8(Box::new(AST_I8 { tok, initializer }))
 }
 
-fn rule_244<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_253<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9276,7 +9441,7 @@ fn rule_244<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I16(Box::new(AST_I16 { tok, initializer }))
 }
 
-fn rule_245<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_254<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9286,7 +9451,7 @@ fn rule_245<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I16(Box::new(AST_I16 { tok, initializer }))
 }
 
-fn rule_246<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_255<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9297,7 +9462,7 @@ fn rule_246<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I32(Box::new(AST_I32 { tok, initializer }))
 }
 
-fn rule_247<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_256<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9307,7 +9472,7 @@ fn rule_247<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I32(Box::new(AST_I32 { tok, initializer }))
 }
 
-fn rule_248<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_257<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9318,7 +9483,7 @@ fn rule_248<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I64(Box::new(AST_I64 { tok, initializer }))
 }
 
-fn rule_249<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_258<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9328,7 +9493,7 @@ fn rule_249<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I64(Box::new(AST_I64 { tok, initializer }))
 }
 
-fn rule_250<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_259<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9339,7 +9504,7 @@ fn rule_250<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I64(Box::new(AST_I64 { tok, initializer }))
 }
 
-fn rule_251<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_260<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9349,7 +9514,7 @@ fn rule_251<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_I64(Box::new(AST_I64 { tok, initializer }))
 }
 
-fn rule_252<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_261<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9360,7 +9525,7 @@ fn rule_252<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F16(Box::new(AST_F16 { tok, initializer }))
 }
 
-fn rule_253<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_262<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9370,7 +9535,7 @@ fn rule_253<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F16(Box::new(AST_F16 { tok, initializer }))
 }
 
-fn rule_254<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_263<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9381,7 +9546,7 @@ fn rule_254<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F32(Box::new(AST_F32 { tok, initializer }))
 }
 
-fn rule_255<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_264<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9391,7 +9556,7 @@ fn rule_255<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F32(Box::new(AST_F32 { tok, initializer }))
 }
 
-fn rule_256<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_265<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9402,7 +9567,7 @@ fn rule_256<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F64(Box::new(AST_F64 { tok, initializer }))
 }
 
-fn rule_257<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_266<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9412,7 +9577,7 @@ fn rule_257<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F64(Box::new(AST_F64 { tok, initializer }))
 }
 
-fn rule_258<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_267<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9423,7 +9588,7 @@ fn rule_258<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F128(Box::new(AST_F128 { tok, initializer }))
 }
 
-fn rule_259<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_268<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9433,7 +9598,7 @@ fn rule_259<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_F128(Box::new(AST_F128 { tok, initializer }))
 }
 
-fn rule_260<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_269<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let value = true;
@@ -9441,7 +9606,7 @@ fn rule_260<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_BoolLiteral(Box::new(AST_BoolLiteral { value }))
 }
 
-fn rule_261<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_270<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let value = false;
@@ -9449,7 +9614,7 @@ fn rule_261<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_BoolLiteral(Box::new(AST_BoolLiteral { value }))
 }
 
-fn rule_262<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_271<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let value = nodes[0].clone();
@@ -9459,7 +9624,7 @@ fn rule_262<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_NumberLiteral(Box::new(AST_NumberLiteral { value }))
 }
 
-fn rule_263<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_272<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9471,7 +9636,7 @@ fn rule_263<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_StringLiteral(Box::new(AST_StringLiteral { tok, value }))
 }
 
-fn rule_264<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_273<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let end_trim = nodes[3].clone();
@@ -9485,7 +9650,7 @@ fn rule_264<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Range(Box::new(Range { end_trim, start_trim }))
 }
 
-fn rule_265<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_274<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let end_trim = 0;
@@ -9497,14 +9662,14 @@ fn rule_265<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Range(Box::new(Range { end_trim, start_trim }))
 }
 
-fn rule_266<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_275<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[1].clone();
   let out = out.to_token().unwrap();
   ASTNode::Token(out)
 }
 
-fn rule_267<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_276<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let ty = nodes[0].clone();
@@ -9519,7 +9684,7 @@ fn rule_267<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_Flag(Box::new(AST_Flag { ty, tok, val }))
 }
 
-fn rule_268<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_277<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9539,7 +9704,7 @@ fn rule_268<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Matches(Box::new(Matches { tok, mode, scanner, matches }))
 }
 
-fn rule_269<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_278<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -9558,7 +9723,7 @@ fn rule_269<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Matches(Box::new(Matches { tok, mode, scanner, matches }))
 }
 
-fn rule_270<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_279<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[1].clone();
   let out = out.to_token().unwrap();
@@ -9566,28 +9731,28 @@ fn rule_270<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_271<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_280<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_IntMatch().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_272<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_281<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_DefaultMatch().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_273<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_282<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_FailHint().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_274<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_283<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 21
   to val type: AscriptType
@@ -9607,7 +9772,7 @@ fn rule_274<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_275<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_284<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 21
   to val type: AscriptType
@@ -9627,7 +9792,7 @@ fn rule_275<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_276<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_285<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 21
   to val type: AscriptType
@@ -9647,7 +9812,7 @@ fn rule_276<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_277<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_286<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 21
   to val type: AscriptType
@@ -9673,7 +9838,7 @@ fn rule_277<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_278<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_287<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 21
   to val type: AscriptType
@@ -9699,7 +9864,7 @@ fn rule_278<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_279<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_288<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 21
   to val type: AscriptType
@@ -9725,7 +9890,7 @@ fn rule_279<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_280<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_289<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 22
   to val type: AscriptType
@@ -9744,7 +9909,7 @@ fn rule_280<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_281<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_290<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 22
   to val type: AscriptType
@@ -9765,7 +9930,7 @@ fn rule_281<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_282<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_291<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let matches = std::mem::take(&mut nodes[3]);
@@ -9776,28 +9941,28 @@ fn rule_282<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ProductionMatches(Box::new(ProductionMatches { matches }))
 }
 
-fn rule_283<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_292<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_NonTermMatch().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_284<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_293<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_FailHint().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_285<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_294<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_DefaultMatch().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_286<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_295<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 24
   to val type: AscriptType
@@ -9817,7 +9982,7 @@ fn rule_286<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_287<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_296<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 24
   to val type: AscriptType
@@ -9837,7 +10002,7 @@ fn rule_287<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_288<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_297<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 24
   to val type: AscriptType
@@ -9857,7 +10022,7 @@ fn rule_288<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_289<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_298<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 24
   to val type: AscriptType
@@ -9883,7 +10048,7 @@ fn rule_289<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_290<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_299<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 24
   to val type: AscriptType
@@ -9909,7 +10074,7 @@ fn rule_290<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_291<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_300<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 24
   to val type: AscriptType
@@ -9935,7 +10100,7 @@ fn rule_291<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_292<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_301<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 25
   to val type: AscriptType
@@ -9954,7 +10119,7 @@ fn rule_292<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_293<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_302<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 25
   to val type: AscriptType
@@ -9975,7 +10140,7 @@ fn rule_293<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_294<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_303<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let matches = std::mem::take(&mut nodes[3]);
@@ -9986,28 +10151,28 @@ fn rule_294<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::TerminalMatches(Box::new(TerminalMatches { matches }))
 }
 
-fn rule_295<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_304<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_TermMatch().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_296<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_305<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_FailHint().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_297<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_306<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_DefaultMatch().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_298<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_307<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 26
   to val type: AscriptType
@@ -10027,7 +10192,7 @@ fn rule_298<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_299<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_308<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 26
   to val type: AscriptType
@@ -10047,7 +10212,7 @@ fn rule_299<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_300<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_309<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 26
   to val type: AscriptType
@@ -10067,7 +10232,7 @@ fn rule_300<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_301<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_310<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 26
   to val type: AscriptType
@@ -10093,7 +10258,7 @@ fn rule_301<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_302<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_311<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 26
   to val type: AscriptType
@@ -10119,7 +10284,7 @@ fn rule_302<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_303<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_312<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 26
   to val type: AscriptType
@@ -10145,7 +10310,7 @@ fn rule_303<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_304<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_313<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 27
   to val type: AscriptType
@@ -10164,7 +10329,7 @@ fn rule_304<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_305<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_314<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 27
   to val type: AscriptType
@@ -10185,7 +10350,7 @@ fn rule_305<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_306<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_315<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10204,7 +10369,7 @@ fn rule_306<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Push(Box::new(Push { tok, name, nonterminal }))
 }
 
-fn rule_307<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_316<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10224,7 +10389,7 @@ fn rule_307<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Goto(Box::new(Goto { tok, name, nonterminal }))
 }
 
-fn rule_308<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_317<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10235,7 +10400,7 @@ fn rule_308<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Fork(Box::new(Fork { tok, paths }))
 }
 
-fn rule_309<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_318<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10254,7 +10419,7 @@ fn rule_309<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Goto(Box::new(Goto { tok, name, nonterminal }))
 }
 
-fn rule_310<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_319<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = std::mem::take(&mut nodes[0]);
@@ -10264,7 +10429,7 @@ fn rule_310<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_311<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_320<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = std::mem::take(&mut nodes[1]);
   let out_r = unsafe { out_r.into_Goto().unwrap_unchecked() };
@@ -10275,7 +10440,7 @@ fn rule_311<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_312<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_321<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10289,7 +10454,7 @@ fn rule_312<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::TerminalToken(Box::new(TerminalToken { tok, val, is_exclusive }))
 }
 
-fn rule_313<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_322<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10303,7 +10468,7 @@ fn rule_313<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::TerminalToken(Box::new(TerminalToken { tok, val, is_exclusive }))
 }
 
-fn rule_314<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_323<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10316,7 +10481,7 @@ fn rule_314<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::NonTerminal_Terminal_Symbol(Box::new(NonTerminal_Terminal_Symbol { tok, nonterminal }))
 }
 
-fn rule_315<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_324<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10327,7 +10492,7 @@ fn rule_315<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_316<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_325<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10338,7 +10503,7 @@ fn rule_316<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_317<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_326<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10349,7 +10514,7 @@ fn rule_317<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_318<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_327<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10360,7 +10525,7 @@ fn rule_318<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_319<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_328<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10371,7 +10536,7 @@ fn rule_319<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_320<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_329<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10382,7 +10547,7 @@ fn rule_320<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_321<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_330<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10393,7 +10558,7 @@ fn rule_321<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_322<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_331<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10404,7 +10569,7 @@ fn rule_322<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_323<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_332<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10415,68 +10580,68 @@ fn rule_323<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::ClassSymbol(Box::new(ClassSymbol { tok, val }))
 }
 
-fn rule_324<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_333<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_325<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_334<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_326<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_335<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_327<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_336<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_328<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_337<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_329<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_338<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_330<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_339<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_331<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_340<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_332<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_341<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_333<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_342<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[3]);
@@ -10491,14 +10656,14 @@ fn rule_333<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_334<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_343<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[2]);
@@ -10512,14 +10677,14 @@ fn rule_334<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_335<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_344<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[2]);
@@ -10533,14 +10698,14 @@ fn rule_335<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_336<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_345<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[1]);
@@ -10553,14 +10718,14 @@ fn rule_336<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_337<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_346<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = Default::default();
@@ -10574,14 +10739,14 @@ fn rule_337<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_338<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_347<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = Default::default();
@@ -10594,14 +10759,14 @@ fn rule_338<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_339<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_348<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = Default::default();
@@ -10614,14 +10779,14 @@ fn rule_339<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_340<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_349<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[2]);
@@ -10636,14 +10801,14 @@ fn rule_340<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_341<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_350<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[1]);
@@ -10657,14 +10822,14 @@ fn rule_341<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_342<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_351<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[3]);
@@ -10679,14 +10844,14 @@ fn rule_342<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_343<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_352<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = Default::default();
@@ -10700,14 +10865,14 @@ fn rule_343<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_344<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_353<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[2]);
@@ -10722,14 +10887,14 @@ fn rule_344<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_345<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_354<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[1]);
@@ -10743,14 +10908,14 @@ fn rule_345<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_346<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_355<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[1]);
@@ -10765,14 +10930,14 @@ fn rule_346<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_347<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_356<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbol = std::mem::take(&mut nodes[0]);
   let symbol = unsafe {
-    symbol.into_list_Value/*43*/().unwrap_unchecked()
+    symbol.into_list_Value/*44*/().unwrap_unchecked()
   };
 
   let precedence = std::mem::take(&mut nodes[1]);
@@ -10787,23 +10952,23 @@ fn rule_347<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AnnotatedSymbol(Box::new(AnnotatedSymbol { tok, symbol, precedence, is_optional, reference }))
 }
 
-fn rule_348<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_357<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
-    out.into_list_Value/*43*/().unwrap_unchecked()
+    out.into_list_Value/*44*/().unwrap_unchecked()
   };
   out.into()
 }
 
-fn rule_349<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_358<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbols = std::mem::take(&mut nodes[1]);
   let symbols = unsafe {
-    symbols.into_vec_annotated_symbol_Value/*44*/().unwrap_unchecked()
+    symbols.into_vec_annotated_symbol_Value/*45*/().unwrap_unchecked()
   };
 
   let allow_empty = Default::default();
@@ -10814,14 +10979,14 @@ fn rule_349<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::NotEmptySet(Box::new(NotEmptySet { tok, symbols, allow_empty, unordered }))
 }
 
-fn rule_350<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_359<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbols = std::mem::take(&mut nodes[1]);
   let symbols = unsafe {
-    symbols.into_vec_annotated_symbol_Value/*44*/().unwrap_unchecked()
+    symbols.into_vec_annotated_symbol_Value/*45*/().unwrap_unchecked()
   };
 
   let allow_empty = Default::default();
@@ -10831,14 +10996,14 @@ fn rule_350<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::NotEmptySet(Box::new(NotEmptySet { tok, symbols, allow_empty, unordered }))
 }
 
-fn rule_351<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_360<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
 
   let symbols = std::mem::take(&mut nodes[1]);
   let symbols = unsafe {
-    symbols.into_vec_annotated_symbol_Value/*44*/().unwrap_unchecked()
+    symbols.into_vec_annotated_symbol_Value/*45*/().unwrap_unchecked()
   };
 
   let allow_empty = tokens[2].clone();
@@ -10850,7 +11015,7 @@ fn rule_351<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::NotEmptySet(Box::new(NotEmptySet { tok, symbols, allow_empty, unordered }))
 }
 
-fn rule_352<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_361<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10858,7 +11023,7 @@ fn rule_352<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::EOFSymbol(Box::new(EOFSymbol { tok }))
 }
 
-fn rule_353<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_362<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10871,7 +11036,7 @@ fn rule_353<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Ascript(Box::new(Ascript { tok, ast }))
 }
 
-fn rule_354<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_363<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -10880,7 +11045,7 @@ fn rule_354<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_355<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_364<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10896,7 +11061,7 @@ fn rule_355<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_TrimmedReference(Box::new(AST_TrimmedReference { tok, range, reference }))
 }
 
-fn rule_356<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_365<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10908,7 +11073,7 @@ fn rule_356<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_NamedReference(Box::new(AST_NamedReference { tok, value }))
 }
 
-fn rule_357<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_366<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -10920,7 +11085,7 @@ fn rule_357<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_IndexReference(Box::new(AST_IndexReference { tok, value }))
 }
 
-fn rule_358<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_367<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let expression = std::mem::take(&mut nodes[1]);
@@ -10931,26 +11096,26 @@ fn rule_358<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Init(Box::new(Init { expression }))
 }
 
-fn rule_359<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_368<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_360<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_369<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_361<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_370<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[1].clone();
   let out = out.to_token().unwrap();
   ASTNode::Token(out)
 }
 
-fn rule_362<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_371<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let vals = std::mem::take(&mut nodes[1]);
@@ -10962,7 +11127,7 @@ fn rule_362<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::IntMatch(Box::new(IntMatch { vals, statement }))
 }
 
-fn rule_363<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_372<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = nodes[0].clone();
   let out = out.to_token().unwrap();
@@ -10970,7 +11135,7 @@ fn rule_363<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_364<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_373<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = std::mem::take(&mut nodes[0]);
@@ -10980,7 +11145,7 @@ fn rule_364<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_365<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_374<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = std::mem::take(&mut nodes[2]);
   let out_r = unsafe { out_r.into_U64().unwrap_unchecked() };
@@ -10991,7 +11156,7 @@ fn rule_365<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_366<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_375<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let statement = std::mem::take(&mut nodes[2]);
@@ -11000,7 +11165,7 @@ fn rule_366<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::DefaultMatch(Box::new(DefaultMatch { statement }))
 }
 
-fn rule_367<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_376<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let statement = std::mem::take(&mut nodes[1]);
@@ -11009,7 +11174,7 @@ fn rule_367<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::DefaultMatch(Box::new(DefaultMatch { statement }))
 }
 
-fn rule_368<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_377<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let message = nodes[2].clone();
@@ -11019,7 +11184,7 @@ fn rule_368<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::FailHint(Box::new(FailHint { message }))
 }
 
-fn rule_369<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_378<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym = std::mem::take(&mut nodes[1]);
@@ -11033,7 +11198,7 @@ fn rule_369<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::NonTermMatch(Box::new(NonTermMatch { sym, statement }))
 }
 
-fn rule_370<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_379<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym = std::mem::take(&mut nodes[1]);
@@ -11047,13 +11212,13 @@ fn rule_370<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::TermMatch(Box::new(TermMatch { sym, statement }))
 }
 
-fn rule_371<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_380<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[0].clone();
   ASTNode::Token(out)
 }
 
-fn rule_372<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_381<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11070,7 +11235,7 @@ fn rule_372<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_373<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_382<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11087,7 +11252,7 @@ fn rule_373<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_374<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_383<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11099,7 +11264,7 @@ fn rule_374<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
 
   let optional = Default::default();
 
-  /*to index id: 43
+  /*to index id: 44
   to val type: AscriptType
   to val type: Multi
   to agg agg:
@@ -11111,12 +11276,12 @@ fn rule_374<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   */
   let terminal_symbol = std::mem::take(&mut nodes[2]);
   let terminal_symbol = unsafe { terminal_symbol.into_TerminalToken().unwrap_unchecked() };
-  let terminal_symbol: list_Value<Token>/*43*/= terminal_symbol.into();
+  let terminal_symbol: list_Value<Token>/*44*/= terminal_symbol.into();
 
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_375<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_384<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11128,7 +11293,7 @@ fn rule_375<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
 
   let optional = Default::default();
 
-  /*to index id: 43
+  /*to index id: 44
   to val type: AscriptType
   to val type: Multi
   to agg agg:
@@ -11140,12 +11305,12 @@ fn rule_375<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   */
   let terminal_symbol = std::mem::take(&mut nodes[2]);
   let terminal_symbol = unsafe { terminal_symbol.into_ClassSymbol().unwrap_unchecked() };
-  let terminal_symbol: list_Value<Token>/*43*/= terminal_symbol.into();
+  let terminal_symbol: list_Value<Token>/*44*/= terminal_symbol.into();
 
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_376<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_385<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11162,7 +11327,7 @@ fn rule_376<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_377<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_386<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11174,7 +11339,7 @@ fn rule_377<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
 
   let optional = true;
 
-  /*to index id: 43
+  /*to index id: 44
   to val type: AscriptType
   to val type: Multi
   to agg agg:
@@ -11186,12 +11351,12 @@ fn rule_377<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   */
   let terminal_symbol = std::mem::take(&mut nodes[2]);
   let terminal_symbol = unsafe { terminal_symbol.into_TerminalToken().unwrap_unchecked() };
-  let terminal_symbol: list_Value<Token>/*43*/= terminal_symbol.into();
+  let terminal_symbol: list_Value<Token>/*44*/= terminal_symbol.into();
 
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_378<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_387<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11203,7 +11368,7 @@ fn rule_378<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
 
   let optional = true;
 
-  /*to index id: 43
+  /*to index id: 44
   to val type: AscriptType
   to val type: Multi
   to agg agg:
@@ -11215,12 +11380,12 @@ fn rule_378<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   */
   let terminal_symbol = std::mem::take(&mut nodes[2]);
   let terminal_symbol = unsafe { terminal_symbol.into_ClassSymbol().unwrap_unchecked() };
-  let terminal_symbol: list_Value<Token>/*43*/= terminal_symbol.into();
+  let terminal_symbol: list_Value<Token>/*44*/= terminal_symbol.into();
 
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_379<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_388<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11237,7 +11402,7 @@ fn rule_379<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::List_Rules(Box::new(List_Rules { tok, symbol, optional, terminal_symbol }))
 }
 
-fn rule_380<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_389<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -11246,35 +11411,35 @@ fn rule_380<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_381<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_390<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_TerminalToken().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_382<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_391<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_ClassSymbol().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_383<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_392<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_TerminalToken().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_384<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_393<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_ClassSymbol().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_385<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_394<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym_prec = tokens[1].clone();
@@ -11288,7 +11453,7 @@ fn rule_385<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Precedence(Box::new(Precedence { sym_prec, kot_prec, is_keyword }))
 }
 
-fn rule_386<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_395<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym_prec = 0;
@@ -11301,7 +11466,7 @@ fn rule_386<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Precedence(Box::new(Precedence { sym_prec, kot_prec, is_keyword }))
 }
 
-fn rule_387<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_396<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym_prec = tokens[1].clone();
@@ -11314,7 +11479,7 @@ fn rule_387<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Precedence(Box::new(Precedence { sym_prec, kot_prec, is_keyword }))
 }
 
-fn rule_388<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_397<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym_prec = 0;
@@ -11326,7 +11491,7 @@ fn rule_388<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Precedence(Box::new(Precedence { sym_prec, kot_prec, is_keyword }))
 }
 
-fn rule_389<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_398<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym_prec = tokens[1].clone();
@@ -11339,7 +11504,7 @@ fn rule_389<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Precedence(Box::new(Precedence { sym_prec, kot_prec, is_keyword }))
 }
 
-fn rule_390<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_399<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let sym_prec = 0;
@@ -11351,26 +11516,26 @@ fn rule_390<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Precedence(Box::new(Precedence { sym_prec, kot_prec, is_keyword }))
 }
 
-fn rule_391<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_400<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[1].clone();
   let out: u32 = out.to_string().parse().unwrap_or_default();
   out.into()
 }
 
-fn rule_392<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_401<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = 0;
   out.into()
 }
 
-fn rule_393<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_402<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = tokens[1].clone();
   ASTNode::Token(out)
 }
 
-fn rule_394<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_403<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -11379,7 +11544,7 @@ fn rule_394<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_395<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_404<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*1*//*to index id: 39
   to val type: AscriptType
@@ -11401,7 +11566,7 @@ fn rule_395<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_396<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_405<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /*to index id: 39
   to val type: AscriptType
@@ -11429,7 +11594,7 @@ fn rule_396<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_397<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_406<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -11438,14 +11603,14 @@ fn rule_397<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_398<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_407<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_AST_Token().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_399<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_408<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -11454,7 +11619,7 @@ fn rule_399<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_400<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_409<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -11463,14 +11628,14 @@ fn rule_400<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_401<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_410<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe { out.into_Template_NonTerminal_Symbol().unwrap_unchecked() };
   out.into()
 }
 
-fn rule_402<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_411<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
@@ -11479,7 +11644,7 @@ fn rule_402<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_403<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_412<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let tok = nterm_tok.clone();
@@ -11497,7 +11662,7 @@ fn rule_403<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::Template_NonTerminal_Symbol(Box::new(Template_NonTerminal_Symbol { tok, name, template_args }))
 }
 
-fn rule_404<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_413<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   /* 1 */
   let out_0 = std::mem::take(&mut nodes[0]);
@@ -11509,7 +11674,7 @@ fn rule_404<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_405<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_414<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out_r = std::mem::take(&mut nodes[2]);
   let out_r = unsafe {
@@ -11524,16 +11689,16 @@ fn rule_405<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   out.into()
 }
 
-fn rule_406<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_415<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
   let out = std::mem::take(&mut nodes[0]);
   let out = unsafe {
-    out.into_list_Value/*43*/().unwrap_unchecked()
+    out.into_list_Value/*44*/().unwrap_unchecked()
   };
   out.into()
 }
 
-fn rule_407<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
+fn rule_416<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok: Token) -> ASTNode<Token> {
   let nodes = unsafe { &mut *nodes };
 
   let typ = nterm_tok.clone();
@@ -11542,7 +11707,7 @@ fn rule_407<Token: Tk>(nodes: *mut [ASTNode<Token>], tokens: &[Token], nterm_tok
   ASTNode::AST_STRUCT_TEMPLATE_NAME(Box::new(AST_STRUCT_TEMPLATE_NAME { typ }))
 }
 
-pub struct ReduceRules<Token: Tk>(pub [Reducer<Token, ASTNode<Token>>; 408]);
+pub struct ReduceRules<Token: Tk>(pub [Reducer<Token, ASTNode<Token>>; 417]);
 
 impl<Token: Tk> ReduceRules<Token> {
   pub const fn new() -> Self {
@@ -11580,7 +11745,7 @@ impl<Token: Tk> ReduceRules<Token> {
       rule_369, rule_370, rule_371, rule_372, rule_373, rule_374, rule_375, rule_376, rule_377, rule_378, rule_379, rule_380,
       rule_381, rule_382, rule_383, rule_384, rule_385, rule_386, rule_387, rule_388, rule_389, rule_390, rule_391, rule_392,
       rule_393, rule_394, rule_395, rule_396, rule_397, rule_398, rule_399, rule_400, rule_401, rule_402, rule_403, rule_404,
-      rule_405, rule_406, rule_407,
+      rule_405, rule_406, rule_407, rule_408, rule_409, rule_410, rule_411, rule_412, rule_413, rule_414, rule_415, rule_416,
     ])
   }
 }
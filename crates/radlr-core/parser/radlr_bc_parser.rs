@@ -43,55 +43,58 @@ const BINARY: &'static [u8] = include_bytes!("./radlr_bc_parser.bin");
 const NONTERM_NAME_TO_ID: [(&'static str, u32); 6] =
   [("ast_expression", 9), ("ast_struct", 10), ("escaped", 1), ("grammar", 4), ("ir", 0), ("type_eval", 8)];
 
-const TOKEN_ID_TO_STRING: [(u32, &'static str); 141] = [
+const TOKEN_ID_TO_STRING: [(u32, &'static str); 144] = [
   (0, r###"Default"###),
   (1, r###"c:sp"###),
   (10, r###"{"###),
-  (100, r###"u128"###),
-  (101, r###"true"###),
-  (102, r###"false"###),
-  (103, r###"match"###),
-  (104, r###"<id>"###),
-  (105, r###"PRODUCTION"###),
-  (106, r###"TERMINAL"###),
-  (107, r###"push"###),
-  (108, r###"goto"###),
-  (109, r###"fork"###),
+  (100, r###"u16"###),
+  (101, r###"f128"###),
+  (102, r###"i128"###),
+  (103, r###"u128"###),
+  (104, r###"true"###),
+  (105, r###"false"###),
+  (106, r###"match"###),
+  (107, r###"<id>"###),
+  (108, r###"PRODUCTION"###),
+  (109, r###"TERMINAL"###),
   (11, r###"}"###),
-  (110, r###"tk:"###),
-  (111, r###"tk:(c:id+)"###),
-  (112, r###"c:"###),
-  (113, r###"id"###),
-  (114, r###"nl"###),
-  (115, r###"sp"###),
-  (116, r###"tab"###),
-  (117, r###"num"###),
-  (118, r###"any"###),
-  (119, r###"vtab"###),
+  (110, r###"push"###),
+  (111, r###"goto"###),
+  (112, r###"fork"###),
+  (113, r###"tk:"###),
+  (114, r###"tk:(c:id+)"###),
+  (115, r###"c:"###),
+  (116, r###"id"###),
+  (117, r###"nl"###),
+  (118, r###"sp"###),
+  (119, r###"tab"###),
   (12, r###"<type_identifier>"###),
-  (120, r###"?"###),
-  (121, r###"<list>"###),
-  (122, r###"<precedence>"###),
-  (123, r###"<reference>"###),
-  (124, r###"!"###),
-  (125, r###"]!?"###),
-  (126, r###"<not_empty_set>"###),
-  (127, r###"$"###),
-  (128, r###"tk:num_tok"###),
-  (129, r###"tk:string_tok"###),
+  (120, r###"num"###),
+  (121, r###"any"###),
+  (122, r###"vtab"###),
+  (123, r###"?"###),
+  (124, r###"<precedence>"###),
+  (125, r###"<list>"###),
+  (126, r###"<reference>"###),
+  (127, r###"!"###),
+  (128, r###"]!?"###),
+  (129, r###"<not_empty_set>"###),
   (13, r###"then"###),
-  (130, r###"tk:( 'f' "_"{:9999} )"###),
-  (131, r###"default"###),
-  (132, r###"fail-hint"###),
-  (133, r###"<string>"###),
-  (134, r###"<terminal>"###),
-  (135, r###"tk:quote_tok"###),
-  (136, r###"(*"###),
-  (137, r###"(+"###),
-  (138, r###"tk:precedence_num"###),
-  (139, r###"kw"###),
+  (130, r###"$"###),
+  (131, r###"tk:num_tok"###),
+  (132, r###"tk:string_tok"###),
+  (133, r###"tk:( 'f' "_"{:9999} )"###),
+  (134, r###"default"###),
+  (135, r###"fail-hint"###),
+  (136, r###"<string>"###),
+  (137, r###"<terminal>"###),
+  (138, r###"tk:quote_tok"###),
+  (139, r###"(*"###),
   (14, r###"<transitive_statement>"###),
-  (140, r###"::<"###),
+  (140, r###"(+"###),
+  (141, r###"tk:precedence_num"###),
+  (142, r###"kw"###),
+  (143, r###"::<"###),
   (15, r###"<branch_statement>"###),
   (16, r###"<pop>"###),
   (17, r###"c:id"###),
@@ -132,8 +135,8 @@ const TOKEN_ID_TO_STRING: [(u32, &'static str); 141] = [
   (49, r###"set-line"###),
   (5, r###"<nonterminal_symbol>"###),
   (50, r###"set-tok-len"###),
-  (51, r###"<body>"###),
-  (52, r###"<int>"###),
+  (51, r###"<int>"###),
+  (52, r###"<body>"###),
   (53, r###"pop"###),
   (54, r###"tok"###),
   (55, r###"peek"###),
@@ -148,2402 +151,1923 @@ const TOKEN_ID_TO_STRING: [(u32, &'static str); 141] = [
   (63, r###"IMPORT"###),
   (64, r###"NAME"###),
   (65, r###"IGNORE"###),
-  (66, r###"|"###),
-  (67, r###"sym"###),
-  (68, r###"ast"###),
-  (69, r###"tk"###),
+  (66, r###"LEFT"###),
+  (67, r###"RIGHT"###),
+  (68, r###"NONASSOC"###),
+  (69, r###"|"###),
   (7, r###"<block>"###),
-  (70, r###"token"###),
-  (71, r###"<range>"###),
-  (72, r###"tk:int_tok"###),
-  (73, r###"fail"###),
-  (74, r###"pass"###),
-  (75, r###"accept"###),
-  (76, r###"tk:("###),
-  (77, r###"<ast_definition>"###),
-  (78, r###"<end_of_input>"###),
-  (79, r###"::"###),
+  (70, r###"sym"###),
+  (71, r###"ast"###),
+  (72, r###"tk"###),
+  (73, r###"token"###),
+  (74, r###"<range>"###),
+  (75, r###"tk:int_tok"###),
+  (76, r###"fail"###),
+  (77, r###"pass"###),
+  (78, r###"accept"###),
+  (79, r###"tk:("###),
   (8, r###"{EOF}"###),
-  (80, r###"."###),
-  (81, r###"map"###),
-  (82, r###"["###),
-  (83, r###"]"###),
-  (84, r###"str"###),
-  (85, r###"<convert_initializer>"###),
-  (86, r###"bool"###),
-  (87, r###"i8"###),
-  (88, r###"u8"###),
-  (89, r###"f32"###),
+  (80, r###"<ast_definition>"###),
+  (81, r###"<end_of_input>"###),
+  (82, r###"::"###),
+  (83, r###"."###),
+  (84, r###"map"###),
+  (85, r###"["###),
+  (86, r###"]"###),
+  (87, r###"str"###),
+  (88, r###"<convert_initializer>"###),
+  (89, r###"bool"###),
   (9, r###","###),
-  (90, r###"i32"###),
-  (91, r###"u32"###),
-  (92, r###"f64"###),
-  (93, r###"i64"###),
-  (94, r###"u64"###),
-  (95, r###"f16"###),
-  (96, r###"i16"###),
-  (97, r###"u16"###),
-  (98, r###"f128"###),
-  (99, r###"i128"###),
+  (90, r###"i8"###),
+  (91, r###"u8"###),
+  (92, r###"f32"###),
+  (93, r###"i32"###),
+  (94, r###"u32"###),
+  (95, r###"f64"###),
+  (96, r###"i64"###),
+  (97, r###"u64"###),
+  (98, r###"f16"###),
+  (99, r###"i16"###),
 ];
 
-const NONTERM_ID_TO_ADDRESS: [(u32, u32); 6] = [(0, 8), (1, 439811), (10, 615311), (4, 440630), (8, 614318), (9, 614468)];
-
-const STATE_TO_TOKEN_IDS: [(u32, &'static [u32]); 1995] = [
-  (100041, &TOKENS_10),
-  (10054, &TOKENS_29),
-  (100834, &TOKENS_75),
-  (100954, &TOKENS_10),
-  (10122, &TOKENS_9),
-  (101452, &TOKENS_144),
-  (101492, &TOKENS_0),
-  (101645, &TOKENS_88),
-  (101734, &TOKENS_121),
-  (101811, &TOKENS_26),
-  (101917, &TOKENS_18),
-  (102024, &TOKENS_96),
-  (102096, &TOKENS_40),
-  (102162, &TOKENS_18),
-  (102228, &TOKENS_79),
-  (10227, &TOKENS_9),
-  (102346, &TOKENS_40),
-  (102412, &TOKENS_26),
-  (102477, &TOKENS_33),
-  (102582, &TOKENS_105),
-  (102629, &TOKENS_17),
-  (102847, &TOKENS_34),
-  (10291, &TOKENS_9),
-  (102924, &TOKENS_17),
-  (103142, &TOKENS_153),
-  (103278, &TOKENS_20),
-  (103325, &TOKENS_20),
-  (103372, &TOKENS_20),
-  (103419, &TOKENS_20),
-  (103466, &TOKENS_20),
-  (103513, &TOKENS_20),
-  (103560, &TOKENS_20),
-  (103607, &TOKENS_20),
-  (103654, &TOKENS_20),
-  (103701, &TOKENS_20),
-  (103748, &TOKENS_20),
-  (10395, &TOKENS_22),
-  (104091, &TOKENS_114),
-  (104157, &TOKENS_43),
-  (10443, &TOKENS_48),
-  (104664, &TOKENS_67),
-  (104718, &TOKENS_15),
-  (104880, &TOKENS_34),
-  (104957, &TOKENS_17),
-  (105175, &TOKENS_153),
-  (10525, &TOKENS_84),
-  (105797, &TOKENS_67),
-  (105851, &TOKENS_161),
-  (105898, &TOKENS_59),
-  (105957, &TOKENS_49),
-  (10613, &TOKENS_9),
-  (10671, &TOKENS_84),
-  (107149, &TOKENS_50),
-  (10719, &TOKENS_5),
-  (10795, &TOKENS_84),
-  (108147, &TOKENS_0),
-  (108214, &TOKENS_60),
-  (108289, &TOKENS_37),
-  (10842, &TOKENS_29),
-  (108453, &TOKENS_61),
-  (108522, &TOKENS_3),
-  (10910, &TOKENS_9),
-  (109370, &TOKENS_145),
-  (109416, &TOKENS_60),
-  (109491, &TOKENS_37),
-  (10968, &TOKENS_84),
-  (109779, &TOKENS_60),
-  (1098, &TOKENS_17),
-  (109855, &TOKENS_37),
-  (109995, &TOKENS_60),
-  (110007, &TOKENS_61),
-  (110076, &TOKENS_3),
-  (11057, &TOKENS_9),
-  (110924, &TOKENS_10),
-  (11115, &TOKENS_84),
-  (11163, &TOKENS_9),
-  (111754, &TOKENS_10),
-  (11221, &TOKENS_84),
-  (112584, &TOKENS_10),
-  (11309, &TOKENS_84),
-  (113414, &TOKENS_10),
-  (11381, &TOKENS_124),
-  (11441, &TOKENS_22),
-  (114834, &TOKENS_7),
-  (11489, &TOKENS_86),
-  (114970, &TOKENS_10),
-  (11549, &TOKENS_22),
-  (11597, &TOKENS_146),
-  (116095, &TOKENS_7),
-  (116225, &TOKENS_10),
-  (11674, &TOKENS_14),
-  (117055, &TOKENS_10),
-  (11780, &TOKENS_22),
-  (118180, &TOKENS_7),
-  (11828, &TOKENS_48),
-  (118286, &TOKENS_10),
-  (11910, &TOKENS_86),
-  (119116, &TOKENS_10),
-  (11998, &TOKENS_9),
-  (120241, &TOKENS_7),
-  (120347, &TOKENS_10),
-  (12056, &TOKENS_86),
-  (12104, &TOKENS_5),
-  (121177, &TOKENS_10),
-  (12180, &TOKENS_86),
-  (12227, &TOKENS_29),
-  (122302, &TOKENS_7),
-  (122432, &TOKENS_10),
-  (12295, &TOKENS_9),
-  (123262, &TOKENS_10),
-  (12353, &TOKENS_86),
-  (124387, &TOKENS_7),
-  (12442, &TOKENS_9),
-  (124493, &TOKENS_10),
-  (12500, &TOKENS_86),
-  (125323, &TOKENS_10),
-  (12548, &TOKENS_9),
-  (12606, &TOKENS_86),
-  (126448, &TOKENS_7),
-  (126518, &TOKENS_10),
-  (12694, &TOKENS_86),
-  (127348, &TOKENS_10),
-  (12754, &TOKENS_22),
-  (12802, &TOKENS_114),
-  (128178, &TOKENS_10),
-  (12868, &TOKENS_43),
-  (129324, &TOKENS_2),
-  (12934, &TOKENS_22),
-  (129454, &TOKENS_10),
-  (130579, &TOKENS_2),
-  (130709, &TOKENS_10),
-  (13122, &TOKENS_34),
-  (131539, &TOKENS_10),
-  (1316, &TOKENS_17),
-  (132664, &TOKENS_2),
-  (132770, &TOKENS_10),
-  (13300, &TOKENS_167),
-  (13359, &TOKENS_17),
-  (133600, &TOKENS_10),
-  (134725, &TOKENS_2),
-  (134831, &TOKENS_10),
-  (135661, &TOKENS_10),
-  (136786, &TOKENS_2),
-  (136916, &TOKENS_10),
-  (137746, &TOKENS_10),
-  (13884, &TOKENS_105),
-  (138871, &TOKENS_2),
-  (138941, &TOKENS_10),
-  (13943, &TOKENS_141),
-  (139771, &TOKENS_10),
-  (14002, &TOKENS_55),
-  (140896, &TOKENS_2),
-  (141002, &TOKENS_10),
-  (141832, &TOKENS_60),
-  (141844, &TOKENS_10),
-  (14197, &TOKENS_22),
-  (14245, &TOKENS_57),
-  (142674, &TOKENS_10),
-  (143504, &TOKENS_10),
-  (14375, &TOKENS_84),
-  (144334, &TOKENS_10),
-  (14447, &TOKENS_124),
-  (14513, &TOKENS_86),
-  (145459, &TOKENS_7),
-  (145595, &TOKENS_10),
-  (14579, &TOKENS_146),
-  (146425, &TOKENS_10),
-  (14656, &TOKENS_14),
-  (147255, &TOKENS_10),
-  (14802, &TOKENS_86),
-  (148085, &TOKENS_10),
-  (14868, &TOKENS_104),
-  (149210, &TOKENS_7),
-  (149340, &TOKENS_10),
-  (14986, &TOKENS_124),
-  (150170, &TOKENS_10),
-  (15052, &TOKENS_14),
-  (151, &TOKENS_117),
-  (151295, &TOKENS_7),
-  (151401, &TOKENS_10),
-  (15157, &TOKENS_20),
-  (152231, &TOKENS_10),
-  (15262, &TOKENS_45),
-  (153356, &TOKENS_7),
-  (1534, &TOKENS_17),
-  (153462, &TOKENS_10),
-  (15371, &TOKENS_45),
-  (154587, &TOKENS_7),
-  (154693, &TOKENS_10),
-  (15480, &TOKENS_20),
-  (155523, &TOKENS_10),
-  (15575, &TOKENS_45),
-  (156648, &TOKENS_7),
-  (156778, &TOKENS_10),
-  (15679, &TOKENS_57),
-  (157608, &TOKENS_10),
-  (15828, &TOKENS_67),
-  (158438, &TOKENS_10),
-  (15886, &TOKENS_57),
-  (15934, &TOKENS_67),
-  (159563, &TOKENS_7),
-  (159633, &TOKENS_10),
-  (15992, &TOKENS_57),
-  (16040, &TOKENS_57),
-  (160463, &TOKENS_10),
-  (16087, &TOKENS_45),
-  (161293, &TOKENS_10),
-  (16191, &TOKENS_57),
-  (162123, &TOKENS_31),
-  (162210, &TOKENS_4),
-  (162261, &TOKENS_27),
-  (162336, &TOKENS_4),
-  (162387, &TOKENS_19),
-  (162450, &TOKENS_7),
-  (162462, &TOKENS_0),
-  (162845, &TOKENS_2),
-  (162975, &TOKENS_10),
-  (16340, &TOKENS_67),
-  (163805, &TOKENS_10),
-  (16398, &TOKENS_57),
-  (16446, &TOKENS_67),
-  (164635, &TOKENS_10),
-  (16504, &TOKENS_57),
-  (165465, &TOKENS_10),
-  (16552, &TOKENS_57),
-  (16599, &TOKENS_20),
-  (166590, &TOKENS_2),
-  (166696, &TOKENS_10),
-  (167821, &TOKENS_2),
-  (167927, &TOKENS_10),
-  (168757, &TOKENS_10),
-  (169882, &TOKENS_2),
-  (169988, &TOKENS_10),
-  (170818, &TOKENS_10),
-  (17087, &TOKENS_119),
-  (17146, &TOKENS_67),
-  (17194, &TOKENS_20),
-  (171943, &TOKENS_2),
-  (172013, &TOKENS_10),
-  (17242, &TOKENS_70),
-  (172843, &TOKENS_10),
-  (17301, &TOKENS_123),
-  (173968, &TOKENS_2),
-  (174098, &TOKENS_10),
-  (174928, &TOKENS_10),
-  (1752, &TOKENS_15),
-  (176053, &TOKENS_2),
-  (176183, &TOKENS_10),
-  (177013, &TOKENS_10),
-  (177843, &TOKENS_148),
-  (177918, &TOKENS_101),
-  (177930, &TOKENS_148),
-  (178005, &TOKENS_101),
-  (178017, &TOKENS_148),
-  (178092, &TOKENS_101),
-  (178228, &TOKENS_61),
-  (178297, &TOKENS_3),
-  (17872, &TOKENS_33),
-  (179145, &TOKENS_10),
-  (17977, &TOKENS_45),
-  (179975, &TOKENS_10),
-  (180805, &TOKENS_10),
-  (18086, &TOKENS_45),
-  (181635, &TOKENS_10),
-  (18195, &TOKENS_70),
-  (18254, &TOKENS_119),
-  (182760, &TOKENS_7),
-  (182896, &TOKENS_10),
-  (18313, &TOKENS_88),
-  (18402, &TOKENS_79),
-  (184021, &TOKENS_7),
-  (184151, &TOKENS_10),
-  (18479, &TOKENS_26),
-  (184981, &TOKENS_10),
-  (18544, &TOKENS_48),
-  (186106, &TOKENS_7),
-  (186212, &TOKENS_10),
-  (18626, &TOKENS_40),
-  (187042, &TOKENS_10),
-  (18714, &TOKENS_9),
-  (18772, &TOKENS_40),
-  (188167, &TOKENS_7),
-  (18820, &TOKENS_5),
-  (188273, &TOKENS_10),
-  (18896, &TOKENS_40),
-  (189103, &TOKENS_10),
-  (18943, &TOKENS_29),
-  (19011, &TOKENS_9),
-  (190228, &TOKENS_7),
-  (190358, &TOKENS_10),
-  (19069, &TOKENS_40),
-  (191188, &TOKENS_10),
-  (1914, &TOKENS_17),
-  (19158, &TOKENS_9),
-  (19216, &TOKENS_40),
-  (192313, &TOKENS_7),
-  (192419, &TOKENS_10),
-  (19264, &TOKENS_9),
-  (19322, &TOKENS_40),
-  (193249, &TOKENS_10),
-  (19410, &TOKENS_40),
-  (194374, &TOKENS_7),
-  (194444, &TOKENS_10),
-  (19476, &TOKENS_121),
-  (195274, &TOKENS_10),
-  (19553, &TOKENS_48),
-  (196104, &TOKENS_10),
-  (19635, &TOKENS_18),
-  (19723, &TOKENS_9),
-  (197250, &TOKENS_2),
-  (197380, &TOKENS_10),
-  (19781, &TOKENS_18),
-  (19829, &TOKENS_5),
-  (198505, &TOKENS_2),
-  (198611, &TOKENS_10),
-  (19905, &TOKENS_18),
-  (199441, &TOKENS_10),
-  (19952, &TOKENS_29),
-  (20020, &TOKENS_9),
-  (200566, &TOKENS_2),
-  (200696, &TOKENS_10),
-  (20078, &TOKENS_18),
-  (201526, &TOKENS_10),
-  (20167, &TOKENS_9),
-  (20225, &TOKENS_18),
-  (202651, &TOKENS_2),
-  (20273, &TOKENS_9),
-  (202781, &TOKENS_10),
-  (20331, &TOKENS_18),
-  (203611, &TOKENS_10),
-  (20419, &TOKENS_18),
-  (204736, &TOKENS_2),
-  (204842, &TOKENS_10),
-  (20485, &TOKENS_26),
-  (20550, &TOKENS_48),
-  (205672, &TOKENS_10),
-  (20632, &TOKENS_96),
-  (206797, &TOKENS_2),
-  (206867, &TOKENS_10),
-  (20720, &TOKENS_9),
-  (207697, &TOKENS_10),
-  (20778, &TOKENS_96),
-  (20826, &TOKENS_5),
-  (208822, &TOKENS_2),
-  (208928, &TOKENS_10),
-  (20902, &TOKENS_96),
-  (20949, &TOKENS_29),
-  (209758, &TOKENS_60),
-  (209770, &TOKENS_60),
-  (209782, &TOKENS_9),
-  (21, &TOKENS_0),
-  (21017, &TOKENS_9),
-  (210228, &TOKENS_52),
-  (210347, &TOKENS_10),
-  (21075, &TOKENS_96),
-  (211177, &TOKENS_10),
-  (21164, &TOKENS_9),
-  (212007, &TOKENS_10),
-  (21222, &TOKENS_96),
-  (21270, &TOKENS_9),
-  (213132, &TOKENS_52),
-  (21328, &TOKENS_96),
-  (213475, &TOKENS_52),
-  (213571, &TOKENS_10),
-  (21416, &TOKENS_96),
-  (214696, &TOKENS_52),
-  (214792, &TOKENS_10),
-  (21488, &TOKENS_18),
-  (21554, &TOKENS_40),
-  (215622, &TOKENS_10),
-  (21620, &TOKENS_88),
-  (216452, &TOKENS_10),
-  (21709, &TOKENS_121),
-  (217577, &TOKENS_52),
-  (217673, &TOKENS_10),
-  (21786, &TOKENS_26),
-  (218503, &TOKENS_10),
-  (21892, &TOKENS_18),
-  (21958, &TOKENS_79),
-  (219628, &TOKENS_52),
-  (219688, &TOKENS_10),
-  (220518, &TOKENS_10),
-  (22076, &TOKENS_40),
-  (22142, &TOKENS_26),
-  (221643, &TOKENS_52),
-  (221763, &TOKENS_10),
-  (22248, &TOKENS_96),
-  (222593, &TOKENS_10),
-  (22320, &TOKENS_40),
-  (223718, &TOKENS_52),
-  (223838, &TOKENS_10),
-  (22386, &TOKENS_18),
-  (22452, &TOKENS_33),
-  (224668, &TOKENS_10),
-  (225498, &TOKENS_10),
-  (22557, &TOKENS_34),
-  (226328, &TOKENS_10),
-  (227158, &TOKENS_10),
-  (22736, &TOKENS_20),
-  (228283, &TOKENS_7),
-  (228419, &TOKENS_10),
-  (229249, &TOKENS_10),
-  (230079, &TOKENS_10),
-  (23080, &TOKENS_114),
-  (230909, &TOKENS_10),
-  (23146, &TOKENS_43),
-  (232034, &TOKENS_7),
-  (23212, &TOKENS_17),
-  (232164, &TOKENS_10),
-  (232994, &TOKENS_10),
-  (234119, &TOKENS_7),
-  (234225, &TOKENS_10),
-  (23430, &TOKENS_15),
-  (235055, &TOKENS_10),
-  (236180, &TOKENS_7),
-  (236286, &TOKENS_10),
-  (237116, &TOKENS_10),
-  (238241, &TOKENS_7),
-  (238371, &TOKENS_10),
-  (23888, &TOKENS_114),
-  (239201, &TOKENS_10),
-  (23954, &TOKENS_43),
-  (24020, &TOKENS_34),
-  (240326, &TOKENS_7),
-  (240432, &TOKENS_10),
-  (241262, &TOKENS_10),
-  (24198, &TOKENS_64),
-  (242387, &TOKENS_7),
-  (242457, &TOKENS_10),
-  (243287, &TOKENS_10),
-  (244117, &TOKENS_10),
-  (244947, &TOKENS_106),
-  (245005, &TOKENS_10),
-  (245835, &TOKENS_10),
-  (24663, &TOKENS_70),
-  (246665, &TOKENS_10),
-  (24722, &TOKENS_123),
-  (247495, &TOKENS_10),
-  (248325, &TOKENS_10),
-  (249450, &TOKENS_7),
-  (24951, &TOKENS_82),
-  (249586, &TOKENS_10),
-  (25016, &TOKENS_49),
-  (250416, &TOKENS_10),
-  (251246, &TOKENS_10),
-  (252076, &TOKENS_10),
-  (253201, &TOKENS_7),
-  (253331, &TOKENS_10),
-  (254161, &TOKENS_10),
-  (255286, &TOKENS_7),
-  (255392, &TOKENS_10),
-  (25558, &TOKENS_50),
-  (256222, &TOKENS_10),
-  (2573, &TOKENS_119),
-  (257347, &TOKENS_7),
-  (257453, &TOKENS_10),
-  (258283, &TOKENS_10),
-  (259408, &TOKENS_7),
-  (259538, &TOKENS_10),
-  (260368, &TOKENS_10),
-  (26074, &TOKENS_10),
-  (261493, &TOKENS_7),
-  (261599, &TOKENS_10),
-  (262429, &TOKENS_10),
-  (2632, &TOKENS_0),
-  (263554, &TOKENS_7),
-  (263624, &TOKENS_10),
-  (264454, &TOKENS_10),
-  (265284, &TOKENS_10),
-  (26572, &TOKENS_10),
-  (266409, &TOKENS_32),
-  (266539, &TOKENS_10),
-  (267664, &TOKENS_7),
-  (267801, &TOKENS_10),
-  (268631, &TOKENS_10),
-  (269461, &TOKENS_10),
-  (270291, &TOKENS_10),
-  (271416, &TOKENS_32),
-  (271546, &TOKENS_10),
-  (2723, &TOKENS_67),
-  (272376, &TOKENS_10),
-  (273501, &TOKENS_32),
-  (273607, &TOKENS_10),
-  (27365, &TOKENS_32),
-  (27405, &TOKENS_170),
-  (274437, &TOKENS_10),
-  (27492, &TOKENS_4),
-  (27538, &TOKENS_27),
-  (275562, &TOKENS_32),
-  (275668, &TOKENS_10),
-  (27618, &TOKENS_27),
-  (276498, &TOKENS_10),
-  (27694, &TOKENS_4),
-  (2771, &TOKENS_141),
-  (277623, &TOKENS_32),
-  (277753, &TOKENS_10),
-  (27786, &TOKENS_19),
-  (278583, &TOKENS_10),
-  (27891, &TOKENS_0),
-  (279708, &TOKENS_32),
-  (279814, &TOKENS_10),
-  (28004, &TOKENS_0),
-  (280644, &TOKENS_10),
-  (28118, &TOKENS_60),
-  (28158, &TOKENS_6),
-  (281769, &TOKENS_32),
-  (281839, &TOKENS_10),
-  (2819, &TOKENS_38),
-  (282669, &TOKENS_52),
-  (282681, &TOKENS_6),
-  (283528, &TOKENS_10),
-  (284358, &TOKENS_10),
-  (285188, &TOKENS_10),
-  (286018, &TOKENS_10),
-  (28673, &TOKENS_10),
-  (287143, &TOKENS_7),
-  (287279, &TOKENS_10),
-  (2878, &TOKENS_17),
-  (288109, &TOKENS_10),
-  (288939, &TOKENS_10),
-  (289769, &TOKENS_10),
-  (290894, &TOKENS_7),
-  (291024, &TOKENS_10),
-  (29171, &TOKENS_10),
-  (291854, &TOKENS_10),
-  (292979, &TOKENS_7),
-  (293085, &TOKENS_10),
-  (293915, &TOKENS_10),
-  (295040, &TOKENS_7),
-  (295146, &TOKENS_10),
-  (295976, &TOKENS_10),
-  (29669, &TOKENS_6),
-  (297101, &TOKENS_7),
-  (297231, &TOKENS_10),
-  (298061, &TOKENS_10),
-  (299186, &TOKENS_7),
-  (299292, &TOKENS_10),
-  (300122, &TOKENS_10),
-  (301247, &TOKENS_7),
-  (301317, &TOKENS_10),
-  (302147, &TOKENS_10),
-  (302977, &TOKENS_10),
-  (304119, &TOKENS_35),
-  (304248, &TOKENS_10),
-  (30496, &TOKENS_35),
-  (305078, &TOKENS_10),
-  (305908, &TOKENS_10),
-  (30625, &TOKENS_10),
-  (306738, &TOKENS_10),
-  (307863, &TOKENS_35),
-  (307993, &TOKENS_10),
-  (308823, &TOKENS_10),
-  (3096, &TOKENS_17),
-  (309948, &TOKENS_35),
-  (310054, &TOKENS_10),
-  (310884, &TOKENS_10),
-  (312009, &TOKENS_35),
-  (312079, &TOKENS_10),
-  (312909, &TOKENS_10),
-  (314034, &TOKENS_35),
-  (314164, &TOKENS_10),
-  (31418, &TOKENS_35),
-  (314994, &TOKENS_10),
-  (31524, &TOKENS_10),
-  (316119, &TOKENS_35),
-  (316225, &TOKENS_10),
-  (317055, &TOKENS_10),
-  (318180, &TOKENS_35),
-  (318286, &TOKENS_10),
-  (319116, &TOKENS_115),
-  (319192, &TOKENS_10),
-  (32022, &TOKENS_10),
-  (320322, &TOKENS_35),
-  (320452, &TOKENS_28),
-  (320464, &TOKENS_51),
-  (320529, &TOKENS_93),
-  (320823, &TOKENS_67),
-  (320835, &TOKENS_67),
-  (320910, &TOKENS_69),
-  (320981, &TOKENS_133),
-  (321050, &TOKENS_137),
-  (321114, &TOKENS_67),
-  (321177, &TOKENS_8),
-  (321189, &TOKENS_32),
-  (321201, &TOKENS_51),
-  (321266, &TOKENS_8),
-  (321278, &TOKENS_7),
-  (321290, &TOKENS_51),
-  (321355, &TOKENS_8),
-  (321367, &TOKENS_51),
-  (321432, &TOKENS_8),
-  (321444, &TOKENS_51),
-  (321509, &TOKENS_8),
-  (321521, &TOKENS_51),
-  (321586, &TOKENS_8),
-  (321598, &TOKENS_51),
-  (321663, &TOKENS_8),
-  (321675, &TOKENS_51),
-  (321740, &TOKENS_8),
-  (321752, &TOKENS_51),
-  (321817, &TOKENS_8),
-  (321829, &TOKENS_51),
-  (321894, &TOKENS_8),
-  (321906, &TOKENS_51),
-  (321971, &TOKENS_8),
-  (321983, &TOKENS_51),
-  (322048, &TOKENS_8),
-  (322060, &TOKENS_51),
-  (322125, &TOKENS_8),
-  (322137, &TOKENS_51),
-  (322202, &TOKENS_8),
-  (322214, &TOKENS_51),
-  (322279, &TOKENS_8),
-  (322291, &TOKENS_51),
-  (322356, &TOKENS_8),
-  (322368, &TOKENS_52),
-  (322380, &TOKENS_128),
-  (322392, &TOKENS_122),
-  (322476, &TOKENS_160),
-  (322847, &TOKENS_28),
-  (322966, &TOKENS_10),
-  (323796, &TOKENS_10),
-  (324626, &TOKENS_10),
-  (325456, &TOKENS_10),
-  (326581, &TOKENS_28),
-  (326677, &TOKENS_10),
-  (327507, &TOKENS_10),
-  (32815, &TOKENS_35),
-  (328632, &TOKENS_28),
-  (328692, &TOKENS_10),
-  (32921, &TOKENS_10),
-  (329522, &TOKENS_10),
-  (330647, &TOKENS_28),
-  (330767, &TOKENS_10),
-  (3314, &TOKENS_15),
-  (331597, &TOKENS_10),
-  (332722, &TOKENS_28),
-  (332818, &TOKENS_10),
-  (333648, &TOKENS_10),
-  (33419, &TOKENS_10),
-  (334773, &TOKENS_28),
-  (334893, &TOKENS_10),
-  (335723, &TOKENS_10),
-  (336848, &TOKENS_28),
-  (336944, &TOKENS_10),
-  (337774, &TOKENS_157),
-  (337786, &TOKENS_0),
-  (337853, &TOKENS_53),
-  (337865, &TOKENS_1),
-  (337877, &TOKENS_144),
-  (337917, &TOKENS_0),
-  (338069, &TOKENS_161),
-  (338128, &TOKENS_81),
-  (338176, &TOKENS_125),
-  (338277, &TOKENS_59),
-  (338336, &TOKENS_49),
-  (339528, &TOKENS_53),
-  (339540, &TOKENS_107),
-  (339616, &TOKENS_89),
-  (339664, &TOKENS_4),
-  (339728, &TOKENS_107),
-  (339804, &TOKENS_89),
-  (339852, &TOKENS_4),
-  (339916, &TOKENS_4),
-  (339980, &TOKENS_4),
-  (340373, &TOKENS_53),
-  (340432, &TOKENS_97),
-  (340806, &TOKENS_53),
-  (340853, &TOKENS_53),
-  (340912, &TOKENS_73),
-  (341191, &TOKENS_44),
-  (341203, &TOKENS_44),
-  (341215, &TOKENS_94),
-  (341263, &TOKENS_127),
-  (341335, &TOKENS_94),
-  (341347, &TOKENS_44),
-  (341359, &TOKENS_53),
-  (341406, &TOKENS_4),
-  (341470, &TOKENS_53),
-  (341529, &TOKENS_73),
-  (341808, &TOKENS_44),
-  (341820, &TOKENS_23),
-  (341902, &TOKENS_109),
-  (341972, &TOKENS_109),
-  (342042, &TOKENS_23),
-  (34212, &TOKENS_35),
-  (342124, &TOKENS_109),
-  (342194, &TOKENS_53),
-  (342253, &TOKENS_97),
-  (34282, &TOKENS_10),
-  (342957, &TOKENS_53),
-  (343017, &TOKENS_97),
-  (343394, &TOKENS_53),
-  (343441, &TOKENS_53),
-  (343501, &TOKENS_73),
-  (343783, &TOKENS_44),
-  (343796, &TOKENS_44),
-  (343809, &TOKENS_53),
-  (343869, &TOKENS_73),
-  (344151, &TOKENS_44),
-  (344164, &TOKENS_53),
-  (344211, &TOKENS_53),
-  (344270, &TOKENS_73),
-  (344549, &TOKENS_44),
-  (344561, &TOKENS_44),
-  (344573, &TOKENS_83),
-  (344621, &TOKENS_46),
-  (344704, &TOKENS_130),
-  (344800, &TOKENS_116),
-  (344932, &TOKENS_117),
-  (345474, &TOKENS_9),
-  (345527, &TOKENS_21),
-  (345539, &TOKENS_103),
-  (345587, &TOKENS_117),
-  (346129, &TOKENS_9),
-  (346182, &TOKENS_21),
-  (346194, &TOKENS_103),
-  (346242, &TOKENS_30),
-  (346301, &TOKENS_9),
-  (346461, &TOKENS_21),
-  (346587, &TOKENS_21),
-  (346599, &TOKENS_21),
-  (346611, &TOKENS_21),
-  (346623, &TOKENS_21),
-  (346635, &TOKENS_4),
-  (346801, &TOKENS_38),
-  (346861, &TOKENS_103),
-  (346909, &TOKENS_117),
-  (347451, &TOKENS_9),
-  (347505, &TOKENS_4),
-  (347580, &TOKENS_44),
-  (347592, &TOKENS_44),
-  (3476, &TOKENS_17),
-  (347604, &TOKENS_0),
-  (347668, &TOKENS_72),
-  (347746, &TOKENS_44),
-  (347758, &TOKENS_72),
-  (34780, &TOKENS_10),
-  (347836, &TOKENS_116),
-  (347968, &TOKENS_21),
-  (347980, &TOKENS_21),
-  (348099, &TOKENS_21),
-  (348225, &TOKENS_21),
-  (348237, &TOKENS_21),
-  (348249, &TOKENS_21),
-  (348261, &TOKENS_21),
-  (348273, &TOKENS_34),
-  (348351, &TOKENS_67),
-  (348399, &TOKENS_103),
-  (348447, &TOKENS_117),
-  (348989, &TOKENS_9),
-  (349042, &TOKENS_44),
-  (349054, &TOKENS_95),
-  (349066, &TOKENS_72),
-  (349144, &TOKENS_116),
-  (349276, &TOKENS_21),
-  (349288, &TOKENS_21),
-  (349407, &TOKENS_21),
-  (349533, &TOKENS_21),
-  (349545, &TOKENS_21),
-  (349557, &TOKENS_21),
-  (349569, &TOKENS_21),
-  (349581, &TOKENS_66),
-  (349741, &TOKENS_17),
-  (349959, &TOKENS_17),
-  (350177, &TOKENS_17),
-  (350395, &TOKENS_15),
-  (350557, &TOKENS_17),
-  (351216, &TOKENS_38),
-  (351275, &TOKENS_17),
-  (351493, &TOKENS_17),
-  (351711, &TOKENS_17),
-  (351929, &TOKENS_15),
-  (352091, &TOKENS_17),
-  (352309, &TOKENS_17),
-  (352527, &TOKENS_15),
-  (352985, &TOKENS_141),
-  (353044, &TOKENS_55),
-  (353239, &TOKENS_17),
-  (353457, &TOKENS_34),
-  (353885, &TOKENS_108),
-  (353936, &TOKENS_55),
-  (354482, &TOKENS_27),
-  (354558, &TOKENS_55),
-  (354753, &TOKENS_17),
-  (354971, &TOKENS_17),
-  (355522, &TOKENS_27),
-  (355592, &TOKENS_22),
-  (35573, &TOKENS_35),
-  (355780, &TOKENS_17),
-  (355998, &TOKENS_34),
-  (356382, &TOKENS_141),
-  (356441, &TOKENS_55),
-  (35679, &TOKENS_10),
-  (357077, &TOKENS_70),
-  (357136, &TOKENS_123),
-  (357707, &TOKENS_88),
-  (357796, &TOKENS_121),
-  (357873, &TOKENS_26),
-  (357938, &TOKENS_48),
-  (358020, &TOKENS_18),
-  (358108, &TOKENS_9),
-  (358166, &TOKENS_18),
-  (358214, &TOKENS_5),
-  (358290, &TOKENS_18),
-  (358337, &TOKENS_29),
-  (358405, &TOKENS_9),
-  (358463, &TOKENS_18),
-  (358552, &TOKENS_9),
-  (358610, &TOKENS_18),
-  (358658, &TOKENS_9),
-  (358716, &TOKENS_18),
-  (358804, &TOKENS_18),
-  (358870, &TOKENS_79),
-  (358947, &TOKENS_26),
-  (359012, &TOKENS_48),
-  (359094, &TOKENS_40),
-  (359182, &TOKENS_9),
-  (359240, &TOKENS_40),
-  (359288, &TOKENS_5),
-  (359364, &TOKENS_40),
-  (359411, &TOKENS_29),
-  (359479, &TOKENS_9),
-  (359537, &TOKENS_40),
-  (359626, &TOKENS_9),
-  (359684, &TOKENS_40),
-  (359732, &TOKENS_9),
-  (359790, &TOKENS_40),
-  (359878, &TOKENS_40),
-  (359944, &TOKENS_48),
-  (360026, &TOKENS_96),
-  (360114, &TOKENS_9),
-  (360172, &TOKENS_96),
-  (360220, &TOKENS_5),
-  (360296, &TOKENS_96),
-  (360343, &TOKENS_29),
-  (360411, &TOKENS_9),
-  (360469, &TOKENS_96),
-  (360558, &TOKENS_9),
-  (360616, &TOKENS_96),
-  (360664, &TOKENS_9),
-  (360722, &TOKENS_96),
-  (360810, &TOKENS_96),
-  (360882, &TOKENS_18),
-  (360948, &TOKENS_40),
-  (361014, &TOKENS_33),
-  (361119, &TOKENS_70),
-  (361178, &TOKENS_119),
-  (361237, &TOKENS_88),
-  (361326, &TOKENS_121),
-  (361403, &TOKENS_79),
-  (361480, &TOKENS_34),
-  (36177, &TOKENS_10),
-  (361853, &TOKENS_17),
-  (362071, &TOKENS_15),
-  (362529, &TOKENS_34),
-  (362707, &TOKENS_64),
-  (363172, &TOKENS_70),
-  (363231, &TOKENS_123),
-  (363460, &TOKENS_82),
-  (363525, &TOKENS_49),
-  (364067, &TOKENS_50),
-  (364583, &TOKENS_10),
-  (365081, &TOKENS_10),
-  (365579, &TOKENS_6),
-  (366094, &TOKENS_10),
-  (366592, &TOKENS_10),
-  (367090, &TOKENS_6),
-  (367917, &TOKENS_35),
-  (368046, &TOKENS_10),
-  (368839, &TOKENS_35),
-  (368945, &TOKENS_10),
-  (3694, &TOKENS_17),
-  (369443, &TOKENS_10),
-  (36970, &TOKENS_35),
-  (370236, &TOKENS_35),
-  (370306, &TOKENS_10),
-  (370804, &TOKENS_10),
-  (37100, &TOKENS_10),
-  (371597, &TOKENS_35),
-  (371703, &TOKENS_10),
-  (372201, &TOKENS_10),
-  (372994, &TOKENS_35),
-  (373124, &TOKENS_10),
-  (373622, &TOKENS_10),
-  (374415, &TOKENS_35),
-  (374521, &TOKENS_10),
-  (375019, &TOKENS_10),
-  (375812, &TOKENS_35),
-  (375942, &TOKENS_10),
-  (37598, &TOKENS_10),
-  (376440, &TOKENS_115),
-  (376516, &TOKENS_10),
-  (377314, &TOKENS_35),
-  (377782, &TOKENS_41),
-  (377911, &TOKENS_10),
-  (378704, &TOKENS_41),
-  (378810, &TOKENS_10),
-  (379308, &TOKENS_10),
-  (380101, &TOKENS_41),
-  (380207, &TOKENS_10),
-  (380705, &TOKENS_10),
-  (381498, &TOKENS_41),
-  (381628, &TOKENS_10),
-  (382126, &TOKENS_10),
-  (382919, &TOKENS_41),
-  (382989, &TOKENS_10),
-  (383487, &TOKENS_10),
-  (38391, &TOKENS_35),
-  (384280, &TOKENS_41),
-  (384410, &TOKENS_10),
-  (384908, &TOKENS_10),
-  (38521, &TOKENS_10),
-  (385701, &TOKENS_41),
-  (385807, &TOKENS_10),
-  (386305, &TOKENS_60),
-  (386381, &TOKENS_37),
-  (386610, &TOKENS_60),
-  (386687, &TOKENS_37),
-  (386893, &TOKENS_61),
-  (386962, &TOKENS_3),
-  (387478, &TOKENS_145),
-  (387565, &TOKENS_10),
-  (388063, &TOKENS_10),
-  (388561, &TOKENS_6),
-  (389392, &TOKENS_2),
-  (389522, &TOKENS_10),
-  (39019, &TOKENS_115),
-  (390315, &TOKENS_2),
-  (390445, &TOKENS_10),
-  (390943, &TOKENS_10),
-  (39095, &TOKENS_10),
-  (3912, &TOKENS_17),
-  (391736, &TOKENS_2),
-  (391842, &TOKENS_10),
-  (392340, &TOKENS_10),
-  (393133, &TOKENS_2),
-  (393263, &TOKENS_10),
-  (393761, &TOKENS_10),
-  (394554, &TOKENS_2),
-  (394660, &TOKENS_10),
-  (395158, &TOKENS_10),
-  (395951, &TOKENS_2),
-  (396057, &TOKENS_10),
-  (396555, &TOKENS_10),
-  (397348, &TOKENS_2),
-  (397418, &TOKENS_10),
-  (397916, &TOKENS_10),
-  (398414, &TOKENS_10),
-  (398912, &TOKENS_10),
-  (39893, &TOKENS_35),
-  (399410, &TOKENS_10),
-  (399908, &TOKENS_6),
-  (40023, &TOKENS_71),
-  (400718, &TOKENS_7),
-  (400854, &TOKENS_10),
-  (40098, &TOKENS_93),
-  (401647, &TOKENS_7),
-  (401777, &TOKENS_10),
-  (402275, &TOKENS_10),
-  (40290, &TOKENS_126),
-  (403068, &TOKENS_7),
-  (403174, &TOKENS_10),
-  (403672, &TOKENS_10),
-  (40405, &TOKENS_126),
-  (404465, &TOKENS_7),
-  (404571, &TOKENS_10),
-  (405069, &TOKENS_10),
-  (40520, &TOKENS_126),
-  (405862, &TOKENS_7),
-  (405992, &TOKENS_10),
-  (406490, &TOKENS_10),
-  (407283, &TOKENS_7),
-  (407389, &TOKENS_10),
-  (407887, &TOKENS_10),
-  (40825, &TOKENS_67),
-  (408680, &TOKENS_7),
-  (408750, &TOKENS_10),
-  (409248, &TOKENS_10),
-  (40929, &TOKENS_132),
-  (409746, &TOKENS_10),
-  (410244, &TOKENS_106),
-  (410302, &TOKENS_10),
-  (41044, &TOKENS_12),
-  (410800, &TOKENS_10),
-  (411298, &TOKENS_10),
-  (41159, &TOKENS_12),
-  (411796, &TOKENS_106),
-  (411854, &TOKENS_10),
-  (412352, &TOKENS_6),
-  (41274, &TOKENS_132),
-  (4130, &TOKENS_15),
-  (413162, &TOKENS_32),
-  (413292, &TOKENS_10),
-  (413790, &TOKENS_10),
-  (41389, &TOKENS_12),
-  (414288, &TOKENS_106),
-  (414346, &TOKENS_10),
-  (41504, &TOKENS_12),
-  (415139, &TOKENS_32),
-  (415269, &TOKENS_10),
-  (415767, &TOKENS_6),
-  (41619, &TOKENS_12),
-  (416577, &TOKENS_7),
-  (416714, &TOKENS_10),
-  (417212, &TOKENS_10),
-  (41734, &TOKENS_71),
-  (418005, &TOKENS_32),
-  (418135, &TOKENS_10),
-  (41849, &TOKENS_171),
-  (418633, &TOKENS_10),
-  (419426, &TOKENS_32),
-  (419532, &TOKENS_10),
-  (41964, &TOKENS_71),
-  (420030, &TOKENS_10),
-  (42079, &TOKENS_172),
-  (420823, &TOKENS_32),
-  (420929, &TOKENS_10),
-  (421722, &TOKENS_32),
-  (421828, &TOKENS_10),
-  (42194, &TOKENS_132),
-  (422326, &TOKENS_10),
-  (42309, &TOKENS_132),
-  (423119, &TOKENS_32),
-  (423249, &TOKENS_10),
-  (423747, &TOKENS_10),
-  (42424, &TOKENS_12),
-  (424245, &TOKENS_10),
-  (425038, &TOKENS_32),
-  (425108, &TOKENS_10),
-  (42539, &TOKENS_12),
-  (425606, &TOKENS_6),
-  (426520, &TOKENS_75),
-  (426639, &TOKENS_10),
-  (427432, &TOKENS_75),
-  (427528, &TOKENS_10),
-  (428026, &TOKENS_10),
-  (428819, &TOKENS_75),
-  (428879, &TOKENS_10),
-  (429377, &TOKENS_10),
-  (42992, &TOKENS_9),
-  (430170, &TOKENS_75),
-  (430290, &TOKENS_10),
-  (43056, &TOKENS_60),
-  (430788, &TOKENS_10),
-  (43132, &TOKENS_37),
-  (431581, &TOKENS_75),
-  (431677, &TOKENS_10),
-  (432175, &TOKENS_10),
-  (432968, &TOKENS_75),
-  (433088, &TOKENS_10),
-  (433586, &TOKENS_10),
-  (43361, &TOKENS_60),
-  (434379, &TOKENS_75),
-  (43438, &TOKENS_37),
-  (434475, &TOKENS_10),
-  (434973, &TOKENS_88),
-  (435062, &TOKENS_79),
-  (435139, &TOKENS_121),
-  (435216, &TOKENS_17),
-  (435434, &TOKENS_34),
-  (436248, &TOKENS_17),
-  (43644, &TOKENS_61),
-  (436466, &TOKENS_15),
-  (436628, &TOKENS_34),
-  (43713, &TOKENS_3),
-  (437146, &TOKENS_34),
-  (437324, &TOKENS_163),
-  (437517, &TOKENS_67),
-  (437565, &TOKENS_103),
-  (437613, &TOKENS_117),
-  (438155, &TOKENS_9),
-  (438208, &TOKENS_44),
-  (438220, &TOKENS_9),
-  (438232, &TOKENS_95),
-  (438334, &TOKENS_34),
-  (438412, &TOKENS_94),
-  (438531, &TOKENS_94),
-  (438543, &TOKENS_34),
-  (438621, &TOKENS_44),
-  (438633, &TOKENS_9),
-  (438645, &TOKENS_103),
-  (438693, &TOKENS_34),
-  (438788, &TOKENS_56),
-  (438800, &TOKENS_56),
-  (438812, &TOKENS_154),
-  (438871, &TOKENS_0),
-  (439073, &TOKENS_56),
-  (439181, &TOKENS_56),
-  (439193, &TOKENS_95),
-  (439205, &TOKENS_9),
-  (439217, &TOKENS_95),
-  (439229, &TOKENS_95),
-  (439241, &TOKENS_117),
-  (439798, &TOKENS_95),
-  (439824, &TOKENS_87),
-  (440116, &TOKENS_138),
-  (440220, &TOKENS_87),
-  (440232, &TOKENS_87),
-  (440351, &TOKENS_87),
-  (440618, &TOKENS_87),
-  (440643, &TOKENS_136),
-  (440846, &TOKENS_0),
-  (440968, &TOKENS_19),
-  (441016, &TOKENS_17),
-  (441234, &TOKENS_17),
-  (441452, &TOKENS_17),
-  (441670, &TOKENS_15),
-  (441832, &TOKENS_17),
-  (44229, &TOKENS_145),
-  (442491, &TOKENS_38),
-  (442550, &TOKENS_17),
-  (442768, &TOKENS_17),
-  (442986, &TOKENS_17),
-  (44316, &TOKENS_10),
-  (443204, &TOKENS_17),
-  (443422, &TOKENS_17),
-  (443640, &TOKENS_15),
-  (443802, &TOKENS_17),
-  (444461, &TOKENS_67),
-  (444509, &TOKENS_141),
-  (444557, &TOKENS_105),
-  (444604, &TOKENS_17),
-  (445263, &TOKENS_67),
-  (445311, &TOKENS_20),
-  (445359, &TOKENS_70),
-  (445418, &TOKENS_55),
-  (445613, &TOKENS_17),
-  (445831, &TOKENS_34),
-  (446259, &TOKENS_108),
-  (446310, &TOKENS_55),
-  (446505, &TOKENS_27),
-  (446581, &TOKENS_55),
-  (447109, &TOKENS_123),
-  (447338, &TOKENS_15),
-  (447796, &TOKENS_141),
-  (447855, &TOKENS_55),
-  (44814, &TOKENS_10),
-  (448401, &TOKENS_27),
-  (448471, &TOKENS_22),
-  (448659, &TOKENS_34),
-  (448736, &TOKENS_17),
-  (449261, &TOKENS_141),
-  (449662, &TOKENS_70),
-  (449721, &TOKENS_34),
-  (449900, &TOKENS_20),
-  (449948, &TOKENS_153),
-  (450084, &TOKENS_20),
-  (450131, &TOKENS_20),
-  (450178, &TOKENS_20),
-  (450225, &TOKENS_20),
-  (450272, &TOKENS_20),
-  (450319, &TOKENS_20),
-  (450366, &TOKENS_20),
-  (450413, &TOKENS_20),
-  (450460, &TOKENS_20),
-  (450507, &TOKENS_20),
-  (450554, &TOKENS_20),
-  (451338, &TOKENS_15),
-  (451500, &TOKENS_34),
-  (451983, &TOKENS_70),
-  (452042, &TOKENS_123),
-  (452271, &TOKENS_82),
-  (452336, &TOKENS_49),
-  (452878, &TOKENS_50),
-  (45312, &TOKENS_6),
-  (453394, &TOKENS_10),
-  (453892, &TOKENS_10),
-  (454390, &TOKENS_6),
-  (454905, &TOKENS_10),
-  (455403, &TOKENS_10),
-  (455901, &TOKENS_6),
-  (456728, &TOKENS_35),
-  (456857, &TOKENS_10),
-  (457650, &TOKENS_35),
-  (457756, &TOKENS_10),
-  (458254, &TOKENS_10),
-  (4588, &TOKENS_141),
-  (459047, &TOKENS_35),
-  (459153, &TOKENS_10),
-  (459651, &TOKENS_10),
-  (460444, &TOKENS_35),
-  (460514, &TOKENS_10),
-  (461012, &TOKENS_10),
-  (46143, &TOKENS_2),
-  (461805, &TOKENS_35),
-  (461935, &TOKENS_10),
-  (462433, &TOKENS_10),
-  (46273, &TOKENS_10),
-  (463226, &TOKENS_35),
-  (463356, &TOKENS_10),
-  (4635, &TOKENS_105),
-  (463854, &TOKENS_10),
-  (464647, &TOKENS_35),
-  (464753, &TOKENS_10),
-  (465251, &TOKENS_115),
-  (465327, &TOKENS_10),
-  (466125, &TOKENS_35),
-  (466593, &TOKENS_60),
-  (466669, &TOKENS_37),
-  (466898, &TOKENS_60),
-  (466975, &TOKENS_37),
-  (467181, &TOKENS_61),
-  (467250, &TOKENS_3),
-  (467766, &TOKENS_145),
-  (467853, &TOKENS_10),
-  (468351, &TOKENS_10),
-  (468849, &TOKENS_6),
-  (4694, &TOKENS_0),
-  (469680, &TOKENS_2),
-  (469810, &TOKENS_10),
-  (470603, &TOKENS_2),
-  (47066, &TOKENS_2),
-  (470733, &TOKENS_10),
-  (471231, &TOKENS_10),
-  (47172, &TOKENS_10),
-  (472024, &TOKENS_2),
-  (472094, &TOKENS_10),
-  (472592, &TOKENS_10),
-  (473385, &TOKENS_2),
-  (473515, &TOKENS_10),
-  (474013, &TOKENS_10),
-  (474806, &TOKENS_2),
-  (474912, &TOKENS_10),
-  (475410, &TOKENS_10),
-  (476203, &TOKENS_2),
-  (476309, &TOKENS_10),
-  (47670, &TOKENS_10),
-  (476807, &TOKENS_10),
-  (477600, &TOKENS_2),
-  (477706, &TOKENS_10),
-  (478204, &TOKENS_41),
-  (478333, &TOKENS_10),
-  (4786, &TOKENS_141),
-  (479126, &TOKENS_41),
-  (479232, &TOKENS_10),
-  (479730, &TOKENS_10),
-  (480523, &TOKENS_41),
-  (480593, &TOKENS_10),
-  (481091, &TOKENS_10),
-  (481884, &TOKENS_41),
-  (481990, &TOKENS_10),
-  (482488, &TOKENS_10),
-  (483281, &TOKENS_41),
-  (4834, &TOKENS_141),
-  (483411, &TOKENS_10),
-  (483909, &TOKENS_10),
-  (48463, &TOKENS_2),
-  (484702, &TOKENS_41),
-  (484832, &TOKENS_10),
-  (485330, &TOKENS_10),
-  (48593, &TOKENS_10),
-  (486123, &TOKENS_41),
-  (486229, &TOKENS_10),
-  (486727, &TOKENS_10),
-  (487225, &TOKENS_10),
-  (487723, &TOKENS_10),
-  (488221, &TOKENS_10),
-  (488719, &TOKENS_6),
-  (4893, &TOKENS_55),
-  (489529, &TOKENS_7),
-  (489665, &TOKENS_10),
-  (490458, &TOKENS_7),
-  (490588, &TOKENS_10),
-  (49091, &TOKENS_10),
-  (491086, &TOKENS_10),
-  (491879, &TOKENS_7),
-  (491985, &TOKENS_10),
-  (492483, &TOKENS_10),
-  (493276, &TOKENS_7),
-  (493382, &TOKENS_10),
-  (493880, &TOKENS_10),
-  (494673, &TOKENS_7),
-  (494803, &TOKENS_10),
-  (495301, &TOKENS_10),
-  (496094, &TOKENS_7),
-  (496200, &TOKENS_10),
-  (496698, &TOKENS_10),
-  (497491, &TOKENS_7),
-  (497561, &TOKENS_10),
-  (498059, &TOKENS_10),
-  (498557, &TOKENS_10),
-  (49884, &TOKENS_2),
-  (499055, &TOKENS_106),
-  (499113, &TOKENS_10),
-  (49954, &TOKENS_10),
-  (499611, &TOKENS_10),
-  (500109, &TOKENS_10),
-  (500607, &TOKENS_106),
-  (500665, &TOKENS_10),
-  (501163, &TOKENS_6),
-  (501973, &TOKENS_32),
-  (502103, &TOKENS_10),
-  (502601, &TOKENS_106),
-  (502659, &TOKENS_10),
-  (503452, &TOKENS_32),
-  (503582, &TOKENS_10),
-  (504080, &TOKENS_6),
-  (50452, &TOKENS_10),
-  (504890, &TOKENS_7),
-  (505027, &TOKENS_10),
-  (505820, &TOKENS_32),
-  (505950, &TOKENS_10),
-  (506448, &TOKENS_10),
-  (507241, &TOKENS_32),
-  (507347, &TOKENS_10),
-  (507845, &TOKENS_10),
-  (508638, &TOKENS_32),
-  (508744, &TOKENS_10),
-  (5088, &TOKENS_17),
-  (509242, &TOKENS_10),
-  (510035, &TOKENS_32),
-  (510165, &TOKENS_10),
-  (510663, &TOKENS_10),
-  (511456, &TOKENS_32),
-  (511562, &TOKENS_10),
-  (512060, &TOKENS_10),
-  (51245, &TOKENS_2),
-  (512853, &TOKENS_32),
-  (512923, &TOKENS_10),
-  (513421, &TOKENS_6),
-  (51351, &TOKENS_10),
-  (514335, &TOKENS_75),
-  (514454, &TOKENS_10),
-  (515247, &TOKENS_75),
-  (515343, &TOKENS_10),
-  (515841, &TOKENS_10),
-  (516634, &TOKENS_75),
-  (516754, &TOKENS_10),
-  (517252, &TOKENS_10),
-  (518045, &TOKENS_75),
-  (518141, &TOKENS_10),
-  (51849, &TOKENS_10),
-  (518639, &TOKENS_10),
-  (519432, &TOKENS_75),
-  (519552, &TOKENS_10),
-  (520050, &TOKENS_10),
-  (520843, &TOKENS_75),
-  (520903, &TOKENS_10),
-  (521401, &TOKENS_10),
-  (522194, &TOKENS_75),
-  (522290, &TOKENS_10),
-  (522788, &TOKENS_17),
-  (523006, &TOKENS_17),
-  (523224, &TOKENS_15),
-  (523827, &TOKENS_34),
-  (523904, &TOKENS_17),
-  (524418, &TOKENS_34),
-  (524495, &TOKENS_17),
-  (525154, &TOKENS_17),
-  (525372, &TOKENS_15),
-  (525830, &TOKENS_114),
-  (525896, &TOKENS_155),
-  (525962, &TOKENS_34),
-  (526140, &TOKENS_80),
-  (52642, &TOKENS_2),
-  (526645, &TOKENS_65),
-  (526704, &TOKENS_168),
-  (526763, &TOKENS_17),
-  (526981, &TOKENS_15),
-  (527143, &TOKENS_34),
-  (527626, &TOKENS_54),
-  (527685, &TOKENS_85),
-  (52772, &TOKENS_10),
-  (527909, &TOKENS_15),
-  (528367, &TOKENS_114),
-  (528433, &TOKENS_155),
-  (528499, &TOKENS_159),
-  (528844, &TOKENS_15),
-  (529327, &TOKENS_65),
-  (529386, &TOKENS_54),
-  (529445, &TOKENS_91),
-  (529534, &TOKENS_150),
-  (529611, &TOKENS_48),
-  (529693, &TOKENS_100),
-  (529781, &TOKENS_9),
-  (529839, &TOKENS_100),
-  (529887, &TOKENS_5),
-  (529963, &TOKENS_100),
-  (530010, &TOKENS_29),
-  (530078, &TOKENS_9),
-  (530136, &TOKENS_100),
-  (530225, &TOKENS_9),
-  (530283, &TOKENS_100),
-  (530331, &TOKENS_9),
-  (530389, &TOKENS_100),
-  (530477, &TOKENS_100),
-  (530543, &TOKENS_42),
-  (5306, &TOKENS_0),
-  (530608, &TOKENS_140),
-  (530685, &TOKENS_48),
-  (530767, &TOKENS_98),
-  (530855, &TOKENS_9),
-  (530913, &TOKENS_98),
-  (530961, &TOKENS_5),
-  (531037, &TOKENS_98),
-  (531084, &TOKENS_29),
-  (531152, &TOKENS_9),
-  (531210, &TOKENS_98),
-  (531299, &TOKENS_9),
-  (531357, &TOKENS_98),
-  (531405, &TOKENS_9),
-  (531463, &TOKENS_98),
-  (531551, &TOKENS_98),
-  (531617, &TOKENS_42),
-  (531682, &TOKENS_48),
-  (531764, &TOKENS_16),
-  (531852, &TOKENS_9),
-  (531910, &TOKENS_16),
-  (531958, &TOKENS_5),
-  (532034, &TOKENS_16),
-  (532081, &TOKENS_29),
-  (532149, &TOKENS_9),
-  (532207, &TOKENS_16),
-  (532296, &TOKENS_9),
-  (532354, &TOKENS_16),
-  (532402, &TOKENS_9),
-  (532460, &TOKENS_16),
-  (532548, &TOKENS_16),
-  (532620, &TOKENS_98),
-  (532686, &TOKENS_100),
-  (53270, &TOKENS_10),
-  (532752, &TOKENS_139),
-  (532852, &TOKENS_45),
-  (532961, &TOKENS_45),
-  (533070, &TOKENS_134),
-  (533130, &TOKENS_131),
-  (533535, &TOKENS_111),
-  (533600, &TOKENS_49),
-  (534142, &TOKENS_10),
-  (534640, &TOKENS_10),
-  (535433, &TOKENS_128),
-  (535481, &TOKENS_68),
-  (535552, &TOKENS_133),
-  (535621, &TOKENS_137),
-  (535685, &TOKENS_6),
-  (536200, &TOKENS_36),
-  (536265, &TOKENS_36),
-  (536330, &TOKENS_36),
-  (536395, &TOKENS_36),
-  (536460, &TOKENS_36),
-  (536525, &TOKENS_36),
-  (536590, &TOKENS_36),
-  (536655, &TOKENS_36),
-  (536720, &TOKENS_36),
-  (536785, &TOKENS_36),
-  (536850, &TOKENS_36),
-  (536915, &TOKENS_36),
-  (536980, &TOKENS_36),
-  (537045, &TOKENS_36),
-  (537110, &TOKENS_36),
-  (537175, &TOKENS_36),
-  (537639, &TOKENS_128),
-  (537758, &TOKENS_10),
-  (538551, &TOKENS_128),
-  (538611, &TOKENS_10),
-  (539109, &TOKENS_10),
-  (539902, &TOKENS_128),
-  (539998, &TOKENS_10),
-  (540496, &TOKENS_10),
-  (54063, &TOKENS_2),
-  (541289, &TOKENS_128),
-  (541409, &TOKENS_10),
-  (54169, &TOKENS_10),
-  (5419, &TOKENS_34),
-  (541907, &TOKENS_10),
-  (542700, &TOKENS_128),
-  (542820, &TOKENS_10),
-  (543318, &TOKENS_10),
-  (544111, &TOKENS_128),
-  (544207, &TOKENS_10),
-  (544705, &TOKENS_10),
-  (545498, &TOKENS_128),
-  (545594, &TOKENS_10),
-  (546092, &TOKENS_91),
-  (546222, &TOKENS_16),
-  (546294, &TOKENS_98),
-  (546360, &TOKENS_100),
-  (546426, &TOKENS_140),
-  (546503, &TOKENS_42),
-  (546609, &TOKENS_98),
-  (54667, &TOKENS_41),
-  (546675, &TOKENS_150),
-  (546793, &TOKENS_100),
-  (546859, &TOKENS_42),
-  (546924, &TOKENS_139),
-  (547024, &TOKENS_134),
-  (547084, &TOKENS_0),
-  (547206, &TOKENS_19),
-  (547254, &TOKENS_17),
-  (547918, &TOKENS_19),
-  (54796, &TOKENS_10),
-  (547966, &TOKENS_34),
-  (548145, &TOKENS_92),
-  (548204, &TOKENS_19),
-  (548252, &TOKENS_17),
-  (548916, &TOKENS_47),
-  (549090, &TOKENS_27),
-  (549150, &TOKENS_47),
-  (549301, &TOKENS_0),
-  (549423, &TOKENS_19),
-  (549471, &TOKENS_17),
-  (550136, &TOKENS_83),
-  (550184, &TOKENS_152),
-  (550237, &TOKENS_149),
-  (550296, &TOKENS_143),
-  (550349, &TOKENS_34),
-  (550421, &TOKENS_17),
-  (551080, &TOKENS_67),
-  (551128, &TOKENS_78),
-  (551176, &TOKENS_135),
-  (551315, &TOKENS_78),
-  (551362, &TOKENS_135),
-  (551421, &TOKENS_0),
-  (551513, &TOKENS_78),
-  (551561, &TOKENS_78),
-  (551633, &TOKENS_0),
-  (551733, &TOKENS_0),
-  (551833, &TOKENS_78),
-  (551880, &TOKENS_165),
-  (551970, &TOKENS_102),
-  (552013, &TOKENS_102),
-  (552056, &TOKENS_102),
-  (552140, &TOKENS_102),
-  (552220, &TOKENS_112),
-  (552280, &TOKENS_0),
-  (552380, &TOKENS_0),
-  (552480, &TOKENS_102),
-  (552523, &TOKENS_102),
-  (552566, &TOKENS_102),
-  (552609, &TOKENS_0),
-  (552708, &TOKENS_103),
-  (552756, &TOKENS_25),
-  (552909, &TOKENS_34),
-  (553087, &TOKENS_13),
-  (553319, &TOKENS_25),
-  (553473, &TOKENS_34),
-  (554017, &TOKENS_136),
-  (554220, &TOKENS_34),
-  (554292, &TOKENS_17),
-  (554510, &TOKENS_103),
-  (554558, &TOKENS_25),
-  (555009, &TOKENS_95),
-  (555158, &TOKENS_0),
-  (555280, &TOKENS_19),
-  (555328, &TOKENS_17),
-  (555546, &TOKENS_0),
-  (555668, &TOKENS_19),
-  (555716, &TOKENS_17),
-  (55589, &TOKENS_41),
-  (555934, &TOKENS_19),
-  (555982, &TOKENS_34),
-  (556161, &TOKENS_19),
-  (556209, &TOKENS_17),
-  (556427, &TOKENS_47),
-  (556601, &TOKENS_27),
-  (556661, &TOKENS_0),
-  (556783, &TOKENS_19),
-  (556831, &TOKENS_17),
-  (55719, &TOKENS_10),
-  (557192, &TOKENS_95),
-  (557340, &TOKENS_120),
-  (557400, &TOKENS_117),
-  (557726, &TOKENS_4),
-  (557780, &TOKENS_58),
-  (557868, &TOKENS_58),
-  (557970, &TOKENS_17),
-  (558629, &TOKENS_67),
-  (558677, &TOKENS_77),
-  (558725, &TOKENS_156),
-  (558772, &TOKENS_81),
-  (558820, &TOKENS_125),
-  (559019, &TOKENS_77),
-  (559066, &TOKENS_156),
-  (559125, &TOKENS_0),
-  (559217, &TOKENS_77),
-  (559265, &TOKENS_77),
-  (559326, &TOKENS_49),
-  (5597, &TOKENS_113),
-  (559868, &TOKENS_10),
-  (560366, &TOKENS_10),
-  (561159, &TOKENS_63),
-  (561207, &TOKENS_76),
-  (561278, &TOKENS_6),
-  (561793, &TOKENS_74),
-  (561858, &TOKENS_74),
-  (561923, &TOKENS_74),
-  (561988, &TOKENS_74),
-  (562053, &TOKENS_74),
-  (562118, &TOKENS_74),
-  (56217, &TOKENS_10),
-  (562183, &TOKENS_74),
-  (562248, &TOKENS_74),
-  (562313, &TOKENS_74),
-  (562378, &TOKENS_74),
-  (562443, &TOKENS_74),
-  (562508, &TOKENS_74),
-  (562573, &TOKENS_74),
-  (562638, &TOKENS_74),
-  (562703, &TOKENS_74),
-  (562768, &TOKENS_74),
-  (563234, &TOKENS_63),
-  (563353, &TOKENS_10),
-  (564146, &TOKENS_63),
-  (564242, &TOKENS_10),
-  (564740, &TOKENS_10),
-  (565533, &TOKENS_63),
-  (565653, &TOKENS_10),
-  (566151, &TOKENS_10),
-  (566944, &TOKENS_63),
-  (567040, &TOKENS_10),
-  (567538, &TOKENS_10),
-  (568331, &TOKENS_63),
-  (568391, &TOKENS_10),
-  (568889, &TOKENS_10),
-  (569682, &TOKENS_63),
-  (569778, &TOKENS_10),
-  (57010, &TOKENS_41),
-  (570276, &TOKENS_10),
-  (571069, &TOKENS_63),
-  (571189, &TOKENS_10),
-  (57140, &TOKENS_10),
-  (571687, &TOKENS_107),
-  (571758, &TOKENS_89),
-  (571806, &TOKENS_4),
-  (571949, &TOKENS_77),
-  (571996, &TOKENS_107),
-  (572152, &TOKENS_77),
-  (572213, &TOKENS_49),
-  (573156, &TOKENS_107),
-  (573227, &TOKENS_89),
-  (573275, &TOKENS_4),
-  (573418, &TOKENS_4),
-  (573517, &TOKENS_4),
-  (573616, &TOKENS_4),
-  (573715, &TOKENS_83),
-  (573763, &TOKENS_46),
-  (573841, &TOKENS_130),
-  (573929, &TOKENS_130),
-  (574019, &TOKENS_116),
-  (574127, &TOKENS_117),
-  (574453, &TOKENS_4),
-  (574548, &TOKENS_58),
-  (574650, &TOKENS_17),
-  (575309, &TOKENS_67),
-  (575357, &TOKENS_129),
-  (575405, &TOKENS_142),
-  (575452, &TOKENS_81),
-  (575500, &TOKENS_125),
-  (575699, &TOKENS_129),
-  (575746, &TOKENS_142),
-  (575805, &TOKENS_0),
-  (575897, &TOKENS_129),
-  (575945, &TOKENS_129),
-  (576006, &TOKENS_49),
-  (57638, &TOKENS_10),
-  (576548, &TOKENS_10),
-  (577046, &TOKENS_10),
-  (577839, &TOKENS_99),
-  (577887, &TOKENS_118),
-  (577964, &TOKENS_6),
-  (578479, &TOKENS_39),
-  (578544, &TOKENS_39),
-  (578609, &TOKENS_39),
-  (578674, &TOKENS_39),
-  (578739, &TOKENS_39),
-  (578804, &TOKENS_39),
-  (578869, &TOKENS_39),
-  (578934, &TOKENS_39),
-  (578999, &TOKENS_39),
-  (579064, &TOKENS_39),
-  (579129, &TOKENS_39),
-  (579194, &TOKENS_39),
-  (579259, &TOKENS_39),
-  (579324, &TOKENS_39),
-  (579389, &TOKENS_39),
-  (579454, &TOKENS_39),
-  (579920, &TOKENS_99),
-  (580039, &TOKENS_10),
-  (580832, &TOKENS_99),
-  (580952, &TOKENS_10),
-  (581450, &TOKENS_10),
-  (582243, &TOKENS_99),
-  (582339, &TOKENS_10),
-  (582837, &TOKENS_10),
-  (583630, &TOKENS_99),
-  (583690, &TOKENS_10),
-  (584188, &TOKENS_10),
-  (58431, &TOKENS_41),
-  (584981, &TOKENS_99),
-  (58501, &TOKENS_10),
-  (585101, &TOKENS_10),
-  (585599, &TOKENS_10),
-  (586392, &TOKENS_99),
-  (586488, &TOKENS_10),
-  (586986, &TOKENS_10),
-  (587779, &TOKENS_99),
-  (587875, &TOKENS_10),
-  (588373, &TOKENS_129),
-  (588529, &TOKENS_129),
-  (588590, &TOKENS_49),
-  (589533, &TOKENS_34),
-  (589711, &TOKENS_169),
-  (58999, &TOKENS_10),
-  (590121, &TOKENS_9),
-  (590175, &TOKENS_1),
-  (590234, &TOKENS_97),
-  (590506, &TOKENS_4),
-  (590606, &TOKENS_1),
-  (590946, &TOKENS_1),
-  (591006, &TOKENS_73),
-  (591439, &TOKENS_1),
-  (591498, &TOKENS_73),
-  (591930, &TOKENS_1),
-  (591989, &TOKENS_97),
-  (592256, &TOKENS_4),
-  (592351, &TOKENS_58),
-  (592453, &TOKENS_81),
-  (592501, &TOKENS_125),
-  (592700, &TOKENS_129),
-  (592761, &TOKENS_49),
-  (593703, &TOKENS_1),
-  (593753, &TOKENS_107),
-  (593824, &TOKENS_89),
-  (593872, &TOKENS_4),
-  (593972, &TOKENS_1),
-  (594063, &TOKENS_1),
-  (594222, &TOKENS_129),
-  (594283, &TOKENS_49),
-  (595225, &TOKENS_1),
-  (595275, &TOKENS_107),
-  (595346, &TOKENS_89),
-  (595394, &TOKENS_4),
-  (595494, &TOKENS_1),
-  (595585, &TOKENS_1),
-  (595635, &TOKENS_4),
-  (595735, &TOKENS_1),
-  (595783, &TOKENS_1),
-  (595830, &TOKENS_4),
-  (595930, &TOKENS_1),
-  (596284, &TOKENS_1),
-  (596344, &TOKENS_97),
-  (596910, &TOKENS_1),
-  (596971, &TOKENS_73),
-  (597405, &TOKENS_1),
-  (597453, &TOKENS_1),
-  (597500, &TOKENS_1),
-  (597560, &TOKENS_73),
-  (59792, &TOKENS_41),
-  (597993, &TOKENS_94),
-  (598041, &TOKENS_127),
-  (598173, &TOKENS_103),
-  (598221, &TOKENS_117),
-  (598898, &TOKENS_9),
-  (598952, &TOKENS_103),
-  (59898, &TOKENS_10),
-  (599000, &TOKENS_30),
-  (599100, &TOKENS_9),
-  (599261, &TOKENS_21),
-  (599459, &TOKENS_4),
-  (599603, &TOKENS_38),
-  (599663, &TOKENS_103),
-  (599711, &TOKENS_117),
-  (5999, &TOKENS_151),
-  (600388, &TOKENS_9),
-  (600443, &TOKENS_4),
-  (600564, &TOKENS_0),
-  (600743, &TOKENS_72),
-  (600879, &TOKENS_72),
-  (600951, &TOKENS_116),
-  (601166, &TOKENS_21),
-  (601364, &TOKENS_34),
-  (601543, &TOKENS_164),
-  (601602, &TOKENS_67),
-  (601650, &TOKENS_103),
-  (601698, &TOKENS_117),
-  (602375, &TOKENS_9),
-  (602492, &TOKENS_72),
-  (602564, &TOKENS_116),
-  (602779, &TOKENS_21),
-  (602977, &TOKENS_66),
-  (603113, &TOKENS_34),
-  (603442, &TOKENS_67),
-  (603490, &TOKENS_103),
-  (603538, &TOKENS_117),
-  (60396, &TOKENS_10),
-  (604215, &TOKENS_9),
-  (604332, &TOKENS_34),
-  (604510, &TOKENS_90),
-  (604569, &TOKENS_34),
-  (604747, &TOKENS_147),
-  (604806, &TOKENS_103),
-  (604854, &TOKENS_34),
-  (6050, &TOKENS_108),
-  (605077, &TOKENS_154),
-  (605136, &TOKENS_56),
-  (605704, &TOKENS_24),
-  (605763, &TOKENS_97),
-  (606035, &TOKENS_4),
-  (606135, &TOKENS_24),
-  (606475, &TOKENS_24),
-  (606535, &TOKENS_73),
-  (606968, &TOKENS_24),
-  (607027, &TOKENS_73),
-  (607459, &TOKENS_24),
-  (607518, &TOKENS_97),
-  (607785, &TOKENS_4),
-  (607880, &TOKENS_58),
-  (607982, &TOKENS_81),
-  (608030, &TOKENS_125),
-  (608229, &TOKENS_77),
-  (608290, &TOKENS_49),
-  (609232, &TOKENS_24),
-  (609282, &TOKENS_107),
-  (609353, &TOKENS_89),
-  (609401, &TOKENS_4),
-  (609501, &TOKENS_24),
-  (609592, &TOKENS_24),
-  (609751, &TOKENS_77),
-  (609812, &TOKENS_49),
-  (6101, &TOKENS_55),
-  (610754, &TOKENS_24),
-  (610804, &TOKENS_107),
-  (610875, &TOKENS_89),
-  (610923, &TOKENS_4),
-  (611023, &TOKENS_24),
-  (611114, &TOKENS_24),
-  (611164, &TOKENS_4),
-  (611264, &TOKENS_24),
-  (611312, &TOKENS_24),
-  (611359, &TOKENS_4),
-  (611459, &TOKENS_24),
-  (611813, &TOKENS_24),
-  (611873, &TOKENS_97),
-  (61189, &TOKENS_41),
-  (612439, &TOKENS_24),
-  (612487, &TOKENS_24),
-  (612548, &TOKENS_73),
-  (61295, &TOKENS_10),
-  (612982, &TOKENS_24),
-  (613029, &TOKENS_24),
-  (613089, &TOKENS_73),
-  (613522, &TOKENS_94),
-  (613570, &TOKENS_127),
-  (613642, &TOKENS_117),
-  (614331, &TOKENS_166),
-  (614396, &TOKENS_110),
-  (614432, &TOKENS_110),
-  (614481, &TOKENS_10),
-  (615324, &TOKENS_103),
-  (61793, &TOKENS_10),
-  (62586, &TOKENS_41),
-  (62692, &TOKENS_10),
-  (63190, &TOKENS_10),
-  (63688, &TOKENS_10),
-  (64186, &TOKENS_10),
-  (64684, &TOKENS_10),
-  (65182, &TOKENS_6),
-  (65992, &TOKENS_7),
-  (66128, &TOKENS_10),
-  (6647, &TOKENS_27),
-  (66921, &TOKENS_7),
-  (67051, &TOKENS_10),
-  (6723, &TOKENS_55),
-  (67549, &TOKENS_10),
-  (68342, &TOKENS_7),
-  (68448, &TOKENS_10),
-  (68946, &TOKENS_10),
-  (69739, &TOKENS_7),
-  (69845, &TOKENS_10),
-  (70343, &TOKENS_10),
-  (708, &TOKENS_4),
-  (71136, &TOKENS_7),
-  (71266, &TOKENS_10),
-  (71764, &TOKENS_10),
-  (7251, &TOKENS_62),
-  (72557, &TOKENS_7),
-  (72663, &TOKENS_10),
-  (73161, &TOKENS_10),
-  (7348, &TOKENS_45),
-  (73954, &TOKENS_7),
-  (74024, &TOKENS_10),
-  (74522, &TOKENS_10),
-  (75020, &TOKENS_10),
-  (7559, &TOKENS_67),
-  (75813, &TOKENS_75),
-  (75861, &TOKENS_162),
-  (75938, &TOKENS_106),
-  (75996, &TOKENS_10),
-  (7623, &TOKENS_67),
-  (76494, &TOKENS_10),
-  (767, &TOKENS_58),
-  (7687, &TOKENS_45),
-  (76992, &TOKENS_10),
-  (77490, &TOKENS_106),
-  (77548, &TOKENS_10),
-  (78046, &TOKENS_6),
-  (78856, &TOKENS_32),
-  (7898, &TOKENS_67),
-  (78986, &TOKENS_10),
-  (79484, &TOKENS_10),
-  (7962, &TOKENS_67),
-  (79982, &TOKENS_106),
-  (80040, &TOKENS_10),
-  (8026, &TOKENS_27),
-  (80833, &TOKENS_32),
-  (8096, &TOKENS_20),
-  (80963, &TOKENS_10),
-  (8144, &TOKENS_20),
-  (81461, &TOKENS_6),
-  (8191, &TOKENS_141),
-  (82271, &TOKENS_7),
-  (8238, &TOKENS_20),
-  (82408, &TOKENS_10),
-  (8285, &TOKENS_20),
-  (83201, &TOKENS_32),
-  (8332, &TOKENS_20),
-  (83331, &TOKENS_10),
-  (8379, &TOKENS_22),
-  (83829, &TOKENS_10),
-  (8426, &TOKENS_20),
-  (84622, &TOKENS_32),
-  (84728, &TOKENS_10),
-  (8473, &TOKENS_20),
-  (8520, &TOKENS_57),
-  (85226, &TOKENS_10),
-  (86019, &TOKENS_32),
-  (8609, &TOKENS_104),
-  (86125, &TOKENS_10),
-  (86623, &TOKENS_10),
-  (8686, &TOKENS_48),
-  (87416, &TOKENS_32),
-  (87546, &TOKENS_10),
-  (8768, &TOKENS_124),
-  (880, &TOKENS_17),
-  (88044, &TOKENS_10),
-  (8815, &TOKENS_158),
-  (88837, &TOKENS_32),
-  (88943, &TOKENS_10),
-  (8912, &TOKENS_9),
-  (89441, &TOKENS_10),
-  (8970, &TOKENS_124),
-  (9018, &TOKENS_5),
-  (90234, &TOKENS_32),
-  (90304, &TOKENS_10),
-  (90802, &TOKENS_6),
-  (9094, &TOKENS_124),
-  (91, &TOKENS_120),
-  (91317, &TOKENS_11),
-  (91382, &TOKENS_11),
-  (9141, &TOKENS_29),
-  (91447, &TOKENS_11),
-  (91512, &TOKENS_11),
-  (91577, &TOKENS_11),
-  (91642, &TOKENS_11),
-  (91707, &TOKENS_11),
-  (91772, &TOKENS_11),
-  (91837, &TOKENS_11),
-  (91902, &TOKENS_11),
-  (91967, &TOKENS_11),
-  (92032, &TOKENS_11),
-  (9209, &TOKENS_9),
-  (92097, &TOKENS_11),
-  (92162, &TOKENS_11),
-  (92227, &TOKENS_11),
-  (92292, &TOKENS_11),
-  (92357, &TOKENS_122),
-  (92487, &TOKENS_0),
-  (9267, &TOKENS_124),
-  (92999, &TOKENS_75),
-  (93118, &TOKENS_10),
-  (9356, &TOKENS_9),
-  (93911, &TOKENS_75),
-  (94007, &TOKENS_10),
-  (9414, &TOKENS_124),
-  (94505, &TOKENS_10),
-  (9462, &TOKENS_9),
-  (9520, &TOKENS_124),
-  (95298, &TOKENS_75),
-  (95418, &TOKENS_10),
-  (95916, &TOKENS_10),
-  (9608, &TOKENS_124),
-  (9668, &TOKENS_22),
-  (96709, &TOKENS_75),
-  (96805, &TOKENS_10),
-  (9716, &TOKENS_14),
-  (97303, &TOKENS_10),
-  (9781, &TOKENS_48),
-  (98096, &TOKENS_75),
-  (98192, &TOKENS_10),
-  (98690, &TOKENS_10),
-  (9909, &TOKENS_9),
-  (99483, &TOKENS_75),
-  (99543, &TOKENS_10),
-  (9973, &TOKENS_5),
+const NONTERM_ID_TO_ADDRESS: [(u32, u32); 6] = [(0, 8), (1, 270320), (10, 400443), (4, 271139), (8, 399450), (9, 399600)];
+
+const STATE_TO_TOKEN_IDS: [(u32, &'static [u32]); 1517] = [
+  (10012, &TOKENS_3),
+  (10101, &TOKENS_36),
+  (101032, &TOKENS_16),
+  (101162, &TOKENS_4),
+  (10159, &TOKENS_3),
+  (10207, &TOKENS_36),
+  (102287, &TOKENS_16),
+  (102393, &TOKENS_4),
+  (10265, &TOKENS_3),
+  (103518, &TOKENS_16),
+  (10353, &TOKENS_3),
+  (103588, &TOKENS_4),
+  (10425, &TOKENS_96),
+  (104418, &TOKENS_89),
+  (104505, &TOKENS_24),
+  (104556, &TOKENS_124),
+  (104631, &TOKENS_24),
+  (104682, &TOKENS_40),
+  (104745, &TOKENS_16),
+  (104757, &TOKENS_31),
+  (10485, &TOKENS_6),
+  (105140, &TOKENS_5),
+  (105270, &TOKENS_4),
+  (10533, &TOKENS_84),
+  (10593, &TOKENS_6),
+  (106395, &TOKENS_5),
+  (10641, &TOKENS_82),
+  (106501, &TOKENS_4),
+  (10718, &TOKENS_47),
+  (107626, &TOKENS_5),
+  (107756, &TOKENS_4),
+  (10800, &TOKENS_84),
+  (10888, &TOKENS_36),
+  (108881, &TOKENS_5),
+  (109011, &TOKENS_4),
+  (10946, &TOKENS_84),
+  (1098, &TOKENS_21),
+  (10994, &TOKENS_71),
+  (110136, &TOKENS_5),
+  (110206, &TOKENS_4),
+  (11070, &TOKENS_84),
+  (11117, &TOKENS_65),
+  (111331, &TOKENS_5),
+  (111437, &TOKENS_4),
+  (11185, &TOKENS_36),
+  (11243, &TOKENS_84),
+  (112562, &TOKENS_5),
+  (112668, &TOKENS_90),
+  (112743, &TOKENS_137),
+  (112755, &TOKENS_90),
+  (112830, &TOKENS_137),
+  (112842, &TOKENS_90),
+  (112917, &TOKENS_137),
+  (113053, &TOKENS_68),
+  (113122, &TOKENS_117),
+  (11332, &TOKENS_36),
+  (11390, &TOKENS_84),
+  (113970, &TOKENS_4),
+  (11438, &TOKENS_36),
+  (11496, &TOKENS_84),
+  (115095, &TOKENS_16),
+  (115231, &TOKENS_4),
+  (11584, &TOKENS_84),
+  (116356, &TOKENS_16),
+  (11644, &TOKENS_6),
+  (116486, &TOKENS_4),
+  (11692, &TOKENS_103),
+  (11757, &TOKENS_47),
+  (117611, &TOKENS_16),
+  (117717, &TOKENS_4),
+  (118842, &TOKENS_16),
+  (11885, &TOKENS_36),
+  (118948, &TOKENS_4),
+  (11949, &TOKENS_71),
+  (120073, &TOKENS_16),
+  (120203, &TOKENS_4),
+  (12030, &TOKENS_65),
+  (12098, &TOKENS_36),
+  (121328, &TOKENS_16),
+  (121434, &TOKENS_4),
+  (12203, &TOKENS_36),
+  (122559, &TOKENS_16),
+  (122629, &TOKENS_4),
+  (12267, &TOKENS_36),
+  (12371, &TOKENS_6),
+  (123775, &TOKENS_5),
+  (123905, &TOKENS_4),
+  (12419, &TOKENS_114),
+  (12496, &TOKENS_103),
+  (125030, &TOKENS_5),
+  (125160, &TOKENS_4),
+  (12602, &TOKENS_6),
+  (126285, &TOKENS_5),
+  (126355, &TOKENS_4),
+  (12650, &TOKENS_47),
+  (12732, &TOKENS_96),
+  (127480, &TOKENS_5),
+  (127586, &TOKENS_4),
+  (12820, &TOKENS_36),
+  (128711, &TOKENS_5),
+  (12878, &TOKENS_96),
+  (128817, &TOKENS_4),
+  (12926, &TOKENS_71),
+  (129942, &TOKENS_5),
+  (13002, &TOKENS_96),
+  (130048, &TOKENS_4),
+  (13049, &TOKENS_65),
+  (13117, &TOKENS_36),
+  (131173, &TOKENS_5),
+  (131303, &TOKENS_13),
+  (131315, &TOKENS_13),
+  (131327, &TOKENS_36),
+  (13175, &TOKENS_96),
+  (131773, &TOKENS_93),
+  (131892, &TOKENS_4),
+  (13264, &TOKENS_36),
+  (132722, &TOKENS_4),
+  (13322, &TOKENS_96),
+  (13370, &TOKENS_36),
+  (133847, &TOKENS_93),
+  (134190, &TOKENS_93),
+  (13428, &TOKENS_96),
+  (134286, &TOKENS_4),
+  (13516, &TOKENS_96),
+  (135411, &TOKENS_93),
+  (135531, &TOKENS_4),
+  (13576, &TOKENS_6),
+  (13624, &TOKENS_9),
+  (136656, &TOKENS_93),
+  (136776, &TOKENS_4),
+  (13690, &TOKENS_97),
+  (13756, &TOKENS_6),
+  (137901, &TOKENS_93),
+  (137997, &TOKENS_4),
+  (139122, &TOKENS_93),
+  (139182, &TOKENS_4),
+  (13944, &TOKENS_33),
+  (140307, &TOKENS_93),
+  (140403, &TOKENS_4),
+  (141233, &TOKENS_4),
+  (142358, &TOKENS_16),
+  (142494, &TOKENS_4),
+  (143324, &TOKENS_4),
+  (144154, &TOKENS_4),
+  (14429, &TOKENS_121),
+  (14488, &TOKENS_74),
+  (145279, &TOKENS_16),
+  (145409, &TOKENS_4),
+  (14547, &TOKENS_6),
+  (14595, &TOKENS_50),
+  (146534, &TOKENS_16),
+  (146640, &TOKENS_4),
+  (14684, &TOKENS_82),
+  (14761, &TOKENS_103),
+  (147765, &TOKENS_16),
+  (147871, &TOKENS_4),
+  (148996, &TOKENS_16),
+  (14907, &TOKENS_84),
+  (149126, &TOKENS_4),
+  (14973, &TOKENS_114),
+  (150251, &TOKENS_16),
+  (150357, &TOKENS_4),
+  (15050, &TOKENS_103),
+  (151, &TOKENS_92),
+  (151482, &TOKENS_16),
+  (151552, &TOKENS_4),
+  (15196, &TOKENS_96),
+  (152382, &TOKENS_83),
+  (152440, &TOKENS_4),
+  (15303, &TOKENS_3),
+  (153270, &TOKENS_4),
+  (15375, &TOKENS_96),
+  (154395, &TOKENS_16),
+  (15441, &TOKENS_84),
+  (154531, &TOKENS_4),
+  (15507, &TOKENS_76),
+  (155361, &TOKENS_4),
+  (15612, &TOKENS_76),
+  (156191, &TOKENS_4),
+  (15707, &TOKENS_50),
+  (157316, &TOKENS_16),
+  (157446, &TOKENS_4),
+  (15754, &TOKENS_50),
+  (15801, &TOKENS_141),
+  (158571, &TOKENS_16),
+  (158677, &TOKENS_4),
+  (15905, &TOKENS_50),
+  (159802, &TOKENS_16),
+  (159908, &TOKENS_4),
+  (16054, &TOKENS_10),
+  (161033, &TOKENS_16),
+  (16112, &TOKENS_50),
+  (161163, &TOKENS_4),
+  (16160, &TOKENS_10),
+  (16218, &TOKENS_50),
+  (162288, &TOKENS_16),
+  (162394, &TOKENS_4),
+  (16266, &TOKENS_141),
+  (163519, &TOKENS_16),
+  (163589, &TOKENS_4),
+  (16370, &TOKENS_50),
+  (164714, &TOKENS_11),
+  (164844, &TOKENS_4),
+  (16519, &TOKENS_10),
+  (16577, &TOKENS_50),
+  (165969, &TOKENS_16),
+  (166106, &TOKENS_4),
+  (16625, &TOKENS_10),
+  (16683, &TOKENS_50),
+  (167231, &TOKENS_11),
+  (16731, &TOKENS_76),
+  (167361, &TOKENS_4),
+  (16778, &TOKENS_33),
+  (168486, &TOKENS_11),
+  (168592, &TOKENS_4),
+  (16956, &TOKENS_151),
+  (169717, &TOKENS_11),
+  (169823, &TOKENS_4),
+  (170948, &TOKENS_11),
+  (171078, &TOKENS_4),
+  (172203, &TOKENS_11),
+  (172309, &TOKENS_4),
+  (173434, &TOKENS_11),
+  (173504, &TOKENS_29),
+  (173516, &TOKENS_14),
+  (17421, &TOKENS_144),
+  (174363, &TOKENS_4),
+  (17480, &TOKENS_69),
+  (175488, &TOKENS_16),
+  (175624, &TOKENS_4),
+  (1757, &TOKENS_81),
+  (176454, &TOKENS_4),
+  (177284, &TOKENS_4),
+  (178409, &TOKENS_16),
+  (178539, &TOKENS_4),
+  (179664, &TOKENS_16),
+  (179770, &TOKENS_4),
+  (18051, &TOKENS_60),
+  (180895, &TOKENS_16),
+  (181001, &TOKENS_4),
+  (18116, &TOKENS_63),
+  (1816, &TOKENS_31),
+  (182126, &TOKENS_16),
+  (182256, &TOKENS_4),
+  (183381, &TOKENS_16),
+  (183487, &TOKENS_4),
+  (184612, &TOKENS_16),
+  (184682, &TOKENS_4),
+  (185824, &TOKENS_18),
+  (185953, &TOKENS_4),
+  (18658, &TOKENS_23),
+  (187078, &TOKENS_18),
+  (187148, &TOKENS_4),
+  (188273, &TOKENS_18),
+  (188379, &TOKENS_4),
+  (189504, &TOKENS_18),
+  (189610, &TOKENS_4),
+  (1907, &TOKENS_10),
+  (190735, &TOKENS_18),
+  (190865, &TOKENS_4),
+  (19174, &TOKENS_4),
+  (191990, &TOKENS_18),
+  (192120, &TOKENS_4),
+  (193245, &TOKENS_18),
+  (193351, &TOKENS_17),
+  (193427, &TOKENS_4),
+  (194557, &TOKENS_18),
+  (194687, &TOKENS_18),
+  (194699, &TOKENS_8),
+  (194764, &TOKENS_165),
+  (195058, &TOKENS_10),
+  (195070, &TOKENS_10),
+  (195145, &TOKENS_170),
+  (195216, &TOKENS_113),
+  (195285, &TOKENS_154),
+  (195349, &TOKENS_10),
+  (195412, &TOKENS_95),
+  (195424, &TOKENS_5),
+  (195436, &TOKENS_8),
+  (195501, &TOKENS_95),
+  (195513, &TOKENS_16),
+  (195525, &TOKENS_8),
+  (195590, &TOKENS_95),
+  (195602, &TOKENS_8),
+  (195667, &TOKENS_95),
+  (195679, &TOKENS_8),
+  (195744, &TOKENS_95),
+  (195756, &TOKENS_8),
+  (195821, &TOKENS_95),
+  (195833, &TOKENS_8),
+  (195898, &TOKENS_95),
+  (195910, &TOKENS_8),
+  (195975, &TOKENS_95),
+  (195987, &TOKENS_8),
+  (196052, &TOKENS_95),
+  (196064, &TOKENS_8),
+  (1961, &TOKENS_56),
+  (196129, &TOKENS_95),
+  (196141, &TOKENS_8),
+  (196206, &TOKENS_95),
+  (196218, &TOKENS_8),
+  (196283, &TOKENS_95),
+  (196295, &TOKENS_8),
+  (196360, &TOKENS_95),
+  (196372, &TOKENS_8),
+  (196437, &TOKENS_95),
+  (196449, &TOKENS_8),
+  (196514, &TOKENS_95),
+  (196526, &TOKENS_8),
+  (196591, &TOKENS_95),
+  (196603, &TOKENS_16),
+  (196615, &TOKENS_5),
+  (196627, &TOKENS_12),
+  (196711, &TOKENS_1),
+  (197082, &TOKENS_51),
+  (197201, &TOKENS_4),
+  (198031, &TOKENS_4),
+  (199156, &TOKENS_51),
+  (199216, &TOKENS_4),
+  (19967, &TOKENS_16),
+  (20007, &TOKENS_120),
+  (200341, &TOKENS_51),
+  (200437, &TOKENS_4),
+  (20094, &TOKENS_31),
+  (201562, &TOKENS_51),
+  (201658, &TOKENS_4),
+  (2020, &TOKENS_21),
+  (20207, &TOKENS_24),
+  (20253, &TOKENS_124),
+  (202783, &TOKENS_51),
+  (202879, &TOKENS_4),
+  (20333, &TOKENS_124),
+  (204004, &TOKENS_51),
+  (20409, &TOKENS_24),
+  (204124, &TOKENS_4),
+  (20501, &TOKENS_40),
+  (205249, &TOKENS_51),
+  (205369, &TOKENS_56),
+  (205381, &TOKENS_31),
+  (205448, &TOKENS_48),
+  (205460, &TOKENS_129),
+  (205472, &TOKENS_53),
+  (205512, &TOKENS_31),
+  (205664, &TOKENS_107),
+  (205723, &TOKENS_126),
+  (205771, &TOKENS_127),
+  (205872, &TOKENS_139),
+  (205931, &TOKENS_63),
+  (20606, &TOKENS_31),
+  (207123, &TOKENS_48),
+  (207135, &TOKENS_26),
+  (20720, &TOKENS_13),
+  (207211, &TOKENS_15),
+  (207259, &TOKENS_24),
+  (207323, &TOKENS_26),
+  (207399, &TOKENS_15),
+  (207447, &TOKENS_24),
+  (207511, &TOKENS_24),
+  (207575, &TOKENS_24),
+  (20760, &TOKENS_91),
+  (207968, &TOKENS_48),
+  (208027, &TOKENS_41),
+  (20835, &TOKENS_165),
+  (208401, &TOKENS_48),
+  (208448, &TOKENS_48),
+  (208507, &TOKENS_25),
+  (208786, &TOKENS_35),
+  (208798, &TOKENS_35),
+  (208810, &TOKENS_39),
+  (208858, &TOKENS_2),
+  (208930, &TOKENS_39),
+  (208942, &TOKENS_35),
+  (208954, &TOKENS_48),
+  (209001, &TOKENS_24),
+  (209065, &TOKENS_48),
+  (209124, &TOKENS_25),
+  (209403, &TOKENS_35),
+  (209415, &TOKENS_98),
+  (209497, &TOKENS_106),
+  (209567, &TOKENS_106),
+  (209637, &TOKENS_98),
+  (209719, &TOKENS_106),
+  (209789, &TOKENS_48),
+  (209848, &TOKENS_41),
+  (21, &TOKENS_31),
+  (21027, &TOKENS_78),
+  (210552, &TOKENS_48),
+  (210612, &TOKENS_41),
+  (210989, &TOKENS_48),
+  (211036, &TOKENS_48),
+  (211096, &TOKENS_25),
+  (211378, &TOKENS_35),
+  (211391, &TOKENS_35),
+  (211404, &TOKENS_48),
+  (21142, &TOKENS_160),
+  (211464, &TOKENS_25),
+  (211746, &TOKENS_35),
+  (211759, &TOKENS_48),
+  (211806, &TOKENS_48),
+  (211865, &TOKENS_25),
+  (212144, &TOKENS_35),
+  (212156, &TOKENS_35),
+  (212168, &TOKENS_131),
+  (212216, &TOKENS_99),
+  (212299, &TOKENS_128),
+  (212395, &TOKENS_133),
+  (212527, &TOKENS_92),
+  (21257, &TOKENS_160),
+  (213069, &TOKENS_36),
+  (213122, &TOKENS_37),
+  (213134, &TOKENS_42),
+  (213182, &TOKENS_92),
+  (213724, &TOKENS_36),
+  (213777, &TOKENS_37),
+  (213789, &TOKENS_42),
+  (213837, &TOKENS_164),
+  (213896, &TOKENS_36),
+  (214056, &TOKENS_37),
+  (214182, &TOKENS_37),
+  (214194, &TOKENS_37),
+  (214206, &TOKENS_37),
+  (214218, &TOKENS_37),
+  (214230, &TOKENS_24),
+  (214396, &TOKENS_56),
+  (214456, &TOKENS_42),
+  (214504, &TOKENS_92),
+  (215046, &TOKENS_36),
+  (215100, &TOKENS_24),
+  (215175, &TOKENS_35),
+  (215187, &TOKENS_35),
+  (215199, &TOKENS_31),
+  (215263, &TOKENS_105),
+  (215341, &TOKENS_35),
+  (215353, &TOKENS_105),
+  (215431, &TOKENS_133),
+  (215563, &TOKENS_37),
+  (215575, &TOKENS_37),
+  (21562, &TOKENS_10),
+  (215694, &TOKENS_37),
+  (215820, &TOKENS_37),
+  (215832, &TOKENS_37),
+  (215844, &TOKENS_37),
+  (215856, &TOKENS_37),
+  (215868, &TOKENS_33),
+  (215946, &TOKENS_10),
+  (215994, &TOKENS_42),
+  (216042, &TOKENS_92),
+  (216584, &TOKENS_36),
+  (216637, &TOKENS_35),
+  (216649, &TOKENS_36),
+  (21666, &TOKENS_111),
+  (216661, &TOKENS_105),
+  (216739, &TOKENS_133),
+  (216871, &TOKENS_37),
+  (216883, &TOKENS_37),
+  (217002, &TOKENS_37),
+  (217128, &TOKENS_37),
+  (217140, &TOKENS_37),
+  (217152, &TOKENS_37),
+  (217164, &TOKENS_37),
+  (217176, &TOKENS_49),
+  (217336, &TOKENS_21),
+  (217554, &TOKENS_21),
+  (21781, &TOKENS_91),
+  (218213, &TOKENS_56),
+  (218272, &TOKENS_21),
+  (218490, &TOKENS_21),
+  (218708, &TOKENS_21),
+  (218926, &TOKENS_28),
+  (21896, &TOKENS_147),
+  (219088, &TOKENS_21),
+  (219747, &TOKENS_21),
+  (22011, &TOKENS_147),
+  (220406, &TOKENS_33),
+  (220779, &TOKENS_74),
+  (220838, &TOKENS_64),
+  (221033, &TOKENS_33),
+  (22126, &TOKENS_91),
+  (221461, &TOKENS_30),
+  (221512, &TOKENS_64),
+  (222058, &TOKENS_124),
+  (222134, &TOKENS_64),
+  (22241, &TOKENS_32),
+  (222662, &TOKENS_124),
+  (222732, &TOKENS_6),
+  (222920, &TOKENS_33),
+  (223304, &TOKENS_74),
+  (223363, &TOKENS_33),
+  (22356, &TOKENS_147),
+  (2238, &TOKENS_21),
+  (223846, &TOKENS_144),
+  (223905, &TOKENS_69),
+  (224476, &TOKENS_60),
+  (224541, &TOKENS_63),
+  (22471, &TOKENS_91),
+  (225083, &TOKENS_23),
+  (225599, &TOKENS_4),
+  (22586, &TOKENS_91),
+  (226435, &TOKENS_13),
+  (226511, &TOKENS_20),
+  (226740, &TOKENS_13),
+  (226817, &TOKENS_20),
+  (22701, &TOKENS_111),
+  (227023, &TOKENS_68),
+  (227092, &TOKENS_117),
+  (227608, &TOKENS_138),
+  (228011, &TOKENS_5),
+  (228141, &TOKENS_4),
+  (22816, &TOKENS_32),
+  (228934, &TOKENS_5),
+  (229040, &TOKENS_4),
+  (22931, &TOKENS_147),
+  (229833, &TOKENS_5),
+  (229939, &TOKENS_4),
+  (23046, &TOKENS_100),
+  (230732, &TOKENS_5),
+  (230802, &TOKENS_4),
+  (231595, &TOKENS_5),
+  (23161, &TOKENS_147),
+  (231701, &TOKENS_4),
+  (232494, &TOKENS_5),
+  (232624, &TOKENS_4),
+  (23276, &TOKENS_32),
+  (233417, &TOKENS_5),
+  (233547, &TOKENS_29),
+  (233676, &TOKENS_4),
+  (234469, &TOKENS_29),
+  (234575, &TOKENS_4),
+  (235368, &TOKENS_29),
+  (235498, &TOKENS_4),
+  (236291, &TOKENS_29),
+  (236421, &TOKENS_4),
+  (237214, &TOKENS_29),
+  (237284, &TOKENS_4),
+  (23729, &TOKENS_13),
+  (23805, &TOKENS_20),
+  (238077, &TOKENS_29),
+  (238183, &TOKENS_4),
+  (238976, &TOKENS_29),
+  (239082, &TOKENS_4),
+  (239875, &TOKENS_16),
+  (240011, &TOKENS_4),
+  (24034, &TOKENS_13),
+  (240804, &TOKENS_16),
+  (240934, &TOKENS_4),
+  (24111, &TOKENS_20),
+  (241727, &TOKENS_16),
+  (241833, &TOKENS_4),
+  (242626, &TOKENS_16),
+  (242732, &TOKENS_4),
+  (24317, &TOKENS_68),
+  (243525, &TOKENS_16),
+  (243655, &TOKENS_4),
+  (24386, &TOKENS_117),
+  (244448, &TOKENS_16),
+  (244554, &TOKENS_4),
+  (245347, &TOKENS_16),
+  (245417, &TOKENS_4),
+  (2456, &TOKENS_21),
+  (245915, &TOKENS_83),
+  (245973, &TOKENS_4),
+  (246766, &TOKENS_11),
+  (246896, &TOKENS_4),
+  (247689, &TOKENS_16),
+  (247826, &TOKENS_4),
+  (248619, &TOKENS_11),
+  (248749, &TOKENS_4),
+  (24902, &TOKENS_138),
+  (249542, &TOKENS_11),
+  (249648, &TOKENS_4),
+  (250441, &TOKENS_11),
+  (250547, &TOKENS_4),
+  (251340, &TOKENS_11),
+  (251470, &TOKENS_4),
+  (252263, &TOKENS_11),
+  (252369, &TOKENS_4),
+  (25305, &TOKENS_5),
+  (253162, &TOKENS_11),
+  (253232, &TOKENS_14),
+  (254059, &TOKENS_18),
+  (254188, &TOKENS_4),
+  (25435, &TOKENS_4),
+  (254981, &TOKENS_18),
+  (255087, &TOKENS_4),
+  (255880, &TOKENS_18),
+  (256010, &TOKENS_4),
+  (256803, &TOKENS_18),
+  (256909, &TOKENS_4),
+  (257702, &TOKENS_18),
+  (257772, &TOKENS_4),
+  (258565, &TOKENS_18),
+  (258671, &TOKENS_4),
+  (259464, &TOKENS_18),
+  (259594, &TOKENS_17),
+  (259670, &TOKENS_4),
+  (260468, &TOKENS_18),
+  (260997, &TOKENS_0),
+  (261116, &TOKENS_4),
+  (261909, &TOKENS_0),
+  (262029, &TOKENS_4),
+  (26228, &TOKENS_5),
+  (262822, &TOKENS_0),
+  (262918, &TOKENS_4),
+  (26298, &TOKENS_4),
+  (263711, &TOKENS_0),
+  (263831, &TOKENS_4),
+  (264624, &TOKENS_0),
+  (264684, &TOKENS_4),
+  (265477, &TOKENS_0),
+  (265573, &TOKENS_4),
+  (266366, &TOKENS_0),
+  (266462, &TOKENS_28),
+  (266624, &TOKENS_21),
+  (266842, &TOKENS_21),
+  (267060, &TOKENS_33),
+  (267137, &TOKENS_33),
+  (2674, &TOKENS_28),
+  (267655, &TOKENS_33),
+  (267833, &TOKENS_171),
+  (268026, &TOKENS_10),
+  (268074, &TOKENS_42),
+  (268122, &TOKENS_92),
+  (268664, &TOKENS_36),
+  (268717, &TOKENS_35),
+  (268729, &TOKENS_35),
+  (268741, &TOKENS_36),
+  (268843, &TOKENS_33),
+  (268921, &TOKENS_39),
+  (269040, &TOKENS_39),
+  (269052, &TOKENS_33),
+  (269130, &TOKENS_35),
+  (269142, &TOKENS_36),
+  (269154, &TOKENS_42),
+  (269202, &TOKENS_33),
+  (269297, &TOKENS_57),
+  (269309, &TOKENS_57),
+  (269321, &TOKENS_123),
+  (269380, &TOKENS_31),
+  (269582, &TOKENS_57),
+  (269690, &TOKENS_57),
+  (269702, &TOKENS_102),
+  (269714, &TOKENS_102),
+  (269726, &TOKENS_35),
+  (269738, &TOKENS_36),
+  (269750, &TOKENS_92),
+  (270307, &TOKENS_102),
+  (270333, &TOKENS_135),
+  (270625, &TOKENS_122),
+  (270729, &TOKENS_135),
+  (270741, &TOKENS_135),
+  (270860, &TOKENS_135),
+  (27091, &TOKENS_5),
+  (271127, &TOKENS_135),
+  (271152, &TOKENS_45),
+  (271409, &TOKENS_31),
+  (271531, &TOKENS_40),
+  (271579, &TOKENS_21),
+  (271797, &TOKENS_21),
+  (272015, &TOKENS_28),
+  (272177, &TOKENS_21),
+  (27221, &TOKENS_4),
+  (272836, &TOKENS_56),
+  (272895, &TOKENS_21),
+  (273113, &TOKENS_21),
+  (273331, &TOKENS_21),
+  (273990, &TOKENS_28),
+  (274152, &TOKENS_21),
+  (274370, &TOKENS_21),
+  (275029, &TOKENS_33),
+  (275106, &TOKENS_33),
+  (275589, &TOKENS_144),
+  (275648, &TOKENS_64),
+  (275843, &TOKENS_33),
+  (276271, &TOKENS_30),
+  (276322, &TOKENS_124),
+  (276398, &TOKENS_64),
+  (276926, &TOKENS_69),
+  (277497, &TOKENS_60),
+  (277562, &TOKENS_63),
+  (278104, &TOKENS_23),
+  (278620, &TOKENS_4),
+  (279456, &TOKENS_29),
+  (279585, &TOKENS_4),
+  (28014, &TOKENS_5),
+  (280378, &TOKENS_29),
+  (280448, &TOKENS_4),
+  (281241, &TOKENS_29),
+  (281347, &TOKENS_4),
+  (28144, &TOKENS_4),
+  (282140, &TOKENS_29),
+  (282246, &TOKENS_4),
+  (283039, &TOKENS_29),
+  (283145, &TOKENS_4),
+  (2836, &TOKENS_21),
+  (283938, &TOKENS_29),
+  (284068, &TOKENS_4),
+  (284861, &TOKENS_29),
+  (284991, &TOKENS_13),
+  (285067, &TOKENS_20),
+  (285296, &TOKENS_13),
+  (285373, &TOKENS_20),
+  (285579, &TOKENS_68),
+  (285648, &TOKENS_117),
+  (286164, &TOKENS_138),
+  (286567, &TOKENS_5),
+  (286697, &TOKENS_4),
+  (287490, &TOKENS_5),
+  (287596, &TOKENS_4),
+  (288389, &TOKENS_5),
+  (288519, &TOKENS_4),
+  (289312, &TOKENS_5),
+  (28937, &TOKENS_5),
+  (289418, &TOKENS_4),
+  (290211, &TOKENS_5),
+  (290281, &TOKENS_4),
+  (29043, &TOKENS_4),
+  (291074, &TOKENS_5),
+  (291204, &TOKENS_4),
+  (291997, &TOKENS_5),
+  (292103, &TOKENS_4),
+  (292896, &TOKENS_16),
+  (293032, &TOKENS_4),
+  (293825, &TOKENS_16),
+  (293955, &TOKENS_4),
+  (294748, &TOKENS_16),
+  (294854, &TOKENS_4),
+  (295647, &TOKENS_16),
+  (295753, &TOKENS_4),
+  (296546, &TOKENS_16),
+  (296676, &TOKENS_4),
+  (297469, &TOKENS_16),
+  (297575, &TOKENS_4),
+  (29836, &TOKENS_5),
+  (298368, &TOKENS_16),
+  (298438, &TOKENS_4),
+  (298936, &TOKENS_83),
+  (298994, &TOKENS_4),
+  (29942, &TOKENS_4),
+  (299787, &TOKENS_11),
+  (299917, &TOKENS_4),
+  (300710, &TOKENS_16),
+  (300847, &TOKENS_4),
+  (301640, &TOKENS_11),
+  (301770, &TOKENS_4),
+  (302563, &TOKENS_11),
+  (302669, &TOKENS_4),
+  (303462, &TOKENS_11),
+  (303568, &TOKENS_4),
+  (304361, &TOKENS_11),
+  (304491, &TOKENS_4),
+  (305284, &TOKENS_11),
+  (305390, &TOKENS_4),
+  (306183, &TOKENS_11),
+  (306253, &TOKENS_14),
+  (307080, &TOKENS_18),
+  (307209, &TOKENS_4),
+  (30735, &TOKENS_5),
+  (308002, &TOKENS_18),
+  (308132, &TOKENS_4),
+  (30841, &TOKENS_36),
+  (308925, &TOKENS_18),
+  (309031, &TOKENS_4),
+  (30905, &TOKENS_29),
+  (309824, &TOKENS_18),
+  (309930, &TOKENS_4),
+  (31034, &TOKENS_4),
+  (310723, &TOKENS_18),
+  (310853, &TOKENS_4),
+  (311646, &TOKENS_18),
+  (311752, &TOKENS_4),
+  (312545, &TOKENS_18),
+  (312615, &TOKENS_17),
+  (312691, &TOKENS_4),
+  (313489, &TOKENS_18),
+  (314018, &TOKENS_0),
+  (314137, &TOKENS_4),
+  (314930, &TOKENS_0),
+  (315026, &TOKENS_4),
+  (315819, &TOKENS_0),
+  (315939, &TOKENS_4),
+  (316732, &TOKENS_0),
+  (316792, &TOKENS_4),
+  (317585, &TOKENS_0),
+  (317681, &TOKENS_4),
+  (31827, &TOKENS_29),
+  (318474, &TOKENS_0),
+  (318570, &TOKENS_4),
+  (31933, &TOKENS_4),
+  (319363, &TOKENS_0),
+  (319483, &TOKENS_21),
+  (319701, &TOKENS_33),
+  (320074, &TOKENS_74),
+  (320133, &TOKENS_64),
+  (320679, &TOKENS_124),
+  (320749, &TOKENS_6),
+  (320937, &TOKENS_33),
+  (321321, &TOKENS_74),
+  (321380, &TOKENS_33),
+  (321898, &TOKENS_21),
+  (322116, &TOKENS_28),
+  (322574, &TOKENS_9),
+  (322640, &TOKENS_61),
+  (322706, &TOKENS_33),
+  (322884, &TOKENS_161),
+  (323389, &TOKENS_143),
+  (323448, &TOKENS_129),
+  (323507, &TOKENS_21),
+  (323725, &TOKENS_28),
+  (323887, &TOKENS_33),
+  (324370, &TOKENS_134),
+  (324429, &TOKENS_166),
+  (324653, &TOKENS_172),
+  (324986, &TOKENS_28),
+  (325811, &TOKENS_155),
+  (325876, &TOKENS_63),
+  (326418, &TOKENS_4),
+  (327211, &TOKENS_75),
+  (327259, &TOKENS_163),
+  (32726, &TOKENS_29),
+  (327330, &TOKENS_113),
+  (327399, &TOKENS_154),
+  (327463, &TOKENS_52),
+  (327528, &TOKENS_52),
+  (327593, &TOKENS_52),
+  (327658, &TOKENS_52),
+  (327723, &TOKENS_52),
+  (327788, &TOKENS_52),
+  (327853, &TOKENS_52),
+  (327918, &TOKENS_52),
+  (327983, &TOKENS_52),
+  (328048, &TOKENS_52),
+  (328113, &TOKENS_52),
+  (328178, &TOKENS_52),
+  (328243, &TOKENS_52),
+  (328308, &TOKENS_52),
+  (32832, &TOKENS_4),
+  (328373, &TOKENS_52),
+  (328438, &TOKENS_52),
+  (328902, &TOKENS_75),
+  (329021, &TOKENS_4),
+  (329814, &TOKENS_75),
+  (329874, &TOKENS_4),
+  (330667, &TOKENS_75),
+  (330787, &TOKENS_4),
+  (331580, &TOKENS_75),
+  (331700, &TOKENS_4),
+  (332493, &TOKENS_75),
+  (332589, &TOKENS_4),
+  (333382, &TOKENS_75),
+  (333478, &TOKENS_4),
+  (334271, &TOKENS_75),
+  (334367, &TOKENS_168),
+  (334456, &TOKENS_47),
+  (334538, &TOKENS_80),
+  (334626, &TOKENS_36),
+  (334684, &TOKENS_80),
+  (334732, &TOKENS_71),
+  (334808, &TOKENS_80),
+  (334855, &TOKENS_65),
+  (334923, &TOKENS_36),
+  (334981, &TOKENS_80),
+  (335070, &TOKENS_36),
+  (335128, &TOKENS_80),
+  (335176, &TOKENS_36),
+  (335234, &TOKENS_80),
+  (335322, &TOKENS_80),
+  (335394, &TOKENS_38),
+  (335460, &TOKENS_55),
+  (335526, &TOKENS_101),
+  (335603, &TOKENS_77),
+  (335668, &TOKENS_47),
+  (335750, &TOKENS_55),
+  (335838, &TOKENS_36),
+  (335896, &TOKENS_55),
+  (335944, &TOKENS_71),
+  (336020, &TOKENS_55),
+  (336067, &TOKENS_65),
+  (336135, &TOKENS_36),
+  (336193, &TOKENS_55),
+  (33625, &TOKENS_29),
+  (336282, &TOKENS_36),
+  (336340, &TOKENS_55),
+  (336388, &TOKENS_36),
+  (336446, &TOKENS_55),
+  (336534, &TOKENS_55),
+  (336600, &TOKENS_112),
+  (336677, &TOKENS_47),
+  (336759, &TOKENS_38),
+  (336847, &TOKENS_36),
+  (336905, &TOKENS_38),
+  (33695, &TOKENS_4),
+  (336953, &TOKENS_71),
+  (337029, &TOKENS_38),
+  (337076, &TOKENS_65),
+  (337144, &TOKENS_36),
+  (337202, &TOKENS_38),
+  (337291, &TOKENS_36),
+  (337349, &TOKENS_38),
+  (337397, &TOKENS_36),
+  (337455, &TOKENS_38),
+  (337543, &TOKENS_38),
+  (337609, &TOKENS_77),
+  (337674, &TOKENS_115),
+  (337774, &TOKENS_108),
+  (337834, &TOKENS_159),
+  (337897, &TOKENS_31),
+  (338019, &TOKENS_40),
+  (338067, &TOKENS_21),
+  (338731, &TOKENS_40),
+  (338779, &TOKENS_33),
+  (338958, &TOKENS_158),
+  (339017, &TOKENS_40),
+  (339065, &TOKENS_21),
+  (339729, &TOKENS_145),
+  (339903, &TOKENS_124),
+  (339963, &TOKENS_145),
+  (340114, &TOKENS_31),
+  (340236, &TOKENS_40),
+  (340284, &TOKENS_21),
+  (340949, &TOKENS_131),
+  (340997, &TOKENS_142),
+  (341050, &TOKENS_156),
+  (341109, &TOKENS_62),
+  (341162, &TOKENS_33),
+  (341234, &TOKENS_21),
+  (341893, &TOKENS_10),
+  (341941, &TOKENS_66),
+  (341989, &TOKENS_22),
+  (342128, &TOKENS_66),
+  (342175, &TOKENS_22),
+  (342234, &TOKENS_31),
+  (342326, &TOKENS_66),
+  (342374, &TOKENS_66),
+  (342446, &TOKENS_31),
+  (342546, &TOKENS_31),
+  (342646, &TOKENS_66),
+  (342693, &TOKENS_153),
+  (342783, &TOKENS_87),
+  (342826, &TOKENS_87),
+  (342869, &TOKENS_87),
+  (342953, &TOKENS_87),
+  (343033, &TOKENS_157),
+  (343093, &TOKENS_31),
+  (343193, &TOKENS_31),
+  (343293, &TOKENS_87),
+  (343336, &TOKENS_87),
+  (343379, &TOKENS_87),
+  (343422, &TOKENS_31),
+  (343521, &TOKENS_42),
+  (343569, &TOKENS_94),
+  (343722, &TOKENS_33),
+  (343900, &TOKENS_136),
+  (344132, &TOKENS_94),
+  (344286, &TOKENS_33),
+  (344513, &TOKENS_42),
+  (344561, &TOKENS_49),
+  (344870, &TOKENS_94),
+  (34488, &TOKENS_29),
+  (345174, &TOKENS_42),
+  (345222, &TOKENS_49),
+  (345531, &TOKENS_94),
+  (345685, &TOKENS_42),
+  (345733, &TOKENS_49),
+  (346042, &TOKENS_94),
+  (34618, &TOKENS_4),
+  (346535, &TOKENS_45),
+  (346792, &TOKENS_33),
+  (346864, &TOKENS_21),
+  (347082, &TOKENS_42),
+  (347130, &TOKENS_94),
+  (347283, &TOKENS_42),
+  (347331, &TOKENS_49),
+  (347467, &TOKENS_42),
+  (347515, &TOKENS_49),
+  (347651, &TOKENS_42),
+  (347699, &TOKENS_49),
+  (348155, &TOKENS_102),
+  (348298, &TOKENS_31),
+  (348420, &TOKENS_40),
+  (348468, &TOKENS_21),
+  (348686, &TOKENS_31),
+  (348808, &TOKENS_40),
+  (348856, &TOKENS_21),
+  (349074, &TOKENS_40),
+  (349122, &TOKENS_33),
+  (349301, &TOKENS_40),
+  (349349, &TOKENS_21),
+  (3495, &TOKENS_10),
+  (349567, &TOKENS_145),
+  (349741, &TOKENS_124),
+  (349801, &TOKENS_31),
+  (349923, &TOKENS_40),
+  (349971, &TOKENS_21),
+  (350332, &TOKENS_102),
+  (350474, &TOKENS_130),
+  (350534, &TOKENS_92),
+  (350860, &TOKENS_24),
+  (350914, &TOKENS_73),
+  (351002, &TOKENS_73),
+  (351104, &TOKENS_21),
+  (351763, &TOKENS_10),
+  (351811, &TOKENS_86),
+  (351859, &TOKENS_104),
+  (351906, &TOKENS_126),
+  (351954, &TOKENS_127),
+  (352153, &TOKENS_86),
+  (352200, &TOKENS_104),
+  (352259, &TOKENS_31),
+  (352351, &TOKENS_86),
+  (352399, &TOKENS_86),
+  (352460, &TOKENS_63),
+  (353002, &TOKENS_4),
+  (353795, &TOKENS_58),
+  (353843, &TOKENS_150),
+  (353914, &TOKENS_85),
+  (353979, &TOKENS_85),
+  (354044, &TOKENS_85),
+  (354109, &TOKENS_85),
+  (35411, &TOKENS_29),
+  (354174, &TOKENS_85),
+  (354239, &TOKENS_85),
+  (3543, &TOKENS_74),
+  (354304, &TOKENS_85),
+  (354369, &TOKENS_85),
+  (354434, &TOKENS_85),
+  (354499, &TOKENS_85),
+  (354564, &TOKENS_85),
+  (354629, &TOKENS_85),
+  (354694, &TOKENS_85),
+  (354759, &TOKENS_85),
+  (354824, &TOKENS_85),
+  (354889, &TOKENS_85),
+  (35517, &TOKENS_4),
+  (355355, &TOKENS_58),
+  (355474, &TOKENS_4),
+  (356267, &TOKENS_58),
+  (356363, &TOKENS_4),
+  (357156, &TOKENS_58),
+  (357252, &TOKENS_4),
+  (358045, &TOKENS_58),
+  (358165, &TOKENS_4),
+  (358958, &TOKENS_58),
+  (359018, &TOKENS_4),
+  (3591, &TOKENS_121),
+  (359811, &TOKENS_58),
+  (359931, &TOKENS_4),
+  (360724, &TOKENS_58),
+  (360820, &TOKENS_26),
+  (360891, &TOKENS_15),
+  (360939, &TOKENS_24),
+  (361082, &TOKENS_86),
+  (361129, &TOKENS_26),
+  (361285, &TOKENS_86),
+  (361346, &TOKENS_63),
+  (362289, &TOKENS_26),
+  (362360, &TOKENS_15),
+  (362408, &TOKENS_24),
+  (362551, &TOKENS_24),
+  (362650, &TOKENS_24),
+  (362749, &TOKENS_24),
+  (362848, &TOKENS_131),
+  (362896, &TOKENS_99),
+  (362974, &TOKENS_128),
+  (363062, &TOKENS_128),
+  (36310, &TOKENS_29),
+  (363152, &TOKENS_133),
+  (363260, &TOKENS_92),
+  (363586, &TOKENS_24),
+  (363681, &TOKENS_73),
+  (363783, &TOKENS_21),
+  (3638, &TOKENS_21),
+  (36440, &TOKENS_4),
+  (364442, &TOKENS_10),
+  (364490, &TOKENS_70),
+  (364538, &TOKENS_67),
+  (364585, &TOKENS_126),
+  (364633, &TOKENS_127),
+  (364832, &TOKENS_70),
+  (364879, &TOKENS_67),
+  (364938, &TOKENS_31),
+  (365030, &TOKENS_70),
+  (365078, &TOKENS_70),
+  (365139, &TOKENS_63),
+  (365681, &TOKENS_4),
+  (366474, &TOKENS_34),
+  (366522, &TOKENS_140),
+  (366599, &TOKENS_46),
+  (366664, &TOKENS_46),
+  (366729, &TOKENS_46),
+  (366794, &TOKENS_46),
+  (366859, &TOKENS_46),
+  (366924, &TOKENS_46),
+  (366989, &TOKENS_46),
+  (367054, &TOKENS_46),
+  (367119, &TOKENS_46),
+  (367184, &TOKENS_46),
+  (367249, &TOKENS_46),
+  (367314, &TOKENS_46),
+  (367379, &TOKENS_46),
+  (367444, &TOKENS_46),
+  (367509, &TOKENS_46),
+  (367574, &TOKENS_46),
+  (368040, &TOKENS_34),
+  (368159, &TOKENS_4),
+  (368952, &TOKENS_34),
+  (369072, &TOKENS_4),
+  (369865, &TOKENS_34),
+  (369961, &TOKENS_4),
+  (370754, &TOKENS_34),
+  (370850, &TOKENS_4),
+  (371643, &TOKENS_34),
+  (371763, &TOKENS_4),
+  (37233, &TOKENS_16),
+  (372556, &TOKENS_34),
+  (372616, &TOKENS_4),
+  (373409, &TOKENS_34),
+  (373505, &TOKENS_70),
+  (373661, &TOKENS_70),
+  (37369, &TOKENS_4),
+  (373722, &TOKENS_63),
+  (374665, &TOKENS_33),
+  (374843, &TOKENS_109),
+  (375253, &TOKENS_36),
+  (375307, &TOKENS_59),
+  (375366, &TOKENS_41),
+  (375638, &TOKENS_24),
+  (375738, &TOKENS_59),
+  (376078, &TOKENS_59),
+  (376138, &TOKENS_25),
+  (376571, &TOKENS_59),
+  (376630, &TOKENS_25),
+  (377062, &TOKENS_59),
+  (377121, &TOKENS_41),
+  (377388, &TOKENS_24),
+  (377483, &TOKENS_73),
+  (377585, &TOKENS_126),
+  (377633, &TOKENS_127),
+  (377832, &TOKENS_70),
+  (377893, &TOKENS_63),
+  (378835, &TOKENS_59),
+  (378885, &TOKENS_26),
+  (378956, &TOKENS_15),
+  (379004, &TOKENS_24),
+  (379104, &TOKENS_59),
+  (379195, &TOKENS_59),
+  (379354, &TOKENS_70),
+  (379415, &TOKENS_63),
+  (380357, &TOKENS_59),
+  (380407, &TOKENS_26),
+  (380478, &TOKENS_15),
+  (380526, &TOKENS_24),
+  (380626, &TOKENS_59),
+  (380717, &TOKENS_59),
+  (380767, &TOKENS_24),
+  (380867, &TOKENS_59),
+  (380915, &TOKENS_59),
+  (380962, &TOKENS_24),
+  (381062, &TOKENS_59),
+  (381416, &TOKENS_59),
+  (381476, &TOKENS_41),
+  (38162, &TOKENS_16),
+  (382042, &TOKENS_59),
+  (382090, &TOKENS_59),
+  (382151, &TOKENS_25),
+  (382585, &TOKENS_59),
+  (382632, &TOKENS_59),
+  (382692, &TOKENS_25),
+  (38292, &TOKENS_4),
+  (383125, &TOKENS_39),
+  (383173, &TOKENS_2),
+  (383305, &TOKENS_42),
+  (383353, &TOKENS_92),
+  (384030, &TOKENS_36),
+  (384084, &TOKENS_42),
+  (384132, &TOKENS_164),
+  (384232, &TOKENS_36),
+  (384393, &TOKENS_37),
+  (384591, &TOKENS_24),
+  (384735, &TOKENS_56),
+  (384795, &TOKENS_42),
+  (384843, &TOKENS_92),
+  (385520, &TOKENS_36),
+  (385575, &TOKENS_24),
+  (385696, &TOKENS_31),
+  (385875, &TOKENS_105),
+  (386011, &TOKENS_105),
+  (386083, &TOKENS_133),
+  (386298, &TOKENS_37),
+  (386496, &TOKENS_33),
+  (386675, &TOKENS_152),
+  (386734, &TOKENS_10),
+  (386782, &TOKENS_42),
+  (386830, &TOKENS_92),
+  (387507, &TOKENS_36),
+  (387624, &TOKENS_105),
+  (387696, &TOKENS_133),
+  (387911, &TOKENS_37),
+  (388109, &TOKENS_49),
+  (388245, &TOKENS_33),
+  (388574, &TOKENS_10),
+  (388622, &TOKENS_42),
+  (388670, &TOKENS_92),
+  (389347, &TOKENS_36),
+  (389464, &TOKENS_33),
+  (389642, &TOKENS_110),
+  (389701, &TOKENS_33),
+  (389879, &TOKENS_167),
+  (389938, &TOKENS_42),
+  (389986, &TOKENS_33),
+  (390209, &TOKENS_123),
+  (390268, &TOKENS_57),
+  (390836, &TOKENS_27),
+  (39085, &TOKENS_16),
+  (390895, &TOKENS_41),
+  (391167, &TOKENS_24),
+  (391267, &TOKENS_27),
+  (391607, &TOKENS_27),
+  (391667, &TOKENS_25),
+  (39191, &TOKENS_4),
+  (392100, &TOKENS_27),
+  (392159, &TOKENS_25),
+  (392591, &TOKENS_27),
+  (392650, &TOKENS_41),
+  (392917, &TOKENS_24),
+  (393012, &TOKENS_73),
+  (393114, &TOKENS_126),
+  (393162, &TOKENS_127),
+  (393361, &TOKENS_86),
+  (393422, &TOKENS_63),
+  (394364, &TOKENS_27),
+  (394414, &TOKENS_26),
+  (394485, &TOKENS_15),
+  (394533, &TOKENS_24),
+  (394633, &TOKENS_27),
+  (394724, &TOKENS_27),
+  (394883, &TOKENS_86),
+  (394944, &TOKENS_63),
+  (395886, &TOKENS_27),
+  (395936, &TOKENS_26),
+  (396007, &TOKENS_15),
+  (396055, &TOKENS_24),
+  (396155, &TOKENS_27),
+  (396246, &TOKENS_27),
+  (396296, &TOKENS_24),
+  (396396, &TOKENS_27),
+  (396444, &TOKENS_27),
+  (396491, &TOKENS_24),
+  (396591, &TOKENS_27),
+  (396945, &TOKENS_27),
+  (397005, &TOKENS_41),
+  (397571, &TOKENS_27),
+  (397632, &TOKENS_25),
+  (398066, &TOKENS_27),
+  (398114, &TOKENS_27),
+  (398161, &TOKENS_27),
+  (398221, &TOKENS_25),
+  (398654, &TOKENS_39),
+  (398702, &TOKENS_2),
+  (398774, &TOKENS_92),
+  (399463, &TOKENS_162),
+  (399528, &TOKENS_43),
+  (399564, &TOKENS_43),
+  (399613, &TOKENS_4),
+  (39984, &TOKENS_16),
+  (400456, &TOKENS_42),
+  (40090, &TOKENS_4),
+  (40883, &TOKENS_16),
+  (41013, &TOKENS_4),
+  (41806, &TOKENS_16),
+  (41912, &TOKENS_4),
+  (42705, &TOKENS_16),
+  (42775, &TOKENS_4),
+  (4297, &TOKENS_10),
+  (4345, &TOKENS_76),
+  (43568, &TOKENS_0),
+  (43616, &TOKENS_169),
+  (43693, &TOKENS_83),
+  (43751, &TOKENS_4),
+  (4393, &TOKENS_33),
+  (44544, &TOKENS_11),
+  (44674, &TOKENS_4),
+  (45467, &TOKENS_16),
+  (45604, &TOKENS_4),
+  (4572, &TOKENS_119),
+  (4631, &TOKENS_76),
+  (46397, &TOKENS_11),
+  (46527, &TOKENS_4),
+  (4679, &TOKENS_118),
+  (47320, &TOKENS_11),
+  (47426, &TOKENS_4),
+  (4815, &TOKENS_76),
+  (48219, &TOKENS_11),
+  (48325, &TOKENS_4),
+  (4862, &TOKENS_76),
+  (4909, &TOKENS_76),
+  (49118, &TOKENS_11),
+  (49248, &TOKENS_4),
+  (4956, &TOKENS_76),
+  (5003, &TOKENS_76),
+  (50041, &TOKENS_11),
+  (50147, &TOKENS_4),
+  (5050, &TOKENS_76),
+  (50940, &TOKENS_11),
+  (5097, &TOKENS_76),
+  (51010, &TOKENS_14),
+  (5144, &TOKENS_76),
+  (51837, &TOKENS_18),
+  (5191, &TOKENS_76),
+  (51966, &TOKENS_4),
+  (5238, &TOKENS_76),
+  (52759, &TOKENS_18),
+  (5285, &TOKENS_76),
+  (52889, &TOKENS_4),
+  (53682, &TOKENS_18),
+  (53752, &TOKENS_4),
+  (54545, &TOKENS_18),
+  (54675, &TOKENS_4),
+  (55468, &TOKENS_18),
+  (55574, &TOKENS_4),
+  (5628, &TOKENS_74),
+  (56367, &TOKENS_18),
+  (56473, &TOKENS_4),
+  (5675, &TOKENS_121),
+  (57266, &TOKENS_18),
+  (5734, &TOKENS_31),
+  (57372, &TOKENS_17),
+  (57448, &TOKENS_4),
+  (58246, &TOKENS_18),
+  (5826, &TOKENS_74),
+  (58376, &TOKENS_79),
+  (58441, &TOKENS_79),
+  (58506, &TOKENS_79),
+  (58571, &TOKENS_79),
+  (58636, &TOKENS_79),
+  (58701, &TOKENS_79),
+  (5874, &TOKENS_74),
+  (58766, &TOKENS_79),
+  (58831, &TOKENS_79),
+  (58896, &TOKENS_79),
+  (58961, &TOKENS_79),
+  (59026, &TOKENS_79),
+  (59091, &TOKENS_79),
+  (59156, &TOKENS_79),
+  (59221, &TOKENS_79),
+  (59286, &TOKENS_79),
+  (5933, &TOKENS_64),
+  (59351, &TOKENS_79),
+  (59416, &TOKENS_12),
+  (59546, &TOKENS_31),
+  (60058, &TOKENS_0),
+  (60177, &TOKENS_4),
+  (60970, &TOKENS_0),
+  (61066, &TOKENS_4),
+  (6128, &TOKENS_31),
+  (61859, &TOKENS_0),
+  (61979, &TOKENS_4),
+  (6241, &TOKENS_33),
+  (62772, &TOKENS_0),
+  (62868, &TOKENS_4),
+  (63661, &TOKENS_0),
+  (63721, &TOKENS_4),
+  (6419, &TOKENS_88),
+  (64514, &TOKENS_0),
+  (64634, &TOKENS_4),
+  (65427, &TOKENS_0),
+  (65523, &TOKENS_53),
+  (65563, &TOKENS_31),
+  (65716, &TOKENS_54),
+  (65805, &TOKENS_47),
+  (65887, &TOKENS_7),
+  (65975, &TOKENS_36),
+  (66033, &TOKENS_7),
+  (66081, &TOKENS_71),
+  (66157, &TOKENS_7),
+  (66204, &TOKENS_65),
+  (66272, &TOKENS_36),
+  (66330, &TOKENS_7),
+  (66419, &TOKENS_36),
+  (66477, &TOKENS_7),
+  (66525, &TOKENS_36),
+  (66583, &TOKENS_7),
+  (66671, &TOKENS_7),
+  (66743, &TOKENS_44),
+  (66809, &TOKENS_19),
+  (66875, &TOKENS_146),
+  (66952, &TOKENS_72),
+  (67017, &TOKENS_47),
+  (67099, &TOKENS_44),
+  (67187, &TOKENS_36),
+  (67245, &TOKENS_44),
+  (67293, &TOKENS_71),
+  (67369, &TOKENS_44),
+  (67416, &TOKENS_65),
+  (67484, &TOKENS_36),
+  (67542, &TOKENS_44),
+  (67631, &TOKENS_36),
+  (67689, &TOKENS_44),
+  (67737, &TOKENS_36),
+  (67795, &TOKENS_44),
+  (67883, &TOKENS_44),
+  (67949, &TOKENS_125),
+  (68026, &TOKENS_47),
+  (68108, &TOKENS_19),
+  (68196, &TOKENS_36),
+  (6821, &TOKENS_148),
+  (68254, &TOKENS_19),
+  (68302, &TOKENS_71),
+  (68378, &TOKENS_19),
+  (68425, &TOKENS_65),
+  (68493, &TOKENS_36),
+  (68551, &TOKENS_19),
+  (68640, &TOKENS_36),
+  (68698, &TOKENS_19),
+  (6872, &TOKENS_30),
+  (68746, &TOKENS_36),
+  (68804, &TOKENS_19),
+  (68892, &TOKENS_19),
+  (68958, &TOKENS_72),
+  (69023, &TOKENS_116),
+  (69128, &TOKENS_28),
+  (6923, &TOKENS_64),
+  (69290, &TOKENS_21),
+  (69508, &TOKENS_21),
+  (69726, &TOKENS_33),
+  (69803, &TOKENS_33),
+  (69880, &TOKENS_118),
+  (70502, &TOKENS_10),
+  (70556, &TOKENS_107),
+  (70603, &TOKENS_139),
+  (70662, &TOKENS_63),
+  (708, &TOKENS_24),
+  (71854, &TOKENS_23),
+  (72852, &TOKENS_31),
+  (72919, &TOKENS_13),
+  (72994, &TOKENS_20),
+  (73158, &TOKENS_68),
+  (73227, &TOKENS_117),
+  (74075, &TOKENS_138),
+  (74121, &TOKENS_13),
+  (74196, &TOKENS_20),
+  (74484, &TOKENS_13),
+  (74560, &TOKENS_20),
+  (7469, &TOKENS_124),
+  (74700, &TOKENS_13),
+  (74712, &TOKENS_68),
+  (74781, &TOKENS_117),
+  (7545, &TOKENS_64),
+  (75629, &TOKENS_4),
+  (767, &TOKENS_73),
+  (76754, &TOKENS_16),
+  (76890, &TOKENS_4),
+  (78015, &TOKENS_16),
+  (78145, &TOKENS_4),
+  (79270, &TOKENS_16),
+  (79376, &TOKENS_4),
+  (80501, &TOKENS_16),
+  (80607, &TOKENS_4),
+  (8073, &TOKENS_149),
+  (8170, &TOKENS_141),
+  (81732, &TOKENS_16),
+  (81862, &TOKENS_4),
+  (82987, &TOKENS_16),
+  (83093, &TOKENS_4),
+  (8381, &TOKENS_10),
+  (84218, &TOKENS_16),
+  (84288, &TOKENS_4),
+  (8445, &TOKENS_10),
+  (8509, &TOKENS_141),
+  (85729, &TOKENS_5),
+  (85859, &TOKENS_4),
+  (86984, &TOKENS_5),
+  (87114, &TOKENS_4),
+  (8720, &TOKENS_10),
+  (8784, &TOKENS_10),
+  (880, &TOKENS_21),
+  (88239, &TOKENS_5),
+  (88345, &TOKENS_4),
+  (8848, &TOKENS_124),
+  (8918, &TOKENS_76),
+  (89470, &TOKENS_5),
+  (89540, &TOKENS_4),
+  (8966, &TOKENS_76),
+  (9013, &TOKENS_74),
+  (9060, &TOKENS_76),
+  (90665, &TOKENS_5),
+  (90771, &TOKENS_4),
+  (91, &TOKENS_130),
+  (9107, &TOKENS_76),
+  (9154, &TOKENS_76),
+  (91896, &TOKENS_5),
+  (92002, &TOKENS_4),
+  (9201, &TOKENS_6),
+  (9248, &TOKENS_76),
+  (9295, &TOKENS_76),
+  (93127, &TOKENS_5),
+  (93257, &TOKENS_13),
+  (93269, &TOKENS_4),
+  (9342, &TOKENS_50),
+  (9431, &TOKENS_47),
+  (94394, &TOKENS_16),
+  (94530, &TOKENS_4),
+  (9513, &TOKENS_3),
+  (95360, &TOKENS_4),
+  (9560, &TOKENS_132),
+  (96190, &TOKENS_4),
+  (9657, &TOKENS_36),
+  (9715, &TOKENS_3),
+  (97315, &TOKENS_16),
+  (97445, &TOKENS_4),
+  (9763, &TOKENS_71),
+  (9839, &TOKENS_3),
+  (98570, &TOKENS_16),
+  (98676, &TOKENS_4),
+  (9886, &TOKENS_65),
+  (9954, &TOKENS_36),
+  (99801, &TOKENS_16),
+  (99907, &TOKENS_4),
 ];
 
-const TOKENS_0: [u32; 1] = [41];
+const TOKENS_0: [u32; 8] = [25, 30, 32, 33, 34, 35, 36, 69];
 
-const TOKENS_1: [u32; 2] = [11, 13];
+const TOKENS_1: [u32; 17] = [11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 33, 34, 35, 36, 41, 69, 83];
 
-const TOKENS_10: [u32; 25] =
-  [31, 34, 81, 82, 84, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 127, 128, 129];
+const TOKENS_10: [u32; 1] = [32];
 
-const TOKENS_100: [u32; 17] = [8, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 120, 127, 129, 135];
+const TOKENS_100: [u32; 8] = [11, 25, 30, 31, 33, 34, 35, 36];
 
-const TOKENS_101: [u32; 3] = [9, 11, 32];
+const TOKENS_101: [u32; 17] = [10, 22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 126, 130, 132, 138];
 
-const TOKENS_102: [u32; 4] = [1, 17, 18, 19];
+const TOKENS_102: [u32; 5] = [22, 24, 25, 27, 41];
 
-const TOKENS_103: [u32; 1] = [10];
+const TOKENS_103: [u32; 10] = [10, 31, 41, 79, 86, 113, 115, 128, 132, 138];
 
-const TOKENS_104: [u32; 11] = [10, 31, 41, 76, 83, 110, 112, 123, 125, 129, 135];
+const TOKENS_104: [u32; 8] = [13, 22, 24, 25, 27, 41, 45, 82];
 
-const TOKENS_105: [u32; 18] = [10, 25, 31, 33, 41, 76, 79, 83, 110, 112, 120, 123, 125, 129, 135, 136, 137, 140];
+const TOKENS_105: [u32; 2] = [10, 31];
 
-const TOKENS_106: [u32; 1] = [31];
+const TOKENS_106: [u32; 2] = [54, 56];
 
-const TOKENS_107: [u32; 2] = [44, 72];
+const TOKENS_107: [u32; 39] = [
+  9, 10, 11, 13, 21, 22, 24, 25, 27, 31, 32, 33, 41, 45, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 79, 82, 85, 86, 113, 115, 123,
+  126, 128, 130, 132, 138, 139, 140, 143,
+];
 
-const TOKENS_108: [u32; 7] = [9, 21, 25, 33, 136, 137, 140];
+const TOKENS_108: [u32; 19] = [10, 21, 22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138];
 
-const TOKENS_109: [u32; 2] = [54, 56];
+const TOKENS_109: [u32; 2] = [11, 82];
 
-const TOKENS_11: [u32; 9] = [25, 30, 31, 32, 33, 34, 35, 36, 66];
+const TOKENS_11: [u32; 7] = [9, 25, 30, 33, 34, 35, 36];
 
-const TOKENS_110: [u32; 1] = [8];
+const TOKENS_110: [u32; 2] = [13, 82];
 
-const TOKENS_111: [u32; 8] = [8, 22, 24, 25, 27, 41, 45, 66];
+const TOKENS_111: [u32; 8] = [25, 30, 31, 32, 33, 34, 35, 36];
 
-const TOKENS_112: [u32; 2] = [60, 61];
+const TOKENS_112: [u32; 17] = [10, 22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 123, 130, 132, 138];
 
-const TOKENS_113: [u32; 7] = [9, 21, 25, 33, 79, 136, 137];
+const TOKENS_113: [u32; 3] = [40, 41, 75];
 
-const TOKENS_114: [u32; 2] = [83, 125];
+const TOKENS_114: [u32; 11] = [10, 31, 41, 79, 86, 113, 115, 126, 128, 132, 138];
 
-const TOKENS_115: [u32; 2] = [9, 83];
+const TOKENS_115: [u32; 21] = [10, 22, 24, 25, 27, 31, 33, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140];
 
-const TOKENS_116: [u32; 4] = [10, 31, 131, 132];
+const TOKENS_116: [u32; 19] = [10, 25, 31, 32, 33, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140];
 
-const TOKENS_117: [u32; 15] = [46, 47, 49, 50, 53, 55, 58, 59, 73, 74, 75, 103, 107, 108, 109];
+const TOKENS_117: [u32; 26] =
+  [10, 31, 34, 84, 85, 87, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 130, 131, 132];
 
-const TOKENS_118: [u32; 10] = [11, 13, 25, 27, 30, 33, 34, 35, 36, 80];
+const TOKENS_118: [u32; 9] = [70, 114, 116, 117, 118, 119, 120, 121, 122];
 
-const TOKENS_119: [u32; 21] = [10, 25, 31, 32, 33, 41, 45, 66, 76, 79, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137, 140];
+const TOKENS_119: [u32; 17] = [10, 25, 31, 33, 41, 79, 82, 86, 113, 115, 123, 126, 128, 132, 138, 139, 140];
 
-const TOKENS_12: [u32; 8] = [9, 25, 30, 31, 33, 34, 35, 36];
+const TOKENS_12: [u32; 2] = [41, 75];
 
-const TOKENS_120: [u32; 2] = [3, 4];
+const TOKENS_120: [u32; 3] = [27, 32, 83];
 
-const TOKENS_121: [u32; 14] = [10, 31, 32, 41, 45, 66, 76, 82, 110, 112, 123, 127, 129, 135];
+const TOKENS_121: [u32; 18] = [10, 25, 31, 33, 41, 79, 82, 86, 113, 115, 123, 126, 128, 132, 138, 139, 140, 143];
 
-const TOKENS_122: [u32; 2] = [41, 72];
+const TOKENS_122: [u32; 5] = [1, 2, 17, 18, 19];
 
-const TOKENS_123: [u32; 12] = [31, 32, 41, 45, 66, 76, 82, 110, 112, 127, 129, 135];
+const TOKENS_123: [u32; 4] = [11, 31, 41, 82];
 
-const TOKENS_124: [u32; 10] = [31, 41, 76, 83, 110, 112, 123, 125, 129, 135];
+const TOKENS_124: [u32; 2] = [9, 21];
 
-const TOKENS_125: [u32; 3] = [31, 41, 72];
+const TOKENS_125: [u32; 14] = [10, 31, 32, 41, 45, 69, 79, 85, 113, 115, 126, 130, 132, 138];
 
-const TOKENS_126: [u32; 3] = [9, 11, 27];
+const TOKENS_126: [u32; 1] = [42];
 
-const TOKENS_127: [u32; 2] = [107, 108];
+const TOKENS_127: [u32; 3] = [31, 41, 75];
 
-const TOKENS_128: [u32; 13] = [8, 22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41, 66];
+const TOKENS_128: [u32; 3] = [10, 31, 39];
 
-const TOKENS_129: [u32; 3] = [11, 13, 45];
+const TOKENS_129: [u32; 6] = [22, 24, 25, 27, 41, 69];
 
-const TOKENS_13: [u32; 7] = [11, 76, 79, 110, 112, 129, 135];
+const TOKENS_13: [u32; 2] = [9, 11];
 
-const TOKENS_130: [u32; 3] = [10, 31, 39];
+const TOKENS_130: [u32; 2] = [3, 4];
 
-const TOKENS_131: [u32; 1] = [25];
+const TOKENS_131: [u32; 1] = [39];
 
-const TOKENS_132: [u32; 9] = [9, 11, 25, 30, 31, 33, 34, 35, 36];
+const TOKENS_132: [u32; 2] = [11, 141];
 
-const TOKENS_133: [u32; 3] = [40, 41, 72];
+const TOKENS_133: [u32; 4] = [10, 31, 134, 135];
 
-const TOKENS_134: [u32; 20] = [8, 10, 21, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135];
+const TOKENS_134: [u32; 22] = [10, 22, 24, 25, 27, 31, 33, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140, 143];
 
-const TOKENS_135: [u32; 12] = [22, 24, 25, 27, 41, 60, 61, 62, 63, 64, 65, 79];
+const TOKENS_135: [u32; 6] = [1, 2, 17, 18, 19, 20];
 
-const TOKENS_136: [u32; 9] = [22, 24, 25, 27, 41, 62, 63, 64, 65];
+const TOKENS_136: [u32; 7] = [11, 79, 82, 113, 115, 132, 138];
 
-const TOKENS_137: [u32; 1] = [27];
+const TOKENS_137: [u32; 3] = [9, 11, 32];
 
-const TOKENS_138: [u32; 5] = [1, 2, 17, 18, 19];
+const TOKENS_138: [u32; 1] = [40];
 
-const TOKENS_139: [u32; 22] = [8, 10, 22, 24, 25, 27, 31, 33, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137];
+const TOKENS_139: [u32; 8] = [11, 13, 22, 24, 25, 27, 41, 45];
 
-const TOKENS_14: [u32; 10] = [10, 31, 41, 76, 83, 110, 112, 125, 129, 135];
+const TOKENS_14: [u32; 26] =
+  [31, 34, 84, 85, 86, 87, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 130, 131, 132];
 
-const TOKENS_140: [u32; 18] = [8, 10, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 123, 127, 129, 135];
+const TOKENS_140: [u32; 10] = [11, 13, 25, 27, 30, 33, 34, 35, 36, 83];
 
-const TOKENS_141: [u32; 17] = [10, 25, 31, 33, 41, 76, 83, 110, 112, 120, 123, 125, 129, 135, 136, 137, 140];
+const TOKENS_141: [u32; 4] = [32, 115, 132, 138];
 
-const TOKENS_142: [u32; 4] = [11, 13, 45, 79];
+const TOKENS_142: [u32; 1] = [71];
 
-const TOKENS_143: [u32; 1] = [67];
+const TOKENS_143: [u32; 23] =
+  [10, 22, 24, 25, 27, 31, 33, 41, 45, 69, 79, 82, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140, 143];
 
-const TOKENS_144: [u32; 1] = [79];
+const TOKENS_144: [u32; 20] = [10, 25, 31, 32, 33, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140, 143];
 
-const TOKENS_145: [u32; 1] = [40];
+const TOKENS_145: [u32; 2] = [40, 41];
 
-const TOKENS_146: [u32; 11] = [10, 31, 41, 76, 83, 110, 112, 120, 125, 129, 135];
+const TOKENS_146: [u32; 14] = [10, 31, 32, 41, 45, 69, 79, 85, 113, 115, 123, 130, 132, 138];
 
-const TOKENS_147: [u32; 7] = [8, 22, 24, 25, 27, 41, 79];
+const TOKENS_147: [u32; 9] = [9, 11, 25, 30, 31, 33, 34, 35, 36];
 
-const TOKENS_148: [u32; 4] = [9, 11, 27, 32];
+const TOKENS_148: [u32; 8] = [9, 21, 25, 33, 82, 139, 140, 143];
 
-const TOKENS_149: [u32; 3] = [9, 21, 39];
+const TOKENS_149: [u32; 6] = [9, 21, 25, 33, 139, 140];
 
-const TOKENS_15: [u32; 7] = [31, 41, 76, 110, 112, 129, 135];
+const TOKENS_15: [u32; 1] = [43];
 
-const TOKENS_150: [u32; 18] = [8, 10, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 120, 127, 129, 135];
+const TOKENS_150: [u32; 13] = [13, 22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41, 83];
 
-const TOKENS_151: [u32; 8] = [9, 21, 25, 33, 79, 136, 137, 140];
+const TOKENS_151: [u32; 20] = [10, 25, 31, 32, 33, 41, 45, 69, 79, 82, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140];
 
-const TOKENS_152: [u32; 1] = [68];
+const TOKENS_152: [u32; 2] = [32, 82];
 
-const TOKENS_153: [u32; 9] = [67, 111, 113, 114, 115, 116, 117, 118, 119];
+const TOKENS_153: [u32; 3] = [17, 18, 19];
 
-const TOKENS_154: [u32; 4] = [11, 31, 41, 79];
+const TOKENS_154: [u32; 1] = [27];
 
-const TOKENS_155: [u32; 17] = [8, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 124, 127, 129, 135];
+const TOKENS_155: [u32; 7] = [22, 24, 25, 27, 41, 45, 69];
 
-const TOKENS_156: [u32; 9] = [8, 13, 22, 24, 25, 27, 41, 45, 79];
+const TOKENS_156: [u32; 3] = [9, 21, 39];
 
-const TOKENS_157: [u32; 10] = [8, 11, 13, 22, 24, 25, 27, 32, 41, 66];
+const TOKENS_157: [u32; 2] = [60, 61];
 
-const TOKENS_158: [u32; 2] = [11, 138];
+const TOKENS_158: [u32; 2] = [21, 82];
 
-const TOKENS_159: [u32; 26] =
-  [3, 4, 8, 10, 22, 24, 25, 27, 31, 33, 41, 45, 66, 76, 79, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137, 140];
+const TOKENS_159: [u32; 1] = [25];
 
-const TOKENS_16: [u32; 18] = [8, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135];
+const TOKENS_16: [u32; 7] = [25, 30, 32, 33, 34, 35, 36];
 
-const TOKENS_160: [u32; 18] = [8, 11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 33, 34, 35, 36, 41, 66, 80];
+const TOKENS_160: [u32; 3] = [9, 11, 27];
 
-const TOKENS_161: [u32; 37] = [
-  8, 9, 10, 11, 13, 21, 22, 24, 25, 27, 31, 32, 33, 41, 45, 60, 61, 62, 63, 64, 65, 66, 76, 79, 82, 83, 110, 112, 120, 123, 125,
-  127, 129, 135, 136, 137, 140,
-];
+const TOKENS_161: [u32; 22] = [10, 22, 24, 25, 27, 31, 33, 41, 45, 69, 79, 82, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140];
 
-const TOKENS_162: [u32; 10] = [25, 27, 30, 32, 33, 34, 35, 36, 66, 80];
+const TOKENS_162: [u32; 2] = [28, 29];
 
-const TOKENS_163: [u32; 29] =
-  [8, 9, 10, 11, 21, 22, 24, 25, 27, 31, 32, 33, 41, 45, 66, 76, 79, 82, 83, 110, 112, 120, 123, 125, 127, 129, 135, 136, 137];
+const TOKENS_163: [u32; 13] = [22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41, 69, 83];
 
-const TOKENS_164: [u32; 2] = [32, 79];
+const TOKENS_164: [u32; 1] = [132];
 
-const TOKENS_165: [u32; 3] = [17, 18, 19];
+const TOKENS_165: [u32; 8] = [54, 72, 73, 104, 105, 130, 131, 132];
 
-const TOKENS_166: [u32; 2] = [28, 29];
+const TOKENS_166: [u32; 15] = [22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 130, 132, 138];
 
-const TOKENS_167: [u32; 17] = [10, 25, 31, 33, 41, 76, 79, 83, 110, 112, 120, 123, 125, 129, 135, 136, 137];
+const TOKENS_167: [u32; 6] = [22, 24, 25, 27, 41, 82];
 
-const TOKENS_168: [u32; 7] = [8, 22, 24, 25, 27, 41, 66];
+const TOKENS_168: [u32; 18] = [10, 22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138];
 
-const TOKENS_169: [u32; 2] = [11, 79];
+const TOKENS_169: [u32; 10] = [25, 27, 30, 32, 33, 34, 35, 36, 69, 83];
 
-const TOKENS_17: [u32; 9] = [31, 41, 76, 82, 110, 112, 127, 129, 135];
+const TOKENS_17: [u32; 2] = [9, 86];
 
-const TOKENS_170: [u32; 9] = [9, 25, 27, 30, 33, 34, 35, 36, 80];
+const TOKENS_170: [u32; 21] = [9, 9, 11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 32, 33, 34, 35, 36, 41, 69, 83, 86];
 
-const TOKENS_171: [u32; 9] = [9, 25, 30, 31, 33, 34, 35, 36, 83];
+const TOKENS_171: [u32; 28] =
+  [9, 10, 11, 21, 22, 24, 25, 27, 31, 32, 33, 41, 45, 69, 79, 82, 85, 86, 113, 115, 123, 126, 128, 130, 132, 138, 139, 140];
 
-const TOKENS_172: [u32; 8] = [25, 30, 31, 32, 33, 34, 35, 36];
+const TOKENS_172: [u32; 25] =
+  [3, 4, 10, 22, 24, 25, 27, 31, 33, 41, 45, 69, 79, 82, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140, 143];
 
-const TOKENS_18: [u32; 13] = [31, 32, 41, 45, 66, 76, 82, 110, 112, 123, 127, 129, 135];
+const TOKENS_18: [u32; 8] = [9, 25, 30, 33, 34, 35, 36, 86];
 
-const TOKENS_19: [u32; 1] = [21];
+const TOKENS_19: [u32; 13] = [31, 32, 41, 45, 69, 79, 85, 113, 115, 126, 130, 132, 138];
 
-const TOKENS_2: [u32; 8] = [9, 11, 25, 30, 33, 34, 35, 36];
+const TOKENS_2: [u32; 2] = [110, 111];
 
-const TOKENS_20: [u32; 16] = [10, 25, 31, 33, 41, 76, 83, 110, 112, 120, 123, 125, 129, 135, 136, 137];
+const TOKENS_20: [u32; 4] = [41, 54, 72, 73];
 
-const TOKENS_21: [u32; 5] = [10, 11, 31, 131, 132];
+const TOKENS_21: [u32; 9] = [31, 41, 79, 85, 113, 115, 130, 132, 138];
 
-const TOKENS_22: [u32; 9] = [31, 41, 76, 83, 110, 112, 125, 129, 135];
+const TOKENS_22: [u32; 15] = [22, 24, 25, 27, 41, 60, 61, 62, 63, 64, 65, 66, 67, 68, 82];
 
-const TOKENS_23: [u32; 3] = [54, 56, 57];
+const TOKENS_23: [u32; 26] =
+  [31, 34, 40, 84, 85, 87, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 130, 131, 132];
 
-const TOKENS_24: [u32; 7] = [8, 13, 22, 24, 25, 27, 41];
+const TOKENS_24: [u32; 1] = [75];
 
-const TOKENS_25: [u32; 6] = [11, 76, 110, 112, 129, 135];
+const TOKENS_25: [u32; 7] = [76, 77, 78, 106, 110, 111, 112];
 
-const TOKENS_26: [u32; 13] = [10, 31, 32, 41, 45, 66, 76, 82, 110, 112, 127, 129, 135];
+const TOKENS_26: [u32; 2] = [44, 75];
 
-const TOKENS_27: [u32; 2] = [9, 21];
+const TOKENS_27: [u32; 6] = [13, 22, 24, 25, 27, 41];
 
-const TOKENS_28: [u32; 17] = [8, 11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 33, 34, 35, 36, 41, 66];
+const TOKENS_28: [u32; 7] = [31, 41, 79, 113, 115, 132, 138];
 
-const TOKENS_29: [u32; 3] = [11, 138, 139];
+const TOKENS_29: [u32; 7] = [11, 25, 30, 33, 34, 35, 36];
 
-const TOKENS_3: [u32; 26] =
-  [10, 31, 34, 81, 82, 84, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 127, 128, 129];
+const TOKENS_3: [u32; 11] = [31, 41, 79, 86, 113, 115, 123, 126, 128, 132, 138];
 
-const TOKENS_30: [u32; 1] = [129];
+const TOKENS_30: [u32; 7] = [9, 21, 25, 33, 139, 140, 143];
 
-const TOKENS_31: [u32; 10] = [9, 25, 27, 30, 33, 34, 35, 36, 80, 83];
+const TOKENS_31: [u32; 1] = [41];
 
-const TOKENS_32: [u32; 7] = [9, 25, 30, 33, 34, 35, 36];
+const TOKENS_32: [u32; 9] = [9, 25, 30, 31, 33, 34, 35, 36, 86];
 
-const TOKENS_33: [u32; 19] = [10, 25, 31, 32, 33, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137];
+const TOKENS_33: [u32; 2] = [31, 41];
 
-const TOKENS_34: [u32; 2] = [31, 41];
+const TOKENS_34: [u32; 8] = [11, 13, 25, 30, 33, 34, 35, 36];
 
-const TOKENS_35: [u32; 8] = [9, 25, 30, 33, 34, 35, 36, 83];
+const TOKENS_35: [u32; 6] = [11, 22, 24, 25, 27, 41];
 
-const TOKENS_36: [u32; 14] = [8, 22, 24, 25, 25, 27, 30, 31, 33, 34, 35, 36, 41, 66];
+const TOKENS_36: [u32; 1] = [11];
 
-const TOKENS_37: [u32; 4] = [41, 54, 69, 70];
+const TOKENS_37: [u32; 5] = [10, 11, 31, 134, 135];
 
-const TOKENS_38: [u32; 2] = [32, 66];
+const TOKENS_38: [u32; 16] = [22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 123, 130, 132, 138];
 
-const TOKENS_39: [u32; 9] = [11, 13, 25, 30, 31, 33, 34, 35, 36];
+const TOKENS_39: [u32; 1] = [13];
 
-const TOKENS_4: [u32; 1] = [72];
+const TOKENS_4: [u32; 25] =
+  [31, 34, 84, 85, 87, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 130, 131, 132];
 
-const TOKENS_40: [u32; 13] = [31, 32, 41, 45, 66, 76, 82, 110, 112, 120, 127, 129, 135];
+const TOKENS_40: [u32; 1] = [21];
 
-const TOKENS_41: [u32; 7] = [11, 25, 30, 33, 34, 35, 36];
+const TOKENS_41: [u32; 12] = [46, 47, 49, 50, 53, 76, 77, 78, 106, 110, 111, 112];
 
-const TOKENS_42: [u32; 17] = [8, 10, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 127, 129, 135];
+const TOKENS_42: [u32; 1] = [10];
 
-const TOKENS_43: [u32; 13] = [31, 32, 41, 45, 66, 76, 82, 110, 112, 124, 127, 129, 135];
+const TOKENS_43: [u32; 1] = [8];
 
-const TOKENS_44: [u32; 7] = [8, 11, 22, 24, 25, 27, 41];
+const TOKENS_44: [u32; 13] = [31, 32, 41, 45, 69, 79, 85, 113, 115, 123, 130, 132, 138];
 
-const TOKENS_45: [u32; 4] = [32, 112, 129, 135];
+const TOKENS_45: [u32; 12] = [22, 24, 25, 27, 41, 62, 63, 64, 65, 66, 67, 68];
 
-const TOKENS_46: [u32; 3] = [41, 105, 106];
+const TOKENS_46: [u32; 9] = [11, 13, 25, 30, 31, 33, 34, 35, 36];
 
-const TOKENS_47: [u32; 2] = [40, 41];
+const TOKENS_47: [u32; 4] = [11, 39, 141, 142];
 
-const TOKENS_48: [u32; 4] = [11, 39, 138, 139];
+const TOKENS_48: [u32; 7] = [11, 13, 22, 24, 25, 27, 41];
 
-const TOKENS_49: [u32; 27] =
-  [10, 31, 34, 81, 82, 84, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 127, 128, 129, 130];
+const TOKENS_49: [u32; 5] = [79, 113, 115, 132, 138];
 
-const TOKENS_5: [u32; 2] = [11, 39];
+const TOKENS_5: [u32; 8] = [9, 11, 25, 30, 33, 34, 35, 36];
 
-const TOKENS_50: [u32; 26] =
-  [31, 34, 40, 81, 82, 84, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 127, 128, 129];
+const TOKENS_50: [u32; 12] = [10, 31, 41, 79, 86, 113, 115, 123, 126, 128, 132, 138];
 
-const TOKENS_51: [u32; 22] = [8, 9, 9, 11, 11, 13, 22, 24, 25, 25, 27, 30, 31, 32, 32, 33, 34, 35, 36, 41, 66, 83];
+const TOKENS_51: [u32; 16] = [11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 33, 34, 35, 36, 41, 69];
 
-const TOKENS_52: [u32; 16] = [8, 11, 13, 22, 24, 25, 25, 27, 30, 32, 33, 34, 35, 36, 41, 66];
+const TOKENS_52: [u32; 13] = [22, 24, 25, 25, 27, 30, 31, 33, 34, 35, 36, 41, 69];
 
-const TOKENS_53: [u32; 8] = [8, 11, 13, 22, 24, 25, 27, 41];
+const TOKENS_53: [u32; 1] = [82];
 
-const TOKENS_54: [u32; 23] =
-  [8, 10, 22, 24, 25, 27, 31, 33, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137, 140];
+const TOKENS_54: [u32; 15] = [10, 31, 32, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138];
 
-const TOKENS_55: [u32; 8] = [31, 40, 41, 76, 110, 112, 129, 135];
+const TOKENS_55: [u32; 16] = [22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 126, 130, 132, 138];
 
-const TOKENS_56: [u32; 3] = [11, 31, 41];
+const TOKENS_56: [u32; 2] = [32, 69];
 
-const TOKENS_57: [u32; 12] = [10, 31, 41, 76, 83, 110, 112, 120, 123, 125, 129, 135];
+const TOKENS_57: [u32; 3] = [11, 31, 41];
 
-const TOKENS_58: [u32; 4] = [31, 41, 48, 72];
+const TOKENS_58: [u32; 12] = [13, 22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41];
 
-const TOKENS_59: [u32; 9] = [8, 11, 13, 22, 24, 25, 27, 41, 45];
+const TOKENS_59: [u32; 2] = [11, 13];
 
-const TOKENS_6: [u32; 26] =
-  [31, 34, 81, 82, 83, 84, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 127, 128, 129];
+const TOKENS_6: [u32; 9] = [31, 41, 79, 86, 113, 115, 128, 132, 138];
 
-const TOKENS_60: [u32; 2] = [9, 11];
+const TOKENS_60: [u32; 3] = [32, 45, 69];
 
-const TOKENS_61: [u32; 3] = [9, 11, 39];
+const TOKENS_61: [u32; 16] = [22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 127, 130, 132, 138];
 
-const TOKENS_62: [u32; 6] = [9, 21, 25, 33, 136, 137];
+const TOKENS_62: [u32; 1] = [70];
 
-const TOKENS_63: [u32; 13] = [8, 13, 22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41];
+const TOKENS_63: [u32; 27] =
+  [10, 31, 34, 84, 85, 87, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 130, 131, 132, 133];
 
-const TOKENS_64: [u32; 20] = [10, 25, 31, 32, 33, 41, 45, 66, 76, 79, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137];
+const TOKENS_64: [u32; 8] = [31, 40, 41, 79, 113, 115, 132, 138];
 
-const TOKENS_65: [u32; 24] =
-  [8, 10, 22, 24, 25, 27, 31, 33, 41, 45, 66, 76, 79, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137, 140];
+const TOKENS_65: [u32; 3] = [11, 141, 142];
 
-const TOKENS_66: [u32; 5] = [76, 110, 112, 129, 135];
+const TOKENS_66: [u32; 14] = [22, 24, 25, 27, 41, 60, 61, 62, 63, 64, 65, 66, 67, 68];
 
-const TOKENS_67: [u32; 1] = [32];
+const TOKENS_67: [u32; 4] = [11, 13, 45, 82];
 
-const TOKENS_68: [u32; 14] = [8, 22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41, 66, 80];
+const TOKENS_68: [u32; 3] = [9, 11, 39];
 
-const TOKENS_69: [u32; 22] = [8, 9, 9, 11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 32, 33, 34, 35, 36, 41, 66, 80, 83];
+const TOKENS_69: [u32; 12] = [31, 32, 41, 45, 69, 79, 85, 113, 115, 130, 132, 138];
 
-const TOKENS_7: [u32; 7] = [25, 30, 32, 33, 34, 35, 36];
+const TOKENS_7: [u32; 14] = [31, 32, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138];
 
-const TOKENS_70: [u32; 20] = [10, 25, 31, 32, 33, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137, 140];
+const TOKENS_70: [u32; 3] = [11, 13, 45];
 
-const TOKENS_71: [u32; 8] = [11, 25, 30, 31, 33, 34, 35, 36];
+const TOKENS_71: [u32; 2] = [11, 39];
 
-const TOKENS_72: [u32; 2] = [10, 31];
+const TOKENS_72: [u32; 13] = [10, 31, 32, 41, 45, 69, 79, 85, 113, 115, 130, 132, 138];
 
-const TOKENS_73: [u32; 7] = [73, 74, 75, 103, 107, 108, 109];
+const TOKENS_73: [u32; 4] = [31, 41, 48, 75];
 
-const TOKENS_74: [u32; 14] = [8, 13, 22, 24, 25, 25, 27, 30, 31, 33, 34, 35, 36, 41];
+const TOKENS_74: [u32; 17] = [10, 25, 31, 33, 41, 79, 86, 113, 115, 123, 126, 128, 132, 138, 139, 140, 143];
 
-const TOKENS_75: [u32; 8] = [25, 30, 32, 33, 34, 35, 36, 66];
+const TOKENS_75: [u32; 12] = [22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41, 69];
 
-const TOKENS_76: [u32; 14] = [8, 13, 22, 24, 25, 25, 27, 30, 33, 34, 35, 36, 41, 80];
+const TOKENS_76: [u32; 16] = [10, 25, 31, 33, 41, 79, 86, 113, 115, 123, 126, 128, 132, 138, 139, 140];
 
-const TOKENS_77: [u32; 8] = [8, 13, 22, 24, 25, 27, 41, 45];
+const TOKENS_77: [u32; 16] = [10, 22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 130, 132, 138];
 
-const TOKENS_78: [u32; 11] = [22, 24, 25, 27, 41, 60, 61, 62, 63, 64, 65];
+const TOKENS_78: [u32; 2] = [27, 32];
 
-const TOKENS_79: [u32; 14] = [10, 31, 32, 41, 45, 66, 76, 82, 110, 112, 120, 127, 129, 135];
+const TOKENS_79: [u32; 9] = [25, 30, 31, 32, 33, 34, 35, 36, 69];
 
-const TOKENS_8: [u32; 21] = [8, 9, 9, 11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 32, 33, 34, 35, 36, 41, 66, 83];
+const TOKENS_8: [u32; 21] = [9, 9, 11, 11, 13, 22, 24, 25, 25, 27, 30, 31, 32, 32, 33, 34, 35, 36, 41, 69, 86];
 
-const TOKENS_80: [u32; 23] = [8, 10, 22, 24, 25, 27, 31, 33, 41, 45, 66, 76, 79, 82, 110, 112, 120, 123, 127, 129, 135, 136, 137];
+const TOKENS_80: [u32; 17] = [22, 24, 25, 27, 31, 41, 45, 69, 79, 85, 113, 115, 123, 126, 130, 132, 138];
 
-const TOKENS_81: [u32; 1] = [42];
+const TOKENS_81: [u32; 21] = [10, 25, 31, 32, 33, 41, 45, 69, 79, 82, 85, 113, 115, 123, 126, 130, 132, 138, 139, 140, 143];
 
-const TOKENS_82: [u32; 3] = [32, 45, 66];
+const TOKENS_82: [u32; 11] = [10, 31, 41, 79, 86, 113, 115, 123, 128, 132, 138];
 
-const TOKENS_83: [u32; 1] = [39];
+const TOKENS_83: [u32; 1] = [31];
 
-const TOKENS_84: [u32; 11] = [31, 41, 76, 83, 110, 112, 120, 123, 125, 129, 135];
+const TOKENS_84: [u32; 10] = [31, 41, 79, 86, 113, 115, 123, 128, 132, 138];
 
-const TOKENS_85: [u32; 16] = [8, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 127, 129, 135];
+const TOKENS_85: [u32; 13] = [13, 22, 24, 25, 25, 27, 30, 31, 33, 34, 35, 36, 41];
 
-const TOKENS_86: [u32; 10] = [31, 41, 76, 83, 110, 112, 120, 125, 129, 135];
+const TOKENS_86: [u32; 7] = [13, 22, 24, 25, 27, 41, 45];
 
-const TOKENS_87: [u32; 6] = [1, 2, 17, 18, 19, 20];
+const TOKENS_87: [u32; 4] = [1, 17, 18, 19];
 
-const TOKENS_88: [u32; 15] = [10, 31, 32, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135];
+const TOKENS_88: [u32; 7] = [9, 21, 25, 33, 82, 139, 140];
 
-const TOKENS_89: [u32; 1] = [43];
+const TOKENS_89: [u32; 9] = [25, 27, 30, 32, 33, 34, 35, 36, 83];
 
-const TOKENS_9: [u32; 1] = [11];
+const TOKENS_9: [u32; 2] = [86, 128];
 
-const TOKENS_90: [u32; 2] = [13, 79];
+const TOKENS_90: [u32; 4] = [9, 11, 27, 32];
 
-const TOKENS_91: [u32; 19] = [8, 10, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135];
+const TOKENS_91: [u32; 8] = [9, 25, 30, 31, 33, 34, 35, 36];
 
-const TOKENS_92: [u32; 2] = [21, 79];
+const TOKENS_92: [u32; 15] = [46, 47, 49, 50, 53, 55, 58, 59, 76, 77, 78, 106, 110, 111, 112];
 
-const TOKENS_93: [u32; 8] = [54, 69, 70, 101, 102, 127, 128, 129];
+const TOKENS_93: [u32; 15] = [11, 13, 22, 24, 25, 25, 27, 30, 32, 33, 34, 35, 36, 41, 69];
 
-const TOKENS_94: [u32; 1] = [13];
+const TOKENS_94: [u32; 6] = [11, 79, 113, 115, 132, 138];
 
-const TOKENS_95: [u32; 6] = [8, 22, 24, 25, 27, 41];
+const TOKENS_95: [u32; 20] = [9, 9, 11, 11, 13, 22, 24, 25, 25, 27, 30, 32, 32, 33, 34, 35, 36, 41, 69, 86];
 
-const TOKENS_96: [u32; 14] = [31, 32, 41, 45, 66, 76, 82, 110, 112, 120, 123, 127, 129, 135];
+const TOKENS_96: [u32; 10] = [31, 41, 79, 86, 113, 115, 126, 128, 132, 138];
 
-const TOKENS_97: [u32; 12] = [46, 47, 49, 50, 53, 73, 74, 75, 103, 107, 108, 109];
+const TOKENS_97: [u32; 13] = [31, 32, 41, 45, 69, 79, 85, 113, 115, 127, 130, 132, 138];
 
-const TOKENS_98: [u32; 17] = [8, 22, 24, 25, 27, 31, 41, 45, 66, 76, 82, 110, 112, 123, 127, 129, 135];
+const TOKENS_98: [u32; 3] = [54, 56, 57];
 
-const TOKENS_99: [u32; 8] = [11, 13, 25, 30, 33, 34, 35, 36];
+const TOKENS_99: [u32; 3] = [41, 108, 109];
 
 /// Parser database for the "" parser
 pub struct ParserDB {
@@ -1,5 +1,12 @@
 #![allow(unused_imports)]
 
+#[cfg(test)]
+mod test_analysis;
+mod test_counterexample;
+#[cfg(test)]
+mod test_dot;
 mod test_grammar;
 mod test_optimizations;
+#[cfg(test)]
+mod test_state_cache;
 pub mod utils;
@@ -0,0 +1,100 @@
+use crate::{types::ErrorId, RadlrError, RadlrGrammar, RadlrResult as R};
+use std::path::PathBuf;
+
+/// Returns the title and message of every warning reported by the analysis of
+/// the grammar `source`.
+fn analyze(source: &str) -> R<Vec<(&'static str, String)>> {
+  Ok(analyze_sources(&[source])?.into_iter().map(|(title, msg, _)| (title, msg)).collect())
+}
+
+/// Returns the title, message, and path of every warning reported by the
+/// analysis of the grammars `sources`, which are added as `A`, `B`, and so on,
+/// with `A` as the root grammar.
+fn analyze_sources(sources: &[&str]) -> R<Vec<(&'static str, String, String)>> {
+  let mut grammar = RadlrGrammar::new();
+
+  for (source, name) in sources.iter().zip('A'..) {
+    grammar.add_source_from_string(source, PathBuf::from(name.to_string()), false)?;
+  }
+
+  let db = grammar.build_db("A", Default::default())?;
+
+  Ok(
+    db.analyze()?
+      .into_iter()
+      .map(|warning| {
+        assert!(warning.is_warning());
+        match warning {
+          RadlrError::SourceError { id: ErrorId(_, _, title), msg, path, .. } => (title, msg, path),
+          RadlrError::SourcesError { id: ErrorId(_, _, title), msg, .. } => (title, msg, Default::default()),
+          warning => panic!("Unexpected warning {warning:?}"),
+        }
+      })
+      .collect(),
+  )
+}
+
+fn titles(warnings: &[(&'static str, String)]) -> Vec<&'static str> {
+  warnings.iter().map(|(title, _)| *title).collect()
+}
+
+#[test]
+fn analysis_of_a_clean_grammar_has_no_warnings() -> R<()> {
+  assert!(analyze("IGNORE { c:sp } <> a > 'b' d \n <> d > c:id")?.is_empty());
+  Ok(())
+}
+
+#[test]
+fn analysis_reports_unreachable_nonterminals() -> R<()> {
+  let warnings = analyze("<> a > 'b' \n <> d > 'e'")?;
+  assert_eq!(titles(&warnings), ["unreachable-non-terminal"]);
+  assert!(warnings[0].1.contains("`d`"));
+  Ok(())
+}
+
+#[test]
+fn analysis_reports_unproductive_rules() -> R<()> {
+  let warnings = analyze("<> a > 'b' | d \n <> d > 'e' d 'f'")?;
+  assert_eq!(titles(&warnings).iter().filter(|t| **t == "unproductive-rule").count(), 2);
+  Ok(())
+}
+
+#[test]
+fn analysis_reports_nullable_nonterminals() -> R<()> {
+  let warnings = analyze("<> a > b 'c' \n <> b > ( 'd'? | 'e' ) ")?;
+  assert!(warnings.iter().any(|(title, msg)| *title == "nullable-non-terminal" && msg.contains("`b`")));
+  Ok(())
+}
+
+#[test]
+fn analysis_reports_recursion_cycles() -> R<()> {
+  let warnings = analyze("<> a > b '+' 'c' | 'c' \n <> b > a")?;
+  assert!(warnings.iter().any(|(title, msg)| *title == "left-recursion-cycle" && msg.contains("`a` > `b` > `a`")));
+
+  let warnings = analyze("<> a > 'c' a | 'c'")?;
+  assert_eq!(titles(&warnings), ["right-recursion-cycle"]);
+  Ok(())
+}
+
+#[test]
+fn analysis_reports_unused_precedence_tokens() -> R<()> {
+  let warnings = analyze("LEFT { '+' '*' } \n <> e > e '+' e | c:num")?;
+  assert!(warnings.iter().any(|(title, msg)| *title == "unused-token" && msg.contains("\"*\"")));
+  assert!(!warnings.iter().any(|(title, msg)| *title == "unused-token" && msg.contains("\"+\"")));
+  Ok(())
+}
+
+#[test]
+fn analysis_reports_unused_symbols_of_imported_grammars() -> R<()> {
+  let warnings = analyze_sources(&[
+    "IMPORT B as b \n IGNORE { c:sp } \n LEFT { '+' } \n <> a > tk:b::c '+' tk:b::c",
+    "IGNORE { c:sp } \n LEFT { '*' } \n <> c > 'x' | c '*' 'x'",
+  ])?;
+
+  let unused = warnings.iter().filter(|(title, ..)| title.starts_with("unused-")).collect::<Vec<_>>();
+  assert_eq!(unused.len(), 2, "{warnings:?}");
+  assert!(unused.iter().all(|(.., path)| path.ends_with("B")));
+  assert!(unused.iter().any(|(title, msg, _)| *title == "unused-token" && msg.contains("\"*\"")));
+  assert!(unused.iter().any(|(title, ..)| *title == "unused-ignore-symbol"));
+  Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::{
+  compile::states::counterexample::find_counterexample,
+  test::utils::build_parse_states_from_source_str as build,
+  Item,
+  RadlrResult as R,
+  TestPackage,
+};
+
+#[test]
+fn dangling_else_counterexample_derives_the_same_input() -> R<()> {
+  build(
+    "<> S > 'if' E 'then' S | 'if' E 'then' S 'else' S | 'x' \n <> E > 'b'",
+    "".into(),
+    false,
+    &|TestPackage { db, .. }| {
+      let db = db.as_ref();
+      let s = db.nonterm_from_name("S");
+      let rules = db.nonterm_rules(s)?;
+
+      let reduce = Item::from((rules[0], db)).to_complete();
+      let shift = Item::from((rules[1], db)).to_complete().decrement().unwrap().decrement().unwrap();
+
+      let counterexample = find_counterexample(db, s, [shift, reduce], shift.sym_id(db)).unwrap();
+
+      let prefix = counterexample.prefix.iter().map(|s| s.debug_string(db).trim().to_string()).collect::<Vec<_>>();
+      assert_eq!(prefix, ["if", "E", "then", "if", "E", "then", "S"]);
+
+      let [shift, reduce] = &counterexample.derivations[..] else { panic!("Expected two derivations") };
+
+      assert_eq!(shift.example_string(db), r#""if" E "then" "if" E "then" S • "else" S"#);
+      assert_eq!(reduce.example_string(db), r#""if" E "then" "if" E "then" S • "else" S"#);
+      assert_eq!(shift.tree_string(db), r#"S ::= [ "if" E "then" S ::= [ "if" E "then" S • "else" S ] ]"#);
+      assert_eq!(reduce.tree_string(db), r#"S ::= [ "if" E "then" S ::= [ "if" E "then" S • ] "else" S ]"#);
+
+      R::Ok(())
+    },
+  )
+}
+
+#[test]
+fn reduce_reduce_conflict_error_includes_a_counterexample() {
+  let err = build("<> A > B 'a' | C 'a' \n <> B > 'x' \n <> C > 'x'", "".into(), false, &|_| R::Ok(())).unwrap_err();
+
+  let message = format!("{err:?}");
+  assert!(message.contains("Counterexample for the lookahead [\"a\"]"));
+  assert!(message.contains(r#"A ::= [ B ::= [ "x" • ] "a" ]"#));
+  assert!(message.contains(r#"A ::= [ C ::= [ "x" • ] "a" ]"#));
+}
@@ -0,0 +1,45 @@
+use crate::{worker_pool::SingleThreadPool, RadlrGrammar, RadlrParseGraph, RadlrResult as R};
+use std::path::PathBuf;
+
+fn build(source: &str) -> R<RadlrParseGraph> {
+  let path = PathBuf::from("/test/dot.sg");
+  let db = RadlrGrammar::new().add_source_from_string(source, &path, false)?.build_db(&path, Default::default())?;
+  db.build_states(Default::default(), &SingleThreadPool {})
+}
+
+#[test]
+fn graph_dot_export_renders_nodes_and_edges() -> R<()> {
+  let graph = build("EXPORT a as first EXPORT d as second \n <> a > 'b' e \n <> d > 'f' e \n <> e > 'g' 'h'")?;
+
+  let dot = graph.to_dot(None)?;
+  assert!(dot.starts_with("digraph graphs {"));
+  assert!(dot.contains(r#"a > • \"b\" e"#));
+  assert!(dot.contains(r#"d > • \"f\" e"#));
+  assert!(dot.contains(r#"[label="\"b\""]"#));
+
+  let dot = graph.to_dot(Some("first"))?;
+  assert!(dot.contains(r#"a > • \"b\" e"#));
+  assert!(!dot.contains(r#"d > • \"f\" e"#));
+
+  assert!(graph.to_dot(Some("third")).is_err());
+  Ok(())
+}
+
+#[test]
+fn ir_dot_export_renders_gotos_and_pops() -> R<()> {
+  let graph = build("EXPORT e as expr EXPORT d as second \n <> e > e '+' e | c:num \n <> d > 'f' 'g'")?;
+  let parser = graph.build_ir_parser(false, false, &SingleThreadPool {})?;
+
+  let dot = parser.to_dot(None)?;
+  assert!(dot.starts_with("digraph ir {"));
+  assert!(dot.contains("entry of expr"));
+  assert!(dot.contains("entry of second"));
+  assert!(dot.contains(r#"[label="goto" style=solid]"#));
+  assert!(dot.contains(r#"[label="push" style=dashed]"#));
+  assert!(dot.contains(r#"-> pop [label="default > pop 1" style=bold]"#));
+
+  let dot = parser.to_dot(Some("expr"))?;
+  assert!(dot.contains("entry of expr"));
+  assert!(!dot.contains("entry of second"));
+  Ok(())
+}
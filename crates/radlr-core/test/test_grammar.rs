@@ -30,6 +30,48 @@ fn grammar_with_peg_nonterminal() -> R<()> {
   build(":> a > 'b'", "".into(), Default::default(), &|_| R::Ok(()))
 }
 
+#[test]
+fn grammar_with_peg_nonterminal_orders_its_rules() -> R<()> {
+  build(":> a > 'b' | d <> d > 'e'", "".into(), Default::default(), &|TestPackage { db, .. }| {
+    let a = db.nonterm_from_name("a");
+    let d = db.nonterm_from_name("d");
+
+    assert!(db.nonterm_is_peg(a));
+    assert!(!db.nonterm_is_peg(d));
+
+    let a_rules = db.nonterm_rules(a)?;
+    assert_eq!(db.rule_peg_choice(a_rules[0]), Some((a, 0)));
+    assert_eq!(db.rule_peg_choice(a_rules[1]), Some((a, 1)));
+    assert_eq!(db.rule_peg_choice(db.nonterm_rules(d)?[0]), None);
+
+    R::Ok(())
+  })
+}
+
+#[test]
+fn grammar_with_precedence_clauses() -> R<()> {
+  build(
+    "LEFT { '+' '-' } \n RIGHT { '^' } \n <> e > e '+' e | e '^' e | e 'x' e | c:num",
+    "".into(),
+    Default::default(),
+    &|TestPackage { db, .. }| {
+      let rules = db.nonterm_rules(db.nonterm_from_name("e"))?;
+
+      assert_eq!(db.rule_operator_precedence(rules[0]), Some((1, crate::Associativity::Left)));
+      assert_eq!(db.rule_operator_precedence(rules[1]), Some((2, crate::Associativity::Right)));
+      assert_eq!(db.rule_operator_precedence(rules[2]), None);
+      assert_eq!(db.rule_operator_precedence(rules[3]), None);
+
+      R::Ok(())
+    },
+  )
+}
+
+#[test]
+fn grammar_with_nonterminal_in_precedence_clause_is_rejected() {
+  assert!(build("NONASSOC { a } <> a > 'b'", "".into(), Default::default(), &|_| R::Ok(())).is_err());
+}
+
 #[test]
 fn grammar_with_append_nonterminal() -> R<()> {
   build("<> t > ('r') \n +> t > ( 'b' :ast 1 )", "".into(), Default::default(), &|TestPackage { db, .. }| {
@@ -290,3 +332,92 @@ fn grammar_ir_state_nonterminal() -> R<()> {
 fn grammar_ir_catch_state_nonterminal() -> R<()> {
   build("test =!> fail", "".into(), Default::default(), &|_| R::Ok(()))
 }
+
+#[test]
+fn grammar_with_unresolvable_reduce_reduce_conflict_is_reported() {
+  let err = build("<> A > B A | C A | 'e' \n <> B > 'x' \n <> C > 'x'", "".into(), false, &|_| R::Ok(())).unwrap_err();
+
+  let errors = err.flatten();
+  assert_eq!(errors.len(), 1);
+  assert!(matches!(&errors[0], crate::RadlrError::SourcesError { sources, .. } if sources.len() == 2));
+}
+
+#[test]
+fn conflicts_in_separate_entry_points_are_all_reported() {
+  let err = build(
+    "EXPORT A as a EXPORT D as d \n <> A > B 'a' | C 'a' \n <> B > 'x' \n <> C > 'x' \n <> D > E 'b' | F 'b' \n <> E > 'y' \n <> F > 'y'",
+    "".into(),
+    false,
+    &|_| R::Ok(()),
+  )
+  .unwrap_err();
+
+  let errors = err.flatten();
+  assert_eq!(errors.len(), 2);
+  assert!(errors.iter().all(|e| matches!(e, crate::RadlrError::SourcesError { sources, .. } if sources.len() == 2)));
+}
+
+#[test]
+fn grammar_first_and_follow_sets() -> R<()> {
+  build("<> e > t '+' e | t \n <> t > '(' e ')' | 'x'", "".into(), false, &|TestPackage { db, .. }| {
+    let names = |set: &crate::proxy::OrderedSet<crate::DBTermKey>| {
+      set
+        .iter()
+        .map(|tok| if *tok == crate::DBTermKey::default_sym() { "{EOF}".to_string() } else { db.sym(*tok).debug_string(&db) })
+        .map(|s| s.trim().to_string())
+        .collect::<Vec<_>>()
+    };
+
+    let first = db.first_sets();
+    let follow = db.follow_sets();
+    let e: usize = db.nonterm_from_name("e").into();
+    let t: usize = db.nonterm_from_name("t").into();
+
+    assert_eq!(names(&first[e]), names(&first[t]));
+    assert!(names(&first[e]).iter().all(|s| s == "(" || s == "x"));
+    assert_eq!(names(&follow[e]).len(), 2);
+    assert!(names(&follow[e]).contains(&"{EOF}".to_string()) && names(&follow[e]).contains(&")".to_string()));
+    assert_eq!(names(&follow[t]).len(), 3);
+
+    assert!(db.first_follow_json().starts_with("[{\"name\":\"e\",\"nullable\":false,"));
+
+    R::Ok(())
+  })
+}
+
+#[test]
+fn import_paths_are_tried_once() {
+  use crate::grammar::utils::resolve_import_path;
+  use std::path::{Path, PathBuf};
+
+  let tried = resolve_import_path(Path::new("missing"), Path::new(""), &[], &["sg", "radlr"]).unwrap_err();
+  assert_eq!(tried, ["missing", "missing.sg", "missing.radlr"].map(PathBuf::from));
+}
+
+#[test]
+fn reduce_reduce_conflict_beyond_max_k_is_reported() {
+  let err = crate::test::utils::build_parse_states_from_multi_sources(
+    &["<> A > B 'x' 'x' 'x' 'x' 'x' 'x' 'x' 'x' 'x' 'a' | C 'x' 'x' 'x' 'x' 'x' 'x' 'x' 'x' 'x' 'b' \n <> B > 'x' \n <> C > 'x'"],
+    "/test".into(),
+    false,
+    &|_| R::Ok(()),
+    crate::ParserConfig::default().lrk(2),
+  )
+  .unwrap_err();
+
+  assert!(!err.flatten().is_empty());
+}
+
+#[test]
+fn peg_choice_that_lookahead_can_not_confirm_is_forked() -> R<()> {
+  crate::test::utils::build_parse_states_from_multi_sources(
+    &[r#"IGNORE { c:sp } :> stmt > "if" c:id "then" stmt "else" stmt | "if" c:id "then" stmt | c:id"#],
+    "/test".into(),
+    false,
+    &|TestPackage { states, .. }| {
+      assert!(states.iter().any(|(_, state)| state.code.contains("fork")));
+      R::Ok(())
+    },
+    crate::ParserConfig::default().use_fork_states(true),
+  )
+}
@@ -26,7 +26,7 @@ fn basic_optimize_unknown() -> R<()> {
 
       let pool = SingleThreadPool {};
 
-      let states = optimize::<ParseStatesVec, _>(&db, &Default::default(), states.into_iter().collect(), false, &pool)?;
+      let states = optimize::<ParseStatesVec, _>(&db, &Default::default(), &Default::default(), states.into_iter().collect(), false, &pool)?;
 
       println!("AFTER -------------------");
 
@@ -39,3 +39,48 @@ fn basic_optimize_unknown() -> R<()> {
     Default::default(),
   )
 }
+
+#[test]
+fn optimize_config_parses_pass_lists() -> R<()> {
+  use crate::{OptimizationPass::*, OptimizeConfig};
+
+  assert_eq!(OptimizeConfig::parse("default")?, OptimizeConfig::default());
+  assert!(OptimizeConfig::parse("none")?.passes.is_empty());
+  assert_eq!(OptimizeConfig::parse("garbage-collect, canonicalize-full")?.passes, [GarbageCollect, CanonicalizeFull]);
+
+  let passes = OptimizeConfig::parse("default,-inline-scanners,-canonicalize")?.passes;
+  assert!(!passes.contains(&InlineScanners) && !passes.contains(&Canonicalize) && passes.contains(&CanonicalizeFull));
+
+  assert!(OptimizeConfig::parse("default,inline-everything").is_err());
+  R::Ok(())
+}
+
+#[test]
+fn optimize_reports_each_pass() -> R<()> {
+  use crate::{OptimizationPass::*, OptimizeConfig, RadlrGrammar};
+
+  let path = std::path::PathBuf::from("/test/optimize.sg");
+  let pool = SingleThreadPool {};
+  let db = RadlrGrammar::new()
+    .add_source_from_string("IGNORE { c:sp } <> e > e '+' e | '(' e ')' | c:num", &path, false)?
+    .build_db(&path, Default::default())?;
+  let graph = db.build_states(Default::default(), &pool)?;
+
+  let opt_config = OptimizeConfig { report_passes: true, ..Default::default() };
+  let parser = graph.build_ir_parser_with_passes(Some(&opt_config), false, &pool)?;
+  let report = &parser.report;
+
+  assert_eq!(report.passes.iter().map(|p| p.pass).collect::<Vec<_>>(), opt_config.passes);
+  assert_eq!(report.passes.last().unwrap().marker.num_of_states, report.end.num_of_states);
+  assert_eq!(report.end.num_of_states, parser.get_states().count());
+  assert!(report.to_string().contains("canonicalize-full"));
+
+  // Only the configured passes are run.
+  let opt_config = OptimizeConfig { passes: vec![GarbageCollect], report_passes: true };
+  let parser = graph.build_ir_parser_with_passes(Some(&opt_config), false, &pool)?;
+  assert_eq!(parser.report.passes.len(), 1);
+  assert_eq!(parser.report.canonical_rounds, 0);
+  assert!(parser.report.end.num_of_states >= report.end.num_of_states);
+
+  R::Ok(())
+}
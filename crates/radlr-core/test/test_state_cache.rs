@@ -0,0 +1,80 @@
+use crate::{worker_pool::SingleThreadPool, RadlrGrammar, RadlrIRParser, RadlrResult as R};
+use std::path::{Path, PathBuf};
+
+const GRAMMAR: &str = r#"
+EXPORT expr as expr
+EXPORT list as list
+
+IGNORE { c:sp }
+
+<> expr > num '+' expr | num | '(' expr ')'
+
+<> num > c:num
+
+<> list > '[' items ']'
+
+<> items > word(+',')
+
+<> word > c:id
+"#;
+
+fn build(source: &str, cache_dir: Option<&Path>) -> R<(usize, RadlrIRParser)> {
+  let path = PathBuf::from("/test/state_cache.sg");
+  let pool = SingleThreadPool {};
+  let db = RadlrGrammar::new().add_source_from_string(source, &path, false)?.build_db(&path, Default::default())?;
+
+  let graph = match cache_dir {
+    Some(cache_dir) => db.build_states_cached(Default::default(), cache_dir, &pool)?,
+    None => db.build_states(Default::default(), &pool)?,
+  };
+
+  Ok((graph.cached_nonterm_count(), graph.build_ir_parser(false, false, &pool)?))
+}
+
+fn states(parser: &RadlrIRParser) -> Vec<(String, String)> {
+  let s_store = parser.get_db().string_store();
+  let mut states = parser.get_states().map(|(name, state)| (name.to_string(s_store), state.code.clone())).collect::<Vec<_>>();
+  states.sort();
+  states
+}
+
+fn cache_dir(name: &str) -> PathBuf {
+  let dir = std::env::temp_dir().join("radlr_state_cache").join(name);
+  let _ = std::fs::remove_dir_all(&dir);
+  dir
+}
+
+#[test]
+fn cached_states_match_freshly_built_states() -> R<()> {
+  let dir = cache_dir("match");
+
+  let (_, fresh) = build(GRAMMAR, None)?;
+
+  let (cached_count, first) = build(GRAMMAR, Some(&dir))?;
+  assert_eq!(cached_count, 0);
+  assert_eq!(states(&first), states(&fresh));
+
+  let (cached_count, second) = build(GRAMMAR, Some(&dir))?;
+  assert_eq!(cached_count, 6);
+  assert_eq!(states(&second), states(&fresh));
+
+  Ok(())
+}
+
+#[test]
+fn changing_a_rule_only_rebuilds_dependent_nonterminals() -> R<()> {
+  let dir = cache_dir("change");
+
+  build(GRAMMAR, Some(&dir))?;
+
+  // `word` is only used by the list of `items`, which is only used by `list`,
+  // so the states of `expr` and `num` remain valid.
+  let changed = GRAMMAR.replace("<> word > c:id", "<> word > c:id c:num");
+  let (cached_count, parser) = build(&changed, Some(&dir))?;
+  let (_, fresh) = build(&changed, None)?;
+
+  assert_eq!(cached_count, 2);
+  assert_eq!(states(&parser), states(&fresh));
+
+  Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{compile::states::build_states::StateConstructionError, proxy::Array};
 use radlr_rust_runtime::{
   deprecate::RadlrParseError,
-  types::{BlameColor, ParserError, Token},
+  types::{BlameColor, PackageError, ParserError, Token},
 };
 use std::{
   hash::Hash,
@@ -158,6 +158,14 @@ pub enum RadlrError {
 
   /// Multiple Errors
   Multi(Vec<RadlrError>),
+
+  /// A job run by a worker pool panicked. Contains the panic message.
+  JobPanic(String),
+
+  /// The operation was cancelled through a
+  /// [CancellationToken](crate::worker_pool::CancellationToken), or exceeded
+  /// the time budget of the token.
+  Cancelled,
 }
 use RadlrError::*;
 
@@ -177,6 +185,7 @@ impl RadlrError {
   /// Return the Errors severity
   pub fn get_severity(&self) -> RadlrErrorSeverity {
     match self {
+      SourceError { severity, .. } | SourcesError { severity, .. } => *severity,
       _ => RadlrErrorSeverity::Critical,
     }
   }
@@ -204,17 +213,27 @@ impl RadlrError {
 
   /// Todo
   pub fn is_critical(&self) -> bool {
-    matches!(self.get_severity(), _Critical)
+    matches!(self.get_severity(), RadlrErrorSeverity::Critical)
   }
 
   /// Todo
   pub fn is_hint(&self) -> bool {
-    matches!(self.get_severity(), _Hint)
+    matches!(self.get_severity(), RadlrErrorSeverity::Hint)
   }
 
   /// Todo
   pub fn is_warning(&self) -> bool {
-    matches!(self.get_severity(), _Warning)
+    matches!(self.get_severity(), RadlrErrorSeverity::Warning)
+  }
+
+  /// Returns `true` if this error, or any error it contains, is a
+  /// [RadlrError::Cancelled] error.
+  pub fn is_cancelled(&self) -> bool {
+    match self {
+      Cancelled => true,
+      Multi(errors) => errors.iter().any(|e| e.is_cancelled()),
+      _ => false,
+    }
   }
 
   pub fn flatten(self) -> Array<RadlrError> {
@@ -303,11 +322,18 @@ impl From<ParserError> for RadlrError {
         severity:   RadlrErrorSeverity::Critical,
       },
       ParserError::InvalidNonTerminal => Self::Text("Invalid non-terminal".into()),
+      ParserError::InvalidPackage(err) => Self::Text(err.to_string()),
       ParserError::Unexpected | _ => Self::Text("Unexpected error".into()),
     }
   }
 }
 
+impl From<PackageError> for RadlrError {
+  fn from(err: PackageError) -> Self {
+    ParserError::from(err).into()
+  }
+}
+
 impl std::fmt::Display for RadlrError {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     std::fmt::Debug::fmt(&self, f)
@@ -345,6 +371,8 @@ impl std::fmt::Debug for RadlrError {
       )),
       PoisonError(err_string) => f.write_fmt(format_args!("\nPoison Error: {}", err_string)),
       IOError(err_string) => f.write_fmt(format_args!("\nIO Error: {}", err_string)),
+      JobPanic(err_string) => f.write_fmt(format_args!("\nJob Panicked: {}", err_string)),
+      Cancelled => f.write_str("\nCancelled"),
       Text(err_string) => f.write_str(&err_string),
       StaticText(err_string) => f.write_str(err_string),
       Self::Error(error) => std::fmt::Debug::fmt(error, f),
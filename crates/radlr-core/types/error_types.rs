@@ -8,15 +8,18 @@ use ErrorClass::*;
 /// This error occurs when source of an imported grammar cannot be found.
 pub(crate) fn create_invalid_import_source_error(
   import: &parser::Import,
-  import_path: &PathBuf,
-  base_path: &PathBuf,
+  grammar_path: &PathBuf,
+  tried_paths: &[PathBuf],
 ) -> RadlrError {
-  let parser::Import { tok, .. } = import;
+  let parser::Import { tok, uri, .. } = import;
   RadlrError::SourceError {
     loc:        tok.clone(),
-    path:       import_path.to_str().unwrap().to_string(),
+    path:       grammar_path.to_str().unwrap().to_string(),
     id:         (Imports, 0, "invalid-import-source").into(),
-    msg:        format!("Could not resolve filepath {}", base_path.to_str().unwrap()),
+    msg:        format!(
+      "Could not resolve the imported grammar {uri}. Tried these paths:\n{}",
+      tried_paths.iter().map(|p| format!("  {}", p.to_string_lossy())).collect::<Vec<_>>().join("\n")
+    ),
     inline_msg: "source not found".to_string(),
     severity:   RadlrErrorSeverity::Critical,
     ps_msg:     Default::default(),
@@ -150,3 +153,108 @@ pub fn create_missing_nonterminal_rules(loc: Token, path: IString, s_store: &ISt
     severity: RadlrErrorSeverity::Critical,
   }
 }
+
+/// Emitted if the symbols of a `LEFT`, `RIGHT`, or `NONASSOC` clause can not be
+/// parsed, or are not all terminals.
+pub fn create_invalid_precedence_clause_error(loc: Token, grammar_path: &PathBuf, msg: String) -> RadlrError {
+  RadlrError::SourceError {
+    loc,
+    path: grammar_path.to_string_lossy().to_string(),
+    id: (Grammar, 5, "invalid-precedence-clause").into(),
+    inline_msg: Default::default(),
+    msg,
+    ps_msg: Default::default(),
+    severity: RadlrErrorSeverity::Critical,
+  }
+}
+
+/// Emitted by grammar analysis for a non-terminal of the root grammar that
+/// cannot be reached from any of its entry points.
+pub fn create_unreachable_nonterminal_warning(loc: Token, path: IString, name: &str, s_store: &IStringStore) -> RadlrError {
+  RadlrError::SourceError {
+    loc,
+    path: path.to_string(s_store),
+    id: (Grammar, 6, "unreachable-non-terminal").into(),
+    inline_msg: "never referenced".into(),
+    msg: format!("The non-terminal `{name}` cannot be reached from any entry point of the grammar"),
+    ps_msg: Default::default(),
+    severity: RadlrErrorSeverity::Warning,
+  }
+}
+
+/// Emitted by grammar analysis for a rule that cannot derive a string of
+/// terminals, because every derivation of it recurses without end.
+pub fn create_unproductive_rule_warning(rule: &Rule, name: &str, s_store: &IStringStore) -> RadlrError {
+  RadlrError::SourceError {
+    loc:        rule.tok.clone(),
+    path:       rule.g_id.path.to_string(s_store),
+    id:         (Grammar, 7, "unproductive-rule").into(),
+    inline_msg: "never completes".into(),
+    msg:        format!("This rule of `{name}` cannot derive a string of terminals"),
+    ps_msg:     "Every derivation of this rule contains a non-terminal that never derives a string of terminals.".into(),
+    severity:   RadlrErrorSeverity::Warning,
+  }
+}
+
+/// Emitted by grammar analysis for a non-terminal that can derive the empty
+/// string.
+pub fn create_nullable_nonterminal_warning(rule: &Rule, name: &str, s_store: &IStringStore) -> RadlrError {
+  RadlrError::SourceError {
+    loc:        rule.tok.clone(),
+    path:       rule.g_id.path.to_string(s_store),
+    id:         (Grammar, 8, "nullable-non-terminal").into(),
+    inline_msg: "derives the empty string".into(),
+    msg:        format!("The non-terminal `{name}` can derive the empty string through this rule"),
+    ps_msg:     Default::default(),
+    severity:   RadlrErrorSeverity::Warning,
+  }
+}
+
+/// Emitted by grammar analysis for a cycle of non-terminals that derive
+/// themselves at the start (left recursion) or end (right recursion) of
+/// their rules. `sources` holds one rule of each non-terminal in the cycle.
+pub fn create_recursion_cycle_warning(sources: Vec<(Token, PathBuf, String)>, left: bool, cycle: &str) -> RadlrError {
+  let (id, direction) =
+    if left { ((Grammar, 9, "left-recursion-cycle"), "left") } else { ((Grammar, 10, "right-recursion-cycle"), "right") };
+  RadlrError::SourcesError {
+    id: id.into(),
+    sources,
+    msg: format!("The non-terminals {cycle} form a {direction} recursion cycle"),
+    ps_msg: if left {
+      "Left recursion requires LR states, and can not be parsed by LL or recursive descent parsers.".into()
+    } else {
+      "Right recursion keeps every element of the recursion on the parse stack until the last one is reduced.".into()
+    },
+    severity: RadlrErrorSeverity::Warning,
+  }
+}
+
+/// Emitted by grammar analysis for a terminal declared in a `LEFT`, `RIGHT`,
+/// or `NONASSOC` clause that no reachable parser rule uses.
+pub fn create_unused_precedence_token_warning(loc: Token, path: IString, name: &str, s_store: &IStringStore) -> RadlrError {
+  RadlrError::SourceError {
+    loc,
+    path: path.to_string(s_store),
+    id: (Grammar, 11, "unused-token").into(),
+    inline_msg: Default::default(),
+    msg: format!("The token {name} is assigned a precedence, but is not used by any rule of the grammar"),
+    ps_msg: Default::default(),
+    severity: RadlrErrorSeverity::Warning,
+  }
+}
+
+/// Emitted by grammar analysis for an `IGNORE` symbol that no reachable parser
+/// rule of its grammar skips.
+pub fn create_unused_ignore_symbol_warning(loc: Token, path: IString, s_store: &IStringStore) -> RadlrError {
+  RadlrError::SourceError {
+    loc,
+    path: path.to_string(s_store),
+    id: (Grammar, 12, "unused-ignore-symbol").into(),
+    inline_msg: "never skipped".into(),
+    msg: "This IGNORE symbol is not skipped by any rule of the parser".into(),
+    ps_msg: "Ignored symbols only apply to the rules of the grammar that declares them, and none of its rules are reachable \
+             from an entry point, except through `tk:` token non-terminals, which do not skip symbols."
+      .into(),
+    severity: RadlrErrorSeverity::Warning,
+  }
+}
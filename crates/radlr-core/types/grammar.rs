@@ -325,6 +325,25 @@ pub struct GrammarHeader {
   pub pub_nterms: OrderedMap<IString, (NonTermId, Token)>,
 
   pub imports: Array<GrammarId>,
+
+  /// Operator precedence levels declared with `LEFT`, `RIGHT`, and `NONASSOC`
+  /// clauses, from the lowest to the highest precedence, along with the
+  /// location of each clause.
+  pub precedence: Array<(Associativity, Array<SymbolId>, Token)>,
+
+  /// Symbols declared in the `IGNORE` clauses of this grammar.
+  pub ignored: Array<(SymbolId, Token)>,
+}
+
+/// The associativity of the terminals of an operator precedence level.
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Associativity {
+  /// `a + b + c` is parsed as `(a + b) + c`
+  Left,
+  /// `a ^ b ^ c` is parsed as `a ^ (b ^ c)`
+  Right,
+  /// `a < b < c` is a syntax error
+  NonAssoc,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
@@ -445,6 +445,14 @@ impl Item {
     self.db_rule(db).nonterm
   }
 
+  /// Returns the PEG non-terminal and the position of its ordered choice
+  /// that this item belongs to, either because the item is a rule of a PEG
+  /// non-terminal, or because the item was derived from the closure of one.
+  pub fn peg_choice(&self, db: &ParserDatabase) -> Option<(DBNonTermKey, usize)> {
+    db.rule_peg_choice(self.rule_id())
+      .or_else(|| (!self.from.is_oos() && self.from != self.index).then(|| db.rule_peg_choice(self.from.get_parts().0)).flatten())
+  }
+
   // --------------- ITEM TRANSFORMATION METHODS
   // -------------------------------------------------
 
@@ -1,10 +1,162 @@
 use crate::types::*;
 
-#[derive(Clone, Copy, Default)]
+/// A transformation applied to the IR states of a parser by
+/// [optimize](crate::compile::ir::optimize).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OptimizationPass {
+  /// Removes states that are not reachable from an exported entry state.
+  GarbageCollect,
+  /// Replaces states that are identical with a single canonical state.
+  Canonicalize,
+  /// Repeats canonicalization, including the merging of self-recursive
+  /// states, until no more states can be merged.
+  CanonicalizeFull,
+  /// Merges match branches that have identical bodies.
+  MergeBranches,
+  /// Combines the branches of nested match statements into a single match
+  /// statement where possible.
+  CombineBranches,
+  /// Replaces goto instructions with the body of the target state.
+  InlineStates,
+  /// Replaces scanner states with matches on the input bytes. Only runs if
+  /// [ParserConfig::ALLOW_SCANNER_INLINING] is set.
+  InlineScanners,
+  /// Combines sequences of byte matches into wide matches. Only runs if
+  /// [ParserConfig::ALLOW_BYTE_SEQUENCES] is set.
+  ByteSequences,
+  /// Removes default branches that duplicate the behavior of the state.
+  RemoveRedundantDefaults,
+}
+
+impl OptimizationPass {
+  pub const ALL: [Self; 9] = [
+    Self::GarbageCollect,
+    Self::Canonicalize,
+    Self::CanonicalizeFull,
+    Self::MergeBranches,
+    Self::CombineBranches,
+    Self::InlineStates,
+    Self::InlineScanners,
+    Self::ByteSequences,
+    Self::RemoveRedundantDefaults,
+  ];
+
+  /// The name of the pass used by [OptimizeConfig::parse].
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::GarbageCollect => "garbage-collect",
+      Self::Canonicalize => "canonicalize",
+      Self::CanonicalizeFull => "canonicalize-full",
+      Self::MergeBranches => "merge-branches",
+      Self::CombineBranches => "combine-branches",
+      Self::InlineStates => "inline-states",
+      Self::InlineScanners => "inline-scanners",
+      Self::ByteSequences => "byte-sequences",
+      Self::RemoveRedundantDefaults => "remove-redundant-defaults",
+    }
+  }
+
+  pub fn from_name(name: &str) -> Option<Self> {
+    Self::ALL.into_iter().find(|pass| pass.name() == name)
+  }
+}
+
+/// The optimization passes to run on the IR states of a parser, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptimizeConfig {
+  pub passes: Array<OptimizationPass>,
+
+  /// Record the number of states and the complexity of the states after
+  /// every pass in [OptimizationReport::passes].
+  ///
+  /// Defaults to `false`
+  pub report_passes: bool,
+}
+
+impl Default for OptimizeConfig {
+  fn default() -> Self {
+    use OptimizationPass::*;
+    Self {
+      passes:        vec![
+        GarbageCollect,
+        Canonicalize,
+        MergeBranches,
+        CombineBranches,
+        Canonicalize,
+        InlineStates,
+        InlineScanners,
+        ByteSequences,
+        MergeBranches,
+        RemoveRedundantDefaults,
+        Canonicalize,
+        CombineBranches,
+        CanonicalizeFull,
+      ],
+      report_passes: false,
+    }
+  }
+}
+
+impl OptimizeConfig {
+  /// A configuration that runs no passes. Unreachable states are still
+  /// removed from the output.
+  pub fn none() -> Self {
+    Self { passes: Default::default(), report_passes: false }
+  }
+
+  /// Parses a comma separated list of pass names. `default` expands to the
+  /// default passes, `none` clears the list, and a name prefixed with `-`
+  /// removes every occurrence of that pass from the passes listed before it.
+  ///
+  /// e.g. `default,-inline-scanners` or `garbage-collect,canonicalize-full`
+  pub fn parse(passes: &str) -> RadlrResult<Self> {
+    let mut config = Self::none();
+
+    for name in passes.split(',').map(|name| name.trim()).filter(|name| !name.is_empty()) {
+      match name {
+        "default" => config.passes.extend(Self::default().passes),
+        "none" => config.passes.clear(),
+        name => {
+          let (remove, pass_name) = match name.strip_prefix('-') {
+            Some(name) => (true, name),
+            None => (false, name),
+          };
+
+          let Some(pass) = OptimizationPass::from_name(pass_name) else {
+            return Err(RadlrError::from(format!(
+              "Unknown optimization pass `{pass_name}`. Expected one of [ default | none | {} ]",
+              OptimizationPass::ALL.map(|p| p.name()).join(" | ")
+            )));
+          };
+
+          if remove {
+            config.passes.retain(|p| *p != pass);
+          } else {
+            config.passes.push(pass);
+          }
+        }
+      }
+    }
+
+    Ok(config)
+  }
+}
+
+/// The number of states and complexity of the states after an optimization
+/// pass.
+#[derive(Clone, Copy, Debug)]
+pub struct PassReport {
+  pub pass:   OptimizationPass,
+  pub marker: ComplexityMarker,
+}
+
+#[derive(Clone, Default)]
 pub struct OptimizationReport {
   pub start:            ComplexityMarker,
   pub end:              ComplexityMarker,
   pub canonical_rounds: usize,
+  /// Populated when [OptimizeConfig::report_passes] is set.
+  pub passes:           Array<PassReport>,
 }
 
 impl OptimizationReport {
@@ -24,11 +176,37 @@ Number of canonical rounds : {}
       ((1.0 - self.end.num_of_states as f64 / self.start.num_of_states as f64) * 100.0).round(),
       ((1.0 - self.end.code_complexity / self.start.code_complexity) * 100.0).round(),
       self.canonical_rounds
-    )
+    ) + &self.passes_string()
+  }
+
+  /// Renders the number of states and the complexity after each pass, along
+  /// with the change relative to the previous pass.
+  fn passes_string(&self) -> String {
+    if self.passes.is_empty() {
+      return Default::default();
+    }
+
+    let mut string = format!("\n{:<26} | {:>8} | {:>8} | {:>12} | {:>8}\n", "Pass", "States", "Change", "Complexity", "Change");
+    string += &format!("{:<26} | {:>8} | {:>8} | {:>12} | {:>8}\n", "start", self.start.num_of_states, "", self.start.code_complexity, "");
+
+    let mut prev = self.start;
+    for PassReport { pass, marker } in &self.passes {
+      string += &format!(
+        "{:<26} | {:>8} | {:>+8} | {:>12} | {:>+8}\n",
+        pass.name(),
+        marker.num_of_states,
+        marker.num_of_states as i64 - prev.num_of_states as i64,
+        marker.code_complexity,
+        marker.code_complexity - prev.code_complexity
+      );
+      prev = *marker;
+    }
+
+    string + "==============================================================================="
   }
 }
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct ComplexityMarker {
   pub num_of_states:   usize,
   pub code_complexity: f64,
@@ -37,17 +215,15 @@ pub struct ComplexityMarker {
 impl ComplexityMarker {
   pub fn from_map_iter<'i, I: Iterator<Item = (&'i IString, &'i Box<ParseState>)>>(db: &ParserDatabase, states: I) -> Self {
     let (num_of_states, code_complexity) = states
-      .enumerate()
-      .map(|(i, (_, s))| (i, s.print(db, false).unwrap_or_default().len()))
-      .fold((0, 0), |(a, c), (b, d)| (a.max(b), c + d));
+      .map(|(_, s)| s.print(db, false).unwrap_or_default().len())
+      .fold((0, 0), |(count, complexity), len| (count + 1, complexity + len));
     Self { num_of_states, code_complexity: code_complexity as f64 }
   }
 
   pub fn from_vec_iter<'i, I: Iterator<Item = &'i (IString, Box<ParseState>)>>(db: &ParserDatabase, states: I) -> Self {
     let (num_of_states, code_complexity) = states
-      .enumerate()
-      .map(|(i, (_, s))| (i, s.print(db, false).unwrap_or_default().len()))
-      .fold((0, 0), |(a, c), (b, d)| (a.max(b), c + d));
+      .map(|(_, s)| s.print(db, false).unwrap_or_default().len())
+      .fold((0, 0), |(count, complexity), len| (count + 1, complexity + len));
     Self { num_of_states, code_complexity: code_complexity as f64 }
   }
 
@@ -1,7 +1,7 @@
 #![allow(non_snake_case, unused)]
 #[derive(Clone, Copy)]
 /// Settings for configuring the type of parser Radlr will generate.
-#[derive(Debug)]
+#[derive(Debug, Hash)]
 #[repr(C)]
 pub struct ParserConfig {
   /// When enable, recursive descent style s`Call` states will be generated
@@ -59,12 +59,6 @@ pub struct ParserConfig {
   pub max_k: u32,
 }
 
-pub struct OptimizeConfig {
-  /// Enables using wide data types ( u16 | u32 | u64 | u128+ ) to recognize a
-  /// sequence of bytes.
-  pub ALLOW_BYTE_SEQUENCES: bool,
-}
-
 impl Default for ParserConfig {
   fn default() -> Self {
     Self {
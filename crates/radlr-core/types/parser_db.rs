@@ -1,6 +1,9 @@
 use super::*;
 use crate::{compile::states::build_graph::graph::GraphType, parser, CachedString, RadlrResult};
-use std::collections::VecDeque;
+use std::{
+  collections::VecDeque,
+  hash::{Hash, Hasher},
+};
 
 pub type SharedParserDatabase = std::sync::Arc<ParserDatabase>;
 
@@ -40,6 +43,11 @@ pub struct ParserDatabase {
   string_store:            IStringStore,
   /// Custom states that should be integrated into the final parsers
   custom_states:           Array<Option<Box<parser::State>>>,
+  /// Non-terminals declared with `:>`, whose rules are ordered choices.
+  peg_nonterms:            OrderedSet<DBNonTermKey>,
+  /// Precedence levels and associativity of the tokens declared in `LEFT`,
+  /// `RIGHT`, and `NONASSOC` clauses. Higher levels bind tighter.
+  operator_precedence:     OrderedMap<DBTermKey, (u16, Associativity)>,
   /// True if the database represents a valid set of rules. This may not be
   /// the case if, for example, the database is comprised of rules that
   /// reference non-extant non-terminals.
@@ -69,10 +77,14 @@ impl ParserDatabase {
     entry_points: Array<DBEntryPoint>,
     string_store: IStringStore,
     custom_states: Array<Option<Box<parser::State>>>,
+    peg_nonterms: OrderedSet<DBNonTermKey>,
+    operator_precedence: OrderedMap<DBTermKey, (u16, Associativity)>,
     valid: bool,
   ) -> Self {
     Self {
       root_grammar_id,
+      peg_nonterms,
+      operator_precedence,
       nonterm_symbols,
       nonterm_names,
       nonterm_nterm_rules,
@@ -251,6 +263,43 @@ impl ParserDatabase {
     self.valid
   }
 
+  /// Returns a hash of the non-terminals, rules, tokens, and entry points of
+  /// the grammar, which is stored in bytecode packages to identify the grammar
+  /// they were built from. The hash changes if the text of any rule changes.
+  pub fn grammar_hash(&self) -> u64 {
+    let s_store = &self.string_store;
+    let mut hasher = StandardHasher::new();
+
+    for (guid_name, friendly_name) in &self.nonterm_names {
+      (guid_name.to_string(s_store), friendly_name.to_string(s_store)).hash(&mut hasher);
+    }
+
+    for DBRule { rule, nonterm, is_scanner } in &self.rules {
+      (nonterm, is_scanner, rule.tok.to_string()).hash(&mut hasher);
+      for sym in &rule.symbols {
+        (sym.id, sym.token_precedence, sym.symbol_precedence).hash(&mut hasher);
+      }
+      rule.skipped.hash(&mut hasher);
+    }
+
+    for DBTokenData { sym_id, name, nonterm_id, tok_id } in &self.tokens {
+      (sym_id, name.to_string(s_store), nonterm_id, tok_id).hash(&mut hasher);
+    }
+
+    for entry in &self.entry_points {
+      (entry.nonterm_key, entry.entry_name.to_string(s_store), entry.export_id, entry.is_export).hash(&mut hasher);
+    }
+
+    for state in self.custom_states.iter().flatten() {
+      state.tok.to_string().hash(&mut hasher);
+    }
+
+    self.peg_nonterms.hash(&mut hasher);
+    self.operator_precedence.hash(&mut hasher);
+
+    hasher.finish()
+  }
+
   /// Returns an array of [DBNonTermKey]s of the entry point non-terminals.
   pub fn entry_nterm_keys(&self) -> Array<DBNonTermKey> {
     self.entry_points.iter().map(|k| k.nonterm_key).collect()
@@ -437,6 +486,35 @@ impl ParserDatabase {
     self.reduction_types[rule_id.0 as usize]
   }
 
+  /// Returns `true` if the non-terminal was declared with `:>`, in which case
+  /// its rules are tried in the order they were declared.
+  pub fn nonterm_is_peg(&self, nonterm: DBNonTermKey) -> bool {
+    self.peg_nonterms.contains(&nonterm)
+  }
+
+  /// If the rule belongs to a PEG non-terminal, returns the non-terminal and
+  /// the position of the rule among the non-terminal's ordered choices.
+  pub fn rule_peg_choice(&self, rule: DBRuleKey) -> Option<(DBNonTermKey, usize)> {
+    let nonterm = self.rule_nonterm(rule);
+    if self.nonterm_is_peg(nonterm) {
+      self.nonterm_nterm_rules.get(nonterm.0 as usize)?.iter().position(|r| *r == rule).map(|position| (nonterm, position))
+    } else {
+      None
+    }
+  }
+
+  /// Returns the precedence level and associativity declared for the token, if
+  /// the token appears in a `LEFT`, `RIGHT`, or `NONASSOC` clause.
+  pub fn token_operator_precedence(&self, tok: DBTermKey) -> Option<(u16, Associativity)> {
+    self.operator_precedence.get(&tok).copied()
+  }
+
+  /// Returns the operator precedence of a rule, which is the precedence of the
+  /// last token in the rule that has a declared precedence.
+  pub fn rule_operator_precedence(&self, rule: DBRuleKey) -> Option<(u16, Associativity)> {
+    self.rule(rule).symbols.iter().rev().find_map(|s| s.id.tok_db_key().and_then(|tok| self.token_operator_precedence(tok)))
+  }
+
   pub fn nonterm_recursion_type(&self, nonterm: DBNonTermKey) -> RecursionType {
     match self.recursion_types[nonterm.0 as usize] {
       3 => RecursionType::LeftRightRecursive,
@@ -471,6 +549,102 @@ impl ParserDatabase {
       .collect()
   }
 
+  /// Returns, for each non-terminal, `true` if the non-terminal can derive the
+  /// empty string.
+  pub fn nullable_nonterms(&self) -> Array<bool> {
+    let mut nullable = vec![false; self.nonterms_len()];
+    let mut changed = true;
+
+    while changed {
+      changed = false;
+      for DBRule { rule, nonterm, .. } in &self.rules {
+        if !nullable[nonterm.0 as usize]
+          && rule.symbols.iter().all(|s| matches!(s.id, SymbolId::DBNonTerminal { key } if nullable[key.0 as usize]))
+        {
+          nullable[nonterm.0 as usize] = true;
+          changed = true;
+        }
+      }
+    }
+
+    nullable
+  }
+
+  /// Returns the FIRST set of each non-terminal, indexed by [DBNonTermKey]:
+  /// the terminals that can begin a string derived from the non-terminal.
+  ///
+  /// Only the rules of parser non-terminals are considered, so the sets of
+  /// scanner non-terminals, and of non-terminals defined by custom states, are
+  /// empty.
+  pub fn first_sets(&self) -> Array<OrderedSet<DBTermKey>> {
+    let nullable = self.nullable_nonterms();
+    let mut first = vec![OrderedSet::new(); self.nonterms_len()];
+    let mut changed = true;
+
+    while changed {
+      changed = false;
+      for DBRule { rule, nonterm, is_scanner } in &self.rules {
+        if *is_scanner {
+          continue;
+        }
+
+        let mut set = OrderedSet::new();
+        sequence_first(&rule.symbols, &first, &nullable, &mut set);
+
+        let target = &mut first[nonterm.0 as usize];
+        let len = target.len();
+        target.extend(set);
+        changed |= target.len() != len;
+      }
+    }
+
+    first
+  }
+
+  /// Returns the FOLLOW set of each non-terminal, indexed by [DBNonTermKey]:
+  /// the terminals that can appear immediately after a string derived from
+  /// the non-terminal. The end of the input is represented by
+  /// [DBTermKey::default_sym], and follows every entry point.
+  ///
+  /// As with [ParserDatabase::first_sets], only the rules of parser
+  /// non-terminals are considered.
+  pub fn follow_sets(&self) -> Array<OrderedSet<DBTermKey>> {
+    let nullable = self.nullable_nonterms();
+    let first = self.first_sets();
+    let mut follow = vec![OrderedSet::new(); self.nonterms_len()];
+
+    for entry in &self.entry_points {
+      follow[entry.nonterm_key.0 as usize].insert(DBTermKey::default_sym());
+    }
+
+    let mut changed = true;
+
+    while changed {
+      changed = false;
+      for DBRule { rule, nonterm, is_scanner } in &self.rules {
+        if *is_scanner {
+          continue;
+        }
+
+        for (index, sym) in rule.symbols.iter().enumerate() {
+          let SymbolId::DBNonTerminal { key } = sym.id else { continue };
+
+          let mut set = OrderedSet::new();
+          if sequence_first(&rule.symbols[index + 1..], &first, &nullable, &mut set) {
+            set.extend(follow[nonterm.0 as usize].iter().cloned());
+          }
+
+          let target = &mut follow[key.0 as usize];
+          let len = target.len();
+          target.extend(set);
+          changed |= target.len() != len;
+        }
+      }
+    }
+
+    follow
+  }
+
   /// Returns an iterator of all items that are `_ = ...•Aa`  for some
   /// [DBNonTermKey] `A`, or in other words this returns the list of items that
   /// would shift over the [DBNonTermKey] `A`. If an item is `B = ...•A`, then
@@ -480,6 +654,34 @@ impl ParserDatabase {
   }
 }
 
+/// Adds the terminals that can begin a string derived from `symbols` to `set`,
+/// returning `true` if `symbols` can derive the empty string.
+fn sequence_first(
+  symbols: &[SymbolRef],
+  first: &[OrderedSet<DBTermKey>],
+  nullable: &[bool],
+  set: &mut OrderedSet<DBTermKey>,
+) -> bool {
+  for sym in symbols {
+    match sym.id {
+      SymbolId::DBNonTerminal { key } => {
+        set.extend(first[key.0 as usize].iter().cloned());
+        if !nullable[key.0 as usize] {
+          return false;
+        }
+      }
+      sym => {
+        if let Some(tok) = sym.tok_db_key() {
+          set.insert(tok);
+        }
+        return false;
+      }
+    }
+  }
+
+  true
+}
+
 macro_rules! indexed_id_implementations {
   ($id_type:ty) => {
     impl $id_type {
@@ -1,33 +1,107 @@
 #![allow(unused)]
 use crate::{RadlrError, RadlrResult};
-use std::{num::NonZeroUsize, sync::mpsc::Receiver, thread::JoinHandle};
+use std::{
+  any::Any,
+  num::NonZeroUsize,
+  panic::AssertUnwindSafe,
+  sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    mpsc::{Receiver, RecvTimeoutError, SendError, Sender},
+    Arc,
+    Mutex,
+  },
+  thread::JoinHandle,
+  time::{Duration, Instant},
+};
+
+/// A cloneable flag that signals long running operations to stop. Jobs run
+/// by a [WorkerPool] should poll [CancellationToken::is_cancelled] and return
+/// [RadlrError::Cancelled] once it is set.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+  cancelled: Arc<AtomicBool>,
+  budget:    Option<Duration>,
+  deadline:  Option<Instant>,
+}
+
+impl CancellationToken {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns a token that is also cancelled once `budget` has elapsed. The
+  /// budget is measured from each call to [CancellationToken::start_budget],
+  /// so the token can be reused for any number of builds.
+  pub fn with_time_budget(self, budget: Duration) -> Self {
+    Self { budget: Some(budget), deadline: None, ..self }
+  }
+
+  /// Returns a clone of this token whose time budget, if it has one, starts
+  /// at the time of this call.
+  pub fn start_budget(&self) -> Self {
+    Self { deadline: self.budget.and_then(|budget| Instant::now().checked_add(budget)), ..self.clone() }
+  }
+
+  /// Cancels this token and all of its clones.
+  pub fn cancel(&self) {
+    self.cancelled.store(true, Ordering::Release)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.cancelled.load(Ordering::Acquire) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+  }
+
+  /// Returns [RadlrError::Cancelled] if the token has been cancelled.
+  pub fn check(&self) -> RadlrResult<()> {
+    if self.is_cancelled() {
+      Err(RadlrError::Cancelled)
+    } else {
+      Ok(())
+    }
+  }
+}
 
 enum Task {
-  Job(Box<dyn FnOnce(usize) -> RadlrResult<()> + Send>),
+  Job(usize, Box<dyn FnOnce(usize) -> RadlrResult<()> + Send>),
   Stop,
 }
 
+/// The result of a job, tagged with the run that created it and the worker
+/// that ran it.
+struct Response {
+  run:    usize,
+  worker: usize,
+  result: RadlrResult<()>,
+}
+
 struct Worker {
   thread:  JoinHandle<()>,
-  channel: std::sync::mpsc::Sender<Task>,
+  channel: Sender<Task>,
   id:      usize,
 }
 
 impl Worker {
-  fn inner_loop(
-    receiver: std::sync::mpsc::Receiver<Task>,
-    response: std::sync::mpsc::Sender<Result<(), RadlrError>>,
-    id: usize,
-  ) -> impl FnOnce() {
+  fn spawn(id: usize, response: Sender<Response>) -> Self {
+    let (sender, receiver) = std::sync::mpsc::channel::<Task>();
+    Worker { channel: sender, thread: std::thread::spawn(Worker::inner_loop(receiver, response, id)), id }
+  }
+
+  fn inner_loop(receiver: Receiver<Task>, response: Sender<Response>, id: usize) -> impl FnOnce() {
     move || {
       while let Ok(task) = receiver.recv() {
         match task {
-          Task::Job(job) => match response.send((job)(id)) {
-            Err(_) => {
+          Task::Job(run, job) => {
+            // Panics are caught so that a failing job does not take the worker
+            // down with it.
+            let result = match std::panic::catch_unwind(AssertUnwindSafe(|| (job)(id))) {
+              Ok(result) => result,
+              Err(payload) => Err(RadlrError::JobPanic(panic_message(payload))),
+            };
+
+            if response.send(Response { run, worker: id, result }).is_err() {
               break;
             }
-            _ => {}
-          },
+          }
           Task::Stop => {
             break;
           }
@@ -37,11 +111,27 @@ impl Worker {
   }
 }
 
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+  match payload.downcast::<String>() {
+    Ok(msg) => *msg,
+    Err(payload) => match payload.downcast::<&'static str>() {
+      Ok(msg) => msg.to_string(),
+      Err(_) => "Unknown panic payload".to_string(),
+    },
+  }
+}
+
 // A basic, multi-threaded worker pool.
+//
+// Jobs that panic are reported as [RadlrError::JobPanic] errors, and workers
+// whose threads have stopped are replaced before the next job is sent.
 pub struct StandardPool {
   size:     usize,
-  workers:  Vec<Worker>,
-  c_signal: Receiver<Result<(), RadlrError>>,
+  workers:  Mutex<Vec<Worker>>,
+  c_sender: Sender<Response>,
+  c_signal: Receiver<Response>,
+  runs:     AtomicUsize,
+  cancel:   CancellationToken,
 }
 
 impl StandardPool {
@@ -55,25 +145,44 @@ impl StandardPool {
 
     Ok(Self {
       size,
-      workers: (0..size)
-        .into_iter()
-        .map(|id| {
-          let (sender, receiver) = std::sync::mpsc::channel::<Task>();
-          Worker {
-            channel: sender,
-            thread: std::thread::spawn(Worker::inner_loop(receiver, c_sender.clone(), id)),
-            id,
-          }
-        })
-        .collect(),
+      workers: Mutex::new((0..size).into_iter().map(|id| Worker::spawn(id, c_sender.clone())).collect()),
+      c_sender,
       c_signal: receiver,
+      runs: AtomicUsize::new(0),
+      cancel: Default::default(),
     })
   }
+
+  /// Uses `token` to cancel the jobs of this pool. Once the token is
+  /// cancelled, [WorkerPool::run] returns [RadlrError::Cancelled] without
+  /// waiting for the remaining jobs to complete.
+  pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+    self.cancel = token;
+    self
+  }
+
+  /// Cancels the jobs of this pool once `budget` has elapsed, measured from
+  /// the start of each run.
+  pub fn with_time_budget(mut self, budget: Duration) -> Self {
+    self.cancel = self.cancel.clone().with_time_budget(budget);
+    self
+  }
+
+  /// Replaces the worker at `index` with a new worker.
+  fn respawn(&self, workers: &mut Vec<Worker>, index: usize) {
+    let worker = std::mem::replace(&mut workers[index], Worker::spawn(index, self.c_sender.clone()));
+    let _ = worker.thread.join();
+  }
 }
 
 impl Drop for StandardPool {
   fn drop(&mut self) {
-    for worker in self.workers.drain(..) {
+    let workers = match self.workers.get_mut() {
+      Ok(workers) => workers,
+      Err(err) => err.into_inner(),
+    };
+
+    for worker in workers.drain(..) {
       match worker.channel.send(Task::Stop) {
         Ok(_) => match worker.thread.join() {
           Ok(_) => {}
@@ -128,6 +237,16 @@ pub trait WorkerPool {
   ///  Ok(())
   /// ```
   fn run<T: FnOnce(usize) -> RadlrResult<()> + Send + 'static>(&self, job_creator: impl Fn(usize) -> T) -> RadlrResult<()>;
+
+  /// Returns the token that cancels the jobs of this pool. Long running jobs
+  /// should poll the token and return [RadlrError::Cancelled] once it is
+  /// cancelled. The time budget of the token, if it has one, starts at the
+  /// time of this call.
+  ///
+  /// The default implementation returns a token that is never cancelled.
+  fn cancellation_token(&self) -> CancellationToken {
+    CancellationToken::new()
+  }
 }
 
 impl WorkerPool for SingleThreadPool {
@@ -139,45 +258,66 @@ impl WorkerPool for SingleThreadPool {
 
 impl WorkerPool for StandardPool {
   fn run<T: FnOnce(usize) -> RadlrResult<()> + Send + 'static>(&self, job_creator: impl Fn(usize) -> T) -> RadlrResult<()> {
+    let cancel = self.cancel.start_budget();
+    cancel.check()?;
+
+    let run = self.runs.fetch_add(1, Ordering::Relaxed);
     let mut errors = vec![];
+    let mut workers = self.workers.lock()?;
+    let mut pending = vec![true; workers.len()];
 
-    for worker in &self.workers {
-      let t = job_creator(self.size);
-      let t = Box::new(t);
-      match worker.channel.send(Task::Job(t)) {
-        Ok(()) => {}
-        Err(io) => {
-          eprintln!("{}", io);
-          todo!("Rebuild worker");
-          // rebuild worker.
-        }
+    for index in 0..workers.len() {
+      let mut task = Task::Job(run, Box::new(job_creator(self.size)));
+
+      if workers[index].thread.is_finished() {
+        self.respawn(&mut workers, index);
+      }
+
+      while let Err(SendError(returned_task)) = workers[index].channel.send(task) {
+        // The worker stopped after the check above.
+        task = returned_task;
+        self.respawn(&mut workers, index);
       }
     }
 
-    let mut count = 0;
+    let receive = |Response { run: response_run, worker, result }: Response, pending: &mut Vec<bool>, errors: &mut Vec<_>| {
+      // Results of cancelled runs are discarded.
+      if response_run == run {
+        pending[worker] = false;
+        if let Err(err) = result {
+          errors.push(err)
+        }
+      }
+    };
 
-    loop {
-      match self.c_signal.recv_timeout(std::time::Duration::from_nanos(1000)) {
-        Ok(response) => {
-          count += 1;
+    while pending.iter().any(|p| *p) {
+      if cancel.is_cancelled() {
+        // Jobs that are still running will have their results discarded by
+        // later runs.
+        return Err(RadlrError::Cancelled);
+      }
 
-          match response {
-            Err(err) => errors.push(err),
-            _ => {}
-          }
-          if count >= self.size {
-            break;
-          }
-        }
-        err @ Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
-          todo!("Handle worker dying. {err:?}");
+      match self.c_signal.recv_timeout(Duration::from_micros(100)) {
+        Ok(response) => receive(response, &mut pending, &mut errors),
+        Err(RecvTimeoutError::Disconnected) => {
+          unreachable!("The pool retains a sender of the response channel")
         }
-        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+        Err(RecvTimeoutError::Timeout) => {}
       }
 
-      for (v, worker) in self.workers.iter().enumerate() {
-        if worker.thread.is_finished() {
-          panic!("Worker [{v}] Died");
+      for index in 0..workers.len() {
+        if pending[index] && workers[index].thread.is_finished() {
+          // The worker may have sent its response before stopping.
+          while let Ok(response) = self.c_signal.try_recv() {
+            receive(response, &mut pending, &mut errors)
+          }
+
+          if pending[index] {
+            pending[index] = false;
+            errors.push(RadlrError::JobPanic(format!("Worker [{index}] stopped before completing its job")));
+          }
+
+          self.respawn(&mut workers, index);
         }
       }
     }
@@ -188,6 +328,10 @@ impl WorkerPool for StandardPool {
       Ok(())
     }
   }
+
+  fn cancellation_token(&self) -> CancellationToken {
+    self.cancel.start_budget()
+  }
 }
 
 #[test]
@@ -226,3 +370,100 @@ fn worker_pool() -> RadlrResult<()> {
 
   Ok(())
 }
+
+#[test]
+fn worker_pool_survives_panicking_jobs() -> RadlrResult<()> {
+  let pool = StandardPool::new(4)?;
+
+  let result = pool.run(|_| {
+    move |id| {
+      if id == 0 {
+        panic!("job {id} failed");
+      }
+      Ok(())
+    }
+  });
+
+  match result.map_err(|err| err.flatten()) {
+    Err(errors) => match errors.as_slice() {
+      [RadlrError::JobPanic(msg)] => assert_eq!(msg, "job 0 failed"),
+      errors => panic!("Expected a job panic, got {errors:?}"),
+    },
+    Ok(_) => panic!("Expected a job panic"),
+  }
+
+  let count = Arc::new(AtomicUsize::new(0));
+  pool.run(|_| {
+    let count = count.clone();
+    move |_| {
+      count.fetch_add(1, Ordering::Relaxed);
+      Ok(())
+    }
+  })?;
+
+  assert_eq!(count.load(Ordering::Relaxed), pool.size);
+
+  Ok(())
+}
+
+#[test]
+fn worker_pool_can_be_cancelled() -> RadlrResult<()> {
+  let token = CancellationToken::new();
+  let pool = StandardPool::new(2)?.with_cancellation(token.clone());
+
+  let start = Instant::now();
+  let result = pool.run(|_| {
+    let token = token.clone();
+    move |_| {
+      token.cancel();
+      std::thread::sleep(Duration::from_millis(500));
+      Ok(())
+    }
+  });
+
+  assert!(result.as_ref().is_err_and(|e| e.is_cancelled()), "{result:?}");
+  assert!(start.elapsed() < Duration::from_millis(500));
+  assert!(pool.run(|_| |_| Ok(())).is_err_and(|e| e.is_cancelled()));
+
+  // Results of the cancelled run are not counted towards later runs.
+  let pool = pool.with_cancellation(CancellationToken::new());
+  let count = Arc::new(AtomicUsize::new(0));
+  pool.run(|_| {
+    let count = count.clone();
+    move |_| {
+      count.fetch_add(1, Ordering::Relaxed);
+      Ok(())
+    }
+  })?;
+  assert_eq!(count.load(Ordering::Relaxed), pool.size);
+
+  let pool = StandardPool::new(2)?.with_time_budget(Duration::from_millis(20));
+  let result = pool.run(|_| |_| {
+    std::thread::sleep(Duration::from_millis(200));
+    Ok(())
+  });
+  assert!(result.is_err_and(|e| e.is_cancelled()));
+
+  // The budget starts again with each run of the pool, once the workers have
+  // finished the jobs of the cancelled run.
+  std::thread::sleep(Duration::from_millis(250));
+  pool.run(|_| |_| Ok(()))?;
+
+  Ok(())
+}
+
+#[test]
+fn cancelled_pool_aborts_state_construction() -> RadlrResult<()> {
+  let path = std::path::PathBuf::from("/test/cancel.sg");
+  let db = crate::RadlrGrammar::new()
+    .add_source_from_string("<> e > e '+' e | c:num", &path, false)?
+    .build_db(&path, Default::default())?;
+
+  let token = CancellationToken::new();
+  token.cancel();
+  let pool = StandardPool::new(2)?.with_cancellation(token);
+
+  assert!(db.build_states(Default::default(), &pool).is_err_and(|e| e.is_cancelled()));
+
+  Ok(())
+}
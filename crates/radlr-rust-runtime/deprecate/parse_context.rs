@@ -579,7 +579,7 @@ pub trait RadlrParser<R: ByteReader + MutByteReader, M, const UPWARD_STACK: bool
         ParseAction::Error { .. } => {
           let last_input = TokenRange::default();
           let mut token: Token = last_input.to_token(self.get_reader_mut());
-          token.set_source(self.get_input().to_string().as_bytes().into());
+          token.set_shared_source(self.get_input().as_bytes());
           break Err(RadlrParseError {
             message:          "Could not recognize the following input:".to_string(),
             inline_message:   "".to_string(),
@@ -638,7 +638,7 @@ pub trait RadlrParser<R: ByteReader + MutByteReader, M, const UPWARD_STACK: bool
           }
           let mut token: Token = last_input.to_token(self.get_reader_mut());
 
-          token.set_source(self.get_input().to_string().as_bytes().into());
+          token.set_shared_source(self.get_input().as_bytes());
           break ShiftsAndSkipsResult::FailedParse(RadlrParseError {
             message:          "Could not recognize the following input:".to_string(),
             inline_message:   "".to_string(),
@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use crate::types::{bytecode::*, TableHeaderData};
+use crate::types::{bytecode::*, BytecodeParserDB, TableHeaderData};
 
 fn header<'a>(address: usize) -> String {
   format!("{}| ", address_string(address))
@@ -11,8 +11,42 @@ pub(crate) fn address_string(bc_address: usize) -> String {
 }
 
 pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool) -> (String, Option<Instruction<'a>>) {
-  use disassemble_parse_block as ds;
-  use header as dh;
+  disassemble_block(i, recursive, None)
+}
+
+/// Returns the name of the state located at `address`, if symbols are
+/// available.
+fn state_name(address: usize, names: Option<&BytecodeParserDB>) -> String {
+  match names.and_then(|n| n.address_to_state_name.get(&(address as u32))) {
+    Some(name) => format!(" <{name}>"),
+    None => Default::default(),
+  }
+}
+
+fn token_name(tok_id: u32, names: Option<&BytecodeParserDB>) -> String {
+  match names.and_then(|n| n.token_id_to_str.get(&tok_id)) {
+    Some(name) => format!(" <{}>", name.replace("\n", "\\n")),
+    None => Default::default(),
+  }
+}
+
+fn nonterm_name(nterm_id: u32, names: Option<&BytecodeParserDB>) -> String {
+  match names.and_then(|n| n.nonterm_name.get(&nterm_id)) {
+    Some(name) => format!(" <{name}>"),
+    None => Default::default(),
+  }
+}
+
+fn disassemble_block<'a>(
+  i: Option<Instruction<'a>>,
+  recursive: bool,
+  names: Option<&BytecodeParserDB>,
+) -> (String, Option<Instruction<'a>>) {
+  let ds = |i, r| disassemble_block(i, r, names);
+  let dh = |address| match names.and_then(|n| n.address_to_state_name.get(&(address as u32))) {
+    Some(name) => format!("\n[STATE {name}]\n{}", header(address)),
+    None => header(address),
+  };
 
   let r = recursive;
 
@@ -23,7 +57,13 @@ pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool)
   } else {
     use Opcode::*;
     match i.get_opcode() {
-      VectorBranch | HashBranch => generate_table_string(i, r),
+      VectorBranch | HashBranch => {
+        let (string, i_last) = generate_table_string(i, r, names);
+        match names.and_then(|n| n.address_to_state_name.get(&(i.address() as u32))) {
+          Some(name) => (format!("\n[STATE {name}]{string}"), i_last),
+          None => (string, i_last),
+        }
+      }
       ByteSequence => {
         let mut iter = i.iter();
         let len = iter.next_u16_le().unwrap();
@@ -57,7 +97,7 @@ pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool)
         let gotos = (0..len)
           .into_iter()
           .map(|_| iter.next_u32_le().unwrap())
-          .map(|address| address_string(address as usize))
+          .map(|address| address_string(address as usize) + &state_name(address as usize, names))
           .collect::<Vec<_>>();
 
         (format!("\n{}FORK states [ {} ]", dh(i.address()), gotos.join(" | ")), i.next())
@@ -66,7 +106,7 @@ pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool)
         let mut iter = i.iter();
         let _state_mode = iter.next_u8().unwrap();
         let address = iter.next_u32_le().unwrap() as usize;
-        (format!("\n{}GOTO {}", dh(i.address()), address_string(address)), i.next())
+        (format!("\n{}GOTO {}{}", dh(i.address()), address_string(address), state_name(address, names)), i.next())
       }
       PopGoto => {
         let (string, i_last) = if r { ds(i.next(), r) } else { Default::default() };
@@ -78,14 +118,14 @@ pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool)
         let _state_mode = iter.next_u8().unwrap();
         let address = iter.next_u32_le().unwrap() as usize;
 
-        (format!("\n{}PUSH {}{string}", dh(i.address()), address_string(address)), i_last)
+        (format!("\n{}PUSH {}{}{string}", dh(i.address()), address_string(address), state_name(address, names)), i_last)
       }
       PushExceptionHandler => {
         let (string, i_last) = if r { ds(i.next(), r) } else { Default::default() };
         let mut iter = i.iter();
         let _state_mode = iter.next_u8().unwrap();
         let address = iter.next_u32_le().unwrap() as usize;
-        (format!("\n{}PUSH-CATCH {}{string}", dh(i.address()), address_string(address)), i_last)
+        (format!("\n{}PUSH-CATCH {}{}{string}", dh(i.address()), address_string(address), state_name(address, names)), i_last)
       }
       Reduce => {
         let (string, i_last) = if r { ds(i.next(), r) } else { Default::default() };
@@ -96,8 +136,21 @@ pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool)
 
         let pluralized = if symbol_count == 1 { "SYMBOL" } else { "SYMBOLS" };
 
+        let diagram = match names.and_then(|n| n.rule_diagram.get(&rule_id)) {
+          Some(diagram) => format!(" [ {diagram} ]"),
+          None => Default::default(),
+        };
+
         (
-          format!("\n{}REDUCE-RULE {} TO [ {} ] ( {} {} ){string} ", dh(i.address()), rule_id, nterm, symbol_count, pluralized,),
+          format!(
+            "\n{}REDUCE-RULE {}{diagram} TO [ {}{} ] ( {} {} ){string} ",
+            dh(i.address()),
+            rule_id,
+            nterm,
+            nonterm_name(nterm, names),
+            symbol_count,
+            pluralized,
+          ),
           i_last,
         )
       }
@@ -106,7 +159,7 @@ pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool)
         let mut iter = i.iter();
         let tok_id = iter.next_u32_le().unwrap();
 
-        (format!("\n{}ASSIGN-TK [{}]{string}", dh(i.address()), tok_id), i_last)
+        (format!("\n{}ASSIGN-TK [{}]{}{string}", dh(i.address()), tok_id, token_name(tok_id, names)), i_last)
       }
       ReadCodepoint => {
         let (string, i_last) = if r { ds(i.next(), r) } else { Default::default() };
@@ -172,7 +225,11 @@ pub fn disassemble_parse_block<'a>(i: Option<Instruction<'a>>, recursive: bool)
   }
 }
 
-pub(crate) fn generate_table_string<'a>(i: Instruction<'a>, recursive: bool) -> (String, Option<Instruction<'a>>) {
+pub(crate) fn generate_table_string<'a>(
+  i: Instruction<'a>,
+  recursive: bool,
+  names: Option<&BytecodeParserDB>,
+) -> (String, Option<Instruction<'a>>) {
   use Opcode::*;
   let TableHeaderData {
     input_type,
@@ -213,7 +270,12 @@ pub(crate) fn generate_table_string<'a>(i: Instruction<'a>, recursive: bool) ->
       strings.push(create_failure_entry(entry_offset, default_block.address()));
     } else {
       delta_offsets.insert(address);
-      strings.push(create_normal_entry(val_id, input_type, entry_offset * 4 + table_start, address, meta));
+      let symbol_name = match input_type {
+        MatchInputType::Token => token_name(val_id, names),
+        MatchInputType::NonTerminal => nonterm_name(val_id, names),
+        _ => Default::default(),
+      };
+      strings.push(create_normal_entry(val_id, input_type, entry_offset * 4 + table_start, address, meta, symbol_name));
     }
   }
 
@@ -223,7 +285,7 @@ pub(crate) fn generate_table_string<'a>(i: Instruction<'a>, recursive: bool) ->
     format!("\n{}{} JUMP \n{: >7} TYPE {} ", header(i.address()), table_name, "", MatchInputType::from(input_type).to_string(),);
 
   string += &(if scan_index.address() > 0 {
-    format!("\n{: >7} SCANNER ADDRESS {}", "", address_string(scan_index.address()))
+    format!("\n{: >7} SCANNER ADDRESS {}{}", "", address_string(scan_index.address()), state_name(scan_index.address(), names))
   } else {
     format!("\n{: >7} NO SCANNER", "")
   });
@@ -234,10 +296,10 @@ pub(crate) fn generate_table_string<'a>(i: Instruction<'a>, recursive: bool) ->
 
   if recursive {
     for address in delta_offsets {
-      string += &disassemble_parse_block(Some((i.bytecode(), address).into()), recursive).0;
+      string += &disassemble_block(Some((i.bytecode(), address).into()), recursive, names).0;
     }
 
-    let (default_string, offset) = disassemble_parse_block(Some(default_block), recursive);
+    let (default_string, offset) = disassemble_block(Some(default_block), recursive, names);
 
     string += &default_string;
 
@@ -254,20 +316,62 @@ fn create_default_entry(goto_offset: usize) -> String {
   format!("\nDEFAULT ---- JUMP TO {} ON FAIL", address_string(goto_offset))
 }
 
-fn create_normal_entry(token_id: u32, input_type: MatchInputType, idx: usize, bc_address: usize, meta: i64) -> String {
+fn create_normal_entry(
+  token_id: u32,
+  input_type: MatchInputType,
+  idx: usize,
+  bc_address: usize,
+  meta: i64,
+  symbol_name: String,
+) -> String {
   let token_string = token_id.to_string();
   format!(
-    "\n{: >6}---- JUMP TO {} ON {} ( {} ) [{}]",
+    "\n{: >6}---- JUMP TO {} ON {} ( {}{} ) [{}]",
     header(idx),
     address_string(bc_address),
     MatchInputType::from(input_type).to_string(),
     token_string,
+    symbol_name,
     meta
   )
 }
 
 /// Returns a "disassembly"  representation of a bytecode parser's opcodes.
 pub fn disassemble_bytecode(bc: &[u8]) -> String {
+  disassemble(bc, None)
+}
+
+/// Returns a "disassembly" representation of a bytecode parser's opcodes,
+/// annotated with the state, token, non-terminal, and rule names stored in
+/// `pkg`.
+///
+/// Names are only available for symbols that were recorded when the
+/// bytecode was compiled; compile with debug symbols enabled to get rule
+/// diagrams and non-terminal names.
+pub fn disassemble_bytecode_db(pkg: &BytecodeParserDB) -> String {
+  let mut strings = vec![];
+
+  let mut entries = pkg
+    .nonterm_name_to_id
+    .iter()
+    .filter_map(|(name, id)| pkg.nonterm_id_to_address.get(id).map(|address| (*address, name)))
+    .collect::<Vec<_>>();
+  entries.sort();
+
+  if !entries.is_empty() {
+    strings.push("ENTRY POINTS:".to_string());
+    for (address, name) in entries {
+      strings.push(format!("  {name} => {}{}", address_string(address as usize), state_name(address as usize, Some(pkg))));
+    }
+    strings.push(Default::default());
+  }
+
+  strings.push(disassemble(&pkg.bytecode, Some(pkg)));
+
+  strings.join("\n")
+}
+
+fn disassemble(bc: &[u8], names: Option<&BytecodeParserDB>) -> String {
   let mut states_strings = vec![];
   let i: Instruction = (bc, 0).into();
   let mut next = Some(i);
@@ -277,7 +381,7 @@ pub fn disassemble_bytecode(bc: &[u8]) -> String {
       states_strings.push("\n".to_string());
     }
 
-    let (string, n) = disassemble_parse_block(next, true);
+    let (string, n) = disassemble_block(next, true, names);
 
     states_strings.push(string);
 
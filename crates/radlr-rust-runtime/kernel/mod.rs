@@ -1,5 +1,7 @@
 mod disassemble;
 mod parser_new;
+mod verify;
 
 pub use disassemble::*;
 pub use parser_new::*;
+pub use verify::*;
@@ -29,6 +29,16 @@ fn dispatch<'a, 'debug>(
   let mut block_base: Instruction = (bc, base_state.address).into();
   let mut i: Instruction = block_base.clone();
 
+  // Branches only jump forward, so a block can only be repeated through a
+  // goto. No block is entered twice without consuming input, so more gotos
+  // than there are bytes in the bytecode means the gotos form a cycle. Compiled
+  // bytecode does not contain such cycles, so this is only tracked in debug
+  // builds to keep it out of the dispatch loop.
+  #[cfg(debug_assertions)]
+  let mut goto_budget = bc.len();
+  #[cfg(debug_assertions)]
+  let mut input_position = (ctx.input_ptr, ctx.sym_ptr);
+
   loop {
     use Opcode::*;
 
@@ -45,10 +55,10 @@ fn dispatch<'a, 'debug>(
       ShiftToken => shift_token(i, ctx, base_state),
       ShiftTokenScanless => shift_token_scanless(i, ctx, base_state),
       ShiftChar => scan_shift(i, ctx),
-      SkipToken => skip_token(block_base, ctx),
-      SkipTokenScanless => skip_token_scanless(block_base, ctx),
-      PeekSkipToken => peek_skip_token(block_base, ctx),
-      PeekSkipTokenScanless => peek_skip_token_scanless(block_base, ctx),
+      SkipToken => skip_token(block_base, ctx, input),
+      SkipTokenScanless => skip_token_scanless(block_base, ctx, input),
+      PeekSkipToken => peek_skip_token(block_base, ctx, input),
+      PeekSkipTokenScanless => peek_skip_token_scanless(block_base, ctx, input),
       PeekToken => peek_token(i, ctx),
       PeekTokenScanless => peek_token_scanless(i, ctx),
       PeekReset => peek_reset(i, ctx),
@@ -56,7 +66,7 @@ fn dispatch<'a, 'debug>(
       Goto => goto(i),
       PushGoto => push_state(i, ctx),
       PushExceptionHandler => push_exception_handler(i, ctx),
-      PopGoto => pop_goto(i, ctx),
+      PopGoto => pop_goto(i, ctx, input),
       AssignToken => assign_token(i, ctx),
       VectorBranch => vector_branch(i, ctx, input, debug, is_scanner),
       HashBranch => hash_branch(i, ctx, input, debug, is_scanner),
@@ -101,6 +111,16 @@ fn dispatch<'a, 'debug>(
 
       OpResult { action: None, next: Some(next_instruction), is_goto, can_debug } => {
         if is_goto {
+          #[cfg(debug_assertions)]
+          if input_position != (ctx.input_ptr, ctx.sym_ptr) {
+            input_position = (ctx.input_ptr, ctx.sym_ptr);
+            goto_budget = bc.len();
+          } else if goto_budget == 0 {
+            break (FailState, Option::None, block_base.address());
+          } else {
+            goto_budget -= 1;
+          }
+
           block_base = next_instruction;
         }
 
@@ -118,6 +138,11 @@ fn byte_sequence<'a>(i: Instruction<'a>, ctx: &mut ParserContext, input: &impl P
   let length = iter.next_u16_le().unwrap() as usize;
   let default_offset = iter.next_u32_le().unwrap() as usize;
   let offset = ctx.input_ptr;
+
+  if input_pending(input, offset, length) {
+    return need_more_input();
+  }
+
   let mut line_incr = 0;
   let mut line_offset = ctx.end_line_off;
   for b in 0..length {
@@ -248,7 +273,14 @@ fn peek_token_scanless<'a>(i: Instruction<'a>, ctx: &mut ParserContext) -> OpRes
   peek_token(i, ctx)
 }
 
-fn __skip_token_core__<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext) -> OpResult<'a> {
+fn __skip_token_core__<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext, input: &impl ParserInput) -> OpResult<'a> {
+  // Skipping an empty token restarts the block at the same input position, and
+  // skipping past the end of the input restarts it at a position that never
+  // yields a token. Neither would terminate.
+  if ctx.tok_byte_len == 0 || ctx.sym_ptr + ctx.tok_byte_len as usize > input.len() {
+    return OpResult { action: ParseAction::FailState, next: None, is_goto: false, can_debug: true };
+  }
+
   let original_offset = ctx.sym_ptr;
   let offset = ctx.sym_ptr + ctx.tok_byte_len as usize;
   let tok_len = ctx.tok_byte_len;
@@ -273,9 +305,9 @@ fn __skip_token_core__<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserCo
 }
 
 /// Performs the [Opcode::SkipToken] operation
-fn skip_token<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext) -> OpResult<'a> {
+fn skip_token<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext, input: &impl ParserInput) -> OpResult<'a> {
   const __HINT__: Opcode = Opcode::SkipToken;
-  let result = __skip_token_core__(base_instruction, ctx);
+  let result = __skip_token_core__(base_instruction, ctx, input);
 
   ctx.end_line_num = ctx.chkp_line_num;
   ctx.end_line_off = ctx.chkp_line_off;
@@ -287,16 +319,20 @@ fn skip_token<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext) ->
 }
 
 /// Performs the [Opcode::SkipTokenScanless] operation
-fn skip_token_scanless<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext) -> OpResult<'a> {
+fn skip_token_scanless<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext, input: &impl ParserInput) -> OpResult<'a> {
   const __HINT__: Opcode = Opcode::SkipTokenScanless;
   ctx.tok_byte_len = ctx.byte_len;
-  skip_token(base_instruction, ctx)
+  skip_token(base_instruction, ctx, input)
 }
 
 /// Performs the [Opcode::PeekSkipToken] operation
-fn peek_skip_token<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext) -> OpResult<'a> {
+fn peek_skip_token<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext, input: &impl ParserInput) -> OpResult<'a> {
   const __HINT__: Opcode = Opcode::PeekSkipToken;
-  __skip_token_core__(base_instruction, ctx);
+  let result = __skip_token_core__(base_instruction, ctx, input);
+
+  if matches!(result.action, ParseAction::FailState) {
+    return result;
+  }
 
   OpResult {
     action:    ParseAction::None,
@@ -307,10 +343,10 @@ fn peek_skip_token<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContex
 }
 
 /// Performs the [Opcode::PeekSkipTokenScanless] operation
-fn peek_skip_token_scanless<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext) -> OpResult<'a> {
+fn peek_skip_token_scanless<'a>(base_instruction: Instruction<'a>, ctx: &mut ParserContext, input: &impl ParserInput) -> OpResult<'a> {
   const __HINT__: Opcode = Opcode::PeekSkipTokenScanless;
   ctx.tok_byte_len = ctx.byte_len;
-  peek_skip_token(base_instruction, ctx)
+  peek_skip_token(base_instruction, ctx, input)
 }
 
 /// Performs the [Opcode::Reduce] operation
@@ -400,10 +436,10 @@ fn goto<'a, 'debug>(i: Instruction<'a>) -> OpResult<'a> {
 }
 
 /// Performs the [Opcode::PopGoto] operation
-fn pop_goto<'a, 'debug>(i: Instruction<'a>, ctx: &mut ParserContext) -> OpResult<'a> {
+fn pop_goto<'a, 'debug>(i: Instruction<'a>, ctx: &mut ParserContext, input: &impl ParserInput) -> OpResult<'a> {
   const __HINT__: Opcode = Opcode::PopGoto;
 
-  ctx.pop_state();
+  pop_state(ctx, input);
 
   OpResult {
     action:    ParseAction::None,
@@ -466,7 +502,9 @@ fn read_codepoint<'a, 'debug>(
 ) -> OpResult<'a> {
   const __HINT__: Opcode = Opcode::ReadCodepoint;
   emit_instruction_debug(debug, i, input, ParserStackTrackers::from(&*ctx), is_scanner);
-  let (cp, is_nl) = get_input_value(MatchInputType::Codepoint, i, ctx, input, debug, is_scanner);
+  let Some((cp, is_nl)) = get_input_value(MatchInputType::Codepoint, i, ctx, input, debug, is_scanner) else {
+    return need_more_input();
+  };
   if cp == 0 {
     OpResult {
       action:    ParseAction::FailState,
@@ -510,7 +548,9 @@ fn hash_branch<'a, 'debug>(
 
   let hash_mask = (1 << modulo_base) - 1;
 
-  let (input_value, is_nl) = get_input_value(input_type, scan_block_instruction, ctx, input, debug, is_scanner);
+  let Some((input_value, is_nl)) = get_input_value(input_type, scan_block_instruction, ctx, input, debug, is_scanner) else {
+    return need_more_input();
+  };
   #[cfg(any(debug_assertions, feature = "wasm-lab"))]
   {
     let tok_id = ctx.tok_id;
@@ -572,7 +612,9 @@ fn vector_branch<'a, 'debug>(
     ..
   } = i.into();
 
-  let (input_value, is_nl) = get_input_value(input_type, scan_block_instruction, ctx, input, debug, is_scanner);
+  let Some((input_value, is_nl)) = get_input_value(input_type, scan_block_instruction, ctx, input, debug, is_scanner) else {
+    return need_more_input();
+  };
 
   loop {
     let value_index = (input_value as i32 - value_offset as i32) as usize;
@@ -607,11 +649,16 @@ fn get_input_value<'a, 'debug>(
   input: &impl ParserInput,
   debug: &mut Option<&'debug mut DebugFnNew>,
   is_scanner: bool,
-) -> (u32, bool) {
+) -> Option<(u32, bool)> {
   let mut is_nl = false;
   let val = match input_type {
     MatchInputType::NonTerminal => ctx.nonterm as u32,
-    MatchInputType::EndOfFile => (ctx.input_ptr >= input.len()) as u32,
+    MatchInputType::EndOfFile => {
+      if input_pending(input, ctx.input_ptr, 1) {
+        return None;
+      }
+      (ctx.input_ptr >= input.len()) as u32
+    }
     MatchInputType::Token => {
       if ctx.recovery_tok_id > 0 {
         ctx.tok_id = ctx.recovery_tok_id;
@@ -619,7 +666,7 @@ fn get_input_value<'a, 'debug>(
         ctx.byte_len = 0;
       } else {
         debug_assert!(!is_scanner);
-        token_scan(scan_index, ctx, input, debug);
+        token_scan(scan_index, ctx, input, debug)?;
       }
       ctx.tok_id as u32
     }
@@ -628,6 +675,10 @@ fn get_input_value<'a, 'debug>(
       u32::MAX
     }
     MatchInputType::Byte => {
+      if input_pending(input, ctx.input_ptr, 1) {
+        return None;
+      }
+
       let byte = input.byte(ctx.input_ptr);
 
       if byte == 10 {
@@ -642,6 +693,10 @@ fn get_input_value<'a, 'debug>(
       byte as u32
     }
     MatchInputType::ByteScanless => {
+      if input_pending(input, ctx.input_ptr, 1) {
+        return None;
+      }
+
       let byte = input.byte(ctx.input_ptr);
 
       if byte == 10 {
@@ -656,6 +711,12 @@ fn get_input_value<'a, 'debug>(
       byte as u32
     }
     input_type => {
+      if input_pending(input, ctx.input_ptr, 1)
+        || input_pending(input, ctx.input_ptr, input.byte(ctx.input_ptr).leading_ones().max(1) as usize)
+      {
+        return None;
+      }
+
       let cp: u32 = input.codepoint(ctx.input_ptr);
 
       let len = get_utf8_byte_length_from_code_point(cp);
@@ -694,7 +755,35 @@ fn get_input_value<'a, 'debug>(
     }
   };
 
-  (val, is_nl)
+  Some((val, is_nl))
+}
+
+/// Pops the top state of the stack. The states of an incomplete input are
+/// kept by the context until its next checkpoint, so they can be restored if
+/// the input runs out of bytes.
+#[inline(always)]
+fn pop_state(ctx: &mut ParserContext, input: &impl ParserInput) -> ParserState {
+  if input.is_complete() {
+    ctx.pop_state()
+  } else {
+    ctx.pop_checkpointed_state()
+  }
+}
+
+/// Returns `true` if the `count` bytes at `cursor` have not yet been appended
+/// to an incomplete input.
+#[inline]
+fn input_pending(input: &impl ParserInput, cursor: usize, count: usize) -> bool {
+  cursor + count > input.len() && !input.is_complete()
+}
+
+fn need_more_input<'a>() -> OpResult<'a> {
+  OpResult {
+    action:    ParseAction::NeedMoreInput,
+    next:      None,
+    is_goto:   false,
+    can_debug: false,
+  }
 }
 
 fn token_scan<'a, 'debug>(
@@ -702,7 +791,7 @@ fn token_scan<'a, 'debug>(
   ctx: &mut ParserContext,
   input: &impl ParserInput,
   debug: &mut Option<&'debug mut DebugFnNew>,
-) {
+) -> Option<()> {
   ctx.tok_id = 0;
   ctx.input_ptr = ctx.sym_ptr;
 
@@ -731,6 +820,9 @@ fn token_scan<'a, 'debug>(
           (ParseAction::FailState, ..) => {
             break Some(());
           }
+          (ParseAction::NeedMoreInput, ..) => {
+            break None;
+          }
           (_, next_block, ..) => {
             if let Some(next_block) = next_block {
               stack.push(NORMAL_STATE_FLAG);
@@ -745,8 +837,9 @@ fn token_scan<'a, 'debug>(
   } {
     Some(()) => {
       ctx.input_ptr = ctx.sym_ptr;
+      Some(())
     }
-    _ => panic!("Unusable State"),
+    None => None,
   }
 }
 
@@ -817,11 +910,22 @@ impl<T: ParserInput> ParserIterator<T> for ByteCodeParserNew {
 
     let bc = bc.as_ref().as_ref();
 
-    let mut state = ctx.pop_state();
+    // An incomplete input may run out of bytes partway through a state. The
+    // context is then restored to this point, so the state can be run again
+    // once more input is available.
+    let checkpoint = (!input.is_complete()).then(|| ctx.checkpoint());
 
-    loop {
-      if state.address < 1 {
-        //Accept never encountered.
+    // States that complete without yielding an action are either on the stack
+    // already, or pushed by a block, and no block can be entered more than once
+    // without consuming input. Exceeding this limit means the bytecode loops
+    // through its states without ever yielding.
+    let mut state_budget = ctx.stack.len() + bc.len();
+
+    let mut state = pop_state(ctx, input);
+
+    let action = loop {
+      if state.address < 1 || state_budget == 0 {
+        // Accept never encountered, or the states loop without yielding.
         ctx.is_finished = true;
         break Some(ParseAction::Error {
           last_nonterminal:  ctx.nonterm,
@@ -838,7 +942,11 @@ impl<T: ParserInput> ParserIterator<T> for ByteCodeParserNew {
         }
         match dispatch(state, ctx, input, bc, &mut debugger, false) {
           (ParseAction::CompleteState, ..) => {
-            state = ctx.pop_state();
+            state_budget -= 1;
+            state = pop_state(ctx, input);
+          }
+          (ParseAction::NeedMoreInput, ..) => {
+            break Some(ParseAction::NeedMoreInput);
           }
           (ParseAction::FailState, _, fail_address) => {
             ctx.is_finished = true;
@@ -859,6 +967,12 @@ impl<T: ParserInput> ParserIterator<T> for ByteCodeParserNew {
           }
         }
       }
+    };
+
+    if let (Some(checkpoint), Some(ParseAction::NeedMoreInput)) = (checkpoint, &action) {
+      ctx.rollback(checkpoint);
     }
+
+    action
   }
 }
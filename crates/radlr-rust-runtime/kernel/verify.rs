@@ -0,0 +1,405 @@
+use std::collections::BTreeSet;
+
+use crate::types::{bytecode::*, BytecodeParserDB};
+
+/// A defect found by [verify_bytecode] that would cause the parser kernel to
+/// panic, read outside of the bytecode, or loop forever.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BytecodeError {
+  /// The address of the instruction that contains the defect.
+  pub address: usize,
+  pub kind:    BytecodeErrorKind,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum BytecodeErrorKind {
+  /// The bytecode is too short to contain the bytecode header.
+  MissingHeader,
+  /// The byte at the address is not an opcode.
+  InvalidOpcode(u8),
+  /// The operands of the instruction extend past the end of the bytecode.
+  Truncated(Opcode),
+  /// The instruction jumps to, or references a parse block at, an address
+  /// outside of the bytecode.
+  JumpOutOfBounds { opcode: Opcode, target: usize },
+  /// The instruction is not a terminal instruction, but is the last
+  /// instruction of the bytecode.
+  FallsOffEnd(Opcode),
+  /// The input type of a branch instruction is not a valid [MatchInputType].
+  InvalidInputType(u8),
+  /// A branch on token ids does not have a scanner address.
+  MissingScanner,
+  /// A branch jumps back into its own instruction or table, which would
+  /// repeat the branch or execute table data without consuming input.
+  JumpIntoTable { opcode: Opcode, target: usize },
+  /// A branch on token ids is reachable from a scanner. Scanners produce
+  /// tokens, so they can not branch on them.
+  TokenBranchInScanner,
+  /// The modulus of a hash table selects slots outside of the table.
+  InvalidHashModulus(u32),
+  /// The collision chain that starts at the given slot of a hash table leaves
+  /// the table or never ends.
+  InvalidHashChain { slot: usize },
+  /// No `Accept` instruction can be reached from the entry points.
+  NoReachableAccept,
+}
+
+impl std::fmt::Display for BytecodeError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    use BytecodeErrorKind::*;
+    let address = self.address;
+    match &self.kind {
+      MissingHeader => f.write_str("Bytecode is missing its header"),
+      InvalidOpcode(byte) => f.write_fmt(format_args!("Invalid opcode {byte} at {address:06X}")),
+      Truncated(op) => f.write_fmt(format_args!("{op:?} instruction at {address:06X} is truncated")),
+      JumpOutOfBounds { opcode, target } => {
+        f.write_fmt(format_args!("{opcode:?} instruction at {address:06X} targets out of bounds address {target:06X}"))
+      }
+      JumpIntoTable { opcode, target } => {
+        f.write_fmt(format_args!("{opcode:?} instruction at {address:06X} jumps into its own table at {target:06X}"))
+      }
+      FallsOffEnd(op) => f.write_fmt(format_args!("{op:?} instruction at {address:06X} falls off the end of the bytecode")),
+      InvalidInputType(ty) => f.write_fmt(format_args!("Branch instruction at {address:06X} has an invalid input type {ty}")),
+      MissingScanner => f.write_fmt(format_args!("Token branch instruction at {address:06X} has no scanner")),
+      TokenBranchInScanner => f.write_fmt(format_args!("Token branch instruction at {address:06X} is reachable from a scanner")),
+      InvalidHashModulus(m) => f.write_fmt(format_args!("Hash table at {address:06X} has an invalid modulus {m}")),
+      InvalidHashChain { slot } => f.write_fmt(format_args!("Hash table at {address:06X} has an invalid chain at slot {slot}")),
+      NoReachableAccept => f.write_str("No accept instruction is reachable from the entry points"),
+    }
+  }
+}
+
+impl std::error::Error for BytecodeError {}
+
+/// The size of the fixed part of `VectorBranch` and `HashBranch` instructions,
+/// after which the table data begins.
+const BRANCH_HEADER_SIZE: usize = 18;
+
+/// Checks that executing `bytecode` from any of `entry_addresses` can not cause
+/// the kernel to panic, read outside of the bytecode, or loop forever within a
+/// hash table lookup.
+///
+/// Every instruction reachable from the entry addresses is decoded. The
+/// verifier checks that opcodes and branch input types are valid, that the
+/// operands of instructions and the data of their tables lie within the
+/// bytecode, that every jump, goto, fork, and scanner address is in bounds,
+/// that scanners do not branch on tokens, and that at least one `Accept`
+/// instruction is reachable.
+pub fn verify_bytecode(bytecode: &[u8], entry_addresses: impl IntoIterator<Item = u32>) -> Result<(), BytecodeError> {
+  if bytecode.len() <= FIRST_PARSE_BLOCK_ADDRESS as usize {
+    return Err(BytecodeError { address: 0, kind: BytecodeErrorKind::MissingHeader });
+  }
+
+  let mut verifier = Verifier {
+    bc: bytecode,
+    visited: Default::default(),
+    pending: Default::default(),
+    scanner: false,
+    accepts: false,
+  };
+
+  for address in entry_addresses {
+    verifier.queue(address as usize, 0, Opcode::NoOp)?;
+  }
+
+  let has_entries = !verifier.pending.is_empty();
+
+  while let Some((scanner, address)) = verifier.pending.pop_first() {
+    verifier.scanner = scanner;
+    verifier.verify_block(address)?;
+  }
+
+  if has_entries && !verifier.accepts {
+    return Err(BytecodeError { address: 0, kind: BytecodeErrorKind::NoReachableAccept });
+  }
+
+  Ok(())
+}
+
+impl BytecodeParserDB {
+  /// Verifies the bytecode from the addresses of every non-terminal entry
+  /// point. See [verify_bytecode].
+  pub fn verify(&self) -> Result<(), BytecodeError> {
+    verify_bytecode(&self.bytecode, self.nonterm_id_to_address.values().copied())
+  }
+}
+
+struct Verifier<'a> {
+  bc:      &'a [u8],
+  /// Instructions that have been verified, as pairs of whether the instruction
+  /// is executed by a scanner and its address.
+  visited: BTreeSet<(bool, usize)>,
+  /// Blocks that still need to be verified, in the same form as `visited`.
+  pending: BTreeSet<(bool, usize)>,
+  /// Whether the block being verified is executed by a scanner.
+  scanner: bool,
+  accepts: bool,
+}
+
+impl<'a> Verifier<'a> {
+  fn error<T>(address: usize, kind: BytecodeErrorKind) -> Result<T, BytecodeError> {
+    Err(BytecodeError { address, kind })
+  }
+
+  /// Queues the block at `target`, which is referenced by the `opcode`
+  /// instruction at `address`, to be executed in the same mode as that
+  /// instruction.
+  fn queue(&mut self, target: usize, address: usize, opcode: Opcode) -> Result<(), BytecodeError> {
+    self.queue_in(self.scanner, target, address, opcode)
+  }
+
+  /// Queues the block at `target` to be executed by a scanner if `scanner` is
+  /// set, or by the parser otherwise.
+  fn queue_in(&mut self, scanner: bool, target: usize, address: usize, opcode: Opcode) -> Result<(), BytecodeError> {
+    if target < FIRST_PARSE_BLOCK_ADDRESS as usize || target >= self.bc.len() {
+      return Self::error(address, BytecodeErrorKind::JumpOutOfBounds { opcode, target });
+    }
+
+    if !self.visited.contains(&(scanner, target)) {
+      self.pending.insert((scanner, target));
+    }
+
+    Ok(())
+  }
+
+  /// Queues the block at the offset `delta` from the `opcode` instruction at
+  /// `address`.
+  fn queue_relative(&mut self, delta: u32, address: usize, opcode: Opcode) -> Result<(), BytecodeError> {
+    self.queue(address.saturating_add(delta as usize), address, opcode)
+  }
+
+  /// Queues the block at the offset `delta` from the branch instruction at
+  /// `address`. Compiled branches always jump past the end of their table,
+  /// which is at `table_end`.
+  fn queue_branch_target(&mut self, delta: u32, address: usize, table_end: usize, opcode: Opcode) -> Result<(), BytecodeError> {
+    let target = address.saturating_add(delta as usize);
+
+    if target < table_end {
+      return Self::error(address, BytecodeErrorKind::JumpIntoTable { opcode, target });
+    }
+
+    self.queue(target, address, opcode)
+  }
+
+  /// Returns the operands of the instruction at `address` if its `len` bytes
+  /// lie within the bytecode.
+  fn operands(&self, address: usize, len: usize, opcode: Opcode) -> Result<ByteCodeIterator<'a>, BytecodeError> {
+    if address.saturating_add(len) > self.bc.len() {
+      Self::error(address, BytecodeErrorKind::Truncated(opcode))
+    } else {
+      Ok((self.bc, address + 1).into())
+    }
+  }
+
+  /// Verifies the instructions that are executed in sequence from `address`,
+  /// queuing the blocks they jump to.
+  fn verify_block(&mut self, mut address: usize) -> Result<(), BytecodeError> {
+    use Opcode::*;
+
+    while self.visited.insert((self.scanner, address)) {
+      let byte = self.bc[address];
+      let opcode = Opcode::from(byte);
+
+      if opcode == NoOp && byte != NoOp as u8 {
+        return Self::error(address, BytecodeErrorKind::InvalidOpcode(byte));
+      }
+
+      let len = match opcode {
+        Pass | Fail => return Ok(()),
+        Accept => {
+          self.accepts = true;
+          return Ok(());
+        }
+        // These restart the current block, which has already been verified.
+        SkipToken | SkipTokenScanless | PeekSkipToken | PeekSkipTokenScanless => return Ok(()),
+        Goto => {
+          let mut iter = self.operands(address, opcode.len(), opcode)?;
+          iter.next_u8();
+          return self.queue(iter.next_u32_le().unwrap() as usize, address, opcode);
+        }
+        PushGoto | PushExceptionHandler => {
+          let mut iter = self.operands(address, opcode.len(), opcode)?;
+          iter.next_u8();
+          self.queue(iter.next_u32_le().unwrap() as usize, address, opcode)?;
+          opcode.len()
+        }
+        Fork => {
+          let count = self.operands(address, 3, opcode)?.next_u16_le().unwrap() as usize;
+          let mut iter = self.operands(address, 3 + count * 4, opcode)?;
+          iter.next_u16_le();
+          for _ in 0..count {
+            self.queue(iter.next_u32_le().unwrap() as usize, address, opcode)?;
+          }
+          return Ok(());
+        }
+        ByteSequence => {
+          let mut iter = self.operands(address, 7, opcode)?;
+          let count = iter.next_u16_le().unwrap() as usize;
+          let default_delta = iter.next_u32_le().unwrap();
+          self.operands(address, 7 + count, opcode)?;
+          if default_delta > 0 {
+            self.queue_relative(default_delta, address, opcode)?;
+          }
+          7 + count
+        }
+        VectorBranch | HashBranch => return self.verify_branch(address, opcode),
+        _ => {
+          self.operands(address, opcode.len(), opcode)?;
+          opcode.len()
+        }
+      };
+
+      address += len;
+
+      if address >= self.bc.len() {
+        return Self::error(address - len, BytecodeErrorKind::FallsOffEnd(opcode));
+      }
+    }
+
+    Ok(())
+  }
+
+  fn verify_branch(&mut self, address: usize, opcode: Opcode) -> Result<(), BytecodeError> {
+    let mut iter = self.operands(address, BRANCH_HEADER_SIZE, opcode)?;
+    let input_type = iter.next_u8().unwrap();
+    let default_delta = iter.next_u32_le().unwrap();
+    let scan_address = iter.next_u32_le().unwrap();
+    let table_length = iter.next_u32_le().unwrap() as usize;
+    let table_meta = iter.next_u32_le().unwrap();
+
+    // Matches the conversion performed by `MatchInputType::from(u32)`.
+    if !matches!(input_type, 0..=5 | 7..=11) {
+      return Self::error(address, BytecodeErrorKind::InvalidInputType(input_type));
+    }
+
+    // Only token branches read the scanner address. Other branches store
+    // `u32::MAX` in its place.
+    if input_type == MatchInputType::Token as u8 {
+      if self.scanner {
+        return Self::error(address, BytecodeErrorKind::TokenBranchInScanner);
+      }
+      if scan_address == 0 || scan_address == u32::MAX {
+        return Self::error(address, BytecodeErrorKind::MissingScanner);
+      }
+      self.queue_in(true, scan_address as usize, address, opcode)?;
+    }
+
+    let table_size = table_length.saturating_mul(4).saturating_add(BRANCH_HEADER_SIZE);
+    let mut table = self.operands(address, table_size, opcode)?;
+    let table_end = address + table_size;
+
+    self.queue_branch_target(default_delta, address, table_end, opcode)?;
+
+    for _ in 0..BRANCH_HEADER_SIZE - 1 {
+      table.next_u8();
+    }
+
+    let cells = (0..table_length).map(|_| table.next_u32_le().unwrap()).collect::<Vec<_>>();
+
+    if opcode == Opcode::VectorBranch {
+      for cell in cells {
+        self.queue_branch_target(cell, address, table_end, opcode)?;
+      }
+      return Ok(());
+    }
+
+    // Hash tables are indexed with `value & ((1 << table_meta) - 1)`.
+    if table_meta >= 32 || (1usize << table_meta) > table_length {
+      return Self::error(address, BytecodeErrorKind::InvalidHashModulus(table_meta));
+    }
+
+    let next_slot = |slot: usize| -> Option<Option<usize>> {
+      let next = ((cells[slot] >> 22) & 0x3FF) as isize - 512;
+      if next == 0 {
+        Some(None)
+      } else {
+        slot.checked_add_signed(next).filter(|slot| *slot < table_length).map(Some)
+      }
+    };
+
+    for (slot, cell) in cells.iter().enumerate() {
+      let mut current = slot;
+      let mut steps = 0;
+
+      loop {
+        match next_slot(current) {
+          Some(None) => break,
+          Some(Some(next)) if steps < table_length => {
+            current = next;
+            steps += 1;
+          }
+          _ => return Self::error(address, BytecodeErrorKind::InvalidHashChain { slot }),
+        }
+      }
+
+      self.queue_branch_target((cell >> 11) & 0x7FF, address, table_end, opcode)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[test]
+fn verifier_rejects_malformed_bytecode() {
+  use BytecodeErrorKind::*;
+  use Opcode::*;
+
+  let verify = |code: &[u8]| {
+    let bytecode = [0; 8].into_iter().chain(code.iter().copied()).collect::<Vec<_>>();
+    verify_bytecode(&bytecode, [FIRST_PARSE_BLOCK_ADDRESS]).map_err(|err| (err.address, err.kind))
+  };
+
+  let goto = |target: u32| [Goto as u8, 0].into_iter().chain(target.to_le_bytes()).collect::<Vec<_>>();
+
+  assert_eq!(verify(&[ShiftChar as u8, Accept as u8]), Ok(()));
+  assert_eq!(verify(&[goto(14), vec![Accept as u8]].concat()), Ok(()));
+
+  assert_eq!(verify_bytecode(&[0; 4], [8]).map_err(|e| e.kind), Err(MissingHeader));
+  assert_eq!(verify(&[200]), Err((8, InvalidOpcode(200))));
+  assert_eq!(verify(&[ShiftChar as u8]), Err((8, FallsOffEnd(ShiftChar))));
+  assert_eq!(verify(&[Pass as u8]), Err((0, NoReachableAccept)));
+  assert_eq!(verify(&goto(4)), Err((8, JumpOutOfBounds { opcode: Goto, target: 4 })));
+  assert_eq!(verify(&goto(99)), Err((8, JumpOutOfBounds { opcode: Goto, target: 99 })));
+  assert_eq!(verify(&[Reduce as u8, 0, 0]), Err((8, Truncated(Reduce))));
+  assert_eq!(verify(&[Fork as u8, 3, 0, 8, 0, 0, 0]), Err((8, Truncated(Fork))));
+
+  let len = |cells: usize| 18 + cells as u32 * 4;
+
+  // A branch whose default and cells all jump to the `Accept` following its table.
+  let branch = |opcode: Opcode, input_type: u8, cells: &[u32], meta: u32| {
+    let mut code = vec![opcode as u8, input_type];
+    code.extend(len(cells.len()).to_le_bytes().into_iter().chain(0u32.to_le_bytes()));
+    code.extend((cells.len() as u32).to_le_bytes().into_iter().chain(meta.to_le_bytes()));
+    code.extend(cells.iter().flat_map(|c| c.to_le_bytes()));
+    code.push(Accept as u8);
+    code
+  };
+
+  assert_eq!(verify(&branch(VectorBranch, 2, &[len(2), len(2)], 0)), Ok(()));
+  assert_eq!(
+    verify(&branch(VectorBranch, 2, &[len(2), 500], 0)),
+    Err((8, JumpOutOfBounds { opcode: VectorBranch, target: 508 }))
+  );
+  assert_eq!(verify(&branch(VectorBranch, 6, &[], 0)), Err((8, InvalidInputType(6))));
+  assert_eq!(verify(&branch(VectorBranch, MatchInputType::Token as u8, &[], 0)), Err((8, MissingScanner)));
+  assert_eq!(
+    verify(&branch(VectorBranch, 2, &[len(2), 4], 0)),
+    Err((8, JumpIntoTable { opcode: VectorBranch, target: 12 }))
+  );
+
+  // A token branch that is its own scanner.
+  let mut scans_itself = branch(VectorBranch, MatchInputType::Token as u8, &[], 0);
+  scans_itself[6..10].copy_from_slice(&8u32.to_le_bytes());
+  assert_eq!(verify(&scans_itself), Err((8, TokenBranchInScanner)));
+
+  let mut truncated = branch(VectorBranch, 2, &[len(2), len(2)], 0);
+  truncated[10] = 9;
+  assert_eq!(verify(&truncated), Err((8, Truncated(VectorBranch))));
+
+  let cell = |value: u32, next: i32| value | (len(2) << 11) | (((next + 512) as u32) << 22);
+
+  assert_eq!(verify(&branch(HashBranch, 2, &[cell(0, 0), cell(1, 0)], 1)), Ok(()));
+  assert_eq!(verify(&branch(HashBranch, 2, &[cell(0, 0), cell(1, 0)], 2)), Err((8, InvalidHashModulus(2))));
+  assert_eq!(verify(&branch(HashBranch, 2, &[cell(0, 1), cell(1, -1)], 1)), Err((8, InvalidHashChain { slot: 0 })));
+  assert_eq!(verify(&branch(HashBranch, 2, &[cell(0, 0), cell(1, 1)], 1)), Err((8, InvalidHashChain { slot: 1 })));
+}
@@ -28,3 +28,22 @@ pub mod kernel;
 pub mod parsers;
 pub mod types;
 pub mod utf8;
+
+/// Includes a parser built by the `radlr_build::cargo` module of a Cargo build
+/// script, declaring the `parser` module and, if an AST was built, the `ast`
+/// module.
+///
+/// Parsers built with a name prefix are included by passing the prefix:
+///
+/// ```ignore
+/// radlr_rust_runtime::include_parser!("json");
+/// ```
+#[macro_export]
+macro_rules! include_parser {
+  () => {
+    include!(concat!(env!("OUT_DIR"), "/radlr.rs"));
+  };
+  ($name_prefix:literal) => {
+    include!(concat!(env!("OUT_DIR"), "/", $name_prefix, "_radlr.rs"));
+  };
+}
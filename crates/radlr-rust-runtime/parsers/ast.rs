@@ -59,10 +59,13 @@ impl Tk for Token {
     } else {
       let start = &slice[0];
       let end = &slice[slice.len() - 1];
-      Token {
-        inner: TokenRange { len: end.inner.off - start.inner.off + end.inner.len, ..start.inner },
-        input: start.input.clone(),
-      }
+      let input_offset = |tok: &Token| tok.input.as_ref().map_or(0, |input| input.offset()) as u32;
+
+      // The tokens of a streamed input may reference different buffers, so the
+      // range is rebased onto the buffer of the last token, which begins at or
+      // before the first token.
+      let off = start.inner.off + input_offset(start) - input_offset(end);
+      Token { inner: TokenRange { len: end.inner.off - off + end.inner.len, off, ..start.inner }, input: end.input.clone() }
     }
   }
 
@@ -117,15 +120,24 @@ fn build_ast<I: ParserInput, DB: ParserProducer<I>, Token: Tk + Debug, N: Node<T
 
   let mut ctx = parser.init(entry)?;
 
-  let input_data = input.get_owned_ref();
+  // The input offsets of the first bytes of `tokens`. Bytes of tokens that are
+  // still on the stack are not released, as the tokens are joined with the
+  // tokens that follow them when they are reduced.
+  let mut token_starts = vec![];
 
   while let Some(action) = parser.next(input, &mut ctx) {
     match action {
       ParseAction::Accept { nonterminal_id, final_offset, token_line_count, token_line_offset } => {
+        // Determine whether an incomplete input continues past the accepted input.
+        while final_offset == input.len() && !input.is_complete() && input.read_more() {}
+
+        let input_data = input.get_owned_ref();
+        let token_offset = (final_offset - input_data.offset()) as u32;
+
         return if final_offset != input.len() {
-          let mut token = TK::from_vals(1, final_offset as u32, token_line_count, token_line_offset);
+          let mut token = TK::from_vals(1, token_offset, token_line_count, token_line_offset);
 
-          token.set_source(input_data.clone());
+          token.set_shared_source(input_data.clone());
 
           Err(ParserError::InputError {
             inline_message:   format!("Failed to read entire input {} {}", input.len(), final_offset),
@@ -134,9 +146,9 @@ fn build_ast<I: ParserInput, DB: ParserProducer<I>, Token: Tk + Debug, N: Node<T
             message:          "Failed to read entire input".to_string(),
           })
         } else if nonterminal_id != entry.nonterm_id {
-          let mut token = TK::from_vals(1, final_offset as u32, token_line_count, token_line_offset);
+          let mut token = TK::from_vals(1, token_offset, token_line_count, token_line_offset);
 
-          token.set_source(input_data.clone());
+          token.set_shared_source(input_data.clone());
 
           Err(ParserError::InputError {
             inline_message:   "Top symbol did not match the target nonterminal".to_string(),
@@ -157,8 +169,11 @@ fn build_ast<I: ParserInput, DB: ParserProducer<I>, Token: Tk + Debug, N: Node<T
         token_line_offset,
       } => {
         println!("{byte_length} {byte_offset}");
-        let mut token = TK::from_vals(byte_length, byte_offset, token_line_count, token_line_offset);
-        token.set_source(input_data);
+        let input_data = input.get_owned_ref();
+
+        let mut token =
+          TK::from_vals(byte_length, byte_offset - input_data.offset() as u32, token_line_count, token_line_offset);
+        token.set_shared_source(input_data);
 
         if let Some(expected_tokens) = db.get_expected_tok_ids_at_state(last_state.address as u32) {
           let token_strings = expected_tokens
@@ -212,11 +227,17 @@ fn build_ast<I: ParserInput, DB: ParserProducer<I>, Token: Tk + Debug, N: Node<T
         let offset_start = token_byte_offset as usize;
         let offset_end = (token_byte_offset + token_byte_length) as usize;
 
+        let input_data = input.get_owned_ref();
+        let base = input_data.offset();
+
         let token =
-          Token::from_range(offset_start, offset_end, token_line_count, token_line_offset, token_id, input_data.clone());
+          Token::from_range(offset_start - base, offset_end - base, token_line_count, token_line_offset, token_id, input_data);
 
         tokens.push(token.clone());
+        token_starts.push(offset_start);
         nodes.push(N::default());
+
+        input.release(token_starts[0].min(ctx.anchor_ptr));
       }
       ParseAction::Reduce { rule_id: _rule_id, symbol_count, .. } => {
         let rule = &rules.as_ref()[_rule_id as usize];
@@ -235,6 +256,14 @@ fn build_ast<I: ParserInput, DB: ParserProducer<I>, Token: Tk + Debug, N: Node<T
         nodes.push(new_node);
         tokens.drain(tok_start..);
         tokens.push(non_term_token);
+
+        let token_start = token_starts.drain(tok_start..).next().unwrap_or(ctx.sym_ptr);
+        token_starts.push(token_start);
+      }
+      ParseAction::NeedMoreInput => {
+        if !input.read_more() {
+          return Err(ParserError::NoData);
+        }
       }
       _ => panic!("Unexpected Action!"),
     }
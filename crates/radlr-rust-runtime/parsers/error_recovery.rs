@@ -52,7 +52,7 @@ pub fn parse_with_recovery<I: ParserInput, DB: ParserProducer<I>>(
 
   while !pending.pop_is_empty() {
     fork_meta_kernel(input, parser.as_mut(), &mut pending, &mut completed, &mut failed_contexts, store)?;
-    handle_failed_contexts(&mut failed_contexts, input, db, &mut best_failure, &mut parser, &mut pending, store);
+    handle_failed_contexts(&mut failed_contexts, input, db, &mut best_failure, &mut parser, &mut pending, store)?;
     pending.swap_buffers();
   }
 
@@ -73,7 +73,7 @@ fn handle_failed_contexts<I: ParserInput, DB: ParserProducer<I>>(
   parser: &mut Box<dyn Parser<I>>,
   pending: &mut ContextQueue<RecCTX>,
   store: &CSTStore,
-) {
+) -> Result<(), ParserError> {
   if failed_contexts.len() > 0 {
     let mut to_process = VecDeque::new();
 
@@ -138,7 +138,7 @@ fn handle_failed_contexts<I: ParserInput, DB: ParserProducer<I>>(
     // Need to sort our context so that we are using only contexts that have
     // the best potential (lowest error). This is also the point where
     // we can join contexts that differ only in symbols.
-    let resolved = resolve_errored_contexts(input, parser, &mut to_process, store);
+    let resolved = resolve_errored_contexts(input, parser, &mut to_process, store)?;
 
     let continued = attempt_merge(
       create_merge_groups(resolved.into_iter().map(|s| (s.last_failed_state.address as u32, s, None))),
@@ -157,6 +157,8 @@ fn handle_failed_contexts<I: ParserInput, DB: ParserProducer<I>>(
       pending.push_with_priority(rec_ctx.prority(), rec_ctx)
     }
   }
+
+  Ok(())
 }
 
 fn resolve_errored_contexts<I: ParserInput>(
@@ -164,7 +166,7 @@ fn resolve_errored_contexts<I: ParserInput>(
   parser: &mut Box<dyn Parser<I>>,
   contexts: &mut VecDeque<RecCTX>,
   store: &CSTStore,
-) -> Vec<RecCTX> {
+) -> Result<Vec<RecCTX>, ParserError> {
   let mut to_continue = vec![];
   let mut best_failure = None;
 
@@ -247,12 +249,19 @@ fn resolve_errored_contexts<I: ParserInput>(
           _ => unreachable!(),
         },
 
+        ParseAction::NeedMoreInput => {
+          if !input.read_more() {
+            return Err(ParserError::NoData);
+          }
+          contexts.push_front(rec_ctx);
+        }
+
         _ => unreachable!(),
       }
     }
   }
 
-  to_continue
+  Ok(to_continue)
 }
 
 fn create_errata<I: ParserInput>(input: &mut I, rec_ctx: &mut RecCTX, token_byte_length: u32, token_byte_offset: u32) {
@@ -149,6 +149,12 @@ pub(crate) fn fork_meta_kernel<I: ParserInput, P: ForkableParser<I> + ?Sized, CT
                     break;
                   }
 
+                  ParseAction::NeedMoreInput => {
+                    if !input.read_more() {
+                      return Err(ParserError::NoData);
+                    }
+                  }
+
                   _ => unreachable!(),
                 }
               } else {
@@ -158,6 +164,13 @@ pub(crate) fn fork_meta_kernel<I: ParserInput, P: ForkableParser<I> + ?Sized, CT
             }
           }
 
+          ParseAction::NeedMoreInput => {
+            if !input.read_more() {
+              return Err(ParserError::NoData);
+            }
+            pending.push_with_priority(rec_ctx.prority(), rec_ctx);
+          }
+
           _ => unreachable!(),
         }
       }
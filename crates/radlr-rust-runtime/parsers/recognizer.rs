@@ -11,11 +11,14 @@ pub trait Recognizer<T: ParserInput>: ParserIterator<T> + ParserInitializer {
     while let Some(action) = self.next(input, &mut ctx) {
       match action {
         ParseAction::Accept { nonterminal_id, final_offset, .. } => {
+          // Determine whether an incomplete input continues past the accepted input.
+          while final_offset == input.len() && !input.is_complete() && input.read_more() {}
+
           return if final_offset != input.len() {
             Err(ParserError::InputError {
               inline_message:   format!(
                 "\nFailed to read entire input \"{}\" \n     end pos: {} \n     expected end pos: {}",
-                input.string_range(0..input.len()),
+                input.string_range(input.window_start()..input.len()),
                 final_offset,
                 input.len(),
               ),
@@ -47,6 +50,8 @@ pub trait Recognizer<T: ParserInput>: ParserIterator<T> + ParserInitializer {
           if let Some(debug) = self.get_debugger() {
             debug(&DebugEventNew::ActionShift { offset_start, offset_end, token_id }, ParserStackTrackers::from(&ctx), input);
           }
+
+          input.release(ctx.anchor_ptr);
         }
 
         ParseAction::Reduce { rule_id: _rule_id, .. } =>
@@ -57,10 +62,15 @@ pub trait Recognizer<T: ParserInput>: ParserIterator<T> + ParserInitializer {
           }
         }
 
+        ParseAction::NeedMoreInput => {
+          if !input.read_more() {
+            return Err(ParserError::NoData);
+          }
+        }
         ParseAction::Error { last_nonterminal, .. } => {
           let last_input = TokenRange {
             len:      ctx.tok_byte_len as u32,
-            off:      ctx.sym_ptr as u32,
+            off:      (ctx.sym_ptr - input.window_start()) as u32,
             line_num: 0,
             line_off: 0,
           };
@@ -17,6 +17,9 @@ pub trait TokenProducer<I: ParserInput>: ParserIterator<I> + ParserInitializer {
     while let Some(action) = self.next(input, &mut ctx) {
       match action {
         ParseAction::Accept { nonterminal_id, final_offset, .. } => {
+          // Determine whether an incomplete input continues past the accepted input.
+          while final_offset == input.len() && !input.is_complete() && input.read_more() {}
+
           return if final_offset != input.len() {
             Err(ParserError::InputError {
               inline_message:   format!("Failed to read entire input {} {}", input.len(), final_offset),
@@ -35,10 +38,15 @@ pub trait TokenProducer<I: ParserInput>: ParserIterator<I> + ParserInitializer {
             return Ok(Tokens { shifts, skips });
           };
         }
+        ParseAction::NeedMoreInput => {
+          if !input.read_more() {
+            return Err(ParserError::NoData);
+          }
+        }
         ParseAction::Error { last_nonterminal, .. } => {
           let last_input = TokenRange {
             len:      ctx.tok_byte_len as u32,
-            off:      ctx.sym_ptr as u32,
+            off:      (ctx.sym_ptr - input.window_start()) as u32,
             line_num: 0,
             line_off: 0,
           };
@@ -71,6 +79,7 @@ pub trait TokenProducer<I: ParserInput>: ParserIterator<I> + ParserInitializer {
             // token_id }, input);
           }
           shifts.push(input.string_range(offset_start..offset_end));
+          input.release(ctx.anchor_ptr);
         }
         ParseAction::Reduce { rule_id: _rule_id, .. } => {
           #[cfg(debug_assertions)]
@@ -157,6 +157,11 @@ pub trait ASTConstructor<T: ParserInput>: ParserIterator<T> + ParserInitializer
             message:          "Unrecognized Token [2]".into(),
           });
         }
+        ParseAction::NeedMoreInput => {
+          if !input.read_more() {
+            return Err(ParserError::NoData);
+          }
+        }
         _ => {
           return Err(ParserError::InputError {
             inline_message:   Default::default(),
@@ -279,6 +284,11 @@ pub trait ASTProducer<I: ParserInput, ASTNode>: ParserProducer<I> {
             last_nonterminal,
           });
         }
+        ParseAction::NeedMoreInput => {
+          if !input.read_more() {
+            return Err(ParserError::NoData);
+          }
+        }
         _ => {}
       }
     }
@@ -9,7 +9,7 @@ use wasm_bindgen::prelude::*;
 
 /// The current set of instruction opcodes
 #[cfg_attr(feature = "wasm-lab", wasm_bindgen)]
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 #[repr(u8)]
 pub enum Opcode {
   /// Default value for unrecognized opcode values.
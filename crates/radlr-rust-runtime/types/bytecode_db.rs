@@ -24,10 +24,14 @@ pub struct BytecodeParserDB {
   pub rule_diagram:           HashMap<u32, String>,
   /// Friendly names of all non-terminals
   pub nonterm_name:           HashMap<u32, String>,
+  /// A hash of the grammar the parser was built from. Stored in the header of
+  /// packages written by [BytecodeParserDB::write_package].
+  pub grammar_hash:           u64,
 }
 
 impl BytecodeParserDB {
-  /// Write the entire bytecode binary as disassembly to the writer.
+  /// Write the raw bytecode to the writer, without the tables and header
+  /// written by [BytecodeParserDB::write_package].
   pub fn write_binary<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
     w.write_all(&self.bytecode)
   }
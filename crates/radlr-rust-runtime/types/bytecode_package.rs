@@ -0,0 +1,408 @@
+//! The on-disk format of a [BytecodeParserDB].
+//!
+//! A package is a fixed size header followed by a body. All integers are
+//! little endian.
+//!
+//! ```text
+//! offset  size  field
+//! 0       8     magic, "RADLRPKG"
+//! 8       4     format version, PACKAGE_FORMAT_VERSION
+//! 12      4     reserved, 0
+//! 16      8     grammar hash
+//! 24      8     checksum, the 64 bit FNV-1a hash of the body
+//! 32      8     body length in bytes
+//! 40      ..    body
+//! ```
+//!
+//! The body stores the fields of the database in the following order:
+//!
+//! ```text
+//! default_entry           u32
+//! bytecode                bytes
+//! token_id_to_str         map<u32, str>
+//! nonterm_name_to_id      map<str, u32>
+//! state_name_to_address   map<str, u32>
+//! address_to_state_name   map<u32, str>
+//! nonterm_id_to_address   map<u32, u32>
+//! state_to_token_ids_map  map<u32, array<u32>>
+//! nonterm_name            map<u32, str>
+//! rule_offsets            map<u32, (u32, u32)>
+//! rule_diagram            map<u32, str>
+//! ir_token_lookup         map<u32, (u32, u32, u32, u32)>
+//! ```
+//!
+//! `bytes` and `str` are a u32 length followed by that many bytes, `str`
+//! being utf8. `array<T>` is a u32 length followed by that many `T`s, and
+//! `map<K, V>` is a u32 entry count followed by each key and value, sorted by
+//! key so that the same database always produces the same package. The
+//! `ir_token_lookup` tokens are stored as their `len`, `off`, `line_num`, and
+//! `line_off`, without their source.
+//!
+//! [PACKAGE_FORMAT_VERSION] is incremented whenever the layout of the package
+//! or the encoding of the bytecode changes, so a package written for another
+//! version of the runtime is rejected before the kernel executes it.
+
+use super::*;
+use crate::kernel::BytecodeError;
+use std::{collections::HashMap, hash::Hash, io::Write};
+
+/// The first bytes of every package.
+pub const PACKAGE_MAGIC: [u8; 8] = *b"RADLRPKG";
+
+/// The version of the package format, and of the bytecode encoding, that this
+/// runtime can execute.
+pub const PACKAGE_FORMAT_VERSION: u32 = 1;
+
+const HEADER_SIZE: usize = 40;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PackageError {
+  /// The data does not begin with [PACKAGE_MAGIC], which is the case for
+  /// raw bytecode written by [BytecodeParserDB::write_binary].
+  InvalidMagic,
+  /// The package was written by a runtime with a different format version.
+  UnsupportedVersion { found: u32, expected: u32 },
+  /// The package was built from a grammar other than the expected one.
+  GrammarMismatch { found: u64, expected: u64 },
+  /// The body of the package has been modified or corrupted.
+  ChecksumMismatch { found: u64, expected: u64 },
+  /// The package ends before the data described by its header.
+  Truncated,
+  /// The body could not be decoded.
+  Malformed(String),
+  /// The bytecode failed verification, see [verify_bytecode](crate::kernel::verify_bytecode).
+  InvalidBytecode(BytecodeError),
+}
+
+impl std::fmt::Display for PackageError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidMagic => f.write_str("Data is not a radlr bytecode package"),
+      Self::UnsupportedVersion { found, expected } => {
+        f.write_fmt(format_args!("Package format version {found} is not supported; this runtime expects version {expected}"))
+      }
+      Self::GrammarMismatch { found, expected } => {
+        f.write_fmt(format_args!("Package was built from grammar {found:016X}, expected grammar {expected:016X}"))
+      }
+      Self::ChecksumMismatch { found, expected } => {
+        f.write_fmt(format_args!("Package checksum {found:016X} does not match its contents {expected:016X}"))
+      }
+      Self::Truncated => f.write_str("Package is truncated"),
+      Self::Malformed(reason) => f.write_fmt(format_args!("Package is malformed: {reason}")),
+      Self::InvalidBytecode(err) => f.write_fmt(format_args!("Package bytecode is invalid: {err}")),
+    }
+  }
+}
+
+impl std::error::Error for PackageError {}
+
+/// The header of a package.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PackageHeader {
+  pub version:      u32,
+  pub grammar_hash: u64,
+  pub checksum:     u64,
+}
+
+/// Validates the header and checksum of `package`, returning the header and
+/// the body. If `grammar_hash` is given, the package must have been built from
+/// that grammar.
+fn validate(package: &[u8], grammar_hash: Option<u64>) -> Result<(PackageHeader, &[u8]), PackageError> {
+  let mut reader = Reader { data: package, offset: 0 };
+
+  if reader.bytes(PACKAGE_MAGIC.len())? != PACKAGE_MAGIC {
+    return Err(PackageError::InvalidMagic);
+  }
+
+  let version = reader.u32()?;
+
+  if version != PACKAGE_FORMAT_VERSION {
+    return Err(PackageError::UnsupportedVersion { found: version, expected: PACKAGE_FORMAT_VERSION });
+  }
+
+  let _reserved = reader.u32()?;
+  let header = PackageHeader { version, grammar_hash: reader.u64()?, checksum: reader.u64()? };
+  let body_len = usize::try_from(reader.u64()?).map_err(|_| PackageError::Truncated)?;
+  let body = reader.bytes(body_len)?;
+
+  if reader.offset != package.len() {
+    return Err(PackageError::Malformed("Unexpected data after the body".into()));
+  }
+
+  if let Some(expected) = grammar_hash.filter(|hash| *hash != header.grammar_hash) {
+    return Err(PackageError::GrammarMismatch { found: header.grammar_hash, expected });
+  }
+
+  let checksum = fnv1a(body);
+
+  if checksum != header.checksum {
+    return Err(PackageError::ChecksumMismatch { found: header.checksum, expected: checksum });
+  }
+
+  Ok((header, body))
+}
+
+/// Validates the header and checksum of `package`. If `grammar_hash` is
+/// given, the package must have been built from that grammar.
+pub fn validate_package(package: &[u8], grammar_hash: Option<u64>) -> Result<PackageHeader, PackageError> {
+  validate(package, grammar_hash).map(|(header, _)| header)
+}
+
+/// Validates `package` and returns its bytecode without copying it.
+///
+/// The bytecode is checked with
+/// [verify_bytecode](crate::kernel::verify_bytecode) from `entry_addresses`,
+/// which should be the addresses of every non-terminal the parser is entered
+/// from.
+pub fn package_bytecode(
+  package: &[u8],
+  grammar_hash: Option<u64>,
+  entry_addresses: impl IntoIterator<Item = u32>,
+) -> Result<&[u8], PackageError> {
+  let (_, body) = validate(package, grammar_hash)?;
+  let mut reader = Reader { data: body, offset: 0 };
+  let _default_entry = reader.u32()?;
+  let bytecode = reader.byte_array()?;
+  check_bytecode(bytecode)?;
+  crate::kernel::verify_bytecode(bytecode, entry_addresses).map_err(PackageError::InvalidBytecode)?;
+  Ok(bytecode)
+}
+
+/// The size of the header that begins all bytecode. The kernel never executes
+/// the header, so every entry point must lie past it.
+const BYTECODE_HEADER_SIZE: usize = 8;
+
+fn check_bytecode(bytecode: &[u8]) -> Result<(), PackageError> {
+  if bytecode.len() <= BYTECODE_HEADER_SIZE {
+    Err(PackageError::Malformed("Bytecode is empty".into()))
+  } else {
+    Ok(())
+  }
+}
+
+impl BytecodeParserDB {
+  /// Encodes the database in the package format described in
+  /// [bytecode_package](crate::types::bytecode_package).
+  pub fn to_package(&self) -> Vec<u8> {
+    let mut body = Vec::with_capacity(self.bytecode.len() * 2);
+
+    write_u32(&mut body, self.default_entry.nonterm_id);
+    write_bytes(&mut body, &self.bytecode);
+    write_map(&mut body, &self.token_id_to_str, |b, k| write_u32(b, *k), |b, v| write_bytes(b, v.as_bytes()));
+    write_map(&mut body, &self.nonterm_name_to_id, |b, k| write_bytes(b, k.as_bytes()), |b, v| write_u32(b, *v));
+    write_map(&mut body, &self.state_name_to_address, |b, k| write_bytes(b, k.as_bytes()), |b, v| write_u32(b, *v));
+    write_map(&mut body, &self.address_to_state_name, |b, k| write_u32(b, *k), |b, v| write_bytes(b, v.as_bytes()));
+    write_map(&mut body, &self.nonterm_id_to_address, |b, k| write_u32(b, *k), |b, v| write_u32(b, *v));
+    write_map(&mut body, &self.state_to_token_ids_map, |b, k| write_u32(b, *k), |b, v| {
+      write_u32(b, v.len() as u32);
+      v.iter().for_each(|id| write_u32(b, *id));
+    });
+    write_map(&mut body, &self.nonterm_name, |b, k| write_u32(b, *k), |b, v| write_bytes(b, v.as_bytes()));
+    write_map(&mut body, &self.rule_offsets, |b, k| write_u32(b, *k), |b, (start, end)| {
+      write_u32(b, *start);
+      write_u32(b, *end);
+    });
+    write_map(&mut body, &self.rule_diagram, |b, k| write_u32(b, *k), |b, v| write_bytes(b, v.as_bytes()));
+
+    write_u32(&mut body, self.ir_token_lookup.len() as u32);
+    for (address, tok) in &self.ir_token_lookup {
+      let TokenRange { len, off, line_num, line_off } = tok.inner;
+      [*address, len, off, line_num, line_off].into_iter().for_each(|v| write_u32(&mut body, v));
+    }
+
+    let mut package = Vec::with_capacity(HEADER_SIZE + body.len());
+    package.extend_from_slice(&PACKAGE_MAGIC);
+    write_u32(&mut package, PACKAGE_FORMAT_VERSION);
+    write_u32(&mut package, 0);
+    package.extend_from_slice(&self.grammar_hash.to_le_bytes());
+    package.extend_from_slice(&fnv1a(&body).to_le_bytes());
+    package.extend_from_slice(&(body.len() as u64).to_le_bytes());
+    package.extend_from_slice(&body);
+
+    package
+  }
+
+  /// Writes the database to the writer in the package format.
+  pub fn write_package<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+    w.write_all(&self.to_package())
+  }
+
+  /// Decodes a database from a package written by
+  /// [BytecodeParserDB::to_package], after validating its header and checksum.
+  /// If `grammar_hash` is given, the package must have been built from that
+  /// grammar.
+  ///
+  /// The bytecode is checked with [BytecodeParserDB::verify] before the
+  /// database is returned, so a corrupt or hostile package is rejected here
+  /// instead of panicking the kernel.
+  pub fn from_package(package: &[u8], grammar_hash: Option<u64>) -> Result<Self, PackageError> {
+    let (header, body) = validate(package, grammar_hash)?;
+    let mut r = Reader { data: body, offset: 0 };
+
+    let mut db = BytecodeParserDB { grammar_hash: header.grammar_hash, ..Default::default() };
+
+    db.default_entry = EntryPoint { nonterm_id: r.u32()? };
+    db.bytecode = r.byte_array()?.to_vec();
+    check_bytecode(&db.bytecode)?;
+    db.token_id_to_str = r.map(|r| r.u32(), |r| r.string())?;
+    db.nonterm_name_to_id = r.map(|r| r.string(), |r| r.u32())?;
+    db.state_name_to_address = r.map(|r| r.string(), |r| r.u32())?;
+    db.address_to_state_name = r.map(|r| r.u32(), |r| r.string())?;
+    db.nonterm_id_to_address = r.map(|r| r.u32(), |r| r.u32())?;
+    db.state_to_token_ids_map = r.map(
+      |r| r.u32(),
+      |r| {
+        let len = r.u32()? as usize;
+        (0..len).map(|_| r.u32()).collect()
+      },
+    )?;
+    db.nonterm_name = r.map(|r| r.u32(), |r| r.string())?;
+    db.rule_offsets = r.map(|r| r.u32(), |r| Ok((r.u32()?, r.u32()?)))?;
+    db.rule_diagram = r.map(|r| r.u32(), |r| r.string())?;
+
+    for _ in 0..r.u32()? {
+      let address = r.u32()?;
+      let range = TokenRange { len: r.u32()?, off: r.u32()?, line_num: r.u32()?, line_off: r.u32()? };
+      db.ir_token_lookup.insert(address, range.into());
+    }
+
+    if r.offset != body.len() {
+      return Err(PackageError::Malformed("Unexpected data after the last section".into()));
+    }
+
+    if !db.nonterm_id_to_address.contains_key(&db.default_entry.nonterm_id) {
+      return Err(PackageError::Malformed("The default entry point has no address".into()));
+    }
+
+    db.verify().map_err(PackageError::InvalidBytecode)?;
+
+    Ok(db)
+  }
+}
+
+/// The 64 bit FNV-1a hash of `bytes`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01b3))
+}
+
+fn write_u32(buffer: &mut Vec<u8>, val: u32) {
+  buffer.extend_from_slice(&val.to_le_bytes());
+}
+
+fn write_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+  write_u32(buffer, bytes.len() as u32);
+  buffer.extend_from_slice(bytes);
+}
+
+fn write_map<K: Ord, V>(
+  buffer: &mut Vec<u8>,
+  map: &HashMap<K, V>,
+  write_key: impl Fn(&mut Vec<u8>, &K),
+  write_val: impl Fn(&mut Vec<u8>, &V),
+) {
+  let mut entries = map.iter().collect::<Vec<_>>();
+  entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+  write_u32(buffer, entries.len() as u32);
+  for (key, val) in entries {
+    write_key(buffer, key);
+    write_val(buffer, val);
+  }
+}
+
+struct Reader<'a> {
+  data:   &'a [u8],
+  offset: usize,
+}
+
+impl<'a> Reader<'a> {
+  fn bytes(&mut self, len: usize) -> Result<&'a [u8], PackageError> {
+    let end = self.offset.checked_add(len).filter(|end| *end <= self.data.len()).ok_or(PackageError::Truncated)?;
+    let bytes = &self.data[self.offset..end];
+    self.offset = end;
+    Ok(bytes)
+  }
+
+  fn u32(&mut self) -> Result<u32, PackageError> {
+    Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+  }
+
+  fn u64(&mut self) -> Result<u64, PackageError> {
+    Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+  }
+
+  fn byte_array(&mut self) -> Result<&'a [u8], PackageError> {
+    let len = self.u32()? as usize;
+    self.bytes(len)
+  }
+
+  fn string(&mut self) -> Result<String, PackageError> {
+    String::from_utf8(self.byte_array()?.to_vec()).map_err(|_| PackageError::Malformed("String is not valid utf8".into()))
+  }
+
+  fn map<K: Eq + Hash, V>(
+    &mut self,
+    read_key: impl Fn(&mut Self) -> Result<K, PackageError>,
+    read_val: impl Fn(&mut Self) -> Result<V, PackageError>,
+  ) -> Result<HashMap<K, V>, PackageError> {
+    let len = self.u32()? as usize;
+    // Every entry takes at least four bytes, which bounds the allocation for
+    // corrupt lengths.
+    let mut map = HashMap::with_capacity(len.min(self.data.len() / 4));
+    for _ in 0..len {
+      let key = read_key(self)?;
+      map.insert(key, read_val(self)?);
+    }
+    Ok(map)
+  }
+}
+
+#[test]
+fn package_round_trips_and_rejects_invalid_data() {
+  let mut db = BytecodeParserDB {
+    default_entry: EntryPoint { nonterm_id: 1 },
+    bytecode: [0, 0, 0, 0, 0, 0, 0, 0, bytecode::Opcode::Accept as u8].into(),
+    grammar_hash: 0xABCD,
+    ..Default::default()
+  };
+  db.nonterm_name_to_id.insert("start".into(), 1);
+  db.nonterm_id_to_address.insert(1, 8);
+  db.token_id_to_str.insert(3, "c:num".into());
+  db.state_name_to_address.insert("start_enter".into(), 8);
+  db.address_to_state_name.insert(8, "start_enter".into());
+  db.state_to_token_ids_map.insert(8, vec![3, 4]);
+  db.rule_offsets.insert(0, (2, 10));
+  db.rule_diagram.insert(0, "start > •c:num".into());
+  db.nonterm_name.insert(1, "start".into());
+  db.ir_token_lookup.insert(8, TokenRange { len: 3, off: 4, line_num: 1, line_off: 2 }.into());
+
+  let package = db.to_package();
+  assert_eq!(package, db.clone().to_package(), "Packages should be deterministic");
+
+  let loaded = BytecodeParserDB::from_package(&package, Some(0xABCD)).unwrap();
+  assert_eq!(loaded.bytecode, db.bytecode);
+  assert_eq!(loaded.grammar_hash, 0xABCD);
+  assert_eq!(loaded.default_entry.nonterm_id, 1);
+  assert_eq!(loaded.nonterm_name_to_id, db.nonterm_name_to_id);
+  assert_eq!(loaded.token_id_to_str, db.token_id_to_str);
+  assert_eq!(loaded.state_name_to_address, db.state_name_to_address);
+  assert_eq!(loaded.address_to_state_name, db.address_to_state_name);
+  assert_eq!(loaded.state_to_token_ids_map, db.state_to_token_ids_map);
+  assert_eq!(loaded.rule_offsets, db.rule_offsets);
+  assert_eq!(loaded.rule_diagram, db.rule_diagram);
+  assert_eq!(loaded.nonterm_name, db.nonterm_name);
+  assert_eq!(loaded.ir_token_lookup.get(&8).map(|t| t.inner), Some(TokenRange { len: 3, off: 4, line_num: 1, line_off: 2 }));
+  assert_eq!(package_bytecode(&package, None, [8]).unwrap(), db.bytecode.as_slice());
+
+  assert_eq!(validate_package(&db.bytecode, None), Err(PackageError::InvalidMagic));
+  assert_eq!(validate_package(&package, Some(1)), Err(PackageError::GrammarMismatch { found: 0xABCD, expected: 1 }));
+  assert_eq!(validate_package(&package[..package.len() - 1], None), Err(PackageError::Truncated));
+
+  let mut stale = package.clone();
+  stale[8] = 0;
+  assert_eq!(validate_package(&stale, None), Err(PackageError::UnsupportedVersion { found: 0, expected: PACKAGE_FORMAT_VERSION }));
+
+  let mut corrupt = package.clone();
+  *corrupt.last_mut().unwrap() ^= 1;
+  assert!(matches!(validate_package(&corrupt, None), Err(PackageError::ChecksumMismatch { .. })));
+}
@@ -106,6 +106,13 @@ pub struct ParserContext {
   pub recovery_tok_id: u32,
 
   pub node: Option<Rc<CSTNode>>,
+
+  /// The lowest length of the stack since the last
+  /// [ParserContext::checkpoint].
+  stack_floor:   usize,
+  /// The states popped below the length the stack had at the last
+  /// [ParserContext::checkpoint], in the order they were popped.
+  popped_states: Vec<ParserState>,
 }
 
 impl Default for ParserContext {
@@ -131,6 +138,8 @@ impl Default for ParserContext {
       goal_nonterm:    u32::MAX,
       is_finished:     false,
       node:            None,
+      stack_floor:     0,
+      popped_states:   vec![],
     }
   }
 }
@@ -153,6 +162,46 @@ impl ParserContext {
   pub fn pop_state(&mut self) -> ParserState {
     unsafe { self.stack.pop().unwrap_unchecked() }
   }
+
+  /// Returns a copy of every field except the state stack. The stack is
+  /// instead restored from its length at this point and the states popped with
+  /// [ParserContext::pop_checkpointed_state] since.
+  pub(crate) fn checkpoint(&mut self) -> ParserContext {
+    let stack = std::mem::take(&mut self.stack);
+    let popped_states = std::mem::take(&mut self.popped_states);
+    let checkpoint = self.clone();
+    self.stack = stack;
+    self.stack_floor = self.stack.len();
+    self.popped_states = popped_states;
+    self.popped_states.clear();
+    checkpoint
+  }
+
+  /// Pops the top state, keeping it if the stack shrinks below its lowest
+  /// length since the last [ParserContext::checkpoint].
+  pub(crate) fn pop_checkpointed_state(&mut self) -> ParserState {
+    let state = self.pop_state();
+
+    if self.stack.len() < self.stack_floor {
+      self.stack_floor = self.stack.len();
+      self.popped_states.push(state);
+    }
+
+    state
+  }
+
+  /// Undoes the changes made to the context since `checkpoint` was taken.
+  pub(crate) fn rollback(&mut self, checkpoint: ParserContext) {
+    let mut stack = std::mem::take(&mut self.stack);
+    let mut popped_states = std::mem::take(&mut self.popped_states);
+
+    stack.truncate(self.stack_floor);
+    stack.extend(popped_states.drain(..).rev());
+
+    *self = checkpoint;
+    self.stack = stack;
+    self.popped_states = popped_states;
+  }
 }
 
 pub trait ForkableContext: QueuedContext {
@@ -0,0 +1,252 @@
+use super::*;
+use std::ops::Range;
+
+/// The encoding of a source that is decoded by [DecodedInput].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SourceEncoding {
+  /// Little endian UTF-16. A leading byte order mark is skipped.
+  Utf16Le,
+  /// Big endian UTF-16. A leading byte order mark is skipped.
+  Utf16Be,
+  /// ISO-8859-1, where each byte is the codepoint of the same value.
+  Latin1,
+}
+
+impl SourceEncoding {
+  /// The number of source bytes in one code unit of this encoding.
+  pub fn code_unit_size(&self) -> usize {
+    match self {
+      Self::Utf16Le | Self::Utf16Be => 2,
+      Self::Latin1 => 1,
+    }
+  }
+}
+
+/// A location in the original, undecoded source.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct SourceOffset {
+  /// The offset in bytes from the start of the source.
+  pub byte:      usize,
+  /// The offset in code units from the start of the source. This is the
+  /// coordinate used for positions by JavaScript strings and by editors that
+  /// address text in UTF-16.
+  pub code_unit: usize,
+}
+
+/// A range of the original, undecoded source.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct SourceRange {
+  pub bytes:      Range<usize>,
+  pub code_units: Range<usize>,
+}
+
+/// A [ParserInput] that decodes a UTF-16 or Latin-1 source into the utf8
+/// stream the parser kernel consumes.
+///
+/// Offsets produced by the parser, such as those of a [TokenRange], are in
+/// the coordinates of the decoded utf8 stream. [DecodedInput::source_offset]
+/// and [DecodedInput::source_range] translate them back to byte and code unit
+/// offsets of the original source.
+///
+/// # Example
+/// ```
+/// use radlr_rust_runtime::types::{DecodedInput, ParserInput, TokenRange};
+///
+/// let units: Vec<u16> = "é = 1".encode_utf16().collect();
+/// let input = DecodedInput::from_utf16(&units);
+///
+/// // `1` is at byte 5 of the utf8 stream, but at code unit 4 of the source.
+/// assert_eq!(input.string_range(5..6), "1");
+///
+/// let range = input.source_range(&TokenRange { off: 5, len: 1, ..Default::default() });
+/// assert_eq!(range.code_units, 4..5);
+/// assert_eq!(range.bytes, 8..10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DecodedInput {
+  encoding: SourceEncoding,
+  utf8:     SharedSymbolBuffer,
+  /// Pairs of utf8 and code unit offsets recorded at the start of the input
+  /// and after each codepoint whose utf8 length differs from its length in
+  /// code units. Between two entries both offsets advance together.
+  offsets:  Vec<(usize, usize)>,
+}
+
+impl DecodedInput {
+  /// Decodes `bytes` from the given encoding. Malformed sequences, such as
+  /// unpaired UTF-16 surrogates or a trailing odd byte of a UTF-16 source, are
+  /// replaced with U+FFFD.
+  pub fn new(bytes: &[u8], encoding: SourceEncoding) -> Self {
+    let truncated = bytes.len() % encoding.code_unit_size() != 0;
+    match encoding {
+      SourceEncoding::Utf16Le => {
+        Self::decode_utf16(bytes.chunks_exact(2).map(|u| u16::from_le_bytes([u[0], u[1]])), encoding, truncated)
+      }
+      SourceEncoding::Utf16Be => {
+        Self::decode_utf16(bytes.chunks_exact(2).map(|u| u16::from_be_bytes([u[0], u[1]])), encoding, truncated)
+      }
+      SourceEncoding::Latin1 => {
+        let mut decoder = Decoder::new(encoding, bytes.len());
+        for byte in bytes {
+          decoder.push(*byte as char, 1);
+        }
+        decoder.finish()
+      }
+    }
+  }
+
+  /// Decodes a string of UTF-16 code units in native byte order, such as a
+  /// string received from JavaScript.
+  pub fn from_utf16(units: &[u16]) -> Self {
+    let encoding = if cfg!(target_endian = "big") { SourceEncoding::Utf16Be } else { SourceEncoding::Utf16Le };
+    Self::decode_utf16(units.iter().copied(), encoding, false)
+  }
+
+  /// Decodes `units`. If `truncated` is set, the source ends with a partial
+  /// code unit, which is decoded as U+FFFD.
+  fn decode_utf16(units: impl Iterator<Item = u16>, encoding: SourceEncoding, truncated: bool) -> Self {
+    let mut units = units.peekable();
+    let mut decoder = Decoder::new(encoding, units.size_hint().0);
+
+    if units.next_if_eq(&0xFEFF).is_some() {
+      decoder.skip(1);
+    }
+
+    for result in char::decode_utf16(units) {
+      match result {
+        Ok(char) => decoder.push(char, char.len_utf16()),
+        Err(_) => decoder.push(char::REPLACEMENT_CHARACTER, 1),
+      }
+    }
+
+    if truncated {
+      decoder.push(char::REPLACEMENT_CHARACTER, 1);
+    }
+
+    decoder.finish()
+  }
+
+  /// The encoding of the original source.
+  pub fn encoding(&self) -> SourceEncoding {
+    self.encoding
+  }
+
+  /// Translates an offset of the decoded utf8 stream into an offset of the
+  /// original source. Offsets past the end of the input are clamped to the
+  /// end of the source.
+  pub fn source_offset(&self, offset: usize) -> SourceOffset {
+    let offset = offset.min(self.utf8.len());
+    let index = self.offsets.partition_point(|(utf8, _)| *utf8 <= offset) - 1;
+    let (utf8, code_unit) = self.offsets[index];
+    let code_unit = code_unit + (offset - utf8);
+
+    SourceOffset { byte: code_unit * self.encoding.code_unit_size(), code_unit }
+  }
+
+  /// Translates a range of the decoded utf8 stream into a range of the
+  /// original source.
+  pub fn source_range(&self, range: &TokenRange) -> SourceRange {
+    let start = self.source_offset(range.off as usize);
+    let end = self.source_offset((range.off + range.len) as usize);
+
+    SourceRange { bytes: start.byte..end.byte, code_units: start.code_unit..end.code_unit }
+  }
+}
+
+struct Decoder {
+  encoding:  SourceEncoding,
+  utf8:      Vec<u8>,
+  offsets:   Vec<(usize, usize)>,
+  code_unit: usize,
+}
+
+impl Decoder {
+  fn new(encoding: SourceEncoding, capacity: usize) -> Self {
+    Self { encoding, utf8: Vec::with_capacity(capacity), offsets: vec![(0, 0)], code_unit: 0 }
+  }
+
+  /// Skips code units that do not produce input, such as a byte order mark.
+  fn skip(&mut self, units: usize) {
+    self.code_unit += units;
+    self.offsets[0] = (0, self.code_unit);
+  }
+
+  fn push(&mut self, char: char, units: usize) {
+    let mut buffer = [0; 4];
+    self.utf8.extend_from_slice(char.encode_utf8(&mut buffer).as_bytes());
+    self.code_unit += units;
+
+    if char.len_utf8() != units {
+      self.offsets.push((self.utf8.len(), self.code_unit));
+    }
+  }
+
+  fn finish(self) -> DecodedInput {
+    DecodedInput { encoding: self.encoding, utf8: self.utf8.into(), offsets: self.offsets }
+  }
+}
+
+impl ParserInput for DecodedInput {
+  fn len(&self) -> usize {
+    self.utf8.len()
+  }
+
+  fn byte(&self, cursor: usize) -> u8 {
+    if self.len() > cursor {
+      self.utf8[cursor]
+    } else {
+      0
+    }
+  }
+
+  fn bytes(&self) -> &[u8] {
+    &self.utf8
+  }
+
+  /// Returns the decoded utf8 bytes.
+  fn get_owned_ref(&self) -> SharedSymbolBuffer {
+    self.utf8.clone()
+  }
+}
+
+#[test]
+fn decoded_input_maps_offsets_to_the_source() {
+  let text = "aé🙂\nb";
+
+  let le: Vec<u8> = [0xFEFF].into_iter().chain(text.encode_utf16()).flat_map(u16::to_le_bytes).collect();
+  let be: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+
+  let le = DecodedInput::new(&le, SourceEncoding::Utf16Le);
+  let be = DecodedInput::new(&be, SourceEncoding::Utf16Be);
+
+  for (input, bom) in [(le, 1), (be, 0)] {
+    assert_eq!(input.bytes(), text.as_bytes());
+    assert_eq!(input.codepoint(3), '🙂' as u32);
+    assert_eq!(input.class(7), crate::utf8::get_token_class_from_codepoint('\n' as u32));
+
+    // a:0 é:1..3 🙂:3..7 \n:7 b:8
+    assert_eq!(input.source_offset(0).code_unit, bom);
+    assert_eq!(input.source_offset(3).code_unit, bom + 2);
+    assert_eq!(input.source_offset(7).code_unit, bom + 4);
+    assert_eq!(input.source_offset(8), SourceOffset { code_unit: bom + 5, byte: (bom + 5) * 2 });
+    assert_eq!(input.source_offset(100).code_unit, bom + 6);
+
+    let range = input.source_range(&TokenRange { off: 1, len: 6, ..Default::default() });
+    assert_eq!(range.code_units, bom + 1..bom + 4);
+    assert_eq!(range.bytes, (bom + 1) * 2..(bom + 4) * 2);
+  }
+
+  let input = DecodedInput::new(&[b'x', 0xE9, b'y'], SourceEncoding::Latin1);
+  assert_eq!(input.string_range(0..4), "xéy");
+  assert_eq!(input.source_offset(3), SourceOffset { code_unit: 2, byte: 2 });
+
+  let input = DecodedInput::from_utf16(&[b'a' as u16, 0xD800, b'b' as u16]);
+  assert_eq!(input.string_range(0..5), "a\u{FFFD}b");
+  assert_eq!(input.source_offset(4).code_unit, 2);
+
+  for (bytes, encoding) in [([b'a', 0, b'b'], SourceEncoding::Utf16Le), ([0, b'a', b'b'], SourceEncoding::Utf16Be)] {
+    let input = DecodedInput::new(&bytes, encoding);
+    assert_eq!(input.string_range(0..4), "a\u{FFFD}");
+    assert_eq!(input.source_offset(1), SourceOffset { code_unit: 1, byte: 2 });
+  }
+}
@@ -0,0 +1,107 @@
+use super::*;
+use std::{fs::File, path::Path};
+
+/// A [ParserInput] backed by a memory mapped file. Requires the `mmap`
+/// feature.
+///
+/// The file is mapped once and shared, without copying, by every [Token]
+/// created from the input's [ParserInput::get_owned_ref] buffer, so large
+/// sources are paged in by the OS as the parser reaches them instead of
+/// being read into memory up front.
+///
+/// # Example
+/// ```no_run
+/// use radlr_rust_runtime::types::{MmapInput, ParserInput};
+///
+/// let input = unsafe { MmapInput::open("./source.txt").unwrap() };
+///
+/// let text = input.as_str().expect("source is not valid utf8");
+/// assert_eq!(text.len(), input.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct MmapInput {
+  map: SharedSymbolBuffer,
+}
+
+impl MmapInput {
+  /// Maps the file at `path` into memory.
+  ///
+  /// # Safety
+  ///
+  /// The file must not be modified or truncated, by this or any other
+  /// process, while the input or any [Token] referencing it is alive.
+  /// See [memmap2::Mmap::map].
+  pub unsafe fn open<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+    Self::from_file(&File::open(path)?)
+  }
+
+  /// Maps an open file into memory.
+  ///
+  /// # Safety
+  ///
+  /// See [MmapInput::open].
+  pub unsafe fn from_file(file: &File) -> std::io::Result<Self> {
+    Ok(Self { map: memmap2::Mmap::map(file)?.into() })
+  }
+
+  /// Returns the mapped bytes as a `str`, or `None` if they are not valid
+  /// utf8. Useful for slicing the source with [TokenRange::to_slice].
+  pub fn as_str(&self) -> Option<&str> {
+    self.map.as_str()
+  }
+}
+
+impl ParserInput for MmapInput {
+  fn len(&self) -> usize {
+    self.map.len()
+  }
+
+  fn byte(&self, cursor: usize) -> u8 {
+    if self.len() > cursor {
+      self.map[cursor]
+    } else {
+      0
+    }
+  }
+
+  fn bytes(&self) -> &[u8] {
+    &self.map
+  }
+
+  /// Returns a handle to the mapping. The bytes are not copied.
+  fn get_owned_ref(&self) -> SharedSymbolBuffer {
+    self.map.clone()
+  }
+}
+
+#[test]
+fn mmap_input_shares_the_mapping_with_tokens() {
+  let path = std::env::temp_dir().join(format!("radlr_mmap_input_{}.txt", std::process::id()));
+  std::fs::write(&path, "hello 🙂 world").unwrap();
+
+  let input = unsafe { MmapInput::open(&path).unwrap() };
+
+  assert_eq!(input.len(), 16);
+  assert_eq!(input.codepoint(6), '🙂' as u32);
+  assert_eq!(input.string_range(11..16), "world");
+
+  let source = input.get_owned_ref();
+  assert!(source.is_mapped());
+  assert_eq!(source.as_ptr(), input.bytes().as_ptr());
+
+  let range = TokenRange { off: 11, len: 5, line_num: 0, line_off: 0 };
+  let token = range.to_token_from_ref(source);
+  assert_eq!(token.to_string(), "world");
+  assert_eq!(range.to_slice(input.as_str().unwrap()), "world");
+
+  drop(input);
+  assert_eq!(token.to_string(), "world");
+  drop(token);
+
+  std::fs::write(&path, "").unwrap();
+  let empty = unsafe { MmapInput::open(&path).unwrap() };
+  assert_eq!(empty.len(), 0);
+  assert_eq!(empty.byte(0), 0);
+
+  std::fs::remove_file(path).unwrap();
+}
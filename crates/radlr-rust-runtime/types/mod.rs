@@ -1,11 +1,15 @@
 pub mod ast;
 pub mod bytecode;
+pub mod bytecode_package;
 mod bytecode_db;
 mod context;
 mod context_queue;
 mod cst;
 mod debug;
+mod decoded_input;
 mod edit_input;
+#[cfg(feature = "mmap")]
+mod mmap_input;
 pub mod entrypoint;
 mod parse_action;
 mod parse_table_data;
@@ -13,15 +17,20 @@ mod parser;
 mod parser_error;
 mod parser_input;
 mod range;
+mod stream_input;
 mod token;
 
 pub use ast::*;
 pub use bytecode_db::*;
+pub use bytecode_package::*;
 pub use context::*;
 pub use context_queue::*;
 pub use cst::*;
 pub use debug::*;
+pub use decoded_input::*;
 pub use edit_input::*;
+#[cfg(feature = "mmap")]
+pub use mmap_input::*;
 pub use entrypoint::*;
 pub use parse_action::*;
 pub use parse_table_data::*;
@@ -29,4 +38,5 @@ pub use parser::*;
 pub use parser_error::*;
 pub use parser_input::*;
 pub use range::*;
+pub use stream_input::*;
 pub use token::*;
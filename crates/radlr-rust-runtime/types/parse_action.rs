@@ -87,6 +87,10 @@ pub enum ParseAction {
   },
   NonTerminalParseStart,
   None,
+  /// The parser has read to the end of the bytes available from an
+  /// incomplete [ParserInput]. The parser resumes from the same point once
+  /// more bytes have been appended, or the input has been marked complete.
+  NeedMoreInput,
 }
 
 #[test]
@@ -8,6 +8,7 @@ pub enum ParserError {
   InvalidEntryName,
   NoData,
   OutOfMemory,
+  InvalidPackage(PackageError),
 }
 
 impl std::fmt::Debug for ParserError {
@@ -22,7 +23,14 @@ impl std::fmt::Debug for ParserError {
       ParserError::OutOfMemory => f.write_str("Out of memory"),
       ParserError::NoData => f.write_str("Out of input data"),
       ParserError::InvalidEntryName => f.write_str("Invalid Entry Name"),
+      ParserError::InvalidPackage(err) => std::fmt::Display::fmt(err, f),
       _ => f.write_str("Unexpected error"),
     }
   }
 }
+
+impl From<PackageError> for ParserError {
+  fn from(err: PackageError) -> Self {
+    Self::InvalidPackage(err)
+  }
+}
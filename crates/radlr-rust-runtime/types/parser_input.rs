@@ -1,11 +1,122 @@
-use std::{ops::Range, sync::Arc};
+use std::{
+  fmt::Debug,
+  ops::{Deref, Range},
+  sync::Arc,
+};
 
+use super::stream_input::StreamSegment;
 use crate::utf8::{get_token_class_from_codepoint, get_utf8_byte_length_from_code_point};
 
 /// A multi-reader, multi-writer view of the underlying parser input
 /// data, used to distribute access to the input string over multiple
 /// Tokens and SymbolReaders.
-pub type SharedSymbolBuffer = Arc<[u8]>;
+///
+/// The bytes are either owned by a reference counted heap allocation, by a
+/// segment of a [StreamInput](super::StreamInput), or, with the `mmap`
+/// feature, by a memory mapped file. Cloning the buffer never copies the
+/// bytes.
+#[derive(Clone)]
+pub struct SharedSymbolBuffer(SymbolStorage);
+
+#[derive(Clone)]
+enum SymbolStorage {
+  Heap(Arc<[u8]>),
+  Stream(Arc<StreamSegment>, Range<usize>),
+  #[cfg(feature = "mmap")]
+  Mapped(Arc<memmap2::Mmap>),
+}
+
+impl SharedSymbolBuffer {
+  pub(crate) fn from_stream(segment: Arc<StreamSegment>, range: Range<usize>) -> Self {
+    Self(SymbolStorage::Stream(segment, range))
+  }
+
+  /// The input offset of the first byte of the buffer. This is only non-zero
+  /// for buffers of streamed inputs, which begin at the first byte that had not
+  /// been released when the buffer was created.
+  pub fn offset(&self) -> usize {
+    match &self.0 {
+      SymbolStorage::Stream(segment, range) => segment.offset() + range.start,
+      _ => 0,
+    }
+  }
+
+  /// Returns the bytes as a `str`, or `None` if they are not valid utf8.
+  pub fn as_str(&self) -> Option<&str> {
+    std::str::from_utf8(self).ok()
+  }
+
+  /// Returns `true` if the bytes are backed by a memory mapped file.
+  pub fn is_mapped(&self) -> bool {
+    match &self.0 {
+      SymbolStorage::Heap(_) | SymbolStorage::Stream(..) => false,
+      #[cfg(feature = "mmap")]
+      SymbolStorage::Mapped(_) => true,
+    }
+  }
+}
+
+impl Deref for SharedSymbolBuffer {
+  type Target = [u8];
+
+  fn deref(&self) -> &Self::Target {
+    match &self.0 {
+      SymbolStorage::Heap(bytes) => bytes,
+      SymbolStorage::Stream(segment, range) => segment.slice(range.clone()),
+      #[cfg(feature = "mmap")]
+      SymbolStorage::Mapped(map) => map,
+    }
+  }
+}
+
+impl AsRef<[u8]> for SharedSymbolBuffer {
+  fn as_ref(&self) -> &[u8] {
+    self
+  }
+}
+
+impl Debug for SharedSymbolBuffer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SharedSymbolBuffer").field("len", &self.len()).field("mapped", &self.is_mapped()).finish()
+  }
+}
+
+impl From<Arc<[u8]>> for SharedSymbolBuffer {
+  fn from(value: Arc<[u8]>) -> Self {
+    Self(SymbolStorage::Heap(value))
+  }
+}
+
+impl From<&[u8]> for SharedSymbolBuffer {
+  fn from(value: &[u8]) -> Self {
+    Self(SymbolStorage::Heap(value.into()))
+  }
+}
+
+impl From<Vec<u8>> for SharedSymbolBuffer {
+  fn from(value: Vec<u8>) -> Self {
+    Self(SymbolStorage::Heap(value.into()))
+  }
+}
+
+impl From<&str> for SharedSymbolBuffer {
+  fn from(value: &str) -> Self {
+    value.as_bytes().into()
+  }
+}
+
+impl From<String> for SharedSymbolBuffer {
+  fn from(value: String) -> Self {
+    value.into_bytes().into()
+  }
+}
+
+#[cfg(feature = "mmap")]
+impl From<memmap2::Mmap> for SharedSymbolBuffer {
+  fn from(value: memmap2::Mmap) -> Self {
+    Self(SymbolStorage::Mapped(Arc::new(value)))
+  }
+}
 
 pub trait ParserInput {
   /// The number of bytes available for reading. This is allowed
@@ -59,6 +170,31 @@ pub trait ParserInput {
     }
     String::from_utf8(bytes).unwrap_or_default()
   }
+
+  /// Returns `false` if more bytes may still be appended to the input. The
+  /// parser yields [ParseAction::NeedMoreInput](super::ParseAction) instead of
+  /// treating the end of an incomplete input as the end of file.
+  fn is_complete(&self) -> bool {
+    true
+  }
+
+  /// Attempts to append more bytes to a streaming input, or to mark it as
+  /// complete. Returns `false` if the input cannot acquire more bytes on its
+  /// own, in which case the host must supply them.
+  fn read_more(&mut self) -> bool {
+    false
+  }
+
+  /// Signals that the bytes before `offset` will not be read again, allowing
+  /// streaming inputs to discard them.
+  fn release(&mut self, _offset: usize) {}
+
+  /// The offset of the first byte that has not been released. The buffer
+  /// returned by [ParserInput::get_owned_ref] begins at this offset, which is
+  /// also its [SharedSymbolBuffer::offset].
+  fn window_start(&self) -> usize {
+    0
+  }
 }
 
 #[derive(Debug)]
@@ -92,7 +228,7 @@ impl ParserInput for StringInput {
   }
 
   fn get_owned_ref(&self) -> SharedSymbolBuffer {
-    self.input.clone()
+    self.input.clone().into()
   }
 
   fn bytes(&self) -> &[u8] {
@@ -168,7 +168,7 @@ impl TokenRange {
   #[deprecated]
   pub fn to_token(&self, reader: &mut dyn ByteReader) -> Token {
     let mut tok: Token = (*self).into();
-    tok.set_source(reader.get_source());
+    tok.set_shared_source(reader.get_source());
     tok
   }
 
@@ -182,13 +182,13 @@ impl TokenRange {
 
   pub fn to_token_from_ref(&self, source: SharedSymbolBuffer) -> Token {
     let mut tok: Token = (*self).into();
-    tok.set_source(source);
+    tok.set_shared_source(source);
     tok
   }
 
   pub fn to_token_with_string(&self, original_string: &str) -> Token {
     let mut tok: Token = (*self).into();
-    tok.set_source(original_string.to_string().as_bytes().into());
+    tok.set_shared_source(original_string);
     tok
   }
 
@@ -0,0 +1,268 @@
+use super::*;
+use std::{cell::UnsafeCell, io::Read, ops::Range, sync::Arc};
+
+/// A [ParserInput] that receives its bytes in chunks, either read from a
+/// [Read] source with [StreamInput::read_chunk] or appended by the host with
+/// [StreamInput::push].
+///
+/// When the parser reaches the end of the bytes received so far, it yields
+/// [ParseAction::NeedMoreInput] and resumes from the same point once more bytes
+/// have been appended or the input has been marked complete. Bytes before an
+/// offset passed to [ParserInput::release] are discarded, so inputs larger
+/// than memory can be parsed as long as the host does not hold on to tokens
+/// that reference the discarded bytes.
+///
+/// Bytes are appended to a [StreamSegment] that is shared, without copying,
+/// by the buffers returned from [ParserInput::get_owned_ref]. Once a segment is
+/// full, the bytes that have not been released are moved to a new segment, and
+/// the old segment is freed once no token references it.
+///
+/// # Example
+/// ```
+/// use radlr_rust_runtime::types::{ParserInput, StreamInput};
+///
+/// let mut input = StreamInput::new("[1, 2, 3]".as_bytes()).with_chunk_size(4);
+///
+/// while input.read_chunk().unwrap() > 0 {}
+///
+/// assert!(input.is_complete());
+/// assert_eq!(input.string_range(0..input.len()), "[1, 2, 3]");
+/// ```
+pub struct StreamInput<R: Read = std::io::Empty> {
+  reader:     Option<R>,
+  /// The segment that bytes are appended to.
+  segment:    Arc<StreamSegment>,
+  /// The number of bytes written to `segment`.
+  written:    usize,
+  /// The offset of the first byte that has not been released.
+  offset:     usize,
+  chunk_size: usize,
+  complete:   bool,
+}
+
+impl<R: Read> StreamInput<R> {
+  const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+  /// Creates an input that reads its bytes from `reader`.
+  pub fn new(reader: R) -> Self {
+    Self {
+      reader:     Some(reader),
+      segment:    Arc::new(StreamSegment::new(0, 0)),
+      written:    0,
+      offset:     0,
+      chunk_size: Self::DEFAULT_CHUNK_SIZE,
+      complete:   false,
+    }
+  }
+
+  /// Sets the maximum number of bytes read by [StreamInput::read_chunk].
+  pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+    self.chunk_size = chunk_size.max(1);
+    self
+  }
+
+  /// Reads the next chunk of bytes from the reader, returning the number of
+  /// bytes read. The input is marked complete once the reader is exhausted.
+  ///
+  /// Returns `Ok(0)` without reading if the input has no reader.
+  pub fn read_chunk(&mut self) -> std::io::Result<usize> {
+    if self.reader.is_none() || self.complete {
+      return Ok(0);
+    }
+
+    self.reserve(self.chunk_size);
+
+    let Some(reader) = self.reader.as_mut() else { unreachable!() };
+
+    // SAFETY: The bytes after `written` have not been handed out.
+    let buffer = unsafe { self.segment.unwritten(self.written, self.chunk_size) };
+
+    let result = loop {
+      match reader.read(buffer) {
+        Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+        result => break result,
+      }
+    };
+
+    let read = *result.as_ref().unwrap_or(&0);
+    self.written += read;
+
+    if read == 0 && result.is_ok() {
+      self.complete = true;
+    }
+
+    result
+  }
+
+  /// Ensures the segment has room for `additional` bytes, moving the bytes
+  /// that have not been released into a new segment if it does not.
+  fn reserve(&mut self, additional: usize) {
+    if self.written + additional <= self.segment.capacity() {
+      return;
+    }
+
+    let retained = self.segment.slice(self.window());
+    let segment = StreamSegment::new(self.offset, (retained.len() + additional) * 2);
+
+    // SAFETY: The new segment has not been shared yet.
+    unsafe { segment.unwritten(0, retained.len()).copy_from_slice(retained) };
+
+    self.written = retained.len();
+    self.segment = Arc::new(segment);
+  }
+
+  /// The bytes of the segment that have not been released.
+  fn window(&self) -> Range<usize> {
+    self.offset - self.segment.offset..self.written
+  }
+}
+
+impl StreamInput {
+  /// Creates an input without a reader. The host appends bytes with
+  /// [StreamInput::push] and calls [StreamInput::finish] once all bytes have
+  /// been appended.
+  pub fn incremental() -> Self {
+    Self { reader: None, ..Self::new(std::io::empty()) }
+  }
+}
+
+impl<R: Read> StreamInput<R> {
+  /// Appends `bytes` to the end of the input.
+  pub fn push(&mut self, bytes: &[u8]) {
+    debug_assert!(!self.complete, "Bytes appended to a complete input");
+    self.reserve(bytes.len());
+
+    // SAFETY: The bytes after `written` have not been handed out.
+    unsafe { self.segment.unwritten(self.written, bytes.len()).copy_from_slice(bytes) };
+    self.written += bytes.len();
+  }
+
+  /// Marks the input as complete. The end of the input is treated as the end
+  /// of file from this point on.
+  pub fn finish(&mut self) {
+    self.complete = true;
+  }
+}
+
+impl<R: Read> ParserInput for StreamInput<R> {
+  fn len(&self) -> usize {
+    self.segment.offset + self.written
+  }
+
+  fn byte(&self, cursor: usize) -> u8 {
+    match cursor.checked_sub(self.offset) {
+      Some(_) if cursor < self.len() => self.segment.slice(self.window())[cursor - self.offset],
+      _ => 0,
+    }
+  }
+
+  fn bytes(&self) -> &[u8] {
+    self.segment.slice(self.window())
+  }
+
+  /// Returns the bytes that have not been released, without copying them.
+  fn get_owned_ref(&self) -> SharedSymbolBuffer {
+    SharedSymbolBuffer::from_stream(self.segment.clone(), self.window())
+  }
+
+  fn is_complete(&self) -> bool {
+    self.complete
+  }
+
+  fn read_more(&mut self) -> bool {
+    self.reader.is_some() && !self.complete && self.read_chunk().is_ok()
+  }
+
+  fn release(&mut self, offset: usize) {
+    self.offset = offset.min(self.len()).max(self.offset);
+  }
+
+  fn window_start(&self) -> usize {
+    self.offset
+  }
+}
+
+/// A fixed capacity allocation that the bytes of a [StreamInput] are appended
+/// to. Written bytes are never modified, so they can be shared while bytes are
+/// appended after them.
+pub(crate) struct StreamSegment {
+  bytes:  Box<[UnsafeCell<u8>]>,
+  /// The input offset of the first byte of the segment.
+  offset: usize,
+}
+
+// SAFETY: Bytes are only written by the [StreamInput] that owns the segment,
+// before they are shared.
+unsafe impl Sync for StreamSegment {}
+
+impl StreamSegment {
+  fn new(offset: usize, capacity: usize) -> Self {
+    Self { bytes: (0..capacity).map(|_| UnsafeCell::new(0)).collect(), offset }
+  }
+
+  fn capacity(&self) -> usize {
+    self.bytes.len()
+  }
+
+  /// The input offset of the first byte of the segment.
+  pub(crate) fn offset(&self) -> usize {
+    self.offset
+  }
+
+  /// Returns the written bytes within `range`.
+  pub(crate) fn slice(&self, range: Range<usize>) -> &[u8] {
+    let bytes = &self.bytes[range];
+    // SAFETY: `UnsafeCell<u8>` has the same layout as `u8`, and written bytes
+    // are never modified.
+    unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const u8, bytes.len()) }
+  }
+
+  /// Returns `len` bytes starting at `start` for writing.
+  ///
+  /// # Safety
+  /// The bytes must not have been handed out by [StreamSegment::slice].
+  #[allow(clippy::mut_from_ref)]
+  unsafe fn unwritten(&self, start: usize, len: usize) -> &mut [u8] {
+    let bytes = &self.bytes[start..start + len];
+    std::slice::from_raw_parts_mut(UnsafeCell::raw_get(bytes.as_ptr()), len)
+  }
+}
+
+#[test]
+fn stream_input_reads_chunks_and_releases_bytes() {
+  let mut input = StreamInput::new("abcdefghij".as_bytes()).with_chunk_size(4);
+
+  assert_eq!(input.len(), 0);
+  assert!(!input.is_complete());
+
+  assert_eq!(input.read_chunk().unwrap(), 4);
+  assert_eq!(input.string_range(0..4), "abcd");
+
+  assert!(input.read_more());
+  assert_eq!(input.len(), 8);
+
+  input.release(5);
+  assert_eq!(input.window_start(), 5);
+  assert_eq!(input.byte(4), 0);
+  assert_eq!(input.byte(5), b'f');
+  assert_eq!(input.bytes(), b"fgh");
+
+  let window = input.get_owned_ref();
+  assert_eq!(window.offset(), 5);
+
+  while input.read_more() {}
+
+  // Buffers handed out before more bytes were read are not modified.
+  assert_eq!(&window[..], b"fgh");
+
+  assert!(input.is_complete());
+  assert_eq!(input.len(), 10);
+  assert_eq!(input.string_range(5..10), "fghij");
+
+  let mut input = StreamInput::incremental();
+  assert!(!input.read_more());
+  input.push("🙂".as_bytes());
+  input.finish();
+  assert!(input.is_complete());
+  assert_eq!(input.codepoint(0), '🙂' as u32);
+}
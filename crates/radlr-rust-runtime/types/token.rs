@@ -1,4 +1,4 @@
-use super::{Range, TokenRange};
+use super::{Range, SharedSymbolBuffer, TokenRange};
 use std::{
   fmt::{self},
   hash::Hash,
@@ -29,7 +29,7 @@ impl BlameColor {
 #[derive(Clone)]
 pub struct Token {
   pub(crate) inner: TokenRange,
-  pub(crate) input: Option<Arc<[u8]>>,
+  pub(crate) input: Option<SharedSymbolBuffer>,
 }
 
 impl Hash for Token {
@@ -165,9 +165,18 @@ impl Token {
   /// Defines the source string for this token. Certain Token
   /// methods will not work correctly if the Token has not been
   /// attached to its source.
+  #[deprecated(note = "use `Token::set_shared_source`, which also accepts memory mapped sources")]
   #[inline(always)]
   pub fn set_source(&mut self, source: Arc<[u8]>) {
-    self.input = Some(source);
+    self.set_shared_source(source);
+  }
+
+  /// Defines the source string for this token. Certain Token
+  /// methods will not work correctly if the Token has not been
+  /// attached to its source.
+  #[inline(always)]
+  pub fn set_shared_source(&mut self, source: impl Into<SharedSymbolBuffer>) {
+    self.input = Some(source.into());
   }
 
   pub fn trim(&self, trim_head: u32, trim_tail: u32) -> Token {
@@ -408,7 +417,7 @@ impl Token {
   ///   is
   /// not defined.
   pub fn blame(&self, max_pre: usize, max_post: usize, inline_comment: &str, colors: Option<BlameColor>) -> String {
-    fn create_line(source: &Arc<[u8]>, prev_line: i64, next_line: i64, line_number: usize) -> String {
+    fn create_line(source: &[u8], prev_line: i64, next_line: i64, line_number: usize) -> String {
       if let Ok(utf_string) = String::from_utf8(Vec::from(&source[(prev_line + 1) as usize..next_line as usize])) {
         format!("{: >4}: {}\n", line_number, utf_string,)
       } else {
@@ -3,3 +3,5 @@
 mod test_parsing;
 
 mod test_common_grammars;
+
+mod test_package;
@@ -0,0 +1,100 @@
+use radlr_bytecode::compile_bytecode;
+use radlr_core::*;
+use radlr_rust_runtime::{
+  kernel::BytecodeError,
+  parsers::recognizer::Recognizer,
+  types::{bytecode::FIRST_PARSE_BLOCK_ADDRESS, *},
+};
+use std::path::PathBuf;
+
+fn build_bytecode(grammar: &str) -> RadlrResult<BytecodeParserDB> {
+  let root_path = PathBuf::from("/test/package.sg");
+  let pool = radlr_core::worker_pool::SingleThreadPool {};
+  let config = ParserConfig::default();
+
+  let parser_data = RadlrGrammar::new()
+    .add_source_from_string(grammar, &root_path, false)?
+    .build_db(&root_path, config)?
+    .build_states(config, &pool)?
+    .build_ir_parser(true, false, &pool)?;
+
+  compile_bytecode(&parser_data, true)
+}
+
+#[test]
+fn packaged_parser_round_trips_and_is_bound_to_its_grammar() -> RadlrResult<()> {
+  let db = build_bytecode("IGNORE { c:sp } <> list > c:num(+',')")?;
+  let package = db.to_package();
+
+  let loaded = BytecodeParserDB::from_package(&package, Some(db.grammar_hash))?;
+  assert_eq!(loaded.bytecode, db.bytecode);
+  assert_eq!(loaded.rule_diagram, db.rule_diagram);
+
+  let mut parser = <BytecodeParserDB as ParserProducer<StringInput>>::get_parser(&loaded)?;
+  let entry = loaded.default_entrypoint();
+  assert!(parser.recognize(&mut StringInput::from("1, 2, 3"), entry).is_ok());
+  assert!(parser.recognize(&mut StringInput::from("1, , 3"), entry).is_err());
+
+  assert_eq!(db.grammar_hash, build_bytecode("IGNORE { c:sp } <> list > c:num(+',')")?.grammar_hash);
+
+  let other = build_bytecode("IGNORE { c:sp } <> list > c:num(+';')")?;
+  assert_ne!(other.grammar_hash, db.grammar_hash);
+
+  match BytecodeParserDB::from_package(&package, Some(other.grammar_hash)) {
+    Err(PackageError::GrammarMismatch { found, expected }) => {
+      assert_eq!(found, db.grammar_hash);
+      assert_eq!(expected, other.grammar_hash);
+    }
+    result => panic!("Expected a grammar mismatch, got {:?}", result.map(|_| ())),
+  }
+
+  assert_eq!(BytecodeParserDB::from_package(&db.bytecode, None).unwrap_err(), PackageError::InvalidMagic);
+
+  Ok(())
+}
+
+#[test]
+fn compiled_bytecode_passes_verification_and_corruption_is_rejected() -> RadlrResult<()> {
+  let json_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../grammars/json/json.radlr").canonicalize().unwrap();
+  let json = std::fs::read_to_string(&json_path)?;
+
+  for grammar in
+    [json.as_str(), "IGNORE { c:sp } <> list > c:num(+',')", "<> A > \"if\" B | \"in\" B | \"int\" B \n <> B > c:id+"]
+  {
+    let db = build_bytecode(grammar)?;
+    assert_eq!(db.verify(), Ok(()));
+  }
+
+  let db = build_bytecode(&json)?;
+  let entry = db.default_entrypoint();
+  let sample = r#"{ "a": [1, 2.5e3, true, null], "b": { "c": "d" } }"#;
+  let mut rejected = 0;
+
+  // Corrupting any byte must produce either an error or a parser that runs to
+  // completion without panicking.
+  for address in FIRST_PARSE_BLOCK_ADDRESS as usize..db.bytecode.len() {
+    for value in [0x00, 0x7F, 0xFF] {
+      let mut corrupt = db.clone();
+      corrupt.bytecode[address] = value;
+
+      if corrupt.verify().is_err() {
+        rejected += 1;
+      } else {
+        let mut parser = <BytecodeParserDB as ParserProducer<StringInput>>::get_parser(&corrupt)?;
+        let _ = parser.recognize(&mut StringInput::from(sample), entry);
+      }
+    }
+  }
+
+  assert!(rejected > 0);
+
+  let mut corrupt = db.clone();
+  corrupt.bytecode.truncate(corrupt.bytecode.len() - 1);
+
+  match BytecodeParserDB::from_package(&corrupt.to_package(), None) {
+    Err(PackageError::InvalidBytecode(BytecodeError { .. })) => {}
+    result => panic!("Expected invalid bytecode, got {:?}", result.map(|_| ())),
+  }
+
+  Ok(())
+}
@@ -1105,3 +1105,177 @@ fn simple_newline_tracking() -> RadlrResult<()> {
     },
   )
 }
+
+#[test]
+pub fn peg_nonterminal_commits_to_the_first_matching_choice() -> RadlrResult<()> {
+  // Once `"a"` matches the first choice of `A`, the second choice is never
+  // tried, so the `"b"` of the second choice can not be consumed by `A`.
+  compile_and_run_grammars(
+    &[r#"
+    <> S > A "b"
+
+    :> A > "a" | "a" "b"
+  "#],
+    &[("default", "ab", true), ("default", "abb", false)],
+    ParserConfig::default(),
+  )
+}
+
+#[test]
+pub fn peg_nonterminal_reduces_the_earliest_ambiguous_choice() -> RadlrResult<()> {
+  build_parse_states_from_source_str(
+    r##"
+    :> A > X | Y
+
+    <> X > "a" "b"
+
+    <> Y > "a" "b"
+    "##,
+    "".into(),
+    true,
+    &|tp| {
+      let pkg = compile_bytecode(&tp, true)?;
+
+      let TestPackage { db, .. } = tp;
+
+      let mut parser = TestParser::new(Rc::new(pkg.bytecode), pkg.nonterm_id_to_address);
+
+      let mut ctx = parser.init(EntryPoint::default())?;
+
+      let result = parser.parse_ast(
+        &mut StringInput::from("ab"),
+        &mut ctx,
+        &map_reduce_function::<StringInput, u32>(&db, vec![
+          ("A", 0, |_, _| {}),
+          ("A", 1, |_, _| {}),
+          ("X", 0, |_, slots| slots.assign(0, AstSlotNew(1, Default::default(), Default::default()))),
+          ("Y", 0, |_, slots| slots.assign(0, AstSlotNew(2, Default::default(), Default::default()))),
+        ]),
+      );
+
+      assert!(matches!(result, Result::Ok(AstSlotNew(1, ..))), "{:?}", result);
+
+      RadlrResult::Ok(())
+    },
+  )
+}
+
+#[test]
+pub fn peg_nonterminal_dangling_else_requires_forks() {
+  // Lookahead can not tell whether the `else` choice will match, so the
+  // parser can only backtrack to the later choice by forking.
+  let result = compile_and_run_grammars(
+    &[r#"
+    IGNORE { c:sp }
+
+    :> stmt > "if" c:id "then" stmt "else" stmt | "if" c:id "then" stmt | c:id
+  "#],
+    &[("default", "if a then if b then c else d", true)],
+    ParserConfig::default(),
+  );
+
+  assert!(result.is_err());
+}
+
+#[test]
+pub fn peg_nonterminal_choices_are_tried_in_order() -> RadlrResult<()> {
+  // The shorter choice is declared first, so it always wins and the `else`
+  // branch can never be reached.
+  compile_and_run_grammars(
+    &[r#"
+    IGNORE { c:sp }
+
+    :> stmt > "if" c:id "then" stmt | "if" c:id "then" stmt "else" stmt | c:id
+  "#],
+    &[("default", "if a then b", true), ("default", "if a then b else c", false)],
+    ParserConfig::default(),
+  )
+}
+
+#[test]
+pub fn peg_nonterminal_backtracks_with_peek_states() -> RadlrResult<()> {
+  // With peeking, the first choice is only taken if the lookahead shows that
+  // it matches; otherwise the parser falls back to the second choice.
+  compile_and_run_grammars(
+    &[r#"
+    <> S > A "b" "d"
+
+    :> A > "a" "b" "c" | "a"
+  "#],
+    &[("default", "abcbd", true), ("default", "abd", true)],
+    ParserConfig::default().lrk(3),
+  )
+}
+
+#[test]
+pub fn operator_precedence_clauses_resolve_flat_expression_rules() -> RadlrResult<()> {
+  build_parse_states_from_source_str(
+    r##"
+    IGNORE { c:sp }
+
+    LEFT { "+" "-" }
+    LEFT { "*" }
+    RIGHT { "^" }
+
+    <> expr > expr "+" expr | expr "-" expr | expr "*" expr | expr "^" expr | c:num
+    "##,
+    "".into(),
+    true,
+    &|tp| {
+      let pkg = compile_bytecode(&tp, true)?;
+
+      let TestPackage { db, .. } = tp;
+
+      let mut parser = TestParser::new(Rc::new(pkg.bytecode), pkg.nonterm_id_to_address);
+
+      let reducers = map_reduce_function::<StringInput, u32>(&db, vec![
+        ("expr", 0, |_, slots| {
+          let value = slots[0].0 + slots[2].0;
+          slots.assign(0, AstSlotNew(value, Default::default(), Default::default()))
+        }),
+        ("expr", 1, |_, slots| {
+          let value = slots[0].0 - slots[2].0;
+          slots.assign(0, AstSlotNew(value, Default::default(), Default::default()))
+        }),
+        ("expr", 2, |_, slots| {
+          let value = slots[0].0 * slots[2].0;
+          slots.assign(0, AstSlotNew(value, Default::default(), Default::default()))
+        }),
+        ("expr", 3, |_, slots| {
+          let value = slots[0].0.pow(slots[2].0);
+          slots.assign(0, AstSlotNew(value, Default::default(), Default::default()))
+        }),
+        ("expr", 4, |input, slots| {
+          let value = slots[0].1.to_string_from_input(input).parse().unwrap();
+          slots.assign(0, AstSlotNew(value, Default::default(), Default::default()))
+        }),
+      ]);
+
+      for (input, expected) in [("1 + 2 * 3 + 4", 11), ("9 - 3 - 2", 4), ("2 ^ 3 ^ 2", 512), ("2 * 3 ^ 2 - 1", 17)] {
+        let mut ctx = parser.init(EntryPoint::default())?;
+
+        let result = parser.parse_ast(&mut StringInput::from(input), &mut ctx, &reducers);
+
+        assert!(matches!(result, Result::Ok(AstSlotNew(value, ..)) if value == expected), "{input}: {result:?}");
+      }
+
+      RadlrResult::Ok(())
+    },
+  )
+}
+
+#[test]
+pub fn nonassoc_operators_can_not_be_chained() -> RadlrResult<()> {
+  compile_and_run_grammars(
+    &[r#"
+    IGNORE { c:sp }
+
+    NONASSOC { "<" }
+    LEFT { "+" }
+
+    <> expr > expr "<" expr | expr "+" expr | c:num
+  "#],
+    &[("default", "1 < 2 + 3", true), ("default", "1 + 2 < 3", true), ("default", "1 < 2 < 3", false)],
+    ParserConfig::default(),
+  )
+}
@@ -10,6 +10,15 @@ mod test_error_recovery;
 #[cfg(test)]
 mod test_cst;
 
+#[cfg(test)]
+mod test_streaming;
+
+#[cfg(test)]
+mod test_mmap_input;
+
+#[cfg(test)]
+mod test_decoded_input;
+
 #[cfg(test)]
 mod bytecode;
 
@@ -0,0 +1,20 @@
+use crate::utils::{assert_same_tokens_as_buffered, build_list_parser};
+use radlr_core::*;
+use radlr_rust_runtime::types::*;
+
+#[test]
+fn decoded_input_produces_the_same_tokens_as_buffered_input() -> RadlrResult<()> {
+  let (mut parser, entry) = build_list_parser()?;
+  let input = "[ 123, abc,\n héllo, 4567890 , xyz ]";
+
+  let units: Vec<u16> = input.encode_utf16().collect();
+  assert_same_tokens_as_buffered(&mut parser, entry, input, &mut DecodedInput::from_utf16(&units))?;
+
+  let bytes: Vec<u8> = input.encode_utf16().flat_map(u16::to_be_bytes).collect();
+  assert_same_tokens_as_buffered(&mut parser, entry, input, &mut DecodedInput::new(&bytes, SourceEncoding::Utf16Be))?;
+
+  let bytes: Vec<u8> = input.chars().map(|c| c as u8).collect();
+  assert_same_tokens_as_buffered(&mut parser, entry, input, &mut DecodedInput::new(&bytes, SourceEncoding::Latin1))?;
+
+  Ok(())
+}
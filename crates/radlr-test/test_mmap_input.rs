@@ -0,0 +1,30 @@
+use crate::utils::{assert_same_tokens_as_buffered, build_list_parser};
+use radlr_core::*;
+use radlr_rust_runtime::{parsers::token::TokenProducer, types::*};
+
+#[test]
+fn mapped_input_produces_the_same_tokens_as_buffered_input() -> RadlrResult<()> {
+  let (mut parser, entry) = build_list_parser()?;
+  let input = "[ 123, abc,\n héllo, 4567890 , xyz ]";
+
+  let path = std::env::temp_dir().join(format!("radlr_mapped_input_{}.txt", std::process::id()));
+  std::fs::write(&path, input).unwrap();
+
+  let mut mapped = unsafe { MmapInput::open(&path).unwrap() };
+  assert_same_tokens_as_buffered(&mut parser, entry, input, &mut mapped)?;
+  drop(mapped);
+
+  std::fs::write(&path, "[ 123, ,abc ]").unwrap();
+
+  let mut mapped = unsafe { MmapInput::open(&path).unwrap() };
+  match parser.collect_shifts_and_skips(&mut mapped, entry) {
+    Err(ParserError::InputError { loc, .. }) => {
+      assert_eq!(loc.to_string(), ",");
+    }
+    result => panic!("Expected an input error, got {result:?}"),
+  }
+
+  std::fs::remove_file(path).unwrap();
+
+  Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::utils::{assert_same_tokens_as_buffered, build_list_package, build_list_parser};
+use radlr_core::*;
+use radlr_rust_runtime::{
+  parsers::{
+    ast::{AstDatabase, Reducer},
+    recognizer::Recognizer,
+  },
+  types::*,
+};
+
+#[test]
+fn streamed_input_produces_the_same_tokens_as_buffered_input() -> RadlrResult<()> {
+  let (mut parser, entry) = build_list_parser()?;
+  let input = "[ 123, abc,\n héllo, 4567890 , xyz ]";
+
+  for chunk_size in [1, 2, 3, 7, 64] {
+    let mut stream = StreamInput::new(input.as_bytes()).with_chunk_size(chunk_size);
+    assert_same_tokens_as_buffered(&mut parser, entry, input, &mut stream)?;
+
+    let mut stream = StreamInput::new(input.as_bytes()).with_chunk_size(chunk_size);
+    parser.recognize(&mut stream, entry)?;
+
+    let mut stream = StreamInput::new("[ 123, abc ] ]".as_bytes()).with_chunk_size(chunk_size);
+    assert!(parser.recognize(&mut stream, entry).is_err());
+
+    let mut stream = StreamInput::new("[ 123, ,abc ]".as_bytes()).with_chunk_size(chunk_size);
+    assert!(parser.recognize(&mut stream, entry).is_err());
+  }
+
+  Ok(())
+}
+
+#[test]
+fn incremental_input_yields_need_more_input() -> RadlrResult<()> {
+  let (mut parser, entry) = build_list_parser()?;
+  let input = "[ 12, ab, é ]".as_bytes();
+
+  let mut stream = StreamInput::incremental();
+  let mut ctx = parser.init(entry)?;
+  let mut fed = 0;
+  let mut requests = 0;
+  let mut shifts = vec![];
+
+  loop {
+    match parser.next(&mut stream, &mut ctx) {
+      Some(ParseAction::NeedMoreInput) => {
+        requests += 1;
+        if fed < input.len() {
+          stream.push(&input[fed..fed + 1]);
+          fed += 1;
+        } else {
+          stream.finish();
+        }
+      }
+      Some(ParseAction::Shift { byte_offset, byte_length, .. }) => {
+        shifts.push(stream.string_range(byte_offset as usize..(byte_offset + byte_length) as usize));
+      }
+      Some(ParseAction::Accept { final_offset, .. }) => {
+        assert_eq!(final_offset, input.len());
+        break;
+      }
+      Some(ParseAction::Error { .. }) | None => panic!("Failed to parse incremental input"),
+      _ => {}
+    }
+  }
+
+  assert!(requests >= input.len());
+  assert_eq!(shifts, ["[", "12", ",", "ab", ",", "é", "]"]);
+
+  Ok(())
+}
+
+#[test]
+fn streamed_input_releases_consumed_bytes() -> RadlrResult<()> {
+  let (mut parser, entry) = build_list_parser()?;
+  let input = "[".to_string() + &vec!["item"; 2000].join(", ") + "]";
+
+  let mut stream = StreamInput::new(input.as_bytes()).with_chunk_size(16);
+  parser.recognize(&mut stream, entry)?;
+
+  assert!(stream.window_start() > input.len() - 64);
+  assert!(stream.bytes().len() < 64);
+
+  Ok(())
+}
+
+/// Collects the text of the tokens reduced into a non-terminal.
+fn collect_tokens(nodes: *mut [Vec<String>], tokens: &[Token], _: Token) -> Vec<String> {
+  let nodes = unsafe { &mut *nodes };
+  nodes.iter_mut().zip(tokens).flat_map(|(node, tok)| if node.is_empty() { vec![tok.to_string()] } else { std::mem::take(node) }).collect()
+}
+
+#[test]
+fn streamed_input_builds_the_same_ast_as_buffered_input() -> RadlrResult<()> {
+  let (pkg, rule_count) = build_list_package()?;
+  let entry = pkg.default_entrypoint();
+  let rules = vec![collect_tokens as Reducer<Token, Vec<String>>; rule_count];
+
+  let input = "[ 123, abc,\n héllo, 4567890 , xyz ]";
+
+  let expected = pkg.build_ast(&mut StringInput::from(input), entry, &rules)?;
+  assert_eq!(expected, ["[", "123", ",", "abc", ",", "héllo", ",", "4567890", ",", "xyz", "]"]);
+
+  for chunk_size in [1, 2, 3, 7, 64] {
+    let mut stream = StreamInput::new(input.as_bytes()).with_chunk_size(chunk_size);
+    assert_eq!(pkg.build_ast(&mut stream, entry, &rules)?, expected, "chunk size {chunk_size}");
+
+    let mut stream = StreamInput::new("[ 123, abc ] ]".as_bytes()).with_chunk_size(chunk_size);
+    assert!(pkg.build_ast(&mut stream, entry, &rules).is_err());
+  }
+
+  let mut stream = StreamInput::incremental();
+  stream.push(&input.as_bytes()[..9]);
+  assert_eq!(pkg.build_ast(&mut stream, entry, &rules), Err(ParserError::NoData));
+
+  Ok(())
+}
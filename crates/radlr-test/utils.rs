@@ -214,3 +214,59 @@ where
     .into_values()
     .collect::<Vec<_>>()
 }
+
+/// A grammar of comma separated lists, parsed by the tests of the
+/// [ParserInput] implementations.
+#[cfg(test)]
+pub const LIST_GRAMMAR: &str = r#"
+IGNORE { c:sp c:nl }
+
+<> list > '[' item(*',') ']'
+
+<> item > tk:( c:num+ ) | tk:( c:id+ )
+"#;
+
+/// Compiles [LIST_GRAMMAR] into a bytecode package, returning the package and
+/// the number of rules in the grammar.
+#[cfg(test)]
+pub fn build_list_package() -> RadlrResult<(BytecodeParserDB, usize)> {
+  let root_path = PathBuf::from("/test/list.sg");
+  let pool = worker_pool::SingleThreadPool {};
+  let config = ParserConfig::default();
+
+  let parser_data = RadlrGrammar::new()
+    .add_source_from_string(LIST_GRAMMAR, &root_path, false)?
+    .build_db(&root_path, config)?
+    .build_states(config, &pool)?
+    .build_ir_parser(true, false, &pool)?;
+
+  Ok((compile_bytecode(&parser_data, false)?, parser_data.get_db().rules().len()))
+}
+
+/// Compiles [LIST_GRAMMAR] into a bytecode parser.
+#[cfg(test)]
+pub fn build_list_parser() -> RadlrResult<(ByteCodeParserNew, radlr_rust_runtime::types::EntryPoint)> {
+  let (pkg, _) = build_list_package()?;
+  let entry = pkg.default_entrypoint();
+  Ok((ByteCodeParserNew::new(std::rc::Rc::new(pkg.bytecode), pkg.nonterm_id_to_address), entry))
+}
+
+/// Asserts that parsing `input` produces the same shifted and skipped tokens
+/// as parsing `text` from a [StringInput].
+#[cfg(test)]
+pub fn assert_same_tokens_as_buffered<I: ParserInput>(
+  parser: &mut ByteCodeParserNew,
+  entry: radlr_rust_runtime::types::EntryPoint,
+  text: &str,
+  input: &mut I,
+) -> RadlrResult<()> {
+  use radlr_rust_runtime::parsers::token::TokenProducer;
+
+  let expected = TokenProducer::<StringInput>::collect_shifts_and_skips(parser, &mut StringInput::from(text), entry)?;
+  let tokens = parser.collect_shifts_and_skips(input, entry)?;
+
+  assert_eq!(tokens.shifts, expected.shifts);
+  assert_eq!(tokens.skips, expected.skips);
+
+  Ok(())
+}
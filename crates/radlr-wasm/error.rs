@@ -92,17 +92,24 @@ impl PositionedErrors {
 
 fn convert_error(err: &RadlrError, origin: ErrorOrigin) -> Vec<JSRadlrSourceError> {
   match err {
-    RadlrError::SourcesError { sources, msg: base_message, .. } => sources
+    RadlrError::SourcesError { sources, msg: base_message, ps_msg, .. } => sources
       .iter()
       .map(|(loc, _, msg)| {
         let range = loc.get_range();
+        let mut message = base_message.clone() + ":\n " + msg;
+        // The post-script holds details, such as conflict counterexamples,
+        // that apply to every source of the error.
+        if !ps_msg.is_empty() {
+          message += "\n\n";
+          message += ps_msg;
+        }
         JSRadlrSourceError {
           col: range.start_column,
           line: range.start_line,
           len: loc.len() as u32,
           start_offset: loc.get_start() as u32,
           end_offset: loc.get_end() as u32,
-          message: base_message.clone() + ":\n " + msg,
+          message,
           origin,
         }
       })
@@ -2,7 +2,9 @@ use radlr_rust_runtime::{
   parsers::ast::{AstDatabase, Tk},
   types::{RuntimeDatabase, SharedSymbolBuffer, StringInput},
 };
-mod parser;
+mod parser {
+  radlr_rust_runtime::include_parser!();
+}
 use crate::parser::ASTNode;
 
 fn main() {